@@ -5077,6 +5077,7 @@ webidl.converters["GPUFeatureName"] = webidl.createEnumConverter(
     "rg11b10ufloat-renderable",
     "bgra8unorm-storage",
     "float32-filterable",
+    "clip-distances",
 
     // extended from spec
 
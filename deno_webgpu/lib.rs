@@ -235,6 +235,9 @@ fn deserialize_features(features: &wgpu_types::Features) -> Vec<&'static str> {
     if features.contains(wgpu_types::Features::FLOAT32_FILTERABLE) {
         return_features.push("float32-filterable");
     }
+    if features.contains(wgpu_types::Features::CLIP_DISTANCES) {
+        return_features.push("clip-distances");
+    }
 
     // extended from spec
 
@@ -486,6 +489,10 @@ impl From<GpuRequiredFeatures> for wgpu_types::Features {
             wgpu_types::Features::FLOAT32_FILTERABLE,
             required_features.0.contains("float32-filterable"),
         );
+        features.set(
+            wgpu_types::Features::CLIP_DISTANCES,
+            required_features.0.contains("clip-distances"),
+        );
 
         // extended from spec
 
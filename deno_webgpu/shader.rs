@@ -43,6 +43,8 @@ pub fn op_webgpu_create_shader_module(
     let descriptor = wgpu_core::pipeline::ShaderModuleDescriptor {
         label: Some(label),
         shader_bound_checks: wgpu_types::ShaderBoundChecks::default(),
+        non_semantic_debug_info: false,
+        optimize: false,
     };
 
     gfx_put!(instance.device_create_shader_module(
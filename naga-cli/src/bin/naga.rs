@@ -62,7 +62,7 @@ struct Args {
     #[argh(option)]
     shader_stage: Option<ShaderStage>,
 
-    /// the kind of input, e.g. 'glsl', 'wgsl', 'spv', or 'bin'.
+    /// the kind of input, e.g. 'glsl', 'wgsl', 'spv', 'ir', or 'bin'.
     #[argh(option)]
     input_kind: Option<InputKind>,
 
@@ -101,6 +101,14 @@ struct Args {
     #[argh(option)]
     before_compaction: Option<String>,
 
+    /// run naga's optimization passes over the module's IR and revalidate.
+    ///
+    /// This inlines functions, propagates constants, eliminates common
+    /// subexpressions and dead stores, and then compacts the module. Output
+    /// files will reflect the optimized IR.
+    #[argh(switch)]
+    optimize: bool,
+
     /// bulk validation mode: all filenames are inputs to read and validate.
     #[argh(switch)]
     bulk_validate: bool,
@@ -201,6 +209,7 @@ impl FromStr for ShaderStage {
 enum InputKind {
     Bincode,
     Glsl,
+    IrText,
     SpirV,
     Wgsl,
 }
@@ -211,6 +220,7 @@ impl FromStr for InputKind {
         Ok(match s.to_lowercase().as_str() {
             "bin" => InputKind::Bincode,
             "glsl" => InputKind::Glsl,
+            "ir" => InputKind::IrText,
             "spv" => InputKind::SpirV,
             "wgsl" => InputKind::Wgsl,
             _ => return Err(anyhow!("Invalid value for --input-kind: {s}")),
@@ -524,6 +534,39 @@ fn run() -> anyhow::Result<()> {
         }
     };
 
+    // Optimize the module, if requested.
+    let info = if args.optimize {
+        // Optimize only if validation succeeded. Otherwise, the passes may panic.
+        if info.is_some() {
+            naga::opt::optimize(&mut module, naga::opt::Passes::all());
+
+            // Re-validate the IR after optimization.
+            match naga::valid::Validator::new(params.validation_flags, validation_caps)
+                .subgroup_stages(naga::valid::ShaderStages::all())
+                .subgroup_operations(naga::valid::SubgroupOperationSet::all())
+                .validate(&module)
+            {
+                Ok(info) => Some(info),
+                Err(error) => {
+                    // Validation failure is not fatal. Just report the error.
+                    eprintln!("Error validating optimized module:");
+                    if let Some(input) = &input_text {
+                        let filename = input_path.file_name().and_then(std::ffi::OsStr::to_str);
+                        error.emit_to_stderr_with_path(input, filename.unwrap_or("input"));
+                    } else {
+                        print_err(&error);
+                    }
+                    None
+                }
+            }
+        } else {
+            eprintln!("Skipping optimization due to validation failure.");
+            None
+        }
+    } else {
+        info
+    };
+
     // Compact the module, if requested.
     let info = if args.compact || args.before_compaction.is_some() {
         // Compact only if validation succeeded. Otherwise, compaction may panic.
@@ -627,6 +670,23 @@ fn parse_input(input_path: &Path, input: Vec<u8>, params: &Parameters) -> anyhow
                 }
             }
         }
+        InputKind::IrText => {
+            let input = String::from_utf8(input)?;
+            match naga::front::ir_text::parse_str(&input) {
+                Ok(module) => Parsed {
+                    module,
+                    input_text: Some(input),
+                    language: naga::back::spv::SourceLanguage::Unknown,
+                },
+                Err(ref e) => {
+                    let message = anyhow!(
+                        "Could not parse IR:\n{}",
+                        e.emit_to_string_with_path(&input, input_path)
+                    );
+                    return Err(message);
+                }
+            }
+        }
         InputKind::Glsl => {
             let shader_stage = match params.shader_stage {
                 Some(shader_stage) => shader_stage,
@@ -693,6 +753,10 @@ fn write_output(
                 writeln!(file, "{info:#?}")?;
             }
         }
+        "ir" => {
+            let text = naga::back::ir_text::write_string(module)?;
+            fs::write(output_path, text)?;
+        }
         "bin" => {
             let file = fs::File::create(output_path)?;
             bincode::serialize_into(file, module)?;
@@ -708,9 +772,13 @@ fn write_output(
                  succeed, and it failed in a previous step",
             ))?;
 
-            let (module, info) =
-                naga::back::pipeline_constants::process_overrides(module, info, &params.overrides)
-                    .unwrap_pretty();
+            let (module, info) = naga::back::pipeline_constants::process_overrides(
+                module,
+                info,
+                None,
+                &params.overrides,
+            )
+            .unwrap_pretty();
 
             let pipeline_options = msl::PipelineOptions::default();
             let (msl, _) =
@@ -742,8 +810,15 @@ fn write_output(
                  succeed, and it failed in a previous step",
             ))?;
 
+            let (module, info) = naga::back::pipeline_constants::process_overrides(
+                module,
+                info,
+                None,
+                &params.overrides,
+            )
+            .unwrap_pretty();
             let (module, info) =
-                naga::back::pipeline_constants::process_overrides(module, info, &params.overrides)
+                naga::back::pointer_arguments::specialize_pointer_arguments(&module, &info)
                     .unwrap_pretty();
 
             let spv =
@@ -779,8 +854,15 @@ fn write_output(
                  succeed, and it failed in a previous step",
             ))?;
 
+            let (module, info) = naga::back::pipeline_constants::process_overrides(
+                module,
+                info,
+                None,
+                &params.overrides,
+            )
+            .unwrap_pretty();
             let (module, info) =
-                naga::back::pipeline_constants::process_overrides(module, info, &params.overrides)
+                naga::back::pointer_arguments::specialize_pointer_arguments(&module, &info)
                     .unwrap_pretty();
 
             let mut buffer = String::new();
@@ -802,6 +884,19 @@ fn write_output(
             let output = dot::write(module, info.as_ref(), params.dot.clone())?;
             fs::write(output_path, output)?;
         }
+        "json" => {
+            use std::io::Write;
+
+            let info = info.as_ref().ok_or(CliError(
+                "Generating reflection output requires validation to \
+                 succeed, and it failed in a previous step",
+            ))?;
+
+            let reflection = naga::reflect::reflect(module, info);
+            let mut file = fs::File::create(output_path)?;
+            serde_json::to_writer_pretty(&mut file, &reflection)?;
+            writeln!(file)?;
+        }
         "hlsl" => {
             use naga::back::hlsl;
 
@@ -810,8 +905,15 @@ fn write_output(
                  succeed, and it failed in a previous step",
             ))?;
 
+            let (module, info) = naga::back::pipeline_constants::process_overrides(
+                module,
+                info,
+                None,
+                &params.overrides,
+            )
+            .unwrap_pretty();
             let (module, info) =
-                naga::back::pipeline_constants::process_overrides(module, info, &params.overrides)
+                naga::back::pointer_arguments::specialize_pointer_arguments(&module, &info)
                     .unwrap_pretty();
 
             let mut buffer = String::new();
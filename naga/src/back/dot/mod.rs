@@ -89,6 +89,7 @@ impl StatementGraph {
                     "Emit"
                 }
                 S::Kill => "Kill", //TODO: link to the beginning
+                S::Demote => "Demote",
                 S::Break => {
                     // Try to link to the break target, otherwise produce
                     // a broken connection
@@ -340,6 +341,34 @@ impl StatementGraph {
                             crate::CollectiveOperation::InclusiveScan,
                             crate::SubgroupOperation::Mul,
                         ) => "SubgroupInclusiveMul",
+                        (
+                            crate::CollectiveOperation::ClusteredReduce(_),
+                            crate::SubgroupOperation::Add,
+                        ) => "SubgroupClusteredAdd",
+                        (
+                            crate::CollectiveOperation::ClusteredReduce(_),
+                            crate::SubgroupOperation::Mul,
+                        ) => "SubgroupClusteredMul",
+                        (
+                            crate::CollectiveOperation::ClusteredReduce(_),
+                            crate::SubgroupOperation::Max,
+                        ) => "SubgroupClusteredMax",
+                        (
+                            crate::CollectiveOperation::ClusteredReduce(_),
+                            crate::SubgroupOperation::Min,
+                        ) => "SubgroupClusteredMin",
+                        (
+                            crate::CollectiveOperation::ClusteredReduce(_),
+                            crate::SubgroupOperation::And,
+                        ) => "SubgroupClusteredAnd",
+                        (
+                            crate::CollectiveOperation::ClusteredReduce(_),
+                            crate::SubgroupOperation::Or,
+                        ) => "SubgroupClusteredOr",
+                        (
+                            crate::CollectiveOperation::ClusteredReduce(_),
+                            crate::SubgroupOperation::Xor,
+                        ) => "SubgroupClusteredXor",
                         _ => unimplemented!(),
                     }
                 }
@@ -349,12 +378,13 @@ impl StatementGraph {
                     result,
                 } => {
                     match mode {
-                        crate::GatherMode::BroadcastFirst => {}
+                        crate::GatherMode::BroadcastFirst | crate::GatherMode::QuadSwap(_) => {}
                         crate::GatherMode::Broadcast(index)
                         | crate::GatherMode::Shuffle(index)
                         | crate::GatherMode::ShuffleDown(index)
                         | crate::GatherMode::ShuffleUp(index)
-                        | crate::GatherMode::ShuffleXor(index) => {
+                        | crate::GatherMode::ShuffleXor(index)
+                        | crate::GatherMode::QuadBroadcast(index) => {
                             self.dependencies.push((id, index, "index"))
                         }
                     }
@@ -367,6 +397,12 @@ impl StatementGraph {
                         crate::GatherMode::ShuffleDown(_) => "SubgroupShuffleDown",
                         crate::GatherMode::ShuffleUp(_) => "SubgroupShuffleUp",
                         crate::GatherMode::ShuffleXor(_) => "SubgroupShuffleXor",
+                        crate::GatherMode::QuadBroadcast(_) => "SubgroupQuadBroadcast",
+                        crate::GatherMode::QuadSwap(crate::Direction::X) => "SubgroupQuadSwapX",
+                        crate::GatherMode::QuadSwap(crate::Direction::Y) => "SubgroupQuadSwapY",
+                        crate::GatherMode::QuadSwap(crate::Direction::Diagonal) => {
+                            "SubgroupQuadSwapDiagonal"
+                        }
                     }
                 }
             };
@@ -52,6 +52,12 @@ bitflags::bitflags! {
         const TEXTURE_SHADOW_LOD = 1 << 23;
         /// Subgroup operations
         const SUBGROUP_OPERATIONS = 1 << 24;
+        /// 2 byte floats.
+        const HALF_FLOAT_TYPE = 1 << 25;
+        /// Quad subgroup operations
+        const SUBGROUP_QUAD = 1 << 26;
+        /// Clustered subgroup operations
+        const SUBGROUP_CLUSTERED = 1 << 27;
     }
 }
 
@@ -120,6 +126,8 @@ impl FeaturesManager {
         check_feature!(DYNAMIC_ARRAY_SIZE, 430, 310);
         check_feature!(DUAL_SOURCE_BLENDING, 330, 300 /* with extension */);
         check_feature!(SUBGROUP_OPERATIONS, 430, 310);
+        check_feature!(SUBGROUP_QUAD, 430, 310);
+        check_feature!(SUBGROUP_CLUSTERED, 430, 310);
         match version {
             Version::Embedded { is_webgl: true, .. } => check_feature!(MULTI_VIEW, 140, 300),
             _ => check_feature!(MULTI_VIEW, 140, 310),
@@ -164,6 +172,14 @@ impl FeaturesManager {
             writeln!(out, "#extension GL_ARB_gpu_shader_fp64 : require")?;
         }
 
+        if self.0.contains(Features::HALF_FLOAT_TYPE) {
+            // https://github.com/KhronosGroup/GLSL/blob/main/extensions/ext/GL_EXT_shader_explicit_arithmetic_types.txt
+            writeln!(
+                out,
+                "#extension GL_EXT_shader_explicit_arithmetic_types_float16 : require"
+            )?;
+        }
+
         if self.0.contains(Features::CUBE_TEXTURES_ARRAY) {
             if options.version.is_es() {
                 // https://www.khronos.org/registry/OpenGL/extensions/EXT/EXT_texture_cube_map_array.txt
@@ -278,6 +294,14 @@ impl FeaturesManager {
             )?;
         }
 
+        if self.0.contains(Features::SUBGROUP_QUAD) {
+            writeln!(out, "#extension GL_KHR_shader_subgroup_quad : require")?;
+        }
+
+        if self.0.contains(Features::SUBGROUP_CLUSTERED) {
+            writeln!(out, "#extension GL_KHR_shader_subgroup_clustered : require")?;
+        }
+
         Ok(())
     }
 }
@@ -546,13 +570,26 @@ impl<'a, W> Writer<'a, W> {
             }
         }
 
+        for body in module
+            .functions
+            .iter()
+            .map(|(_, f)| &f.body)
+            .chain(std::iter::once(&entry_point.function.body))
+        {
+            subgroup_required_features(body, features);
+        }
+
         self.features.check_availability(self.options.version)
     }
 
     /// Helper method that checks the [`Features`] needed by a scalar
     fn scalar_required_features(&mut self, scalar: Scalar) {
-        if scalar.kind == ScalarKind::Float && scalar.width == 8 {
-            self.features.request(Features::DOUBLE_TYPE);
+        if scalar.kind == ScalarKind::Float {
+            match scalar.width {
+                8 => self.features.request(Features::DOUBLE_TYPE),
+                2 => self.features.request(Features::HALF_FLOAT_TYPE),
+                _ => {}
+            }
         }
     }
 
@@ -579,7 +616,7 @@ impl<'a, W> Writer<'a, W> {
                     location: _,
                     interpolation,
                     sampling,
-                    second_blend_source,
+                    blend_src,
                 } => {
                     if interpolation == Some(Interpolation::Linear) {
                         self.features.request(Features::NOPERSPECTIVE_QUALIFIER);
@@ -587,7 +624,7 @@ impl<'a, W> Writer<'a, W> {
                     if sampling == Some(Sampling::Sample) {
                         self.features.request(Features::SAMPLE_QUALIFIER);
                     }
-                    if second_blend_source {
+                    if blend_src.is_some() {
                         self.features.request(Features::DUAL_SOURCE_BLENDING);
                     }
                 }
@@ -595,3 +632,43 @@ impl<'a, W> Writer<'a, W> {
         }
     }
 }
+
+/// Helper function that checks the [`Features`] needed by the subgroup
+/// statements in `block`, since their results don't tell the operations apart.
+fn subgroup_required_features(block: &crate::Block, features: &mut FeaturesManager) {
+    for statement in block.iter() {
+        match *statement {
+            crate::Statement::Block(ref block) => subgroup_required_features(block, features),
+            crate::Statement::If {
+                ref accept,
+                ref reject,
+                ..
+            } => {
+                subgroup_required_features(accept, features);
+                subgroup_required_features(reject, features);
+            }
+            crate::Statement::Switch { ref cases, .. } => {
+                for case in cases {
+                    subgroup_required_features(&case.body, features);
+                }
+            }
+            crate::Statement::Loop {
+                ref body,
+                ref continuing,
+                ..
+            } => {
+                subgroup_required_features(body, features);
+                subgroup_required_features(continuing, features);
+            }
+            crate::Statement::SubgroupGather {
+                mode: crate::GatherMode::QuadBroadcast(_) | crate::GatherMode::QuadSwap(_),
+                ..
+            } => features.request(Features::SUBGROUP_QUAD),
+            crate::Statement::SubgroupCollectiveOperation {
+                collective_op: crate::CollectiveOperation::ClusteredReduce(_),
+                ..
+            } => features.request(Features::SUBGROUP_CLUSTERED),
+            _ => {}
+        }
+    }
+}
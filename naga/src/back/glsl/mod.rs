@@ -427,8 +427,7 @@ impl fmt::Display for VaryingName<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self.binding {
             crate::Binding::Location {
-                second_blend_source: true,
-                ..
+                blend_src: Some(1), ..
             } => {
                 write!(f, "_fs2p_location1",)
             }
@@ -498,6 +497,8 @@ pub enum Error {
     Custom(String),
     #[error("overrides should not be present at this stage")]
     Override,
+    #[error("functions taking pointers to global variables must be specialized with `back::pointer_arguments::specialize_pointer_arguments` first")]
+    GlobalPointerArgument,
     /// [`crate::Sampling::First`] is unsupported.
     #[error("`{:?}` sampling is unsupported", crate::Sampling::First)]
     FirstSamplingNotSupported,
@@ -566,6 +567,10 @@ impl<'a, W: Write> Writer<'a, W> {
     /// - If the version specified is invalid or supported.
     /// - If the entry point couldn't be found in the module.
     /// - If the version specified doesn't support some used features.
+    /// - If a function takes a pointer to a global variable as an argument.
+    ///   Such functions must be specialized with
+    ///   [`specialize_pointer_arguments`](super::pointer_arguments::specialize_pointer_arguments)
+    ///   first.
     pub fn new(
         out: W,
         module: &'a crate::Module,
@@ -577,6 +582,9 @@ impl<'a, W: Write> Writer<'a, W> {
         if !module.overrides.is_empty() {
             return Err(Error::Override);
         }
+        if super::pointer_arguments::has_global_pointer_arguments(module) {
+            return Err(Error::GlobalPointerArgument);
+        }
 
         // Check if the requested version is supported
         if !options.version.is_supported() {
@@ -748,15 +756,16 @@ impl<'a, W: Write> Writer<'a, W> {
             match type_key {
                 &crate::PredeclaredType::ModfResult { size, width }
                 | &crate::PredeclaredType::FrexpResult { size, width } => {
+                    let scalar = glsl_scalar(crate::Scalar {
+                        kind: crate::ScalarKind::Float,
+                        width,
+                    })?;
                     let arg_type_name_owner;
                     let arg_type_name = if let Some(size) = size {
-                        arg_type_name_owner =
-                            format!("{}vec{}", if width == 8 { "d" } else { "" }, size as u8);
+                        arg_type_name_owner = format!("{}vec{}", scalar.prefix, size as u8);
                         &arg_type_name_owner
-                    } else if width == 8 {
-                        "double"
                     } else {
-                        "float"
+                        scalar.full
                     };
 
                     let other_type_name_owner;
@@ -1329,8 +1338,14 @@ impl<'a, W: Write> Writer<'a, W> {
                             self.need_bake_expressions.insert(arg1.unwrap());
                         }
                     }
+                    crate::MathFunction::Dot4I8Packed | crate::MathFunction::Dot4U8Packed => {
+                        self.need_bake_expressions.insert(arg);
+                        self.need_bake_expressions.insert(arg1.unwrap());
+                    }
                     crate::MathFunction::Pack4xI8
                     | crate::MathFunction::Pack4xU8
+                    | crate::MathFunction::Pack4xI8Clamp
+                    | crate::MathFunction::Pack4xU8Clamp
                     | crate::MathFunction::Unpack4xI8
                     | crate::MathFunction::Unpack4xU8 => {
                         self.need_bake_expressions.insert(arg);
@@ -1458,13 +1473,13 @@ impl<'a, W: Write> Writer<'a, W> {
             Some(binding) => binding,
         };
 
-        let (location, interpolation, sampling, second_blend_source) = match *binding {
+        let (location, interpolation, sampling, blend_src) = match *binding {
             crate::Binding::Location {
                 location,
                 interpolation,
                 sampling,
-                second_blend_source,
-            } => (location, interpolation, sampling, second_blend_source),
+                blend_src,
+            } => (location, interpolation, sampling, blend_src),
             crate::Binding::BuiltIn(built_in) => {
                 if let crate::BuiltIn::Position { invariant: true } = built_in {
                     match (self.options.version, self.entry_point.stage) {
@@ -1492,6 +1507,22 @@ impl<'a, W: Write> Writer<'a, W> {
                         }
                     }
                 }
+                if built_in == crate::BuiltIn::ClipDistance && output {
+                    // `gl_ClipDistance` is implicitly sized, and must be redeclared
+                    // with an explicit size before it can be assigned as a whole.
+                    if let TypeInner::Array { base, size, .. } = self.module.types[ty].inner {
+                        write!(
+                            self.out,
+                            "out float {}",
+                            glsl_built_in(
+                                built_in,
+                                VaryingOptions::from_writer_options(self.options, output)
+                            )
+                        )?;
+                        self.write_array_size(base, size)?;
+                        writeln!(self.out, ";")?;
+                    }
+                }
                 return Ok(());
             }
         };
@@ -1511,8 +1542,11 @@ impl<'a, W: Write> Writer<'a, W> {
             || !emit_interpolation_and_auxiliary
         {
             if self.options.version.supports_io_locations() {
-                if second_blend_source {
-                    write!(self.out, "layout(location = {location}, index = 1) ")?;
+                if let Some(blend_src) = blend_src {
+                    write!(
+                        self.out,
+                        "layout(location = {location}, index = {blend_src}) "
+                    )?;
                 } else {
                     write!(self.out, "layout(location = {location}) ")?;
                 }
@@ -1520,7 +1554,7 @@ impl<'a, W: Write> Writer<'a, W> {
             } else {
                 Some(VaryingLocation {
                     location,
-                    index: second_blend_source as u32,
+                    index: blend_src.unwrap_or(0),
                 })
             }
         } else {
@@ -1561,7 +1595,7 @@ impl<'a, W: Write> Writer<'a, W> {
                 location,
                 interpolation: None,
                 sampling: None,
-                second_blend_source,
+                blend_src,
             },
             stage: self.entry_point.stage,
             options: VaryingOptions::from_writer_options(self.options, output),
@@ -2365,7 +2399,10 @@ impl<'a, W: Write> Writer<'a, W> {
             // This is one of the places were glsl adds to the syntax of C in this case the discard
             // keyword which ceases all further processing in a fragment shader, it's called OpKill
             // in spir-v that's why it's called `Statement::Kill`
-            Statement::Kill => writeln!(self.out, "{level}discard;")?,
+            //
+            // GLSL has no demote outside of Vulkan, so `Demote` is lowered to
+            // `discard` as well.
+            Statement::Kill | Statement::Demote => writeln!(self.out, "{level}discard;")?,
             Statement::Barrier(flags) => {
                 self.write_barrier(flags, level)?;
             }
@@ -2538,9 +2575,29 @@ impl<'a, W: Write> Writer<'a, W> {
                     (crate::CollectiveOperation::InclusiveScan, crate::SubgroupOperation::Mul) => {
                         write!(self.out, "subgroupInclusiveMul(")?
                     }
+                    (crate::CollectiveOperation::ClusteredReduce(_), op) => {
+                        let fun = match op {
+                            crate::SubgroupOperation::Add => "Add",
+                            crate::SubgroupOperation::Mul => "Mul",
+                            crate::SubgroupOperation::Max => "Max",
+                            crate::SubgroupOperation::Min => "Min",
+                            crate::SubgroupOperation::And => "And",
+                            crate::SubgroupOperation::Or => "Or",
+                            crate::SubgroupOperation::Xor => "Xor",
+                            crate::SubgroupOperation::All | crate::SubgroupOperation::Any => {
+                                unreachable!(
+                                    "validation rejects clustered `All` and `Any` reductions"
+                                )
+                            }
+                        };
+                        write!(self.out, "subgroupClustered{fun}(")?
+                    }
                     _ => unimplemented!(),
                 }
                 self.write_expr(argument, ctx)?;
+                if let crate::CollectiveOperation::ClusteredReduce(size) = collective_op {
+                    write!(self.out, ", {size}u")?;
+                }
                 writeln!(self.out, ");")?;
             }
             Statement::SubgroupGather {
@@ -2574,15 +2631,26 @@ impl<'a, W: Write> Writer<'a, W> {
                     crate::GatherMode::ShuffleXor(_) => {
                         write!(self.out, "subgroupShuffleXor(")?;
                     }
+                    crate::GatherMode::QuadBroadcast(_) => {
+                        write!(self.out, "subgroupQuadBroadcast(")?;
+                    }
+                    crate::GatherMode::QuadSwap(direction) => match direction {
+                        crate::Direction::X => write!(self.out, "subgroupQuadSwapHorizontal(")?,
+                        crate::Direction::Y => write!(self.out, "subgroupQuadSwapVertical(")?,
+                        crate::Direction::Diagonal => {
+                            write!(self.out, "subgroupQuadSwapDiagonal(")?
+                        }
+                    },
                 }
                 self.write_expr(argument, ctx)?;
                 match mode {
-                    crate::GatherMode::BroadcastFirst => {}
+                    crate::GatherMode::BroadcastFirst | crate::GatherMode::QuadSwap(_) => {}
                     crate::GatherMode::Broadcast(index)
                     | crate::GatherMode::Shuffle(index)
                     | crate::GatherMode::ShuffleDown(index)
                     | crate::GatherMode::ShuffleUp(index)
-                    | crate::GatherMode::ShuffleXor(index) => {
+                    | crate::GatherMode::ShuffleXor(index)
+                    | crate::GatherMode::QuadBroadcast(index) => {
                         write!(self.out, ", ")?;
                         self.write_expr(index, ctx)?;
                     }
@@ -2651,6 +2719,7 @@ impl<'a, W: Write> Writer<'a, W> {
                     // decimal part even it's zero which is needed for a valid glsl float constant
                     crate::Literal::F64(value) => write!(self.out, "{value:?}LF")?,
                     crate::Literal::F32(value) => write!(self.out, "{value:?}")?,
+                    crate::Literal::F16(value) => write!(self.out, "{value:?}hf")?,
                     // Unsigned integers need a `u` at the end
                     //
                     // While `core` doesn't necessarily need it, it's allowed and since `es` needs it we
@@ -3453,6 +3522,38 @@ impl<'a, W: Write> Writer<'a, W> {
                             "Correct TypeInner for dot product should be already validated"
                         ),
                     },
+                    fun @ (Mf::Dot4I8Packed | Mf::Dot4U8Packed) => {
+                        let is_signed = match fun {
+                            Mf::Dot4I8Packed => true,
+                            Mf::Dot4U8Packed => false,
+                            _ => unreachable!(),
+                        };
+
+                        // Unpack each byte of both arguments and sum up their products.
+                        write!(self.out, "(")?;
+                        for i in 0..4 {
+                            if i != 0 {
+                                write!(self.out, " + ")?;
+                            }
+                            for (j, arg) in [arg, arg1.unwrap()].into_iter().enumerate() {
+                                if j != 0 {
+                                    write!(self.out, " * ")?;
+                                }
+                                write!(self.out, "bitfieldExtract(")?;
+                                if is_signed {
+                                    write!(self.out, "int(")?;
+                                }
+                                self.write_expr(arg, ctx)?;
+                                if is_signed {
+                                    write!(self.out, ")")?;
+                                }
+                                write!(self.out, ", {}, 8)", i * 8)?;
+                            }
+                        }
+                        write!(self.out, ")")?;
+
+                        return Ok(());
+                    }
                     Mf::Outer => "outerProduct",
                     Mf::Cross => "cross",
                     Mf::Distance => "distance",
@@ -3495,6 +3596,43 @@ impl<'a, W: Write> Writer<'a, W> {
                     Mf::Inverse => "inverse",
                     Mf::Transpose => "transpose",
                     Mf::Determinant => "determinant",
+                    Mf::QuantizeToF16 => {
+                        // GLSL has no 16-bit float type, so round-trip each pair
+                        // of components through `packHalf2x16`.
+                        let size = match *ctx.resolve_type(arg, &self.module.types) {
+                            TypeInner::Vector { size, .. } => Some(size),
+                            _ => None,
+                        };
+                        match size {
+                            None => {
+                                write!(self.out, "unpackHalf2x16(packHalf2x16(vec2(")?;
+                                self.write_expr(arg, ctx)?;
+                                write!(self.out, "))).x")?;
+                            }
+                            Some(crate::VectorSize::Bi) => {
+                                write!(self.out, "unpackHalf2x16(packHalf2x16(")?;
+                                self.write_expr(arg, ctx)?;
+                                write!(self.out, "))")?;
+                            }
+                            Some(size) => {
+                                write!(
+                                    self.out,
+                                    "vec{}(unpackHalf2x16(packHalf2x16(",
+                                    back::vector_size_str(size)
+                                )?;
+                                self.write_expr(arg, ctx)?;
+                                write!(self.out, ".xy)), unpackHalf2x16(packHalf2x16(")?;
+                                self.write_expr(arg, ctx)?;
+                                if size == crate::VectorSize::Tri {
+                                    write!(self.out, ".zz)).x)")?;
+                                } else {
+                                    write!(self.out, ".zw)))")?;
+                                }
+                            }
+                        }
+
+                        return Ok(());
+                    }
                     // bits
                     Mf::CountTrailingZeros => {
                         match *ctx.resolve_type(arg, &self.module.types) {
@@ -3662,25 +3800,40 @@ impl<'a, W: Write> Writer<'a, W> {
                     Mf::Pack2x16snorm => "packSnorm2x16",
                     Mf::Pack2x16unorm => "packUnorm2x16",
                     Mf::Pack2x16float => "packHalf2x16",
-                    fun @ (Mf::Pack4xI8 | Mf::Pack4xU8) => {
+                    fun @ (Mf::Pack4xI8 | Mf::Pack4xU8 | Mf::Pack4xI8Clamp | Mf::Pack4xU8Clamp) => {
                         let was_signed = match fun {
-                            Mf::Pack4xI8 => true,
-                            Mf::Pack4xU8 => false,
+                            Mf::Pack4xI8 | Mf::Pack4xI8Clamp => true,
+                            Mf::Pack4xU8 | Mf::Pack4xU8Clamp => false,
                             _ => unreachable!(),
                         };
+                        let clamp_bounds = match fun {
+                            Mf::Pack4xI8Clamp => Some(("-128", "127")),
+                            Mf::Pack4xU8Clamp => Some(("0u", "255u")),
+                            _ => None,
+                        };
                         let const_suffix = if was_signed { "" } else { "u" };
                         if was_signed {
                             write!(self.out, "uint(")?;
                         }
-                        write!(self.out, "(")?;
-                        self.write_expr(arg, ctx)?;
-                        write!(self.out, "[0] & 0xFF{const_suffix}) | ((")?;
-                        self.write_expr(arg, ctx)?;
-                        write!(self.out, "[1] & 0xFF{const_suffix}) << 8) | ((")?;
-                        self.write_expr(arg, ctx)?;
-                        write!(self.out, "[2] & 0xFF{const_suffix}) << 16) | ((")?;
-                        self.write_expr(arg, ctx)?;
-                        write!(self.out, "[3] & 0xFF{const_suffix}) << 24)")?;
+                        for i in 0..4 {
+                            if i == 0 {
+                                write!(self.out, "(")?;
+                            } else {
+                                write!(self.out, " | ((")?;
+                            }
+                            if clamp_bounds.is_some() {
+                                write!(self.out, "clamp(")?;
+                            }
+                            self.write_expr(arg, ctx)?;
+                            write!(self.out, "[{i}]")?;
+                            if let Some((min, max)) = clamp_bounds {
+                                write!(self.out, ", {min}, {max})")?;
+                            }
+                            write!(self.out, " & 0xFF{const_suffix})")?;
+                            if i != 0 {
+                                write!(self.out, " << {})", i * 8)?;
+                            }
+                        }
                         if was_signed {
                             write!(self.out, ")")?;
                         }
@@ -4474,6 +4627,9 @@ impl<'a, W: Write> Writer<'a, W> {
         if flags.contains(crate::Barrier::SUB_GROUP) {
             writeln!(self.out, "{level}subgroupMemoryBarrier();")?;
         }
+        if flags.contains(crate::Barrier::TEXTURE) {
+            writeln!(self.out, "{level}memoryBarrierImage();")?;
+        }
         writeln!(self.out, "{level}barrier();")?;
         Ok(())
     }
@@ -4682,6 +4838,10 @@ const fn glsl_scalar(scalar: crate::Scalar) -> Result<ScalarString<'static>, Err
                 prefix: "d",
                 full: "double",
             },
+            2 => ScalarString {
+                prefix: "f16",
+                full: "float16_t",
+            },
             _ => return Err(Error::UnsupportedScalar(scalar)),
         },
         Sk::Bool => ScalarString {
@@ -798,18 +798,17 @@ impl<'a, W: Write> super::Writer<'a, W> {
             match type_key {
                 &crate::PredeclaredType::ModfResult { size, width }
                 | &crate::PredeclaredType::FrexpResult { size, width } => {
+                    let scalar_name = crate::Scalar {
+                        kind: crate::ScalarKind::Float,
+                        width,
+                    }
+                    .to_hlsl_str()?;
                     let arg_type_name_owner;
                     let arg_type_name = if let Some(size) = size {
-                        arg_type_name_owner = format!(
-                            "{}{}",
-                            if width == 8 { "double" } else { "float" },
-                            size as u8
-                        );
+                        arg_type_name_owner = format!("{scalar_name}{}", size as u8);
                         &arg_type_name_owner
-                    } else if width == 8 {
-                        "double"
                     } else {
-                        "float"
+                        scalar_name
                     };
 
                     let (defined_func_name, called_func_name, second_field_name, sign_multiplier) =
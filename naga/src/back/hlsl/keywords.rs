@@ -807,6 +807,7 @@ pub const RESERVED: &[&str] = &[
     "ConstantBuffer",
     "TextureBuffer",
     "RaytracingAccelerationStructure",
+    "RayDesc",
     // DXC templated types, from https://github.com/microsoft/DirectXShaderCompiler/blob/18c9e114f9c314f93e68fbc72ce207d4ed2e65ae/tools/clang/lib/AST/ASTContextHLSL.cpp
     // look for `BuiltinTypeDeclBuilder`
     "matrix",
@@ -819,6 +820,9 @@ pub const RESERVED: &[&str] = &[
     super::writer::FREXP_FUNCTION,
     super::writer::EXTRACT_BITS_FUNCTION,
     super::writer::INSERT_BITS_FUNCTION,
+    super::writer::RAY_DESC_FUNCTION,
+    super::writer::COMMITTED_INTERSECTION_FUNCTION,
+    super::writer::CANDIDATE_INTERSECTION_FUNCTION,
 ];
 
 // DXC scalar types, from https://github.com/microsoft/DirectXShaderCompiler/blob/18c9e114f9c314f93e68fbc72ce207d4ed2e65ae/tools/clang/lib/AST/ASTContextHLSL.cpp#L48-L254
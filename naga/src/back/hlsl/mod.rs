@@ -101,6 +101,7 @@ accessing individual columns by dynamic index.
 mod conv;
 mod help;
 mod keywords;
+mod ray;
 mod storage;
 mod writer;
 
@@ -266,6 +267,8 @@ pub enum Error {
     Custom(String),
     #[error("overrides should not be present at this stage")]
     Override,
+    #[error("functions taking pointers to global variables must be specialized with `back::pointer_arguments::specialize_pointer_arguments` first")]
+    GlobalPointerArgument,
 }
 
 #[derive(Default)]
@@ -0,0 +1,206 @@
+/*!
+Generating HLSL for ray query operations.
+
+Ray queries are lowered to DXR 1.1 inline ray tracing, using `RayQuery<>`
+objects. Naga's [`RayDesc`] and [`RayIntersection`] structs differ from their
+HLSL counterparts, so the writer emits helper functions that convert between
+them.
+
+[`RayDesc`]: crate::SpecialTypes::ray_desc
+[`RayIntersection`]: crate::SpecialTypes::ray_intersection
+*/
+
+use super::{
+    writer::{CANDIDATE_INTERSECTION_FUNCTION, COMMITTED_INTERSECTION_FUNCTION, RAY_DESC_FUNCTION},
+    BackendResult,
+};
+use crate::{
+    back::{self, Baked},
+    proc::NameKey,
+    Handle, Module,
+};
+use std::fmt::Write;
+
+impl<W: Write> super::Writer<'_, W> {
+    /// Write the functions converting between Naga's ray query structs and
+    /// HLSL's ray query API, for those structs the module uses.
+    pub(super) fn write_ray_query_functions(&mut self, module: &Module) -> BackendResult {
+        if let Some(ray_desc) = module.special_types.ray_desc {
+            self.write_ray_desc_function(module, ray_desc)?;
+        }
+        if let Some(ray_intersection) = module.special_types.ray_intersection {
+            self.write_intersection_function(module, ray_intersection, true)?;
+            self.write_intersection_function(module, ray_intersection, false)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a function converting Naga's `RayDesc` struct to HLSL's.
+    fn write_ray_desc_function(
+        &mut self,
+        module: &Module,
+        ray_desc: Handle<crate::Type>,
+    ) -> BackendResult {
+        // Note: member indices must match `generate_ray_desc_type`.
+        let member = |index| &self.names[&NameKey::StructMember(ray_desc, index)];
+        let (tmin, tmax, origin, dir) = (member(2), member(3), member(4), member(5));
+        let tab = back::INDENT;
+
+        let body = format!(
+            "{tab}RayDesc ret = (RayDesc)0;
+{tab}ret.Origin = arg0.{origin};
+{tab}ret.TMin = arg0.{tmin};
+{tab}ret.Direction = arg0.{dir};
+{tab}ret.TMax = arg0.{tmax};
+{tab}return ret;"
+        );
+
+        write!(self.out, "RayDesc {RAY_DESC_FUNCTION}(")?;
+        self.write_type(module, ray_desc)?;
+        writeln!(self.out, " arg0) {{")?;
+        writeln!(self.out, "{body}")?;
+        writeln!(self.out, "}}")?;
+        writeln!(self.out)?;
+
+        Ok(())
+    }
+
+    /// Write a function filling Naga's `RayIntersection` struct from the
+    /// committed or candidate intersection of a `RayQuery`.
+    fn write_intersection_function(
+        &mut self,
+        module: &Module,
+        ray_intersection: Handle<crate::Type>,
+        committed: bool,
+    ) -> BackendResult {
+        // Note: member indices must match `generate_ray_intersection_type`.
+        let member = |index| &self.names[&NameKey::StructMember(ray_intersection, index)];
+        let kind = member(0);
+        let t = member(1);
+        let instance_custom_index = member(2);
+        let instance_id = member(3);
+        let sbt_record_offset = member(4);
+        let geometry_index = member(5);
+        let primitive_index = member(6);
+        let barycentrics = member(7);
+        let front_face = member(8);
+        let object_to_world = member(9);
+        let world_to_object = member(10);
+        let struct_name = self.names[&NameKey::Type(ray_intersection)].clone();
+        let tab = back::INDENT;
+
+        // Map HLSL's `COMMITTED_STATUS` and `CANDIDATE_TYPE` to Naga's kinds.
+        let kind_none = back::RayIntersectionType::None as u32;
+        let kind_triangle = back::RayIntersectionType::Triangle as u32;
+        let kind_generated = back::RayIntersectionType::Generated as u32;
+        let kind_bounding_box = back::RayIntersectionType::BoundingBox as u32;
+
+        let (function_name, body) = if committed {
+            let body = format!(
+                "{tab}ret.{kind} = {kind_none};
+{tab}if (rq.CommittedStatus() != COMMITTED_NOTHING) {{
+{tab}{tab}ret.{t} = rq.CommittedRayT();
+{tab}{tab}ret.{instance_custom_index} = rq.CommittedInstanceID();
+{tab}{tab}ret.{instance_id} = rq.CommittedInstanceIndex();
+{tab}{tab}ret.{sbt_record_offset} = rq.CommittedInstanceContributionToHitGroupIndex();
+{tab}{tab}ret.{geometry_index} = rq.CommittedGeometryIndex();
+{tab}{tab}ret.{primitive_index} = rq.CommittedPrimitiveIndex();
+{tab}{tab}if (rq.CommittedStatus() == COMMITTED_TRIANGLE_HIT) {{
+{tab}{tab}{tab}ret.{kind} = {kind_triangle};
+{tab}{tab}{tab}ret.{barycentrics} = rq.CommittedTriangleBarycentrics();
+{tab}{tab}{tab}ret.{front_face} = rq.CommittedTriangleFrontFace();
+{tab}{tab}}} else {{
+{tab}{tab}{tab}ret.{kind} = {kind_generated};
+{tab}{tab}}}
+{tab}{tab}ret.{object_to_world} = rq.CommittedObjectToWorld4x3();
+{tab}{tab}ret.{world_to_object} = rq.CommittedWorldToObject4x3();
+{tab}}}"
+            );
+            (COMMITTED_INTERSECTION_FUNCTION, body)
+        } else {
+            // Only triangle candidates have a hit distance, barycentrics and
+            // facing.
+            let body = format!(
+                "{tab}ret.{instance_custom_index} = rq.CandidateInstanceID();
+{tab}ret.{instance_id} = rq.CandidateInstanceIndex();
+{tab}ret.{sbt_record_offset} = rq.CandidateInstanceContributionToHitGroupIndex();
+{tab}ret.{geometry_index} = rq.CandidateGeometryIndex();
+{tab}ret.{primitive_index} = rq.CandidatePrimitiveIndex();
+{tab}ret.{object_to_world} = rq.CandidateObjectToWorld4x3();
+{tab}ret.{world_to_object} = rq.CandidateWorldToObject4x3();
+{tab}if (rq.CandidateType() == CANDIDATE_NON_OPAQUE_TRIANGLE) {{
+{tab}{tab}ret.{kind} = {kind_triangle};
+{tab}{tab}ret.{t} = rq.CandidateTriangleRayT();
+{tab}{tab}ret.{barycentrics} = rq.CandidateTriangleBarycentrics();
+{tab}{tab}ret.{front_face} = rq.CandidateTriangleFrontFace();
+{tab}}} else {{
+{tab}{tab}ret.{kind} = {kind_bounding_box};
+{tab}}}"
+            );
+            (CANDIDATE_INTERSECTION_FUNCTION, body)
+        };
+
+        write!(self.out, "{struct_name} {function_name}(")?;
+        self.write_value_type(module, &crate::TypeInner::RayQuery)?;
+        writeln!(self.out, " rq) {{")?;
+        writeln!(self.out, "{tab}{struct_name} ret = ({struct_name})0;")?;
+        writeln!(self.out, "{body}")?;
+        writeln!(self.out, "{tab}return ret;")?;
+        writeln!(self.out, "}}")?;
+        writeln!(self.out)?;
+
+        Ok(())
+    }
+
+    /// Write a [`RayQuery`] statement.
+    ///
+    /// [`RayQuery`]: crate::Statement::RayQuery
+    pub(super) fn write_ray_query_function(
+        &mut self,
+        module: &Module,
+        query: Handle<crate::Expression>,
+        fun: &crate::RayQueryFunction,
+        func_ctx: &back::FunctionCtx<'_>,
+        level: back::Level,
+    ) -> BackendResult {
+        match *fun {
+            crate::RayQueryFunction::Initialize {
+                acceleration_structure,
+                descriptor,
+            } => {
+                // Note: member indices must match `generate_ray_desc_type`.
+                let ray_desc = module.special_types.ray_desc.unwrap();
+                let flags = self.names[&NameKey::StructMember(ray_desc, 0)].clone();
+                let cull_mask = self.names[&NameKey::StructMember(ray_desc, 1)].clone();
+
+                write!(self.out, "{level}")?;
+                self.write_expr(module, query, func_ctx)?;
+                write!(self.out, ".TraceRayInline(")?;
+                self.write_expr(module, acceleration_structure, func_ctx)?;
+                write!(self.out, ", ")?;
+                self.write_expr(module, descriptor, func_ctx)?;
+                write!(self.out, ".{flags}, ")?;
+                self.write_expr(module, descriptor, func_ctx)?;
+                write!(self.out, ".{cull_mask}, {RAY_DESC_FUNCTION}(")?;
+                self.write_expr(module, descriptor, func_ctx)?;
+                writeln!(self.out, "));")?;
+            }
+            crate::RayQueryFunction::Proceed { result } => {
+                write!(self.out, "{level}")?;
+                let name = Baked(result).to_string();
+                write!(self.out, "const bool {name} = ")?;
+                self.named_expressions.insert(result, name);
+                self.write_expr(module, query, func_ctx)?;
+                writeln!(self.out, ".Proceed();")?;
+            }
+            crate::RayQueryFunction::Terminate => {
+                write!(self.out, "{level}")?;
+                self.write_expr(module, query, func_ctx)?;
+                writeln!(self.out, ".Abort();")?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use super::{
         WrappedZeroValue,
     },
     storage::StoreValue,
-    BackendResult, Error, FragmentEntryPoint, Options,
+    BackendResult, Error, FragmentEntryPoint, Options, ShaderModel,
 };
 use crate::{
     back::{self, Baked},
@@ -24,6 +24,9 @@ pub(crate) const MODF_FUNCTION: &str = "naga_modf";
 pub(crate) const FREXP_FUNCTION: &str = "naga_frexp";
 pub(crate) const EXTRACT_BITS_FUNCTION: &str = "naga_extractBits";
 pub(crate) const INSERT_BITS_FUNCTION: &str = "naga_insertBits";
+pub(crate) const RAY_DESC_FUNCTION: &str = "naga_rayDesc";
+pub(crate) const COMMITTED_INTERSECTION_FUNCTION: &str = "naga_committedIntersection";
+pub(crate) const CANDIDATE_INTERSECTION_FUNCTION: &str = "naga_candidateIntersection";
 
 struct EpStructMember {
     name: String,
@@ -146,8 +149,14 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                 self.need_bake_expressions.insert(fun_handle);
             }
 
-            if let Expression::Math { fun, arg, .. } = *expr {
+            if let Expression::Math { fun, arg, arg1, .. } = *expr {
                 match fun {
+                    crate::MathFunction::Dot4I8Packed | crate::MathFunction::Dot4U8Packed
+                        if self.options.shader_model < ShaderModel::V6_4 =>
+                    {
+                        self.need_bake_expressions.insert(arg);
+                        self.need_bake_expressions.insert(arg1.unwrap());
+                    }
                     crate::MathFunction::Asinh
                     | crate::MathFunction::Acosh
                     | crate::MathFunction::Atanh
@@ -164,7 +173,9 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                     | crate::MathFunction::Pack4x8snorm
                     | crate::MathFunction::Pack4x8unorm
                     | crate::MathFunction::Pack4xI8
-                    | crate::MathFunction::Pack4xU8 => {
+                    | crate::MathFunction::Pack4xU8
+                    | crate::MathFunction::Pack4xI8Clamp
+                    | crate::MathFunction::Pack4xU8Clamp => {
                         self.need_bake_expressions.insert(arg);
                     }
                     crate::MathFunction::CountLeadingZeros => {
@@ -194,11 +205,25 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                 } => {
                     self.need_bake_expressions.insert(argument);
                 }
+                crate::Statement::RayQuery {
+                    fun: crate::RayQueryFunction::Initialize { descriptor, .. },
+                    ..
+                } => {
+                    // The descriptor is used several times by `TraceRayInline`.
+                    self.need_bake_expressions.insert(descriptor);
+                }
                 _ => {}
             }
         }
     }
 
+    /// Write `module` as HLSL.
+    ///
+    /// Overrides must already have been replaced by
+    /// [`process_overrides`](back::pipeline_constants::process_overrides), and
+    /// functions taking pointers to global variables must already have been
+    /// specialized with
+    /// [`specialize_pointer_arguments`](back::pointer_arguments::specialize_pointer_arguments).
     pub fn write(
         &mut self,
         module: &Module,
@@ -208,6 +233,9 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
         if !module.overrides.is_empty() {
             return Err(Error::Override);
         }
+        if back::pointer_arguments::has_global_pointer_arguments(module) {
+            return Err(Error::GlobalPointerArgument);
+        }
 
         self.reset(module);
 
@@ -273,6 +301,7 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
         }
 
         self.write_special_functions(module)?;
+        self.write_ray_query_functions(module)?;
 
         self.write_wrapped_compose_functions(module, &module.global_expressions)?;
         self.write_wrapped_zero_value_functions(module, &module.global_expressions)?;
@@ -456,16 +485,11 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                 write!(self.out, " : {builtin_str}")?;
             }
             Some(crate::Binding::Location {
-                second_blend_source: true,
-                ..
+                blend_src: Some(1), ..
             }) => {
                 write!(self.out, " : SV_Target1")?;
             }
-            Some(crate::Binding::Location {
-                location,
-                second_blend_source: false,
-                ..
-            }) => {
+            Some(crate::Binding::Location { location, .. }) => {
                 if stage == Some((ShaderStage::Fragment, Io::Output)) {
                     write!(self.out, " : SV_Target{location}")?;
                 } else {
@@ -506,6 +530,10 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
             }
             self.write_type(module, m.ty)?;
             write!(self.out, " {}", &m.name)?;
+            // HLSL arrays are written as `type name[size]`
+            if let TypeInner::Array { base, size, .. } = module.types[m.ty].inner {
+                self.write_array_size(module, base, size)?;
+            }
             self.write_semantic(&m.binding, Some(shader_stage))?;
             writeln!(self.out, ";")?;
         }
@@ -1024,13 +1052,8 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
         let mut last_offset = 0;
         for (index, member) in members.iter().enumerate() {
             if member.binding.is_none() && member.offset > last_offset {
-                // using int as padding should work as long as the backend
-                // doesn't support a type that's less than 4 bytes in size
-                // (Error::UnsupportedScalar catches this)
-                let padding = (member.offset - last_offset) / 4;
-                for i in 0..padding {
-                    writeln!(self.out, "{}int _pad{}_{};", back::INDENT, index, i)?;
-                }
+                let name = format!("_pad{index}");
+                self.write_struct_padding(&name, member.offset - last_offset)?;
             }
             let ty_inner = &module.types[member.ty].inner;
             last_offset = member.offset + ty_inner.size_hlsl(module.to_ctx());
@@ -1100,16 +1123,28 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
 
         // add padding at the end since sizes of types don't get rounded up to their alignment in HLSL
         if members.last().unwrap().binding.is_none() && span > last_offset {
-            let padding = (span - last_offset) / 4;
-            for i in 0..padding {
-                writeln!(self.out, "{}int _end_pad_{};", back::INDENT, i)?;
-            }
+            self.write_struct_padding("_end_pad", span - last_offset)?;
         }
 
         writeln!(self.out, "}};")?;
         Ok(())
     }
 
+    /// Helper method used to write `size` bytes of padding members named `{name}_{i}`
+    ///
+    /// Padding is written as `int`s, with a trailing `half` if only two bytes
+    /// are left over, which can only follow an `f16` member. Any other
+    /// remainder follows a `bool`, which HLSL already stores in 4 bytes.
+    fn write_struct_padding(&mut self, name: &str, size: u32) -> BackendResult {
+        for i in 0..size / 4 {
+            writeln!(self.out, "{}int {name}_{i};", back::INDENT)?;
+        }
+        if size % 4 == 2 {
+            writeln!(self.out, "{}half {name}_{};", back::INDENT, size / 4)?;
+        }
+        Ok(())
+    }
+
     /// Helper method used to write global/structs non image/sampler types
     ///
     /// # Notes
@@ -1211,6 +1246,13 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                 };
                 write!(self.out, "{sampler}")?;
             }
+            TypeInner::AccelerationStructure => {
+                write!(self.out, "RaytracingAccelerationStructure")?;
+            }
+            TypeInner::RayQuery => {
+                // Flags are given to `TraceRayInline` instead.
+                write!(self.out, "RayQuery<RAY_FLAG_NONE>")?;
+            }
             // HLSL arrays are written as `type name[size]`
             // Current code is written arrays only as `[size]`
             // Base `type` and `name` should be written outside
@@ -1374,13 +1416,17 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                 self.write_array_size(module, base, size)?;
             }
 
-            write!(self.out, " = ")?;
-            // Write the local initializer if needed
-            if let Some(init) = local.init {
-                self.write_expr(module, init, func_ctx)?;
-            } else {
-                // Zero initialize local variables
-                self.write_default_init(module, local.ty)?;
+            // Ray queries can't be zero initialized; they're only usable after
+            // `TraceRayInline` anyway.
+            if module.types[local.ty].inner != TypeInner::RayQuery {
+                write!(self.out, " = ")?;
+                // Write the local initializer if needed
+                if let Some(init) = local.init {
+                    self.write_expr(module, init, func_ctx)?;
+                } else {
+                    // Zero initialize local variables
+                    self.write_default_init(module, local.ty)?;
+                }
             }
 
             // Finish the local with `;` and add a newline (only for readability)
@@ -1669,7 +1715,9 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                 writeln!(self.out, "{level}}}")?
             }
             // TODO: copy-paste from glsl-out
-            Statement::Kill => writeln!(self.out, "{level}discard;")?,
+            // Since Shader Model 6.0, `discard` leaves the invocation running
+            // as a helper, so it serves for both statements.
+            Statement::Kill | Statement::Demote => writeln!(self.out, "{level}discard;")?,
             Statement::Return { value: None } => {
                 writeln!(self.out, "{level}return;")?;
             }
@@ -2158,7 +2206,12 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                     .pointer_space()
                     .unwrap();
 
-                let fun_str = fun.to_hlsl_suffix();
+                let fun_str = match (*fun, result) {
+                    // Without a result, there is no place to store the
+                    // original value, so use the variant that omits it.
+                    (crate::AtomicFunction::Exchange { compare: Some(_) }, None) => "CompareStore",
+                    _ => fun.to_hlsl_suffix(),
+                };
                 match pointer_space {
                     crate::AddressSpace::WorkGroup => {
                         write!(self.out, "Interlocked{fun_str}(")?;
@@ -2192,22 +2245,48 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                         // we just wrote `InterlockedAdd`, so negate the argument
                         write!(self.out, "-")?;
                     }
-                    crate::AtomicFunction::Exchange { compare: Some(_) } => {
-                        return Err(Error::Unimplemented("atomic CompareExchange".to_string()));
+                    crate::AtomicFunction::Exchange { compare: Some(cmp) } => {
+                        self.write_expr(module, cmp, func_ctx)?;
+                        write!(self.out, ", ")?;
                     }
                     _ => {}
                 }
                 self.write_expr(module, value, func_ctx)?;
 
-                // The `original_value` out parameter is optional for all the
-                // `Interlocked` functions we generate other than
-                // `InterlockedExchange`.
-                if let Some((result, name)) = res_name {
-                    write!(self.out, ", {name}")?;
-                    self.named_expressions.insert(result, name);
+                match (*fun, res_name) {
+                    // `InterlockedCompareExchange` only produces the original
+                    // value, so fill in the `exchanged` member of the
+                    // `__atomic_compare_exchange_result` struct ourselves.
+                    (
+                        crate::AtomicFunction::Exchange { compare: Some(cmp) },
+                        Some((result, name)),
+                    ) => {
+                        let res_ty = match func_ctx.info[result].ty {
+                            proc::TypeResolution::Handle(handle) => handle,
+                            proc::TypeResolution::Value(_) => unreachable!(),
+                        };
+                        let old_value = &self.names[&NameKey::StructMember(res_ty, 0)];
+                        let exchanged = &self.names[&NameKey::StructMember(res_ty, 1)];
+                        writeln!(self.out, ", {name}.{old_value});")?;
+                        write!(
+                            self.out,
+                            "{level}{name}.{exchanged} = ({name}.{old_value} == "
+                        )?;
+                        self.write_expr(module, cmp, func_ctx)?;
+                        writeln!(self.out, ");")?;
+                        self.named_expressions.insert(result, name);
+                    }
+                    // The `original_value` out parameter is optional for all the
+                    // `Interlocked` functions we generate other than
+                    // `InterlockedExchange`.
+                    (_, res_name) => {
+                        if let Some((result, name)) = res_name {
+                            write!(self.out, ", {name}")?;
+                            self.named_expressions.insert(result, name);
+                        }
+                        writeln!(self.out, ");")?;
+                    }
                 }
-
-                writeln!(self.out, ");")?;
             }
             Statement::WorkGroupUniformLoad { pointer, result } => {
                 self.write_barrier(crate::Barrier::WORK_GROUP, level)?;
@@ -2223,7 +2302,9 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
             } => {
                 self.write_switch(module, func_ctx, level, selector, cases)?;
             }
-            Statement::RayQuery { .. } => unreachable!(),
+            Statement::RayQuery { query, ref fun } => {
+                self.write_ray_query_function(module, query, fun, func_ctx, level)?;
+            }
             Statement::SubgroupBallot { result, predicate } => {
                 write!(self.out, "{level}")?;
                 let name = Baked(result).to_string();
@@ -2297,6 +2378,11 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                         self.write_expr(module, argument, func_ctx)?;
                         write!(self.out, " * WavePrefixProduct(")?;
                     }
+                    (crate::CollectiveOperation::ClusteredReduce(_), _) => {
+                        return Err(Error::Custom(
+                            "HLSL has no clustered subgroup operations".to_string(),
+                        ));
+                    }
                     _ => unimplemented!(),
                 }
                 self.write_expr(module, argument, func_ctx)?;
@@ -2319,29 +2405,51 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                 write!(self.out, " {name} = ")?;
                 self.named_expressions.insert(result, name);
 
-                if matches!(mode, crate::GatherMode::BroadcastFirst) {
-                    write!(self.out, "WaveReadLaneFirst(")?;
-                    self.write_expr(module, argument, func_ctx)?;
-                } else {
-                    write!(self.out, "WaveReadLaneAt(")?;
-                    self.write_expr(module, argument, func_ctx)?;
-                    write!(self.out, ", ")?;
-                    match mode {
-                        crate::GatherMode::BroadcastFirst => unreachable!(),
-                        crate::GatherMode::Broadcast(index) | crate::GatherMode::Shuffle(index) => {
-                            self.write_expr(module, index, func_ctx)?;
-                        }
-                        crate::GatherMode::ShuffleDown(index) => {
-                            write!(self.out, "WaveGetLaneIndex() + ")?;
-                            self.write_expr(module, index, func_ctx)?;
-                        }
-                        crate::GatherMode::ShuffleUp(index) => {
-                            write!(self.out, "WaveGetLaneIndex() - ")?;
-                            self.write_expr(module, index, func_ctx)?;
+                match mode {
+                    crate::GatherMode::BroadcastFirst => {
+                        write!(self.out, "WaveReadLaneFirst(")?;
+                        self.write_expr(module, argument, func_ctx)?;
+                    }
+                    crate::GatherMode::QuadBroadcast(index) => {
+                        write!(self.out, "QuadReadLaneAt(")?;
+                        self.write_expr(module, argument, func_ctx)?;
+                        write!(self.out, ", ")?;
+                        self.write_expr(module, index, func_ctx)?;
+                    }
+                    crate::GatherMode::QuadSwap(direction) => {
+                        match direction {
+                            crate::Direction::X => write!(self.out, "QuadReadAcrossX(")?,
+                            crate::Direction::Y => write!(self.out, "QuadReadAcrossY(")?,
+                            crate::Direction::Diagonal => {
+                                write!(self.out, "QuadReadAcrossDiagonal(")?
+                            }
                         }
-                        crate::GatherMode::ShuffleXor(index) => {
-                            write!(self.out, "WaveGetLaneIndex() ^ ")?;
-                            self.write_expr(module, index, func_ctx)?;
+                        self.write_expr(module, argument, func_ctx)?;
+                    }
+                    _ => {
+                        write!(self.out, "WaveReadLaneAt(")?;
+                        self.write_expr(module, argument, func_ctx)?;
+                        write!(self.out, ", ")?;
+                        match mode {
+                            crate::GatherMode::BroadcastFirst
+                            | crate::GatherMode::QuadBroadcast(_)
+                            | crate::GatherMode::QuadSwap(_) => unreachable!(),
+                            crate::GatherMode::Broadcast(index)
+                            | crate::GatherMode::Shuffle(index) => {
+                                self.write_expr(module, index, func_ctx)?;
+                            }
+                            crate::GatherMode::ShuffleDown(index) => {
+                                write!(self.out, "WaveGetLaneIndex() + ")?;
+                                self.write_expr(module, index, func_ctx)?;
+                            }
+                            crate::GatherMode::ShuffleUp(index) => {
+                                write!(self.out, "WaveGetLaneIndex() - ")?;
+                                self.write_expr(module, index, func_ctx)?;
+                            }
+                            crate::GatherMode::ShuffleXor(index) => {
+                                write!(self.out, "WaveGetLaneIndex() ^ ")?;
+                                self.write_expr(module, index, func_ctx)?;
+                            }
                         }
                     }
                 }
@@ -2383,6 +2491,7 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                 // decimal part even it's zero
                 crate::Literal::F64(value) => write!(self.out, "{value:?}L")?,
                 crate::Literal::F32(value) => write!(self.out, "{value:?}")?,
+                crate::Literal::F16(value) => write!(self.out, "{value:?}h")?,
                 crate::Literal::U32(value) => write!(self.out, "{value}u")?,
                 crate::Literal::I32(value) => write!(self.out, "{value}")?,
                 crate::Literal::U64(value) => write!(self.out, "{value}uL")?,
@@ -3022,6 +3131,7 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                 enum Function {
                     Asincosh { is_sin: bool },
                     Atanh,
+                    QuantizeToF16,
                     Pack2x16float,
                     Pack2x16snorm,
                     Pack2x16unorm,
@@ -3029,6 +3139,8 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                     Pack4x8unorm,
                     Pack4xI8,
                     Pack4xU8,
+                    Pack4xI8Clamp,
+                    Pack4xU8Clamp,
                     Unpack2x16float,
                     Unpack2x16snorm,
                     Unpack2x16unorm,
@@ -3036,6 +3148,8 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                     Unpack4x8unorm,
                     Unpack4xI8,
                     Unpack4xU8,
+                    Dot4I8Packed,
+                    Dot4U8Packed,
                     Regular(&'static str),
                     MissingIntOverload(&'static str),
                     MissingIntReturnType(&'static str),
@@ -3083,6 +3197,8 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                     Mf::Pow => Function::Regular("pow"),
                     // geometry
                     Mf::Dot => Function::Regular("dot"),
+                    Mf::Dot4I8Packed => Function::Dot4I8Packed,
+                    Mf::Dot4U8Packed => Function::Dot4U8Packed,
                     //Mf::Outer => ,
                     Mf::Cross => Function::Regular("cross"),
                     Mf::Distance => Function::Regular("distance"),
@@ -3102,6 +3218,7 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                     //Mf::Inverse =>,
                     Mf::Transpose => Function::Regular("transpose"),
                     Mf::Determinant => Function::Regular("determinant"),
+                    Mf::QuantizeToF16 => Function::QuantizeToF16,
                     // bits
                     Mf::CountTrailingZeros => Function::CountTrailingZeros,
                     Mf::CountLeadingZeros => Function::CountLeadingZeros,
@@ -3119,6 +3236,8 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                     Mf::Pack4x8unorm => Function::Pack4x8unorm,
                     Mf::Pack4xI8 => Function::Pack4xI8,
                     Mf::Pack4xU8 => Function::Pack4xU8,
+                    Mf::Pack4xI8Clamp => Function::Pack4xI8Clamp,
+                    Mf::Pack4xU8Clamp => Function::Pack4xU8Clamp,
                     // Data Unpacking
                     Mf::Unpack2x16float => Function::Unpack2x16float,
                     Mf::Unpack2x16snorm => Function::Unpack2x16snorm,
@@ -3150,6 +3269,11 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                         self.write_expr(module, arg, func_ctx)?;
                         write!(self.out, "))")?;
                     }
+                    Function::QuantizeToF16 => {
+                        write!(self.out, "f16tof32(f32tof16(")?;
+                        self.write_expr(module, arg, func_ctx)?;
+                        write!(self.out, "))")?;
+                    }
                     Function::Pack2x16float => {
                         write!(self.out, "(f32tof16(")?;
                         self.write_expr(module, arg, func_ctx)?;
@@ -3219,24 +3343,80 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                         self.write_expr(module, arg, func_ctx)?;
                         write!(self.out, "[3], 0.0, 1.0) * {scale}.0)) << 24)")?;
                     }
-                    fun @ (Function::Pack4xI8 | Function::Pack4xU8) => {
-                        let was_signed = matches!(fun, Function::Pack4xI8);
+                    fun @ (Function::Pack4xI8
+                    | Function::Pack4xU8
+                    | Function::Pack4xI8Clamp
+                    | Function::Pack4xU8Clamp) => {
+                        let was_signed =
+                            matches!(fun, Function::Pack4xI8 | Function::Pack4xI8Clamp);
+                        let clamp_bounds = match fun {
+                            Function::Pack4xI8Clamp => Some(("-128", "127")),
+                            Function::Pack4xU8Clamp => Some(("0u", "255u")),
+                            _ => None,
+                        };
                         if was_signed {
                             write!(self.out, "uint(")?;
                         }
-                        write!(self.out, "(")?;
-                        self.write_expr(module, arg, func_ctx)?;
-                        write!(self.out, "[0] & 0xFF) | ((")?;
-                        self.write_expr(module, arg, func_ctx)?;
-                        write!(self.out, "[1] & 0xFF) << 8) | ((")?;
-                        self.write_expr(module, arg, func_ctx)?;
-                        write!(self.out, "[2] & 0xFF) << 16) | ((")?;
-                        self.write_expr(module, arg, func_ctx)?;
-                        write!(self.out, "[3] & 0xFF) << 24)")?;
+                        for i in 0..4 {
+                            if i == 0 {
+                                write!(self.out, "(")?;
+                            } else {
+                                write!(self.out, " | ((")?;
+                            }
+                            if clamp_bounds.is_some() {
+                                write!(self.out, "clamp(")?;
+                            }
+                            self.write_expr(module, arg, func_ctx)?;
+                            write!(self.out, "[{i}]")?;
+                            if let Some((min, max)) = clamp_bounds {
+                                write!(self.out, ", {min}, {max})")?;
+                            }
+                            write!(self.out, " & 0xFF)")?;
+                            if i != 0 {
+                                write!(self.out, " << {})", i * 8)?;
+                            }
+                        }
                         if was_signed {
                             write!(self.out, ")")?;
                         }
                     }
+                    fun @ (Function::Dot4I8Packed | Function::Dot4U8Packed) => {
+                        let arg1 = arg1.unwrap();
+                        if self.options.shader_model >= ShaderModel::V6_4 {
+                            // Intrinsics accumulate into their last argument.
+                            let (fun_name, accumulator) = match fun {
+                                Function::Dot4I8Packed => ("dot4add_i8packed", "0"),
+                                _ => ("dot4add_u8packed", "0u"),
+                            };
+                            write!(self.out, "{fun_name}(")?;
+                            self.write_expr(module, arg, func_ctx)?;
+                            write!(self.out, ", ")?;
+                            self.write_expr(module, arg1, func_ctx)?;
+                            write!(self.out, ", {accumulator})")?;
+                        } else {
+                            // Unpack both arguments the same way as `unpack4x{I,U}8` does.
+                            let vec_ty = match fun {
+                                Function::Dot4I8Packed => "int4",
+                                _ => "uint4",
+                            };
+                            write!(self.out, "dot(")?;
+                            for (i, arg) in [arg, arg1].into_iter().enumerate() {
+                                if i != 0 {
+                                    write!(self.out, ", ")?;
+                                }
+                                write!(self.out, "{vec_ty}(")?;
+                                self.write_expr(module, arg, func_ctx)?;
+                                write!(self.out, ", ")?;
+                                self.write_expr(module, arg, func_ctx)?;
+                                write!(self.out, " >> 8, ")?;
+                                self.write_expr(module, arg, func_ctx)?;
+                                write!(self.out, " >> 16, ")?;
+                                self.write_expr(module, arg, func_ctx)?;
+                                write!(self.out, " >> 24) << 24 >> 24")?;
+                            }
+                            write!(self.out, ")")?;
+                        }
+                    }
 
                     Function::Unpack2x16float => {
                         write!(self.out, "float2(f16tof32(")?;
@@ -3572,8 +3752,16 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
                 self.write_expr(module, reject, func_ctx)?;
                 write!(self.out, ")")?
             }
-            // Not supported yet
-            Expression::RayQueryGetIntersection { .. } => unreachable!(),
+            Expression::RayQueryGetIntersection { query, committed } => {
+                let function_name = if committed {
+                    COMMITTED_INTERSECTION_FUNCTION
+                } else {
+                    CANDIDATE_INTERSECTION_FUNCTION
+                };
+                write!(self.out, "{function_name}(")?;
+                self.write_expr(module, query, func_ctx)?;
+                write!(self.out, ")")?;
+            }
             // Nothing to do here, since call expression already cached
             Expression::CallResult(_)
             | Expression::AtomicResult { .. }
@@ -3645,7 +3833,8 @@ impl<'a, W: fmt::Write> super::Writer<'a, W> {
     }
 
     fn write_barrier(&mut self, barrier: crate::Barrier, level: back::Level) -> BackendResult {
-        if barrier.contains(crate::Barrier::STORAGE) {
+        // UAV textures live in device memory, just like storage buffers.
+        if barrier.intersects(crate::Barrier::STORAGE | crate::Barrier::TEXTURE) {
             writeln!(self.out, "{level}DeviceMemoryBarrierWithGroupSync();")?;
         }
         if barrier.contains(crate::Barrier::WORK_GROUP) {
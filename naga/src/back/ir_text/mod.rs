@@ -0,0 +1,1048 @@
+/*!
+Backend for Naga's textual IR format.
+
+This writes a [`Module`] as text that mirrors the IR directly, for reading,
+diffing, and writing by hand when reproducing back end bugs. Unlike the RON
+serialization, handles are spelled with a prefix naming the arena they refer
+to, types without names are written inline, and each function is a single
+block. [`front::ir_text`] parses the format back into a [`Module`], and the
+result is identical to the original, apart from spans.
+
+Here is a small module:
+
+```text
+type @t0 = f32
+type @t1 = vec4<f32>
+type @t2 "VertexOutput" = struct span=16 {
+    "position": vec4<f32> offset=0 builtin(Position),
+}
+@e0 = literal f32 1.0
+const @c0 "ONE": f32 = @e0
+global @g0 "scale" uniform group(0) binding(0): f32
+entry_point Vertex "main" workgroup_size(0, 0, 0) fn "main"(_: vec4<f32> location(0)) -> @t2 {
+    %0 = argument 0
+    %1 = global @g0
+    %2 = load %1
+    %3 = splat 4 %2
+    %4 = binary Multiply %0, %3
+    %5 = compose @t2(%4)
+    name %2 "s"
+    body {
+        emit %2..%6
+        return %5
+    }
+}
+```
+
+# Handles
+
+Each arena has its own prefix, followed by the handle's index:
+
+| Prefix | Arena                            |
+|--------|----------------------------------|
+| `@t`   | [`Module::types`]                |
+| `@c`   | [`Module::constants`]            |
+| `@o`   | [`Module::overrides`]            |
+| `@g`   | [`Module::global_variables`]     |
+| `@e`   | [`Module::global_expressions`]   |
+| `@f`   | [`Module::functions`]            |
+| `@d`   | [`Module::diagnostic_filters`]   |
+| `%`    | [`Function::expressions`]        |
+| `$`    | [`Function::local_variables`]    |
+
+Declarations carry the handle they introduce, and must appear in the order of
+their arena. References may refer to entries declared later.
+
+# Types
+
+A reference to a type with a name is written as its handle. A type without a
+name is written out in full, as in its declaration: `f32`, `vec3<u32>`,
+`mat4x4<f32>`, `atomic<u32>`, `ptr<function, f32>`, `value_ptr<private, f32>`,
+`array<f32, 4, stride=4>`, `array<f32, stride=4>` for runtime-sized arrays,
+`struct span=N { "member": type offset=N binding, ... }`,
+`image<D2 arrayed, sampled Float multi>`, `image<D2, depth>`,
+`image<D2, storage Rgba8Unorm (LOAD | STORE)>`, `sampler`,
+`sampler_comparison`, `acceleration_structure`, `ray_query`, and
+`binding_array<type, N>` or `binding_array<type>`.
+
+# Expressions and statements
+
+Expressions are written as a keyword followed by their operands, like
+`binary Add %1, %2` or `math Clamp(%0, %1, %2)`. Enumerations from the IR,
+such as operators, math functions, and built-ins, use the names of their Rust
+variants. Statements that produce a result expression are written as an
+assignment to that expression, like `%3 = call @f0(%1, %2)`.
+
+A function lists its local variables, its expressions, the names of its
+named expressions and its diagnostic filter scopes, and then its `body`.
+
+[`Module`]: crate::Module
+[`Module::types`]: crate::Module::types
+[`Module::constants`]: crate::Module::constants
+[`Module::overrides`]: crate::Module::overrides
+[`Module::global_variables`]: crate::Module::global_variables
+[`Module::global_expressions`]: crate::Module::global_expressions
+[`Module::functions`]: crate::Module::functions
+[`Module::diagnostic_filters`]: crate::Module::diagnostic_filters
+[`Function::expressions`]: crate::Function::expressions
+[`Function::local_variables`]: crate::Function::local_variables
+[`front::ir_text`]: crate::front::ir_text
+*/
+
+use std::fmt::{Error as FmtError, Write};
+
+use crate::diagnostic_filter::FilterableTriggeringRule;
+use crate::{
+    AddressSpace, Binding, Block, Expression, Function, Handle, ImageClass, Literal, Module,
+    Scalar, ScalarKind, Statement, TypeInner,
+};
+
+use super::INDENT;
+
+/// Write `module` in Naga's textual IR format.
+pub fn write_string(module: &Module) -> Result<String, FmtError> {
+    let mut writer = Writer {
+        out: String::new(),
+        module,
+    };
+    writer.write_module()?;
+    Ok(writer.out)
+}
+
+/// Which arena the expression handles being written refer to.
+#[derive(Clone, Copy)]
+enum Arena {
+    /// [`Module::global_expressions`], written as `@eN`.
+    ///
+    /// [`Module::global_expressions`]: crate::Module::global_expressions
+    Global,
+
+    /// A function's expressions, written as `%N`.
+    Function,
+}
+
+impl Arena {
+    const fn prefix(self) -> &'static str {
+        match self {
+            Self::Global => "@e",
+            Self::Function => "%",
+        }
+    }
+}
+
+struct Writer<'a> {
+    out: String,
+    module: &'a Module,
+}
+
+impl Writer<'_> {
+    fn write_module(&mut self) -> Result<(), FmtError> {
+        let module = self.module;
+
+        for (handle, ty) in module.types.iter() {
+            write!(self.out, "type @t{}", handle.index())?;
+            if let Some(ref name) = ty.name {
+                write!(self.out, " {name:?}")?;
+            }
+            self.out.write_str(" = ")?;
+            self.write_type_inner(&ty.inner, true)?;
+            writeln!(self.out)?;
+        }
+
+        let special = &module.special_types;
+        if let Some(ty) = special.ray_desc {
+            writeln!(self.out, "special ray_desc @t{}", ty.index())?;
+        }
+        if let Some(ty) = special.ray_intersection {
+            writeln!(self.out, "special ray_intersection @t{}", ty.index())?;
+        }
+        for (predeclared, &ty) in special.predeclared_types.iter() {
+            self.out.write_str("special predeclared ")?;
+            match *predeclared {
+                crate::PredeclaredType::AtomicCompareExchangeWeakResult(scalar) => {
+                    self.out.write_str("AtomicCompareExchangeWeakResult(")?;
+                    write_scalar(&mut self.out, scalar)?;
+                    self.out.write_str(")")?;
+                }
+                crate::PredeclaredType::ModfResult { size, width }
+                | crate::PredeclaredType::FrexpResult { size, width } => {
+                    let name = match *predeclared {
+                        crate::PredeclaredType::ModfResult { .. } => "ModfResult",
+                        _ => "FrexpResult",
+                    };
+                    match size {
+                        Some(size) => write!(self.out, "{name}({}, {width})", size as u8)?,
+                        None => write!(self.out, "{name}(_, {width})")?,
+                    }
+                }
+            }
+            writeln!(self.out, " @t{}", ty.index())?;
+        }
+
+        for (handle, expr) in module.global_expressions.iter() {
+            write!(self.out, "@e{} = ", handle.index())?;
+            self.write_expression(expr, Arena::Global)?;
+            writeln!(self.out)?;
+        }
+
+        for (handle, constant) in module.constants.iter() {
+            write!(self.out, "const @c{}", handle.index())?;
+            self.write_name(constant.name.as_ref())?;
+            self.out.write_str(": ")?;
+            self.write_type(constant.ty)?;
+            writeln!(self.out, " = @e{}", constant.init.index())?;
+        }
+
+        for (handle, o) in module.overrides.iter() {
+            write!(self.out, "override @o{}", handle.index())?;
+            self.write_name(o.name.as_ref())?;
+            if let Some(id) = o.id {
+                write!(self.out, " id({id})")?;
+            }
+            self.out.write_str(": ")?;
+            self.write_type(o.ty)?;
+            if let Some(init) = o.init {
+                write!(self.out, " = @e{}", init.index())?;
+            }
+            writeln!(self.out)?;
+        }
+
+        for (handle, var) in module.global_variables.iter() {
+            write!(self.out, "global @g{}", handle.index())?;
+            self.write_name(var.name.as_ref())?;
+            self.out.write_str(" ")?;
+            write_address_space(&mut self.out, var.space)?;
+            if let Some(ref binding) = var.binding {
+                write!(
+                    self.out,
+                    " group({}) binding({})",
+                    binding.group, binding.binding
+                )?;
+            }
+            self.out.write_str(": ")?;
+            self.write_type(var.ty)?;
+            if let Some(init) = var.init {
+                write!(self.out, " = @e{}", init.index())?;
+            }
+            writeln!(self.out)?;
+        }
+
+        for (handle, node) in module.diagnostic_filters.iter() {
+            write!(
+                self.out,
+                "diagnostic @d{} = {:?} ",
+                handle.index(),
+                node.inner.new_severity
+            )?;
+            match node.inner.triggering_rule {
+                FilterableTriggeringRule::Standard(rule) => write!(self.out, "{rule:?}")?,
+                FilterableTriggeringRule::Unknown(ref name) => {
+                    write!(self.out, "unknown {:?}", &**name)?
+                }
+                FilterableTriggeringRule::User(ref names) => {
+                    write!(self.out, "user {:?} {:?}", &*names[0], &*names[1])?
+                }
+            }
+            if let Some(parent) = node.parent {
+                write!(self.out, " parent @d{}", parent.index())?;
+            }
+            writeln!(self.out)?;
+        }
+        if let Some(leaf) = module.diagnostic_filter_leaf {
+            writeln!(self.out, "diagnostic_filter @d{}", leaf.index())?;
+        }
+
+        for (handle, function) in module.functions.iter() {
+            write!(self.out, "fn @f{}", handle.index())?;
+            self.write_function(function)?;
+        }
+
+        for entry_point in module.entry_points.iter() {
+            write!(
+                self.out,
+                "entry_point {:?} {:?}",
+                entry_point.stage, entry_point.name
+            )?;
+            if let Some(early_depth_test) = entry_point.early_depth_test {
+                self.out.write_str(" early_depth_test")?;
+                if let Some(conservative) = early_depth_test.conservative {
+                    write!(self.out, "({conservative:?})")?;
+                }
+            }
+            let [x, y, z] = entry_point.workgroup_size;
+            write!(self.out, " workgroup_size({x}, {y}, {z}) fn")?;
+            self.write_function(&entry_point.function)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the rest of a function, following `fn` and its handle, if any.
+    fn write_function(&mut self, function: &Function) -> Result<(), FmtError> {
+        self.write_name(function.name.as_ref())?;
+        self.out.write_str("(")?;
+        for (index, argument) in function.arguments.iter().enumerate() {
+            if index != 0 {
+                self.out.write_str(", ")?;
+            }
+            match argument.name {
+                Some(ref name) => write!(self.out, "{name:?}: ")?,
+                None => self.out.write_str("_: ")?,
+            }
+            self.write_type(argument.ty)?;
+            self.write_binding(argument.binding.as_ref())?;
+        }
+        self.out.write_str(")")?;
+        if let Some(ref result) = function.result {
+            self.out.write_str(" -> ")?;
+            self.write_type(result.ty)?;
+            self.write_binding(result.binding.as_ref())?;
+        }
+        if let Some(leaf) = function.diagnostic_filter_leaf {
+            write!(self.out, " diagnostic_filter @d{}", leaf.index())?;
+        }
+        writeln!(self.out, " {{")?;
+
+        for (handle, local) in function.local_variables.iter() {
+            write!(self.out, "{INDENT}local ${}", handle.index())?;
+            self.write_name(local.name.as_ref())?;
+            self.out.write_str(": ")?;
+            self.write_type(local.ty)?;
+            if let Some(init) = local.init {
+                write!(self.out, " = %{}", init.index())?;
+            }
+            writeln!(self.out)?;
+        }
+        for (handle, expr) in function.expressions.iter() {
+            write!(self.out, "{INDENT}%{} = ", handle.index())?;
+            self.write_expression(expr, Arena::Function)?;
+            writeln!(self.out)?;
+        }
+        for (handle, name) in function.named_expressions.iter() {
+            writeln!(self.out, "{INDENT}name %{} {name:?}", handle.index())?;
+        }
+        for scope in function.diagnostic_filter_scopes.iter() {
+            let range = scope.expressions.index_range();
+            writeln!(
+                self.out,
+                "{INDENT}diagnostic_scope %{}..%{} @d{}",
+                range.start,
+                range.end,
+                scope.leaf.index()
+            )?;
+        }
+
+        write!(self.out, "{INDENT}body ")?;
+        self.write_block(&function.body, 1)?;
+        writeln!(self.out)?;
+        writeln!(self.out, "}}")?;
+        Ok(())
+    }
+
+    /// Write `block`, braces included, as if nested `level` blocks deep.
+    fn write_block(&mut self, block: &Block, level: usize) -> Result<(), FmtError> {
+        writeln!(self.out, "{{")?;
+        for stmt in block.iter() {
+            for _ in 0..=level {
+                self.out.write_str(INDENT)?;
+            }
+            self.write_statement(stmt, level + 1)?;
+            writeln!(self.out)?;
+        }
+        for _ in 0..level {
+            self.out.write_str(INDENT)?;
+        }
+        self.out.write_str("}")
+    }
+
+    fn write_statement(&mut self, stmt: &Statement, level: usize) -> Result<(), FmtError> {
+        match *stmt {
+            Statement::Emit(ref range) => {
+                let range = range.index_range();
+                write!(self.out, "emit %{}..%{}", range.start, range.end)?;
+            }
+            Statement::Block(ref block) => {
+                self.out.write_str("block ")?;
+                self.write_block(block, level)?;
+            }
+            Statement::If {
+                condition,
+                ref accept,
+                ref reject,
+            } => {
+                write!(self.out, "if %{} ", condition.index())?;
+                self.write_block(accept, level)?;
+                self.out.write_str(" else ")?;
+                self.write_block(reject, level)?;
+            }
+            Statement::Switch {
+                selector,
+                ref cases,
+            } => {
+                writeln!(self.out, "switch %{} {{", selector.index())?;
+                for case in cases.iter() {
+                    for _ in 0..=level {
+                        self.out.write_str(INDENT)?;
+                    }
+                    match case.value {
+                        crate::SwitchValue::I32(value) => write!(self.out, "case i32 {value} ")?,
+                        crate::SwitchValue::U32(value) => write!(self.out, "case u32 {value} ")?,
+                        crate::SwitchValue::Default => self.out.write_str("default ")?,
+                    }
+                    if case.fall_through {
+                        self.out.write_str("fallthrough ")?;
+                    }
+                    self.write_block(&case.body, level + 1)?;
+                    writeln!(self.out)?;
+                }
+                for _ in 0..level {
+                    self.out.write_str(INDENT)?;
+                }
+                self.out.write_str("}")?;
+            }
+            Statement::Loop {
+                ref body,
+                ref continuing,
+                break_if,
+            } => {
+                self.out.write_str("loop ")?;
+                self.write_block(body, level)?;
+                self.out.write_str(" continuing ")?;
+                self.write_block(continuing, level)?;
+                if let Some(break_if) = break_if {
+                    write!(self.out, " break_if %{}", break_if.index())?;
+                }
+            }
+            Statement::Break => self.out.write_str("break")?,
+            Statement::Continue => self.out.write_str("continue")?,
+            Statement::Return { value } => {
+                self.out.write_str("return")?;
+                if let Some(value) = value {
+                    write!(self.out, " %{}", value.index())?;
+                }
+            }
+            Statement::Kill => self.out.write_str("kill")?,
+            Statement::Demote => self.out.write_str("demote")?,
+            Statement::Barrier(barrier) => {
+                self.out.write_str("barrier ")?;
+                write_flags(&mut self.out, barrier)?;
+            }
+            Statement::Store { pointer, value } => {
+                write!(self.out, "store %{}, %{}", pointer.index(), value.index())?;
+            }
+            Statement::ImageStore {
+                image,
+                coordinate,
+                array_index,
+                value,
+            } => {
+                write!(
+                    self.out,
+                    "image_store %{}, %{}, %{}",
+                    image.index(),
+                    coordinate.index(),
+                    value.index()
+                )?;
+                if let Some(array_index) = array_index {
+                    write!(self.out, " array_index %{}", array_index.index())?;
+                }
+            }
+            Statement::Atomic {
+                pointer,
+                ref fun,
+                value,
+                result,
+            } => {
+                self.write_result(result)?;
+                let compare = match *fun {
+                    crate::AtomicFunction::Exchange { compare } => {
+                        self.out.write_str("atomic Exchange")?;
+                        compare
+                    }
+                    _ => {
+                        write!(self.out, "atomic {fun:?}")?;
+                        None
+                    }
+                };
+                write!(self.out, " %{}, %{}", pointer.index(), value.index())?;
+                if let Some(compare) = compare {
+                    write!(self.out, " compare %{}", compare.index())?;
+                }
+            }
+            Statement::WorkGroupUniformLoad { pointer, result } => {
+                self.write_result(Some(result))?;
+                write!(self.out, "workgroup_uniform_load %{}", pointer.index())?;
+            }
+            Statement::Call {
+                function,
+                ref arguments,
+                result,
+            } => {
+                self.write_result(result)?;
+                write!(self.out, "call @f{}", function.index())?;
+                self.write_operand_list(arguments.iter().copied().map(Some), Arena::Function)?;
+            }
+            Statement::RayQuery { query, ref fun } => match *fun {
+                crate::RayQueryFunction::Initialize {
+                    acceleration_structure,
+                    descriptor,
+                } => write!(
+                    self.out,
+                    "ray_query initialize %{}, %{}, %{}",
+                    query.index(),
+                    acceleration_structure.index(),
+                    descriptor.index()
+                )?,
+                crate::RayQueryFunction::Proceed { result } => {
+                    self.write_result(Some(result))?;
+                    write!(self.out, "ray_query proceed %{}", query.index())?;
+                }
+                crate::RayQueryFunction::Terminate => {
+                    write!(self.out, "ray_query terminate %{}", query.index())?
+                }
+            },
+            Statement::SubgroupBallot { result, predicate } => {
+                self.write_result(Some(result))?;
+                self.out.write_str("subgroup_ballot")?;
+                if let Some(predicate) = predicate {
+                    write!(self.out, " %{}", predicate.index())?;
+                }
+            }
+            Statement::SubgroupGather {
+                mode,
+                argument,
+                result,
+            } => {
+                use crate::GatherMode as Gm;
+
+                self.write_result(Some(result))?;
+                self.out.write_str("subgroup_gather ")?;
+                match mode {
+                    Gm::BroadcastFirst => self.out.write_str("BroadcastFirst")?,
+                    Gm::QuadSwap(direction) => write!(self.out, "QuadSwap({direction:?})")?,
+                    Gm::Broadcast(index)
+                    | Gm::Shuffle(index)
+                    | Gm::ShuffleDown(index)
+                    | Gm::ShuffleUp(index)
+                    | Gm::ShuffleXor(index)
+                    | Gm::QuadBroadcast(index) => {
+                        let name = match mode {
+                            Gm::Broadcast(_) => "Broadcast",
+                            Gm::Shuffle(_) => "Shuffle",
+                            Gm::ShuffleDown(_) => "ShuffleDown",
+                            Gm::ShuffleUp(_) => "ShuffleUp",
+                            Gm::ShuffleXor(_) => "ShuffleXor",
+                            _ => "QuadBroadcast",
+                        };
+                        write!(self.out, "{name}(%{})", index.index())?;
+                    }
+                }
+                write!(self.out, " %{}", argument.index())?;
+            }
+            Statement::SubgroupCollectiveOperation {
+                op,
+                collective_op,
+                argument,
+                result,
+            } => {
+                self.write_result(Some(result))?;
+                write!(
+                    self.out,
+                    "subgroup_collective {op:?} {collective_op:?} %{}",
+                    argument.index()
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_result(&mut self, result: Option<Handle<Expression>>) -> Result<(), FmtError> {
+        if let Some(result) = result {
+            write!(self.out, "%{} = ", result.index())?;
+        }
+        Ok(())
+    }
+
+    fn write_expression(&mut self, expr: &Expression, arena: Arena) -> Result<(), FmtError> {
+        let prefix = arena.prefix();
+        match *expr {
+            Expression::Literal(literal) => {
+                self.out.write_str("literal ")?;
+                write_literal(&mut self.out, literal)?;
+            }
+            Expression::Constant(constant) => write!(self.out, "constant @c{}", constant.index())?,
+            Expression::Override(o) => write!(self.out, "override @o{}", o.index())?,
+            Expression::ZeroValue(ty) => {
+                self.out.write_str("zero ")?;
+                self.write_type(ty)?;
+            }
+            Expression::Compose { ty, ref components } => {
+                self.out.write_str("compose ")?;
+                self.write_type(ty)?;
+                self.write_operand_list(components.iter().copied().map(Some), arena)?;
+            }
+            Expression::Access { base, index } => write!(
+                self.out,
+                "access {prefix}{}, {prefix}{}",
+                base.index(),
+                index.index()
+            )?,
+            Expression::AccessIndex { base, index } => {
+                write!(self.out, "access_index {index} {prefix}{}", base.index())?
+            }
+            Expression::Splat { size, value } => {
+                write!(self.out, "splat {} {prefix}{}", size as u8, value.index())?
+            }
+            Expression::Swizzle {
+                size,
+                vector,
+                pattern,
+            } => {
+                write!(self.out, "swizzle {} ", size as u8)?;
+                for component in pattern {
+                    self.out.write_char(super::COMPONENTS[component as usize])?;
+                }
+                write!(self.out, " {prefix}{}", vector.index())?;
+            }
+            Expression::FunctionArgument(index) => write!(self.out, "argument {index}")?,
+            Expression::GlobalVariable(var) => write!(self.out, "global @g{}", var.index())?,
+            Expression::LocalVariable(local) => write!(self.out, "local ${}", local.index())?,
+            Expression::Load { pointer } => write!(self.out, "load {prefix}{}", pointer.index())?,
+            Expression::ImageSample {
+                image,
+                sampler,
+                gather,
+                coordinate,
+                array_index,
+                offset,
+                level,
+                depth_ref,
+            } => {
+                write!(
+                    self.out,
+                    "image_sample {prefix}{}, {prefix}{}, {prefix}{}",
+                    image.index(),
+                    sampler.index(),
+                    coordinate.index()
+                )?;
+                if let Some(array_index) = array_index {
+                    write!(self.out, " array_index {prefix}{}", array_index.index())?;
+                }
+                if let Some(offset) = offset {
+                    write!(self.out, " offset @e{}", offset.index())?;
+                }
+                self.out.write_str(" level ")?;
+                match level {
+                    crate::SampleLevel::Auto => self.out.write_str("auto")?,
+                    crate::SampleLevel::Zero => self.out.write_str("zero")?,
+                    crate::SampleLevel::Exact(h) => {
+                        write!(self.out, "exact {prefix}{}", h.index())?
+                    }
+                    crate::SampleLevel::Bias(h) => write!(self.out, "bias {prefix}{}", h.index())?,
+                    crate::SampleLevel::Gradient { x, y } => write!(
+                        self.out,
+                        "gradient {prefix}{}, {prefix}{}",
+                        x.index(),
+                        y.index()
+                    )?,
+                }
+                if let Some(depth_ref) = depth_ref {
+                    write!(self.out, " depth_ref {prefix}{}", depth_ref.index())?;
+                }
+                if let Some(gather) = gather {
+                    write!(self.out, " gather {gather:?}")?;
+                }
+            }
+            Expression::ImageLoad {
+                image,
+                coordinate,
+                array_index,
+                sample,
+                level,
+            } => {
+                write!(
+                    self.out,
+                    "image_load {prefix}{}, {prefix}{}",
+                    image.index(),
+                    coordinate.index()
+                )?;
+                for (keyword, operand) in [
+                    ("array_index", array_index),
+                    ("sample", sample),
+                    ("level", level),
+                ] {
+                    if let Some(operand) = operand {
+                        write!(self.out, " {keyword} {prefix}{}", operand.index())?;
+                    }
+                }
+            }
+            Expression::ImageQuery { image, query } => {
+                write!(self.out, "image_query {prefix}{} ", image.index())?;
+                match query {
+                    crate::ImageQuery::Size { level } => {
+                        self.out.write_str("size")?;
+                        if let Some(level) = level {
+                            write!(self.out, " level {prefix}{}", level.index())?;
+                        }
+                    }
+                    crate::ImageQuery::NumLevels => self.out.write_str("num_levels")?,
+                    crate::ImageQuery::NumLayers => self.out.write_str("num_layers")?,
+                    crate::ImageQuery::NumSamples => self.out.write_str("num_samples")?,
+                }
+            }
+            Expression::Unary { op, expr } => {
+                write!(self.out, "unary {op:?} {prefix}{}", expr.index())?
+            }
+            Expression::Binary { op, left, right } => write!(
+                self.out,
+                "binary {op:?} {prefix}{}, {prefix}{}",
+                left.index(),
+                right.index()
+            )?,
+            Expression::Select {
+                condition,
+                accept,
+                reject,
+            } => write!(
+                self.out,
+                "select {prefix}{}, {prefix}{}, {prefix}{}",
+                condition.index(),
+                accept.index(),
+                reject.index()
+            )?,
+            Expression::Derivative { axis, ctrl, expr } => write!(
+                self.out,
+                "derivative {axis:?} {ctrl:?} {prefix}{}",
+                expr.index()
+            )?,
+            Expression::Relational { fun, argument } => {
+                write!(self.out, "relational {fun:?} {prefix}{}", argument.index())?
+            }
+            Expression::Math {
+                fun,
+                arg,
+                arg1,
+                arg2,
+                arg3,
+            } => {
+                write!(self.out, "math {fun:?}")?;
+                let mut args = vec![Some(arg), arg1, arg2, arg3];
+                while let Some(&None) = args.last() {
+                    args.pop();
+                }
+                self.write_operand_list(args, arena)?;
+            }
+            Expression::As {
+                expr,
+                kind,
+                convert,
+            } => match convert {
+                Some(width) => write!(
+                    self.out,
+                    "convert {kind:?} {width} {prefix}{}",
+                    expr.index()
+                )?,
+                None => write!(self.out, "bitcast {kind:?} {prefix}{}", expr.index())?,
+            },
+            Expression::CallResult(function) => {
+                write!(self.out, "call_result @f{}", function.index())?
+            }
+            Expression::AtomicResult { ty, comparison } => {
+                self.out.write_str("atomic_result ")?;
+                self.write_type(ty)?;
+                if comparison {
+                    self.out.write_str(" comparison")?;
+                }
+            }
+            Expression::WorkGroupUniformLoadResult { ty } => {
+                self.out.write_str("workgroup_uniform_load_result ")?;
+                self.write_type(ty)?;
+            }
+            Expression::ArrayLength(expr) => {
+                write!(self.out, "array_length {prefix}{}", expr.index())?
+            }
+            Expression::RayQueryProceedResult => self.out.write_str("ray_query_proceed_result")?,
+            Expression::RayQueryGetIntersection { query, committed } => {
+                write!(
+                    self.out,
+                    "ray_query_get_intersection {prefix}{}",
+                    query.index()
+                )?;
+                if committed {
+                    self.out.write_str(" committed")?;
+                }
+            }
+            Expression::SubgroupBallotResult => self.out.write_str("subgroup_ballot_result")?,
+            Expression::SubgroupOperationResult { ty } => {
+                self.out.write_str("subgroup_operation_result ")?;
+                self.write_type(ty)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a parenthesized list of operands, with `_` for `None`.
+    fn write_operand_list(
+        &mut self,
+        operands: impl IntoIterator<Item = Option<Handle<Expression>>>,
+        arena: Arena,
+    ) -> Result<(), FmtError> {
+        self.out.write_str("(")?;
+        for (index, operand) in operands.into_iter().enumerate() {
+            if index != 0 {
+                self.out.write_str(", ")?;
+            }
+            match operand {
+                Some(operand) => write!(self.out, "{}{}", arena.prefix(), operand.index())?,
+                None => self.out.write_str("_")?,
+            }
+        }
+        self.out.write_str(")")
+    }
+
+    /// Write a reference to `ty`: its handle if it has a name, or its
+    /// definition otherwise.
+    fn write_type(&mut self, ty: Handle<crate::Type>) -> Result<(), FmtError> {
+        let module = self.module;
+        match module.types[ty] {
+            crate::Type { name: Some(_), .. } => write!(self.out, "@t{}", ty.index()),
+            crate::Type {
+                name: None,
+                ref inner,
+            } => self.write_type_inner(inner, false),
+        }
+    }
+
+    /// Write the definition of a type.
+    ///
+    /// If `multiline` is true, put each struct member on its own line.
+    fn write_type_inner(&mut self, inner: &TypeInner, multiline: bool) -> Result<(), FmtError> {
+        match *inner {
+            TypeInner::Scalar(scalar) => write_scalar(&mut self.out, scalar)?,
+            TypeInner::Vector { size, scalar } => {
+                write!(self.out, "vec{}<", size as u8)?;
+                write_scalar(&mut self.out, scalar)?;
+                self.out.write_str(">")?;
+            }
+            TypeInner::Matrix {
+                columns,
+                rows,
+                scalar,
+            } => {
+                write!(self.out, "mat{}x{}<", columns as u8, rows as u8)?;
+                write_scalar(&mut self.out, scalar)?;
+                self.out.write_str(">")?;
+            }
+            TypeInner::Atomic(scalar) => {
+                self.out.write_str("atomic<")?;
+                write_scalar(&mut self.out, scalar)?;
+                self.out.write_str(">")?;
+            }
+            TypeInner::Pointer { base, space } => {
+                self.out.write_str("ptr<")?;
+                write_address_space(&mut self.out, space)?;
+                self.out.write_str(", ")?;
+                self.write_type(base)?;
+                self.out.write_str(">")?;
+            }
+            TypeInner::ValuePointer {
+                size,
+                scalar,
+                space,
+            } => {
+                self.out.write_str("value_ptr<")?;
+                write_address_space(&mut self.out, space)?;
+                self.out.write_str(", ")?;
+                match size {
+                    Some(size) => {
+                        write!(self.out, "vec{}<", size as u8)?;
+                        write_scalar(&mut self.out, scalar)?;
+                        self.out.write_str(">")?;
+                    }
+                    None => write_scalar(&mut self.out, scalar)?,
+                }
+                self.out.write_str(">")?;
+            }
+            TypeInner::Array { base, size, stride } => {
+                self.out.write_str("array<")?;
+                self.write_type(base)?;
+                if let crate::ArraySize::Constant(count) = size {
+                    write!(self.out, ", {count}")?;
+                }
+                write!(self.out, ", stride={stride}>")?;
+            }
+            TypeInner::Struct { ref members, span } => {
+                write!(self.out, "struct span={span} {{")?;
+                for (index, member) in members.iter().enumerate() {
+                    if multiline {
+                        write!(self.out, "\n{INDENT}")?;
+                    } else if index != 0 {
+                        self.out.write_str(", ")?;
+                    } else {
+                        self.out.write_str(" ")?;
+                    }
+                    match member.name {
+                        Some(ref name) => write!(self.out, "{name:?}: ")?,
+                        None => self.out.write_str("_: ")?,
+                    }
+                    self.write_type(member.ty)?;
+                    write!(self.out, " offset={}", member.offset)?;
+                    self.write_binding(member.binding.as_ref())?;
+                    if multiline {
+                        self.out.write_str(",")?;
+                    }
+                }
+                if multiline {
+                    self.out.write_str("\n}")?;
+                } else if members.is_empty() {
+                    self.out.write_str("}")?;
+                } else {
+                    self.out.write_str(" }")?;
+                }
+            }
+            TypeInner::Image {
+                dim,
+                arrayed,
+                class,
+            } => {
+                write!(self.out, "image<{dim:?}")?;
+                if arrayed {
+                    self.out.write_str(" arrayed")?;
+                }
+                self.out.write_str(", ")?;
+                match class {
+                    ImageClass::Sampled { kind, multi } => {
+                        write!(self.out, "sampled {kind:?}")?;
+                        if multi {
+                            self.out.write_str(" multi")?;
+                        }
+                    }
+                    ImageClass::Depth { multi } => {
+                        self.out.write_str("depth")?;
+                        if multi {
+                            self.out.write_str(" multi")?;
+                        }
+                    }
+                    ImageClass::Storage { format, access } => {
+                        write!(self.out, "storage {format:?} ")?;
+                        write_flags(&mut self.out, access)?;
+                    }
+                }
+                self.out.write_str(">")?;
+            }
+            TypeInner::Sampler { comparison } => self.out.write_str(if comparison {
+                "sampler_comparison"
+            } else {
+                "sampler"
+            })?,
+            TypeInner::AccelerationStructure => self.out.write_str("acceleration_structure")?,
+            TypeInner::RayQuery => self.out.write_str("ray_query")?,
+            TypeInner::BindingArray { base, size } => {
+                self.out.write_str("binding_array<")?;
+                self.write_type(base)?;
+                if let crate::ArraySize::Constant(count) = size {
+                    write!(self.out, ", {count}")?;
+                }
+                self.out.write_str(">")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write `binding`, preceded by a space, if present.
+    fn write_binding(&mut self, binding: Option<&Binding>) -> Result<(), FmtError> {
+        match binding {
+            None => {}
+            Some(&Binding::BuiltIn(crate::BuiltIn::Position { invariant })) => {
+                self.out.write_str(" builtin(Position")?;
+                if invariant {
+                    self.out.write_str(", invariant")?;
+                }
+                self.out.write_str(")")?;
+            }
+            Some(&Binding::BuiltIn(built_in)) => write!(self.out, " builtin({built_in:?})")?,
+            Some(&Binding::Location {
+                location,
+                blend_src,
+                interpolation,
+                sampling,
+            }) => {
+                write!(self.out, " location({location})")?;
+                if let Some(interpolation) = interpolation {
+                    write!(self.out, " interpolate({interpolation:?})")?;
+                }
+                if let Some(sampling) = sampling {
+                    write!(self.out, " sampling({sampling:?})")?;
+                }
+                if let Some(blend_src) = blend_src {
+                    write!(self.out, " blend_src({blend_src})")?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write `name`, preceded by a space, if present.
+    fn write_name(&mut self, name: Option<&String>) -> Result<(), FmtError> {
+        if let Some(name) = name {
+            write!(self.out, " {name:?}")?;
+        }
+        Ok(())
+    }
+}
+
+fn write_scalar(out: &mut impl Write, scalar: Scalar) -> Result<(), FmtError> {
+    let bits = scalar.width as u32 * 8;
+    match scalar.kind {
+        ScalarKind::Sint => write!(out, "i{bits}"),
+        ScalarKind::Uint => write!(out, "u{bits}"),
+        ScalarKind::Float => write!(out, "f{bits}"),
+        ScalarKind::Bool => out.write_str("bool"),
+        ScalarKind::AbstractInt => out.write_str("abstract_int"),
+        ScalarKind::AbstractFloat => out.write_str("abstract_float"),
+    }
+}
+
+fn write_literal(out: &mut impl Write, literal: Literal) -> Result<(), FmtError> {
+    // `Debug` produces the shortest representation that parses back to the
+    // same value.
+    match literal {
+        Literal::F64(value) => write!(out, "f64 {value:?}"),
+        Literal::F32(value) => write!(out, "f32 {value:?}"),
+        Literal::F16(value) => write!(out, "f16 {:?}", f32::from(value)),
+        Literal::U32(value) => write!(out, "u32 {value}"),
+        Literal::I32(value) => write!(out, "i32 {value}"),
+        Literal::U64(value) => write!(out, "u64 {value}"),
+        Literal::I64(value) => write!(out, "i64 {value}"),
+        Literal::Bool(value) => write!(out, "bool {value}"),
+        Literal::AbstractInt(value) => write!(out, "abstract_int {value}"),
+        Literal::AbstractFloat(value) => write!(out, "abstract_float {value:?}"),
+    }
+}
+
+fn write_address_space(out: &mut impl Write, space: AddressSpace) -> Result<(), FmtError> {
+    match space {
+        AddressSpace::Function => out.write_str("function"),
+        AddressSpace::Private => out.write_str("private"),
+        AddressSpace::WorkGroup => out.write_str("workgroup"),
+        AddressSpace::Uniform => out.write_str("uniform"),
+        AddressSpace::Storage { access } => {
+            out.write_str("storage")?;
+            write_flags(out, access)
+        }
+        AddressSpace::Handle => out.write_str("handle"),
+        AddressSpace::PushConstant => out.write_str("push_constant"),
+    }
+}
+
+/// Write the names of the flags set in `flags`, like `(LOAD | STORE)`.
+fn write_flags<F: bitflags::Flags>(out: &mut impl Write, flags: F) -> Result<(), FmtError> {
+    out.write_str("(")?;
+    for (index, (name, _)) in flags.iter_names().enumerate() {
+        if index != 0 {
+            out.write_str(" | ")?;
+        }
+        out.write_str(name)?;
+    }
+    out.write_str(")")
+}
@@ -11,6 +11,8 @@ pub mod dot;
 pub mod glsl;
 #[cfg(hlsl_out)]
 pub mod hlsl;
+#[cfg(ir_text_out)]
+pub mod ir_text;
 #[cfg(msl_out)]
 pub mod msl;
 #[cfg(spv_out)]
@@ -20,6 +22,8 @@ pub mod wgsl;
 
 #[cfg(any(hlsl_out, msl_out, spv_out, glsl_out))]
 pub mod pipeline_constants;
+#[cfg(any(hlsl_out, spv_out, glsl_out))]
+pub mod pointer_arguments;
 
 #[cfg(any(hlsl_out, glsl_out))]
 mod continue_forward;
@@ -309,9 +313,18 @@ bitflags::bitflags! {
     }
 }
 
-/// The intersection test to use for ray queries.
+/// The kinds of ray query intersections, as stored in the `kind` member of
+/// Naga's [`RayIntersection`] struct.
+///
+/// Committed intersections are `None`, `Triangle` or `Generated`, the latter
+/// being a procedural hit on a bounding box. Candidate intersections are either
+/// `Triangle` or `BoundingBox`.
+///
+/// [`RayIntersection`]: crate::SpecialTypes::ray_intersection
 #[repr(u32)]
 pub enum RayIntersectionType {
+    None = 0,
     Triangle = 1,
+    Generated = 2,
     BoundingBox = 4,
 }
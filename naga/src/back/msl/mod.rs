@@ -2,7 +2,9 @@
 Backend for [MSL][msl] (Metal Shading Language).
 
 This backend does not support the [`SHADER_INT64_ATOMIC_ALL_OPS`][all-atom]
-capability.
+capability. Metal only provides `atomic_min_explicit` and `atomic_max_explicit`
+on `atomic_ulong`, and since there is no 64-bit compare-exchange either, the
+other operations can't be emulated with a compare-exchange loop.
 
 ## Binding model
 
@@ -89,7 +91,7 @@ enum ResolvedBinding {
     Attribute(u32),
     Color {
         location: u32,
-        second_blend_source: bool,
+        blend_src: Option<u32>,
     },
     User {
         prefix: &'static str,
@@ -404,18 +406,16 @@ impl Options {
                 location,
                 interpolation,
                 sampling,
-                second_blend_source,
+                blend_src,
             } => match mode {
                 LocationMode::VertexInput => Ok(ResolvedBinding::Attribute(location)),
                 LocationMode::FragmentOutput => {
-                    if second_blend_source && self.lang_version < (1, 2) {
-                        return Err(Error::UnsupportedAttribute(
-                            "second_blend_source".to_string(),
-                        ));
+                    if blend_src.is_some() && self.lang_version < (1, 2) {
+                        return Err(Error::UnsupportedAttribute("blend_src".to_string()));
                     }
                     Ok(ResolvedBinding::Color {
                         location,
-                        second_blend_source,
+                        blend_src,
                     })
                 }
                 LocationMode::VertexOutput | LocationMode::FragmentInput => {
@@ -577,10 +577,10 @@ impl ResolvedBinding {
             Self::Attribute(index) => write!(out, "attribute({index})")?,
             Self::Color {
                 location,
-                second_blend_source,
+                blend_src,
             } => {
-                if second_blend_source {
-                    write!(out, "color({location}) index(1)")?
+                if let Some(blend_src) = blend_src {
+                    write!(out, "color({location}) index({blend_src})")?
                 } else {
                     write!(out, "color({location})")?
                 }
@@ -162,9 +162,11 @@ impl<'a> Display for TypeContext<'a> {
                 write!(out, "{}::atomic_{}", NAMESPACE, scalar.to_msl_name())
             }
             crate::TypeInner::Vector { size, scalar } => put_numeric_type(out, scalar, &[size]),
-            crate::TypeInner::Matrix { columns, rows, .. } => {
-                put_numeric_type(out, crate::Scalar::F32, &[rows, columns])
-            }
+            crate::TypeInner::Matrix {
+                columns,
+                rows,
+                scalar,
+            } => put_numeric_type(out, scalar, &[rows, columns]),
             crate::TypeInner::Pointer { base, space } => {
                 let sub = Self {
                     handle: base,
@@ -393,6 +395,10 @@ impl crate::Scalar {
     fn to_msl_name(self) -> &'static str {
         use crate::ScalarKind as Sk;
         match self {
+            Self {
+                kind: Sk::Float,
+                width: 2,
+            } => "half",
             Self {
                 kind: Sk::Float,
                 width: _,
@@ -1401,6 +1407,14 @@ impl<W: Write> Writer<W> {
                         write!(self.out, "{value}{suffix}")?;
                     }
                 }
+                crate::Literal::F16(value) => {
+                    let suffix = if value.to_f32().fract() == 0.0 {
+                        ".0"
+                    } else {
+                        ""
+                    };
+                    write!(self.out, "{value}{suffix}h")?;
+                }
                 crate::Literal::U32(value) => {
                     write!(self.out, "{value}u")?;
                 }
@@ -1911,6 +1925,8 @@ impl<W: Write> Writer<W> {
                             "Correct TypeInner for dot product should be already validated"
                         ),
                     },
+                    Mf::Dot4I8Packed => "",
+                    Mf::Dot4U8Packed => "",
                     Mf::Outer => return Err(Error::UnsupportedCall(format!("{fun:?}"))),
                     Mf::Cross => "cross",
                     Mf::Distance => "distance",
@@ -1936,6 +1952,7 @@ impl<W: Write> Writer<W> {
                     Mf::Inverse => return Err(Error::UnsupportedCall(format!("{fun:?}"))),
                     Mf::Transpose => "transpose",
                     Mf::Determinant => "determinant",
+                    Mf::QuantizeToF16 => "",
                     // bits
                     Mf::CountTrailingZeros => "ctz",
                     Mf::CountLeadingZeros => "clz",
@@ -1953,6 +1970,8 @@ impl<W: Write> Writer<W> {
                     Mf::Pack2x16float => "",
                     Mf::Pack4xI8 => "",
                     Mf::Pack4xU8 => "",
+                    Mf::Pack4xI8Clamp => "",
+                    Mf::Pack4xU8Clamp => "",
                     // data unpacking
                     Mf::Unpack4x8snorm => "unpack_snorm4x8_to_float",
                     Mf::Unpack4x8unorm => "unpack_unorm4x8_to_float",
@@ -2050,6 +2069,17 @@ impl<W: Write> Writer<W> {
                         self.put_expression(arg, context, false)?;
                         write!(self.out, "))")?;
                     }
+                    Mf::QuantizeToF16 => {
+                        match *context.resolve_type(arg) {
+                            crate::TypeInner::Vector { size, .. } => {
+                                let size = back::vector_size_str(size);
+                                write!(self.out, "float{size}(half{size}(")?;
+                            }
+                            _ => write!(self.out, "float(half(")?,
+                        }
+                        self.put_expression(arg, context, false)?;
+                        write!(self.out, "))")?;
+                    }
                     Mf::ExtractBits => {
                         // The behavior of ExtractBits is undefined when offset + count > bit_width. We need
                         // to first sanitize the offset and count first. If we don't do this, Apple chips
@@ -2112,24 +2142,66 @@ impl<W: Write> Writer<W> {
                         write!(self.out, "{fun_name}")?;
                         self.put_call_parameters(iter::once(arg), context)?;
                     }
-                    fun @ (Mf::Pack4xI8 | Mf::Pack4xU8) => {
-                        let was_signed = fun == Mf::Pack4xI8;
+                    fun @ (Mf::Pack4xI8 | Mf::Pack4xU8 | Mf::Pack4xI8Clamp | Mf::Pack4xU8Clamp) => {
+                        let was_signed = matches!(fun, Mf::Pack4xI8 | Mf::Pack4xI8Clamp);
+                        let clamp_bounds = match fun {
+                            Mf::Pack4xI8Clamp => Some(("-128", "127")),
+                            Mf::Pack4xU8Clamp => Some(("0u", "255u")),
+                            _ => None,
+                        };
                         if was_signed {
                             write!(self.out, "uint(")?;
                         }
-                        write!(self.out, "(")?;
-                        self.put_expression(arg, context, true)?;
-                        write!(self.out, "[0] & 0xFF) | ((")?;
-                        self.put_expression(arg, context, true)?;
-                        write!(self.out, "[1] & 0xFF) << 8) | ((")?;
-                        self.put_expression(arg, context, true)?;
-                        write!(self.out, "[2] & 0xFF) << 16) | ((")?;
-                        self.put_expression(arg, context, true)?;
-                        write!(self.out, "[3] & 0xFF) << 24)")?;
+                        for i in 0..4 {
+                            if i == 0 {
+                                write!(self.out, "(")?;
+                            } else {
+                                write!(self.out, " | ((")?;
+                            }
+                            if clamp_bounds.is_some() {
+                                write!(self.out, "{NAMESPACE}::clamp(")?;
+                            }
+                            self.put_expression(arg, context, true)?;
+                            write!(self.out, "[{i}]")?;
+                            if let Some((min, max)) = clamp_bounds {
+                                write!(self.out, ", {min}, {max})")?;
+                            }
+                            write!(self.out, " & 0xFF)")?;
+                            if i != 0 {
+                                write!(self.out, " << {})", i * 8)?;
+                            }
+                        }
                         if was_signed {
                             write!(self.out, ")")?;
                         }
                     }
+                    fun @ (Mf::Dot4I8Packed | Mf::Dot4U8Packed) => {
+                        // Sign- or zero-extend each byte with a pair of shifts,
+                        // like `unpack4x{I,U}8` does, and sum up the products.
+                        let is_signed = fun == Mf::Dot4I8Packed;
+                        write!(self.out, "(")?;
+                        for i in 0..4 {
+                            if i != 0 {
+                                write!(self.out, " + ")?;
+                            }
+                            let shift = 24 - i * 8;
+                            for (j, arg) in [arg, arg1.unwrap()].into_iter().enumerate() {
+                                if j != 0 {
+                                    write!(self.out, " * ")?;
+                                }
+                                write!(self.out, "(")?;
+                                if is_signed {
+                                    write!(self.out, "int(")?;
+                                    self.put_expression(arg, context, true)?;
+                                    write!(self.out, ")")?;
+                                } else {
+                                    self.put_expression(arg, context, true)?;
+                                }
+                                write!(self.out, " << {shift} >> 24)")?;
+                            }
+                        }
+                        write!(self.out, ")")?;
+                    }
                     fun @ (Mf::Unpack4xI8 | Mf::Unpack4xU8) => {
                         if matches!(fun, Mf::Unpack4xU8) {
                             write!(self.out, "u")?;
@@ -2243,7 +2315,9 @@ impl<W: Write> Writer<W> {
                 }
 
                 if !committed {
-                    unimplemented!()
+                    return Err(Error::FeatureNotImplemented(
+                        "candidate intersection".to_string(),
+                    ));
                 }
                 let ty = context.module.special_types.ray_intersection.unwrap();
                 let type_name = &self.names[&NameKey::Type(ty)];
@@ -2770,9 +2844,15 @@ impl<W: Write> Writer<W> {
                             }
                         }
                     }
+                    crate::MathFunction::Dot4I8Packed | crate::MathFunction::Dot4U8Packed => {
+                        self.need_bake_expressions.insert(arg);
+                        self.need_bake_expressions.insert(arg1.unwrap());
+                    }
                     crate::MathFunction::FirstLeadingBit
                     | crate::MathFunction::Pack4xI8
                     | crate::MathFunction::Pack4xU8
+                    | crate::MathFunction::Pack4xI8Clamp
+                    | crate::MathFunction::Pack4xU8Clamp
                     | crate::MathFunction::Unpack4xI8
                     | crate::MathFunction::Unpack4xU8 => {
                         self.need_bake_expressions.insert(arg);
@@ -3079,7 +3159,7 @@ impl<W: Write> Writer<W> {
                 crate::Statement::Return { value: None } => {
                     writeln!(self.out, "{level}return;")?;
                 }
-                crate::Statement::Kill => {
+                crate::Statement::Kill | crate::Statement::Demote => {
                     writeln!(self.out, "{level}{NAMESPACE}::discard_fragment();")?;
                 }
                 crate::Statement::Barrier(flags) => {
@@ -3399,6 +3479,29 @@ impl<W: Write> Writer<W> {
                             crate::CollectiveOperation::InclusiveScan,
                             crate::SubgroupOperation::Mul,
                         ) => write!(self.out, "{NAMESPACE}::simd_prefix_inclusive_product(")?,
+                        // Metal's quad reductions are the only clustered ones it has.
+                        (crate::CollectiveOperation::ClusteredReduce(4), op) => {
+                            let fun = match op {
+                                crate::SubgroupOperation::Add => "sum",
+                                crate::SubgroupOperation::Mul => "product",
+                                crate::SubgroupOperation::Max => "max",
+                                crate::SubgroupOperation::Min => "min",
+                                crate::SubgroupOperation::And => "and",
+                                crate::SubgroupOperation::Or => "or",
+                                crate::SubgroupOperation::Xor => "xor",
+                                crate::SubgroupOperation::All | crate::SubgroupOperation::Any => {
+                                    unreachable!(
+                                        "validation rejects clustered `All` and `Any` reductions"
+                                    )
+                                }
+                            };
+                            write!(self.out, "{NAMESPACE}::quad_{fun}(")?
+                        }
+                        (crate::CollectiveOperation::ClusteredReduce(size), _) => {
+                            return Err(Error::FeatureNotImplemented(format!(
+                                "clustered subgroup reduction with cluster size {size}"
+                            )));
+                        }
                         _ => unimplemented!(),
                     }
                     self.put_expression(argument, &context.expression, true)?;
@@ -3432,6 +3535,12 @@ impl<W: Write> Writer<W> {
                         crate::GatherMode::ShuffleXor(_) => {
                             write!(self.out, "{NAMESPACE}::simd_shuffle_xor(")?;
                         }
+                        crate::GatherMode::QuadBroadcast(_) => {
+                            write!(self.out, "{NAMESPACE}::quad_broadcast(")?;
+                        }
+                        crate::GatherMode::QuadSwap(_) => {
+                            write!(self.out, "{NAMESPACE}::quad_shuffle_xor(")?;
+                        }
                     }
                     self.put_expression(argument, &context.expression, true)?;
                     match mode {
@@ -3440,10 +3549,21 @@ impl<W: Write> Writer<W> {
                         | crate::GatherMode::Shuffle(index)
                         | crate::GatherMode::ShuffleDown(index)
                         | crate::GatherMode::ShuffleUp(index)
-                        | crate::GatherMode::ShuffleXor(index) => {
+                        | crate::GatherMode::ShuffleXor(index)
+                        | crate::GatherMode::QuadBroadcast(index) => {
                             write!(self.out, ", ")?;
                             self.put_expression(index, &context.expression, true)?;
                         }
+                        crate::GatherMode::QuadSwap(direction) => {
+                            // Lanes of a quad are numbered in row-major order,
+                            // so swapping is xor-ing with the direction's mask.
+                            let mask = match direction {
+                                crate::Direction::X => 1,
+                                crate::Direction::Y => 2,
+                                crate::Direction::Diagonal => 3,
+                            };
+                            write!(self.out, ", {mask}u")?;
+                        }
                     }
                     writeln!(self.out, ");")?;
                 }
@@ -3807,18 +3927,17 @@ impl<W: Write> Writer<W> {
             match type_key {
                 &crate::PredeclaredType::ModfResult { size, width }
                 | &crate::PredeclaredType::FrexpResult { size, width } => {
+                    let scalar_name = crate::Scalar {
+                        kind: crate::ScalarKind::Float,
+                        width,
+                    }
+                    .to_msl_name();
                     let arg_type_name_owner;
                     let arg_type_name = if let Some(size) = size {
-                        arg_type_name_owner = format!(
-                            "{NAMESPACE}::{}{}",
-                            if width == 8 { "double" } else { "float" },
-                            size as u8
-                        );
+                        arg_type_name_owner = format!("{NAMESPACE}::{scalar_name}{}", size as u8);
                         &arg_type_name_owner
-                    } else if width == 8 {
-                        "double"
                     } else {
-                        "float"
+                        scalar_name
                     };
 
                     let other_type_name_owner;
@@ -5833,6 +5952,12 @@ template <typename A>
                 "{level}{NAMESPACE}::simdgroup_barrier({NAMESPACE}::mem_flags::mem_threadgroup);",
             )?;
         }
+        if flags.contains(crate::Barrier::TEXTURE) {
+            writeln!(
+                self.out,
+                "{level}{NAMESPACE}::threadgroup_barrier({NAMESPACE}::mem_flags::mem_texture);",
+            )?;
+        }
         Ok(())
     }
 }
@@ -6130,7 +6255,7 @@ impl crate::AtomicFunction {
             Self::Min => "min",
             Self::Max => "max",
             _ => Err(Error::FeatureNotImplemented(
-                "64-bit atomic operation other than min/max".to_string(),
+                "64-bit atomic operation other than min/max (Metal has no 64-bit read-modify-write or compare-exchange)".to_string(),
             ))?,
         })
     }
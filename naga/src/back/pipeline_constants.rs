@@ -3,8 +3,8 @@ use crate::{
     arena::HandleVec,
     proc::{ConstantEvaluator, ConstantEvaluatorError, Emitter},
     valid::{Capabilities, ModuleInfo, ValidationError, ValidationFlags, Validator},
-    Arena, Block, Constant, Expression, Function, Handle, Literal, Module, Override, Range, Scalar,
-    Span, Statement, TypeInner, WithSpan,
+    Block, Constant, Expression, Function, Handle, Literal, Module, Override, Range, Scalar,
+    ShaderStage, Span, Statement, TypeInner, WithSpan,
 };
 use std::{borrow::Cow, collections::HashSet, mem};
 use thiserror::Error;
@@ -29,6 +29,14 @@ pub enum PipelineConstantError {
 
 /// Replace all overrides in `module` with constants.
 ///
+/// If `entry_point` is given and the `compact` feature is enabled, first
+/// drop every other entry point from `module`, along with all the
+/// functions, globals, types, constants, and overrides that the remaining
+/// entry point doesn't use, as [`compact_entry_points`] does. Overrides
+/// removed this way don't need values in `pipeline_constants`. Modules
+/// with only one entry point are left as they are. Without the `compact`
+/// feature, `entry_point` is ignored.
+///
 /// If no changes are needed, this just returns `Cow::Borrowed`
 /// references to `module` and `module_info`. Otherwise, it clones
 /// `module`, edits its [`global_expressions`] arena to contain only
@@ -39,18 +47,32 @@ pub enum PipelineConstantError {
 /// arena, and the `global_expressions` arena contains only
 /// fully-evaluated expressions.
 ///
+/// [`compact_entry_points`]: crate::compact::compact_entry_points
 /// [`global_expressions`]: Module::global_expressions
 pub fn process_overrides<'a>(
     module: &'a Module,
     module_info: &'a ModuleInfo,
+    entry_point: Option<(ShaderStage, &str)>,
     pipeline_constants: &PipelineConstants,
 ) -> Result<(Cow<'a, Module>, Cow<'a, ModuleInfo>), PipelineConstantError> {
-    if module.overrides.is_empty() {
+    // There's nothing to gain from compacting a module with only one entry
+    // point: it's already what the pipeline uses, give or take some unused
+    // items.
+    let compact =
+        entry_point.is_some() && module.entry_points.len() > 1 && cfg!(feature = "compact");
+    if module.overrides.is_empty() && !compact {
         return Ok((Cow::Borrowed(module), Cow::Borrowed(module_info)));
     }
 
     let mut module = module.clone();
 
+    #[cfg(feature = "compact")]
+    if let (true, Some((stage, name))) = (compact, entry_point) {
+        crate::compact::compact_entry_points(&mut module, |ep| {
+            ep.stage == stage && ep.name == name
+        });
+    }
+
     // A map from override handles to the handles of the constants
     // we've replaced them with.
     let mut override_map = HandleVec::with_capacity(module.overrides.len());
@@ -306,7 +328,9 @@ fn process_function(
 
     adjust_block(&adjusted_local_expressions, &mut function.body);
 
-    filter_emits_in_block(&mut function.body, &function.expressions);
+    filter_emits_in_block(&mut function.body, &|expr| {
+        function.expressions[expr].needs_pre_emit()
+    });
 
     // Update local expression initializers.
     for (_, local) in function.local_variables.iter_mut() {
@@ -329,7 +353,10 @@ fn process_function(
 
 /// Replace every expression handle in `expr` with its counterpart
 /// given by `new_pos`.
-fn adjust_expr(new_pos: &HandleVec<Expression, Handle<Expression>>, expr: &mut Expression) {
+pub(super) fn adjust_expr(
+    new_pos: &HandleVec<Expression, Handle<Expression>>,
+    expr: &mut Expression,
+) {
     let adjust = |expr: &mut Handle<Expression>| {
         *expr = new_pos[*expr];
     };
@@ -534,7 +561,7 @@ fn adjust_expr(new_pos: &HandleVec<Expression, Handle<Expression>>, expr: &mut E
 
 /// Replace every expression handle in `block` with its counterpart
 /// given by `new_pos`.
-fn adjust_block(new_pos: &HandleVec<Expression, Handle<Expression>>, block: &mut Block) {
+pub(super) fn adjust_block(new_pos: &HandleVec<Expression, Handle<Expression>>, block: &mut Block) {
     for stmt in block.iter_mut() {
         adjust_stmt(new_pos, stmt);
     }
@@ -668,12 +695,13 @@ fn adjust_stmt(new_pos: &HandleVec<Expression, Handle<Expression>>, stmt: &mut S
             ref mut result,
         } => {
             match *mode {
-                crate::GatherMode::BroadcastFirst => {}
+                crate::GatherMode::BroadcastFirst | crate::GatherMode::QuadSwap(_) => {}
                 crate::GatherMode::Broadcast(ref mut index)
                 | crate::GatherMode::Shuffle(ref mut index)
                 | crate::GatherMode::ShuffleDown(ref mut index)
                 | crate::GatherMode::ShuffleUp(ref mut index)
-                | crate::GatherMode::ShuffleXor(ref mut index) => {
+                | crate::GatherMode::ShuffleXor(ref mut index)
+                | crate::GatherMode::QuadBroadcast(ref mut index) => {
                     adjust(index);
                 }
             }
@@ -711,7 +739,11 @@ fn adjust_stmt(new_pos: &HandleVec<Expression, Handle<Expression>>, stmt: &mut S
                 crate::RayQueryFunction::Terminate => {}
             }
         }
-        Statement::Break | Statement::Continue | Statement::Kill | Statement::Barrier(_) => {}
+        Statement::Break
+        | Statement::Continue
+        | Statement::Kill
+        | Statement::Demote
+        | Statement::Barrier(_) => {}
     }
 }
 
@@ -726,19 +758,21 @@ fn adjust_stmt(new_pos: &HandleVec<Expression, Handle<Expression>>, stmt: &mut S
 ///
 /// This function rewrites all [`Emit`] statements into zero or more new
 /// [`Emit`] statements covering only those expressions in the original range
-/// that are not pre-emitted.
+/// for which `skip` returns false. `process_function` skips pre-emitted
+/// expressions; pointer argument specialization skips the expressions it has
+/// already emitted at the top of the function.
 ///
 /// [`Emit`]: Statement::Emit
 /// [`needs_pre_emit`]: Expression::needs_pre_emit
 /// [`Override`]: Expression::Override
-fn filter_emits_in_block(block: &mut Block, expressions: &Arena<Expression>) {
+pub(super) fn filter_emits_in_block(block: &mut Block, skip: &impl Fn(Handle<Expression>) -> bool) {
     let original = mem::replace(block, Block::with_capacity(block.len()));
     for (stmt, span) in original.span_into_iter() {
         match stmt {
             Statement::Emit(range) => {
                 let mut current = None;
                 for expr_h in range {
-                    if expressions[expr_h].needs_pre_emit() {
+                    if skip(expr_h) {
                         if let Some((first, last)) = current {
                             block.push(Statement::Emit(Range::new_from_bounds(first, last)), span);
                         }
@@ -755,7 +789,7 @@ fn filter_emits_in_block(block: &mut Block, expressions: &Arena<Expression>) {
                 }
             }
             Statement::Block(mut child) => {
-                filter_emits_in_block(&mut child, expressions);
+                filter_emits_in_block(&mut child, skip);
                 block.push(Statement::Block(child), span);
             }
             Statement::If {
@@ -763,8 +797,8 @@ fn filter_emits_in_block(block: &mut Block, expressions: &Arena<Expression>) {
                 mut accept,
                 mut reject,
             } => {
-                filter_emits_in_block(&mut accept, expressions);
-                filter_emits_in_block(&mut reject, expressions);
+                filter_emits_in_block(&mut accept, skip);
+                filter_emits_in_block(&mut reject, skip);
                 block.push(
                     Statement::If {
                         condition,
@@ -779,7 +813,7 @@ fn filter_emits_in_block(block: &mut Block, expressions: &Arena<Expression>) {
                 mut cases,
             } => {
                 for case in &mut cases {
-                    filter_emits_in_block(&mut case.body, expressions);
+                    filter_emits_in_block(&mut case.body, skip);
                 }
                 block.push(Statement::Switch { selector, cases }, span);
             }
@@ -788,8 +822,8 @@ fn filter_emits_in_block(block: &mut Block, expressions: &Arena<Expression>) {
                 mut continuing,
                 break_if,
             } => {
-                filter_emits_in_block(&mut body, expressions);
-                filter_emits_in_block(&mut continuing, expressions);
+                filter_emits_in_block(&mut body, skip);
+                filter_emits_in_block(&mut continuing, skip);
                 block.push(
                     Statement::Loop {
                         body,
@@ -853,6 +887,19 @@ fn map_value_to_literal(value: f64, scalar: Scalar) -> Result<Literal, PipelineC
 
             Ok(Literal::F32(value))
         }
+        Scalar::F16 => {
+            // Like `f32`, but rounding to the nearest `f16`.
+            if !value.is_finite() {
+                return Err(PipelineConstantError::SrcNeedsToBeFinite);
+            }
+
+            let value = half::f16::from_f64(value);
+            if !value.is_finite() {
+                return Err(PipelineConstantError::DstRangeTooSmall);
+            }
+
+            Ok(Literal::F16(value))
+        }
         Scalar::F64 => {
             // https://webidl.spec.whatwg.org/#js-double
             if !value.is_finite() {
@@ -880,7 +927,13 @@ fn test_map_value_to_literal() {
         assert_eq!(map_value_to_literal(value, Scalar::BOOL), res);
     }
 
-    for scalar in [Scalar::I32, Scalar::U32, Scalar::F32, Scalar::F64] {
+    for scalar in [
+        Scalar::I32,
+        Scalar::U32,
+        Scalar::F16,
+        Scalar::F32,
+        Scalar::F64,
+    ] {
         for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
             let res = Err(PipelineConstantError::SrcNeedsToBeFinite);
             assert_eq!(map_value_to_literal(value, scalar), res);
@@ -949,6 +1002,28 @@ fn test_map_value_to_literal() {
         Err(PipelineConstantError::DstRangeTooSmall)
     );
 
+    // f16
+    assert_eq!(
+        map_value_to_literal(f64::from(half::f16::MIN), Scalar::F16),
+        Ok(Literal::F16(half::f16::MIN))
+    );
+    assert_eq!(
+        map_value_to_literal(f64::from(half::f16::MAX), Scalar::F16),
+        Ok(Literal::F16(half::f16::MAX))
+    );
+    assert_eq!(
+        map_value_to_literal(65519.0, Scalar::F16),
+        Ok(Literal::F16(half::f16::MAX))
+    );
+    assert_eq!(
+        map_value_to_literal(-65520.0, Scalar::F16),
+        Err(PipelineConstantError::DstRangeTooSmall)
+    );
+    assert_eq!(
+        map_value_to_literal(65520.0, Scalar::F16),
+        Err(PipelineConstantError::DstRangeTooSmall)
+    );
+
     // f64
     assert_eq!(
         map_value_to_literal(f64::MIN, Scalar::F64),
@@ -0,0 +1,487 @@
+/*!
+Specialize functions that take pointers to global variables.
+
+WGSL's `unrestricted_pointer_parameters` language extension lets functions
+take pointers in the [`Uniform`], [`Storage`] and [`WorkGroup`] address
+spaces as arguments. Some targets can't express such arguments: GLSL and HLSL
+have no way to pass a reference to a buffer or to shared memory, and SPIR-V's
+logical addressing model only accepts whole variables as pointer operands of
+`OpFunctionCall`, and rejects `Uniform` pointers entirely.
+
+[`specialize_pointer_arguments`] rewrites a module so that no function takes
+such a pointer. Every function that does is replaced by one copy per distinct
+pointer its callers pass: within each copy, the pointer argument is replaced by
+an access chain rooted at the global variable it points into. Constant indices
+in the chain are baked into the copy, while dynamic indices are passed as
+extra arguments in place of the pointer.
+
+For example, given:
+
+```wgsl
+fn f(p: ptr<storage, vec4<f32>, read_write>) { (*p).x = 1.0; }
+
+fn g(i: u32) {
+    f(&buf.items[i]);
+    f(&other.value);
+}
+```
+
+the module is rewritten as if it had been written:
+
+```wgsl
+fn f(p_index: u32) { buf.items[p_index].x = 1.0; }
+fn f_1() { other.value.x = 1.0; }
+
+fn g(i: u32) {
+    f(i);
+    f_1();
+}
+```
+
+[`Uniform`]: AddressSpace::Uniform
+[`Storage`]: AddressSpace::Storage
+[`WorkGroup`]: AddressSpace::WorkGroup
+*/
+
+use super::pipeline_constants::{adjust_block, adjust_expr, filter_emits_in_block};
+use crate::{
+    arena::{HandleSet, HandleVec},
+    front::Typifier,
+    proc::{ResolveContext, ResolveError},
+    valid::{Capabilities, ModuleInfo, ValidationError, ValidationFlags, Validator},
+    AddressSpace, Arena, Block, Expression, FastHashMap, Function, FunctionArgument,
+    GlobalVariable, Handle, Module, Statement, Type, WithSpan,
+};
+use std::{borrow::Cow, mem};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum PointerArgumentError {
+    #[error(
+        "Argument {index} of a call to function {function:?} does not point into a global variable"
+    )]
+    UnsupportedPointer { function: String, index: usize },
+    #[error(transparent)]
+    ResolveError(#[from] ResolveError),
+    #[error(transparent)]
+    ValidationError(#[from] WithSpan<ValidationError>),
+}
+
+/// Return true if pointers in `space` can't be passed to functions directly.
+const fn needs_specialization(space: AddressSpace) -> bool {
+    matches!(
+        space,
+        AddressSpace::Uniform | AddressSpace::Storage { .. } | AddressSpace::WorkGroup
+    )
+}
+
+/// Return true if `function` takes a pointer to a global variable as an argument.
+fn takes_global_pointer(module: &Module, function: &Function) -> bool {
+    function.arguments.iter().any(|argument| {
+        module.types[argument.ty]
+            .inner
+            .pointer_space()
+            .is_some_and(needs_specialization)
+    })
+}
+
+/// Return true if any function in `module` takes a pointer in the
+/// [`Uniform`], [`Storage`] or [`WorkGroup`] address space as an argument.
+///
+/// Backends that can't handle such arguments require the module to be
+/// processed by [`specialize_pointer_arguments`] first.
+///
+/// [`Uniform`]: AddressSpace::Uniform
+/// [`Storage`]: AddressSpace::Storage
+/// [`WorkGroup`]: AddressSpace::WorkGroup
+pub fn has_global_pointer_arguments(module: &Module) -> bool {
+    module
+        .functions
+        .iter()
+        .any(|(_, function)| takes_global_pointer(module, function))
+}
+
+/// Replace all functions in `module` that take pointers to global variables
+/// with specialized copies.
+///
+/// If no changes are needed, this just returns `Cow::Borrowed`
+/// references to `module` and `module_info`. Otherwise, it clones
+/// `module`, rewrites its functions as described in the [module
+/// documentation], and returns `Cow::Owned` values holding the new
+/// module and its validation results.
+///
+/// Functions taking pointers to global variables that are never called
+/// are removed from the module.
+///
+/// [module documentation]: self
+pub fn specialize_pointer_arguments<'a>(
+    module: &'a Module,
+    module_info: &'a ModuleInfo,
+) -> Result<(Cow<'a, Module>, Cow<'a, ModuleInfo>), PointerArgumentError> {
+    if !has_global_pointer_arguments(module) {
+        return Ok((Cow::Borrowed(module), Cow::Borrowed(module_info)));
+    }
+
+    let mut module = module.clone();
+    let original = mem::take(&mut module.functions);
+    let mut entry_points = mem::take(&mut module.entry_points);
+
+    let mut specializer = Specializer {
+        module: &mut module,
+        original: &original,
+        function_map: HandleVec::with_capacity(original.len()),
+        specializations: FastHashMap::default(),
+    };
+
+    // Functions may only call functions that precede them in the arena, so
+    // by the time we reach a call, its callee has already been mapped.
+    for (handle, function) in original.iter() {
+        let new_handle = if takes_global_pointer(specializer.module, function) {
+            // Only specialized copies of this function will be kept.
+            None
+        } else {
+            let mut function = function.clone();
+            specializer.rewrite_calls(&mut function)?;
+            Some(
+                specializer
+                    .module
+                    .functions
+                    .append(function, original.get_span(handle)),
+            )
+        };
+        specializer.function_map.insert(handle, new_handle);
+    }
+
+    for entry_point in entry_points.iter_mut() {
+        specializer.rewrite_calls(&mut entry_point.function)?;
+    }
+    module.entry_points = entry_points;
+
+    // We've added, removed and reordered functions, so recompute
+    // all the validation results.
+    let mut validator = Validator::new(ValidationFlags::all(), Capabilities::all());
+    let module_info = validator.validate_no_overrides(&module)?;
+
+    Ok((Cow::Owned(module), Cow::Owned(module_info)))
+}
+
+/// One step of the access chain leading from a global variable to
+/// the pointer passed as an argument.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Step {
+    /// An [`AccessIndex`] with the given index.
+    ///
+    /// [`AccessIndex`]: Expression::AccessIndex
+    Constant(u32),
+
+    /// An [`Access`] whose index is passed as an argument of the given type.
+    ///
+    /// [`Access`]: Expression::Access
+    Dynamic(Handle<Type>),
+}
+
+/// The pointer passed for a specialized argument.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Root {
+    global: Handle<GlobalVariable>,
+    steps: Vec<Step>,
+}
+
+/// Identifies one specialized copy of a function.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Key {
+    /// The function being specialized, in the original arena.
+    function: Handle<Function>,
+
+    /// For each argument, the pointer it is specialized on, if any.
+    roots: Vec<Option<Root>>,
+}
+
+struct Specializer<'a> {
+    /// The module being rewritten, whose function arena we're building.
+    module: &'a mut Module,
+
+    /// The module's original function arena.
+    original: &'a Arena<Function>,
+
+    /// A map from original function handles to their counterparts in the
+    /// new arena, or `None` for functions that need specialization.
+    function_map: HandleVec<Function, Option<Handle<Function>>>,
+
+    /// The specialized copies of functions we've already built.
+    specializations: FastHashMap<Key, Handle<Function>>,
+}
+
+impl Specializer<'_> {
+    /// Point all calls in `function` to the new function arena,
+    /// specializing callees as needed.
+    ///
+    /// Any [`CallResult`] expressions in `function` must still refer to the
+    /// original function arena.
+    ///
+    /// [`CallResult`]: Expression::CallResult
+    fn rewrite_calls(&mut self, function: &mut Function) -> Result<(), PointerArgumentError> {
+        let mut typifier = Typifier::new();
+        if let Some((last, _)) = function.expressions.iter().next_back() {
+            let resolve_ctx = ResolveContext {
+                functions: self.original,
+                ..ResolveContext::with_locals(
+                    self.module,
+                    &function.local_variables,
+                    &function.arguments,
+                )
+            };
+            typifier.grow(last, &function.expressions, &resolve_ctx)?;
+        }
+
+        let mut body = mem::take(&mut function.body);
+        self.rewrite_block(&mut body, function, &typifier)?;
+        function.body = body;
+        Ok(())
+    }
+
+    fn rewrite_block(
+        &mut self,
+        block: &mut Block,
+        function: &mut Function,
+        typifier: &Typifier,
+    ) -> Result<(), PointerArgumentError> {
+        for statement in block.iter_mut() {
+            match *statement {
+                Statement::Block(ref mut block) => self.rewrite_block(block, function, typifier)?,
+                Statement::If {
+                    ref mut accept,
+                    ref mut reject,
+                    ..
+                } => {
+                    self.rewrite_block(accept, function, typifier)?;
+                    self.rewrite_block(reject, function, typifier)?;
+                }
+                Statement::Switch { ref mut cases, .. } => {
+                    for case in cases.iter_mut() {
+                        self.rewrite_block(&mut case.body, function, typifier)?;
+                    }
+                }
+                Statement::Loop {
+                    ref mut body,
+                    ref mut continuing,
+                    ..
+                } => {
+                    self.rewrite_block(body, function, typifier)?;
+                    self.rewrite_block(continuing, function, typifier)?;
+                }
+                Statement::Call {
+                    function: ref mut callee,
+                    ref mut arguments,
+                    result,
+                } => {
+                    let originals = self.original;
+                    let original = &originals[*callee];
+                    *callee = if takes_global_pointer(self.module, original) {
+                        let mut roots = Vec::with_capacity(arguments.len());
+                        let mut new_arguments = Vec::with_capacity(arguments.len());
+                        for (index, (&argument, parameter)) in
+                            arguments.iter().zip(original.arguments.iter()).enumerate()
+                        {
+                            let specialize = self.module.types[parameter.ty]
+                                .inner
+                                .pointer_space()
+                                .is_some_and(needs_specialization);
+                            if specialize {
+                                let mut steps = Vec::new();
+                                let global = self
+                                    .trace(
+                                        argument,
+                                        function,
+                                        typifier,
+                                        &mut steps,
+                                        &mut new_arguments,
+                                    )
+                                    .ok_or_else(|| PointerArgumentError::UnsupportedPointer {
+                                        function: original.name.clone().unwrap_or_default(),
+                                        index,
+                                    })?;
+                                roots.push(Some(Root { global, steps }));
+                            } else {
+                                roots.push(None);
+                                new_arguments.push(argument);
+                            }
+                        }
+                        *arguments = new_arguments;
+                        self.specialize(Key {
+                            function: *callee,
+                            roots,
+                        })?
+                    } else {
+                        self.function_map[*callee]
+                            .expect("callee should precede its caller in the function arena")
+                    };
+                    if let Some(result) = result {
+                        *function.expressions.get_mut(result) = Expression::CallResult(*callee);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Follow the access chain `pointer` back to the global variable it
+    /// points into.
+    ///
+    /// Append the chain's steps to `steps`, and the indices of its
+    /// dynamic steps to `indices`. Return `None` if `pointer` isn't an
+    /// access chain rooted at a global variable.
+    fn trace(
+        &mut self,
+        pointer: Handle<Expression>,
+        function: &Function,
+        typifier: &Typifier,
+        steps: &mut Vec<Step>,
+        indices: &mut Vec<Handle<Expression>>,
+    ) -> Option<Handle<GlobalVariable>> {
+        match function.expressions[pointer] {
+            Expression::GlobalVariable(global) => Some(global),
+            Expression::AccessIndex { base, index } => {
+                let global = self.trace(base, function, typifier, steps, indices)?;
+                steps.push(Step::Constant(index));
+                Some(global)
+            }
+            Expression::Access { base, index } => {
+                let global = self.trace(base, function, typifier, steps, indices)?;
+                let ty = typifier.register_type(index, &mut self.module.types);
+                steps.push(Step::Dynamic(ty));
+                indices.push(index);
+                Some(global)
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the handle of the function specialized as described by `key`,
+    /// building it if necessary.
+    fn specialize(&mut self, key: Key) -> Result<Handle<Function>, PointerArgumentError> {
+        if let Some(&handle) = self.specializations.get(&key) {
+            return Ok(handle);
+        }
+
+        let originals = self.original;
+        let original = &originals[key.function];
+        let mut function = original.clone();
+        let mut expressions = mem::take(&mut function.expressions);
+
+        // Build the new argument list. For each original argument, record the
+        // index of its replacement, or of the first of its dynamic indices.
+        let mut argument_map = Vec::with_capacity(original.arguments.len());
+        function.arguments.clear();
+        for (argument, root) in original.arguments.iter().zip(key.roots.iter()) {
+            argument_map.push(function.arguments.len() as u32);
+            match *root {
+                Some(ref root) => {
+                    for step in root.steps.iter() {
+                        if let Step::Dynamic(ty) = *step {
+                            function.arguments.push(FunctionArgument {
+                                name: argument.name.as_ref().map(|name| format!("{name}_index")),
+                                ty,
+                                binding: None,
+                            });
+                        }
+                    }
+                }
+                None => function.arguments.push(argument.clone()),
+            }
+        }
+
+        // Rebuild the expression arena, replacing specialized arguments
+        // with access chains.
+        let mut expression_map = HandleVec::with_capacity(expressions.len());
+        let mut emits = Block::new();
+        let mut emitted = HandleSet::new();
+        for (old_handle, mut expression, span) in expressions.drain() {
+            let new_handle = match expression {
+                Expression::FunctionArgument(index) => {
+                    let new_index = argument_map[index as usize];
+                    match key.roots[index as usize] {
+                        Some(ref root) => {
+                            let mut pointer = function
+                                .expressions
+                                .append(Expression::GlobalVariable(root.global), span);
+                            let mut indices = Vec::new();
+                            for step in root.steps.iter() {
+                                if let Step::Dynamic(_) = *step {
+                                    let argument = new_index + indices.len() as u32;
+                                    indices.push(
+                                        function
+                                            .expressions
+                                            .append(Expression::FunctionArgument(argument), span),
+                                    );
+                                }
+                            }
+
+                            let start = function.expressions.len();
+                            let mut indices = indices.into_iter();
+                            for step in root.steps.iter() {
+                                let access = match *step {
+                                    Step::Constant(index) => Expression::AccessIndex {
+                                        base: pointer,
+                                        index,
+                                    },
+                                    Step::Dynamic(_) => Expression::Access {
+                                        base: pointer,
+                                        index: indices.next().unwrap(),
+                                    },
+                                };
+                                pointer = function.expressions.append(access, span);
+                            }
+                            if function.expressions.len() > start {
+                                emitted.insert_iter(function.expressions.range_from(start));
+                                emits.push(
+                                    Statement::Emit(function.expressions.range_from(start)),
+                                    span,
+                                );
+                            }
+                            pointer
+                        }
+                        None => function
+                            .expressions
+                            .append(Expression::FunctionArgument(new_index), span),
+                    }
+                }
+                _ => {
+                    adjust_expr(&expression_map, &mut expression);
+                    function.expressions.append(expression, span)
+                }
+            };
+            expression_map.insert(old_handle, new_handle);
+        }
+
+        // Emit statements that covered the argument now cover its access
+        // chain, which is already emitted at the top of the function.
+        adjust_block(&expression_map, &mut function.body);
+        filter_emits_in_block(&mut function.body, &|expr| emitted.contains(expr));
+        emits.append(&mut function.body);
+        function.body = emits;
+
+        for (_, local) in function.local_variables.iter_mut() {
+            if let Some(ref mut init) = local.init {
+                *init = expression_map[*init];
+            }
+        }
+
+        let named_expressions = mem::take(&mut function.named_expressions);
+        for (handle, name) in named_expressions {
+            function
+                .named_expressions
+                .insert(expression_map[handle], name);
+        }
+
+        // Specializing our arguments may have made our own calls specializable.
+        self.rewrite_calls(&mut function)?;
+
+        let span = self.original.get_span(key.function);
+        let handle = self.module.functions.append(function, span);
+        self.specializations.insert(key, handle);
+        Ok(handle)
+    }
+}
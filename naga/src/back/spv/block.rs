@@ -1032,6 +1032,12 @@ impl<'w> BlockContext<'w> {
                         arg0_id,
                     )),
                     Mf::Determinant => MathOp::Ext(spirv::GLOp::Determinant),
+                    Mf::QuantizeToF16 => MathOp::Custom(Instruction::unary(
+                        spirv::Op::QuantizeToF16,
+                        result_type_id,
+                        id,
+                        arg0_id,
+                    )),
                     Mf::ReverseBits => MathOp::Custom(Instruction::unary(
                         spirv::Op::BitReverse,
                         result_type_id,
@@ -1276,10 +1282,10 @@ impl<'w> BlockContext<'w> {
                     Mf::Pack2x16float => MathOp::Ext(spirv::GLOp::PackHalf2x16),
                     Mf::Pack2x16unorm => MathOp::Ext(spirv::GLOp::PackUnorm2x16),
                     Mf::Pack2x16snorm => MathOp::Ext(spirv::GLOp::PackSnorm2x16),
-                    fun @ (Mf::Pack4xI8 | Mf::Pack4xU8) => {
+                    fun @ (Mf::Pack4xI8 | Mf::Pack4xU8 | Mf::Pack4xI8Clamp | Mf::Pack4xU8Clamp) => {
                         let (int_type, is_signed) = match fun {
-                            Mf::Pack4xI8 => (crate::ScalarKind::Sint, true),
-                            Mf::Pack4xU8 => (crate::ScalarKind::Uint, false),
+                            Mf::Pack4xI8 | Mf::Pack4xI8Clamp => (crate::ScalarKind::Sint, true),
+                            Mf::Pack4xU8 | Mf::Pack4xU8Clamp => (crate::ScalarKind::Uint, false),
                             _ => unreachable!(),
                         };
                         let uint_type_id = self.get_type_id(LookupType::Local(LocalType::Numeric(
@@ -1293,6 +1299,52 @@ impl<'w> BlockContext<'w> {
                             }),
                         )));
 
+                        // Clamp the components into the range of an 8-bit integer first.
+                        let clamp_bounds = match fun {
+                            Mf::Pack4xI8Clamp => Some((
+                                spirv::GLOp::SClamp,
+                                crate::Literal::I32(-128),
+                                crate::Literal::I32(127),
+                            )),
+                            Mf::Pack4xU8Clamp => Some((
+                                spirv::GLOp::UClamp,
+                                crate::Literal::U32(0),
+                                crate::Literal::U32(255),
+                            )),
+                            _ => None,
+                        };
+                        let arg0_id = match clamp_bounds {
+                            Some((clamp_op, min, max)) => {
+                                let vec_type_id =
+                                    self.get_expression_type_id(&self.fun_info[arg].ty);
+                                let ty = LocalType::Numeric(NumericType::Vector {
+                                    size: crate::VectorSize::Quad,
+                                    scalar: min.scalar(),
+                                })
+                                .into();
+
+                                self.temp_list.clear();
+                                self.temp_list
+                                    .resize(4, self.writer.get_constant_scalar(min));
+                                let min_id =
+                                    self.writer.get_constant_composite(ty, &self.temp_list);
+                                self.temp_list.fill(self.writer.get_constant_scalar(max));
+                                let max_id =
+                                    self.writer.get_constant_composite(ty, &self.temp_list);
+
+                                let clamped_id = self.gen_id();
+                                block.body.push(Instruction::ext_inst(
+                                    self.writer.gl450_ext_inst_id,
+                                    clamp_op,
+                                    vec_type_id,
+                                    clamped_id,
+                                    &[arg0_id, min_id, max_id],
+                                ));
+                                clamped_id
+                            }
+                            None => arg0_id,
+                        };
+
                         let mut last_instruction = Instruction::new(spirv::Op::Nop);
 
                         let zero = self.writer.get_constant_scalar(crate::Literal::U32(0));
@@ -1352,6 +1404,119 @@ impl<'w> BlockContext<'w> {
 
                         MathOp::Custom(last_instruction)
                     }
+                    fun @ (Mf::Dot4I8Packed | Mf::Dot4U8Packed) => {
+                        if self.writer.physical_layout.version >= 0x10600
+                            && self.writer.capabilities_are_available(&[
+                                spirv::Capability::DotProduct,
+                                spirv::Capability::DotProductInput4x8BitPacked,
+                            ])
+                        {
+                            self.writer.require_any(
+                                "packed 4x8 integer dot product",
+                                &[spirv::Capability::DotProduct],
+                            )?;
+                            self.writer.require_any(
+                                "packed 4x8 integer dot product",
+                                &[spirv::Capability::DotProductInput4x8BitPacked],
+                            )?;
+
+                            let op = match fun {
+                                Mf::Dot4I8Packed => spirv::Op::SDot,
+                                Mf::Dot4U8Packed => spirv::Op::UDot,
+                                _ => unreachable!(),
+                            };
+
+                            MathOp::Custom(Instruction::ternary(
+                                op,
+                                result_type_id,
+                                id,
+                                arg0_id,
+                                arg1_id,
+                                spirv::PackedVectorFormat::PackedVectorFormat4x8Bit as Word,
+                            ))
+                        } else {
+                            // Extract each byte of both arguments, multiply them and
+                            // sum up the products.
+                            let (extract_op, is_signed) = match fun {
+                                Mf::Dot4I8Packed => (spirv::Op::BitFieldSExtract, true),
+                                Mf::Dot4U8Packed => (spirv::Op::BitFieldUExtract, false),
+                                _ => unreachable!(),
+                            };
+
+                            const VEC_LENGTH: u8 = 4;
+                            block
+                                .body
+                                .reserve(usize::from(VEC_LENGTH) * 4 + usize::from(is_signed) * 2);
+
+                            let (arg0_id, arg1_id) = if is_signed {
+                                let casted = [arg0_id, arg1_id].map(|arg_id| {
+                                    let casted = self.gen_id();
+                                    block.body.push(Instruction::unary(
+                                        spirv::Op::Bitcast,
+                                        result_type_id,
+                                        casted,
+                                        arg_id,
+                                    ));
+                                    casted
+                                });
+                                (casted[0], casted[1])
+                            } else {
+                                (arg0_id, arg1_id)
+                            };
+
+                            let eight = self.writer.get_constant_scalar(crate::Literal::U32(8));
+                            let mut partial_sum = None;
+                            let mut last_instruction = Instruction::new(spirv::Op::Nop);
+                            for i in 0..u32::from(VEC_LENGTH) {
+                                let offset =
+                                    self.writer.get_constant_scalar(crate::Literal::U32(i * 8));
+                                let [extracted0, extracted1] = [arg0_id, arg1_id].map(|arg_id| {
+                                    let extracted = self.gen_id();
+                                    block.body.push(Instruction::ternary(
+                                        extract_op,
+                                        result_type_id,
+                                        extracted,
+                                        arg_id,
+                                        offset,
+                                        eight,
+                                    ));
+                                    extracted
+                                });
+
+                                let product = self.gen_id();
+                                block.body.push(Instruction::binary(
+                                    spirv::Op::IMul,
+                                    result_type_id,
+                                    product,
+                                    extracted0,
+                                    extracted1,
+                                ));
+
+                                partial_sum = Some(match partial_sum {
+                                    None => product,
+                                    Some(previous) => {
+                                        let is_last = i == u32::from(VEC_LENGTH - 1);
+                                        let new_sum = if is_last { id } else { self.gen_id() };
+                                        let add = Instruction::binary(
+                                            spirv::Op::IAdd,
+                                            result_type_id,
+                                            new_sum,
+                                            previous,
+                                            product,
+                                        );
+                                        if is_last {
+                                            last_instruction = add;
+                                        } else {
+                                            block.body.push(add);
+                                        }
+                                        new_sum
+                                    }
+                                });
+                            }
+
+                            MathOp::Custom(last_instruction)
+                        }
+                    }
                     Mf::Unpack4x8unorm => MathOp::Ext(spirv::GLOp::UnpackUnorm4x8),
                     Mf::Unpack4x8snorm => MathOp::Ext(spirv::GLOp::UnpackSnorm4x8),
                     Mf::Unpack2x16float => MathOp::Ext(spirv::GLOp::UnpackHalf2x16),
@@ -1922,12 +2087,38 @@ impl<'w> BlockContext<'w> {
             BoundsCheckResult::Computed(computed_index_id) => Ok(computed_index_id),
             BoundsCheckResult::Conditional {
                 condition_id: condition,
-                index_id: index,
+                index_id,
             } => {
+                if let crate::TypeInner::BindingArray { .. } =
+                    *self.fun_info[base].ty.inner_with(&self.ir_module.types)
+                {
+                    // Images and samplers have no zero value we could produce
+                    // instead of loading an out-of-bounds element, so fall back
+                    // to the first element of the binding array.
+                    let index_type_id = match index {
+                        GuardedIndex::Known(_) => self.get_type_id(LookupType::Local(
+                            LocalType::Numeric(NumericType::Scalar(crate::Scalar::U32)),
+                        )),
+                        GuardedIndex::Expression(expr) => {
+                            self.get_expression_type_id(&self.fun_info[expr].ty)
+                        }
+                    };
+                    let fallback_id = self.writer.get_constant_null(index_type_id);
+                    let selected_id = self.gen_id();
+                    block.body.push(Instruction::select(
+                        index_type_id,
+                        selected_id,
+                        condition,
+                        index_id,
+                        fallback_id,
+                    ));
+                    return Ok(selected_id);
+                }
+
                 self.extend_bounds_check_condition_chain(accumulated_checks, condition, block);
 
                 // Use the index from the `Access` expression unchanged.
-                Ok(index)
+                Ok(index_id)
             }
         }
     }
@@ -1982,38 +2173,26 @@ impl<'w> BlockContext<'w> {
         access_type_adjustment: AccessTypeAdjustment,
         result_type_id: Word,
     ) -> Result<Word, Error> {
+        let load = move |atomic: Option<(Word, Word)>, value_id, pointer_id| match atomic {
+            Some((scope_constant_id, semantics_id)) => Instruction::atomic_load(
+                result_type_id,
+                value_id,
+                pointer_id,
+                scope_constant_id,
+                semantics_id,
+            ),
+            None => Instruction::load(result_type_id, value_id, pointer_id, None),
+        };
+
         match self.write_expression_pointer(pointer, block, access_type_adjustment)? {
             ExpressionPointer::Ready { pointer_id } => {
                 let id = self.gen_id();
-                let atomic_space =
-                    match *self.fun_info[pointer].ty.inner_with(&self.ir_module.types) {
-                        crate::TypeInner::Pointer { base, space } => {
-                            match self.ir_module.types[base].inner {
-                                crate::TypeInner::Atomic { .. } => Some(space),
-                                _ => None,
-                            }
-                        }
-                        _ => None,
-                    };
-                let instruction = if let Some(space) = atomic_space {
-                    let (semantics, scope) = space.to_spirv_semantics_and_scope();
-                    let scope_constant_id = self.get_scope_constant(scope as u32);
-                    let semantics_id = self.get_index_constant(semantics.bits());
-                    Instruction::atomic_load(
-                        result_type_id,
-                        id,
-                        pointer_id,
-                        scope_constant_id,
-                        semantics_id,
-                    )
-                } else {
-                    Instruction::load(result_type_id, id, pointer_id, None)
-                };
-                block.body.push(instruction);
+                let atomic = self.atomic_scope_and_semantics(pointer);
+                block.body.push(load(atomic, id, pointer_id));
                 Ok(id)
             }
             ExpressionPointer::Conditional { condition, access } => {
-                //TODO: support atomics?
+                let atomic = self.atomic_scope_and_semantics(pointer);
                 let value = self.write_conditional_indexed_load(
                     result_type_id,
                     condition,
@@ -2023,12 +2202,7 @@ impl<'w> BlockContext<'w> {
                         let pointer_id = access.result_id.unwrap();
                         let value_id = id_gen.next();
                         block.body.push(access);
-                        block.body.push(Instruction::load(
-                            result_type_id,
-                            value_id,
-                            pointer_id,
-                            None,
-                        ));
+                        block.body.push(load(atomic, value_id, pointer_id));
                         value_id
                     },
                 );
@@ -2037,6 +2211,208 @@ impl<'w> BlockContext<'w> {
         }
     }
 
+    /// If `pointer` points to an atomic, return the ids of the scope and
+    /// memory semantics constants to use when accessing it.
+    fn atomic_scope_and_semantics(
+        &mut self,
+        pointer: Handle<crate::Expression>,
+    ) -> Option<(Word, Word)> {
+        let crate::TypeInner::Pointer { base, space } =
+            *self.fun_info[pointer].ty.inner_with(&self.ir_module.types)
+        else {
+            return None;
+        };
+        let crate::TypeInner::Atomic { .. } = self.ir_module.types[base].inner else {
+            return None;
+        };
+        let (semantics, scope) = space.to_spirv_semantics_and_scope();
+        let scope_constant_id = self.get_scope_constant(scope as u32);
+        let semantics_id = self.get_index_constant(semantics.bits());
+        Some((scope_constant_id, semantics_id))
+    }
+
+    /// Build a store of `value_id` through `pointer_id`, using an atomic store
+    /// if `pointer` points to an atomic.
+    fn store_instruction(
+        &mut self,
+        pointer: Handle<crate::Expression>,
+        pointer_id: Word,
+        value_id: Word,
+    ) -> Instruction {
+        match self.atomic_scope_and_semantics(pointer) {
+            Some((scope_constant_id, semantics_id)) => {
+                Instruction::atomic_store(pointer_id, scope_constant_id, semantics_id, value_id)
+            }
+            None => Instruction::store(pointer_id, value_id, None),
+        }
+    }
+
+    /// Emit an atomic operation on the value `pointer_id` points to, with
+    /// result id `id`.
+    #[allow(clippy::too_many_arguments)]
+    fn write_atomic(
+        &mut self,
+        fun: &crate::AtomicFunction,
+        pointer: Handle<crate::Expression>,
+        pointer_id: Word,
+        value: Handle<crate::Expression>,
+        result_type_id: Word,
+        id: Word,
+        block: &mut Block,
+    ) -> Result<(), Error> {
+        let space = self.fun_info[pointer]
+            .ty
+            .inner_with(&self.ir_module.types)
+            .pointer_space()
+            .unwrap();
+        let (semantics, scope) = space.to_spirv_semantics_and_scope();
+        let scope_constant_id = self.get_scope_constant(scope as u32);
+        let semantics_id = self.get_index_constant(semantics.bits());
+        let value_id = self.cached[value];
+        let value_inner = self.fun_info[value].ty.inner_with(&self.ir_module.types);
+
+        let instruction = match *fun {
+            crate::AtomicFunction::Add => Instruction::atomic_binary(
+                spirv::Op::AtomicIAdd,
+                result_type_id,
+                id,
+                pointer_id,
+                scope_constant_id,
+                semantics_id,
+                value_id,
+            ),
+            crate::AtomicFunction::Subtract => Instruction::atomic_binary(
+                spirv::Op::AtomicISub,
+                result_type_id,
+                id,
+                pointer_id,
+                scope_constant_id,
+                semantics_id,
+                value_id,
+            ),
+            crate::AtomicFunction::And => Instruction::atomic_binary(
+                spirv::Op::AtomicAnd,
+                result_type_id,
+                id,
+                pointer_id,
+                scope_constant_id,
+                semantics_id,
+                value_id,
+            ),
+            crate::AtomicFunction::InclusiveOr => Instruction::atomic_binary(
+                spirv::Op::AtomicOr,
+                result_type_id,
+                id,
+                pointer_id,
+                scope_constant_id,
+                semantics_id,
+                value_id,
+            ),
+            crate::AtomicFunction::ExclusiveOr => Instruction::atomic_binary(
+                spirv::Op::AtomicXor,
+                result_type_id,
+                id,
+                pointer_id,
+                scope_constant_id,
+                semantics_id,
+                value_id,
+            ),
+            crate::AtomicFunction::Min => {
+                let spirv_op = match *value_inner {
+                    crate::TypeInner::Scalar(crate::Scalar {
+                        kind: crate::ScalarKind::Sint,
+                        width: _,
+                    }) => spirv::Op::AtomicSMin,
+                    crate::TypeInner::Scalar(crate::Scalar {
+                        kind: crate::ScalarKind::Uint,
+                        width: _,
+                    }) => spirv::Op::AtomicUMin,
+                    _ => unimplemented!(),
+                };
+                Instruction::atomic_binary(
+                    spirv_op,
+                    result_type_id,
+                    id,
+                    pointer_id,
+                    scope_constant_id,
+                    semantics_id,
+                    value_id,
+                )
+            }
+            crate::AtomicFunction::Max => {
+                let spirv_op = match *value_inner {
+                    crate::TypeInner::Scalar(crate::Scalar {
+                        kind: crate::ScalarKind::Sint,
+                        width: _,
+                    }) => spirv::Op::AtomicSMax,
+                    crate::TypeInner::Scalar(crate::Scalar {
+                        kind: crate::ScalarKind::Uint,
+                        width: _,
+                    }) => spirv::Op::AtomicUMax,
+                    _ => unimplemented!(),
+                };
+                Instruction::atomic_binary(
+                    spirv_op,
+                    result_type_id,
+                    id,
+                    pointer_id,
+                    scope_constant_id,
+                    semantics_id,
+                    value_id,
+                )
+            }
+            crate::AtomicFunction::Exchange { compare: None } => Instruction::atomic_binary(
+                spirv::Op::AtomicExchange,
+                result_type_id,
+                id,
+                pointer_id,
+                scope_constant_id,
+                semantics_id,
+                value_id,
+            ),
+            crate::AtomicFunction::Exchange { compare: Some(cmp) } => {
+                let scalar_type_id = match *value_inner {
+                    crate::TypeInner::Scalar(scalar) => self.get_type_id(LookupType::Local(
+                        LocalType::Numeric(NumericType::Scalar(scalar)),
+                    )),
+                    _ => unimplemented!(),
+                };
+                let bool_type_id = self.get_type_id(LookupType::Local(LocalType::Numeric(
+                    NumericType::Scalar(crate::Scalar::BOOL),
+                )));
+
+                let cas_result_id = self.gen_id();
+                let equality_result_id = self.gen_id();
+                let mut cas_instr = Instruction::new(spirv::Op::AtomicCompareExchange);
+                cas_instr.set_type(scalar_type_id);
+                cas_instr.set_result(cas_result_id);
+                cas_instr.add_operand(pointer_id);
+                cas_instr.add_operand(scope_constant_id);
+                cas_instr.add_operand(semantics_id); // semantics if equal
+                cas_instr.add_operand(semantics_id); // semantics if not equal
+                cas_instr.add_operand(value_id);
+                cas_instr.add_operand(self.cached[cmp]);
+                block.body.push(cas_instr);
+                block.body.push(Instruction::binary(
+                    spirv::Op::IEqual,
+                    bool_type_id,
+                    equality_result_id,
+                    cas_result_id,
+                    self.cached[cmp],
+                ));
+                Instruction::composite_construct(
+                    result_type_id,
+                    id,
+                    &[cas_result_id, equality_result_id],
+                )
+            }
+        };
+
+        block.body.push(instruction);
+
+        Ok(())
+    }
+
     fn spill_to_internal_variable(&mut self, base: Handle<crate::Expression>, block: &mut Block) {
         // Generate an internal variable of the appropriate type for `base`.
         let variable_id = self.writer.id_gen.next();
@@ -2281,6 +2657,23 @@ impl<'w> BlockContext<'w> {
         exit: BlockExit,
         loop_context: LoopContext,
         debug_info: Option<&DebugInfoInner>,
+    ) -> Result<BlockExitDisposition, Error> {
+        let parent_scope = self.enter_debug_scope(naga_block, debug_info);
+        let disposition =
+            self.write_block_statements(label_id, naga_block, exit, loop_context, debug_info);
+        self.function.debug_scope = parent_scope;
+        disposition
+    }
+
+    /// Generate the statements of `naga_block`, as described in
+    /// [`write_block`](Self::write_block).
+    fn write_block_statements(
+        &mut self,
+        label_id: Word,
+        naga_block: &crate::Block,
+        exit: BlockExit,
+        loop_context: LoopContext,
+        debug_info: Option<&DebugInfoInner>,
     ) -> Result<BlockExitDisposition, Error> {
         let mut block = Block::new(label_id);
         for (statement, span) in naga_block.span_iter() {
@@ -2302,6 +2695,7 @@ impl<'w> BlockContext<'w> {
                     loc.line_number,
                     loc.line_position,
                 ));
+                block.body.extend(self.debug_line(*span, debug_info));
             };
             match *statement {
                 Statement::Emit(ref range) => {
@@ -2310,6 +2704,9 @@ impl<'w> BlockContext<'w> {
                         if !self.expression_constness.is_const(handle) {
                             self.cache_expression_value(handle, &mut block)?;
                         }
+                        if let Some(debug_info) = debug_info {
+                            self.write_debug_named_expression(handle, &mut block, debug_info);
+                        }
                     }
                 }
                 Statement::Block(ref block_statements) => {
@@ -2508,7 +2905,8 @@ impl<'w> BlockContext<'w> {
                             debug_info.source_file_id,
                             loc.line_number,
                             loc.line_position,
-                        ))
+                        ));
+                        block.body.extend(self.debug_line(*span, debug_info));
                     }
                     block.body.push(Instruction::loop_merge(
                         merge_id,
@@ -2598,6 +2996,27 @@ impl<'w> BlockContext<'w> {
                     self.function.consume(block, Instruction::kill());
                     return Ok(BlockExitDisposition::Discarded);
                 }
+                Statement::Demote => {
+                    if self
+                        .writer
+                        .capabilities_are_available(&[spirv::Capability::DemoteToHelperInvocation])
+                    {
+                        self.writer.require_any(
+                            "demote to helper invocation",
+                            &[spirv::Capability::DemoteToHelperInvocation],
+                        )?;
+                        if self.writer.physical_layout.version < 0x10600 {
+                            self.writer
+                                .use_extension("SPV_EXT_demote_to_helper_invocation");
+                        }
+                        block.body.push(Instruction::demote_to_helper_invocation());
+                    } else {
+                        // Without demote, terminating the invocation is the
+                        // closest we can get.
+                        self.function.consume(block, Instruction::kill());
+                        return Ok(BlockExitDisposition::Discarded);
+                    }
+                }
                 Statement::Barrier(flags) => {
                     self.writer.write_barrier(flags, &mut block);
                 }
@@ -2609,31 +3028,7 @@ impl<'w> BlockContext<'w> {
                         AccessTypeAdjustment::None,
                     )? {
                         ExpressionPointer::Ready { pointer_id } => {
-                            let atomic_space = match *self.fun_info[pointer]
-                                .ty
-                                .inner_with(&self.ir_module.types)
-                            {
-                                crate::TypeInner::Pointer { base, space } => {
-                                    match self.ir_module.types[base].inner {
-                                        crate::TypeInner::Atomic { .. } => Some(space),
-                                        _ => None,
-                                    }
-                                }
-                                _ => None,
-                            };
-                            let instruction = if let Some(space) = atomic_space {
-                                let (semantics, scope) = space.to_spirv_semantics_and_scope();
-                                let scope_constant_id = self.get_scope_constant(scope as u32);
-                                let semantics_id = self.get_index_constant(semantics.bits());
-                                Instruction::atomic_store(
-                                    pointer_id,
-                                    scope_constant_id,
-                                    semantics_id,
-                                    value_id,
-                                )
-                            } else {
-                                Instruction::store(pointer_id, value_id, None)
-                            };
+                            let instruction = self.store_instruction(pointer, pointer_id, value_id);
                             block.body.push(instruction);
                         }
                         ExpressionPointer::Conditional { condition, access } => {
@@ -2643,10 +3038,8 @@ impl<'w> BlockContext<'w> {
                             // The in-bounds path. Perform the access and the store.
                             let pointer_id = access.result_id.unwrap();
                             selection.block().body.push(access);
-                            selection
-                                .block()
-                                .body
-                                .push(Instruction::store(pointer_id, value_id, None));
+                            let instruction = self.store_instruction(pointer, pointer_id, value_id);
+                            selection.block().body.push(instruction);
 
                             // Finish the in-bounds block and start the merge block. This
                             // is the block we'll leave current on return.
@@ -2699,176 +3092,49 @@ impl<'w> BlockContext<'w> {
                     let result_type_id =
                         self.get_expression_type_id(&self.fun_info[result.unwrap_or(value)].ty);
 
-                    if let Some(result) = result {
-                        self.cached[result] = id;
-                    }
-
-                    let pointer_id = match self.write_expression_pointer(
+                    let result_id = match self.write_expression_pointer(
                         pointer,
                         &mut block,
                         AccessTypeAdjustment::None,
                     )? {
-                        ExpressionPointer::Ready { pointer_id } => pointer_id,
-                        ExpressionPointer::Conditional { .. } => {
-                            return Err(Error::FeatureNotImplemented(
-                                "Atomics out-of-bounds handling",
-                            ));
-                        }
-                    };
-
-                    let space = self.fun_info[pointer]
-                        .ty
-                        .inner_with(&self.ir_module.types)
-                        .pointer_space()
-                        .unwrap();
-                    let (semantics, scope) = space.to_spirv_semantics_and_scope();
-                    let scope_constant_id = self.get_scope_constant(scope as u32);
-                    let semantics_id = self.get_index_constant(semantics.bits());
-                    let value_id = self.cached[value];
-                    let value_inner = self.fun_info[value].ty.inner_with(&self.ir_module.types);
-
-                    let instruction = match *fun {
-                        crate::AtomicFunction::Add => Instruction::atomic_binary(
-                            spirv::Op::AtomicIAdd,
-                            result_type_id,
-                            id,
-                            pointer_id,
-                            scope_constant_id,
-                            semantics_id,
-                            value_id,
-                        ),
-                        crate::AtomicFunction::Subtract => Instruction::atomic_binary(
-                            spirv::Op::AtomicISub,
-                            result_type_id,
-                            id,
-                            pointer_id,
-                            scope_constant_id,
-                            semantics_id,
-                            value_id,
-                        ),
-                        crate::AtomicFunction::And => Instruction::atomic_binary(
-                            spirv::Op::AtomicAnd,
-                            result_type_id,
-                            id,
-                            pointer_id,
-                            scope_constant_id,
-                            semantics_id,
-                            value_id,
-                        ),
-                        crate::AtomicFunction::InclusiveOr => Instruction::atomic_binary(
-                            spirv::Op::AtomicOr,
-                            result_type_id,
-                            id,
-                            pointer_id,
-                            scope_constant_id,
-                            semantics_id,
-                            value_id,
-                        ),
-                        crate::AtomicFunction::ExclusiveOr => Instruction::atomic_binary(
-                            spirv::Op::AtomicXor,
-                            result_type_id,
-                            id,
-                            pointer_id,
-                            scope_constant_id,
-                            semantics_id,
-                            value_id,
-                        ),
-                        crate::AtomicFunction::Min => {
-                            let spirv_op = match *value_inner {
-                                crate::TypeInner::Scalar(crate::Scalar {
-                                    kind: crate::ScalarKind::Sint,
-                                    width: _,
-                                }) => spirv::Op::AtomicSMin,
-                                crate::TypeInner::Scalar(crate::Scalar {
-                                    kind: crate::ScalarKind::Uint,
-                                    width: _,
-                                }) => spirv::Op::AtomicUMin,
-                                _ => unimplemented!(),
-                            };
-                            Instruction::atomic_binary(
-                                spirv_op,
-                                result_type_id,
-                                id,
-                                pointer_id,
-                                scope_constant_id,
-                                semantics_id,
-                                value_id,
-                            )
-                        }
-                        crate::AtomicFunction::Max => {
-                            let spirv_op = match *value_inner {
-                                crate::TypeInner::Scalar(crate::Scalar {
-                                    kind: crate::ScalarKind::Sint,
-                                    width: _,
-                                }) => spirv::Op::AtomicSMax,
-                                crate::TypeInner::Scalar(crate::Scalar {
-                                    kind: crate::ScalarKind::Uint,
-                                    width: _,
-                                }) => spirv::Op::AtomicUMax,
-                                _ => unimplemented!(),
-                            };
-                            Instruction::atomic_binary(
-                                spirv_op,
-                                result_type_id,
-                                id,
+                        ExpressionPointer::Ready { pointer_id } => {
+                            self.write_atomic(
+                                fun,
+                                pointer,
                                 pointer_id,
-                                scope_constant_id,
-                                semantics_id,
-                                value_id,
-                            )
-                        }
-                        crate::AtomicFunction::Exchange { compare: None } => {
-                            Instruction::atomic_binary(
-                                spirv::Op::AtomicExchange,
+                                value,
                                 result_type_id,
                                 id,
-                                pointer_id,
-                                scope_constant_id,
-                                semantics_id,
-                                value_id,
-                            )
+                                &mut block,
+                            )?;
+                            id
                         }
-                        crate::AtomicFunction::Exchange { compare: Some(cmp) } => {
-                            let scalar_type_id = match *value_inner {
-                                crate::TypeInner::Scalar(scalar) => {
-                                    self.get_type_id(LookupType::Local(LocalType::Numeric(
-                                        NumericType::Scalar(scalar),
-                                    )))
-                                }
-                                _ => unimplemented!(),
-                            };
-                            let bool_type_id = self.get_type_id(LookupType::Local(
-                                LocalType::Numeric(NumericType::Scalar(crate::Scalar::BOOL)),
-                            ));
+                        ExpressionPointer::Conditional { condition, access } => {
+                            // Skip the atomic operation if the pointer is out of
+                            // bounds, producing a zero value for its result.
+                            let null_id = self.writer.get_constant_null(result_type_id);
+                            let mut selection = Selection::start(&mut block, result_type_id);
+                            selection.if_true(self, condition, null_id);
 
-                            let cas_result_id = self.gen_id();
-                            let equality_result_id = self.gen_id();
-                            let mut cas_instr = Instruction::new(spirv::Op::AtomicCompareExchange);
-                            cas_instr.set_type(scalar_type_id);
-                            cas_instr.set_result(cas_result_id);
-                            cas_instr.add_operand(pointer_id);
-                            cas_instr.add_operand(scope_constant_id);
-                            cas_instr.add_operand(semantics_id); // semantics if equal
-                            cas_instr.add_operand(semantics_id); // semantics if not equal
-                            cas_instr.add_operand(value_id);
-                            cas_instr.add_operand(self.cached[cmp]);
-                            block.body.push(cas_instr);
-                            block.body.push(Instruction::binary(
-                                spirv::Op::IEqual,
-                                bool_type_id,
-                                equality_result_id,
-                                cas_result_id,
-                                self.cached[cmp],
-                            ));
-                            Instruction::composite_construct(
+                            // The in-bounds path. Perform the access and the atomic.
+                            let pointer_id = access.result_id.unwrap();
+                            selection.block().body.push(access);
+                            self.write_atomic(
+                                fun,
+                                pointer,
+                                pointer_id,
+                                value,
                                 result_type_id,
                                 id,
-                                &[cas_result_id, equality_result_id],
-                            )
+                                selection.block(),
+                            )?;
+                            selection.finish(self, id)
                         }
                     };
 
-                    block.body.push(instruction);
+                    if let Some(result) = result {
+                        self.cached[result] = result_id;
+                    }
                 }
                 Statement::WorkGroupUniformLoad { pointer, result } => {
                     self.writer
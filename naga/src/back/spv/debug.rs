@@ -0,0 +1,651 @@
+/*!
+Generating `NonSemantic.Shader.DebugInfo.100` instructions.
+
+This extended instruction set describes the source of a module in much more
+detail than `OpSource` and `OpLine`: it records the compilation unit, the
+functions and their lexical scopes, the variables they declare and the types
+of all of those. Debuggers like RenderDoc and Nsight use it to show local
+variables and to step through the original source.
+
+Everything here is only generated when [`WriterFlags::NON_SEMANTIC_DEBUG_INFO`]
+is set and the writer was given a [`DebugInfo`].
+
+[`WriterFlags::NON_SEMANTIC_DEBUG_INFO`]: super::WriterFlags::NON_SEMANTIC_DEBUG_INFO
+[`DebugInfo`]: super::DebugInfo
+*/
+
+use super::{
+    block::DebugInfoInner, helpers, Block, BlockContext, Instruction, NumericType, Writer,
+};
+use crate::{arena::Handle, proc::TypeResolution, Span};
+use spirv::Word;
+
+/// Name of the extended instruction set.
+const EXT_INST_SET_NAME: &str = "NonSemantic.Shader.DebugInfo.100";
+
+/// The version of the extended instruction set, as expected by
+/// `DebugCompilationUnit`.
+const VERSION: u32 = 100;
+
+/// The DWARF version the debug information corresponds to.
+const DWARF_VERSION: u32 = 4;
+
+/// No `DebugInfoFlags` set.
+const FLAGS_NONE: u32 = 0;
+
+/// `DebugInfoFlags::FlagIsDefinition`.
+const FLAG_IS_DEFINITION: u32 = 1 << 3;
+
+/// Opcodes of the `NonSemantic.Shader.DebugInfo.100` instructions we emit.
+#[derive(Clone, Copy)]
+pub(super) enum DebugOp {
+    InfoNone = 0,
+    CompilationUnit = 1,
+    TypeBasic = 2,
+    TypePointer = 3,
+    TypeArray = 5,
+    TypeVector = 6,
+    TypeFunction = 8,
+    TypeComposite = 10,
+    TypeMember = 11,
+    GlobalVariable = 18,
+    Function = 20,
+    LexicalBlock = 21,
+    Scope = 23,
+    LocalVariable = 26,
+    Declare = 28,
+    Value = 29,
+    Expression = 31,
+    Source = 35,
+    FunctionDefinition = 101,
+    SourceContinued = 102,
+    Line = 103,
+    TypeMatrix = 108,
+}
+
+/// Values of `DebugBaseTypeAttributeEncoding`.
+#[derive(Clone, Copy)]
+enum Encoding {
+    Boolean = 2,
+    Float = 3,
+    Signed = 4,
+    Unsigned = 6,
+}
+
+/// Sizes and offsets are given in bits.
+const BITS_PER_BYTE: u32 = super::BITS_PER_BYTE as u32;
+
+/// `DebugCompositeType::Structure`.
+const COMPOSITE_STRUCTURE: u32 = 1;
+
+/// State for the `NonSemantic.Shader.DebugInfo.100` instructions of a module.
+pub(super) struct NonSemanticDebugInfo {
+    /// The `OpExtInstImport` of the extended instruction set.
+    set_id: Word,
+
+    /// The `DebugSource` describing the source file.
+    source_id: Word,
+
+    /// The `DebugCompilationUnit`, the parent scope of all functions.
+    compilation_unit_id: Word,
+
+    /// A `DebugInfoNone`, used for anything we can't describe.
+    none_id: Word,
+
+    /// An empty `DebugExpression`, used by `DebugValue`.
+    expression_id: Word,
+
+    /// Debug types of the types in the module's arena.
+    types: crate::FastHashMap<Handle<crate::Type>, Word>,
+
+    /// Debug types of numeric types, which aren't always in the arena.
+    numeric_types: crate::FastHashMap<NumericType, Word>,
+}
+
+impl Instruction {
+    pub(super) fn debug_info(
+        set_id: Word,
+        op: DebugOp,
+        result_type_id: Word,
+        id: Word,
+        operands: &[Word],
+    ) -> Self {
+        let mut instruction = Self::new(spirv::Op::ExtInst);
+        instruction.set_type(result_type_id);
+        instruction.set_result(id);
+        instruction.add_operand(set_id);
+        instruction.add_operand(op as u32);
+        for operand in operands {
+            instruction.add_operand(*operand)
+        }
+        instruction
+    }
+
+    fn string_bytes(bytes: &[u8], id: Word) -> Self {
+        let mut instruction = Self::new(spirv::Op::String);
+        instruction.set_result(id);
+        instruction.add_operands(helpers::str_bytes_to_words(bytes));
+        instruction
+    }
+}
+
+const fn scalar_name(scalar: crate::Scalar) -> &'static str {
+    use crate::ScalarKind as Sk;
+
+    match (scalar.kind, scalar.width) {
+        (Sk::Bool, _) => "bool",
+        (Sk::Sint | Sk::AbstractInt, 8) => "i64",
+        (Sk::Sint | Sk::AbstractInt, _) => "i32",
+        (Sk::Uint, 8) => "u64",
+        (Sk::Uint, _) => "u32",
+        (Sk::Float | Sk::AbstractFloat, 2) => "f16",
+        (Sk::Float | Sk::AbstractFloat, 8) => "f64",
+        (Sk::Float | Sk::AbstractFloat, _) => "f32",
+    }
+}
+
+/// Return the location we use for `ir_function` and its arguments.
+///
+/// Naga doesn't keep spans for functions, so we use the first statement of the
+/// body instead.
+pub(super) fn function_span(ir_function: &crate::Function) -> Span {
+    ir_function
+        .body
+        .span_iter()
+        .map(|(_, span)| *span)
+        .find(Span::is_defined)
+        .unwrap_or_default()
+}
+
+impl Writer {
+    fn non_semantic_debug(&self) -> &NonSemanticDebugInfo {
+        self.non_semantic_debug
+            .as_ref()
+            .expect("non-semantic debug info is not enabled")
+    }
+
+    fn debug_constant(&mut self, value: u32) -> Word {
+        self.get_constant_scalar(crate::Literal::U32(value))
+    }
+
+    fn debug_string(&mut self, value: &str) -> Word {
+        let id = self.id_gen.next();
+        self.debug_strings.push(Instruction::string(value, id));
+        id
+    }
+
+    /// Write a module-level debug instruction and return its id.
+    fn write_debug_instruction(&mut self, op: DebugOp, operands: &[Word]) -> Word {
+        let set_id = self.non_semantic_debug().set_id;
+        let id = self.id_gen.next();
+        Instruction::debug_info(set_id, op, self.void_type, id, operands)
+            .to_words(&mut self.logical_layout.declarations);
+        id
+    }
+
+    /// Build a debug instruction to be placed in a function body.
+    pub(super) fn debug_instruction(&mut self, op: DebugOp, operands: &[Word]) -> Instruction {
+        let set_id = self.non_semantic_debug().set_id;
+        let id = self.id_gen.next();
+        Instruction::debug_info(set_id, op, self.void_type, id, operands)
+    }
+
+    /// Return the `Source`, `Line` and `Column` operands for `span`.
+    fn debug_location(&mut self, span: Span, source_code: &str) -> [Word; 3] {
+        let location = span.location(source_code);
+        [
+            self.non_semantic_debug().source_id,
+            self.debug_constant(location.line_number),
+            self.debug_constant(location.line_position),
+        ]
+    }
+
+    /// Import the extended instruction set and describe the source and the
+    /// compilation unit.
+    pub(super) fn write_debug_compilation_unit(
+        &mut self,
+        debug_info: &super::DebugInfo,
+        source_file_id: Word,
+    ) {
+        if self.physical_layout.version < 0x10600 {
+            self.use_extension("SPV_KHR_non_semantic_info");
+        }
+
+        let set_id = self.id_gen.next();
+        Instruction::ext_inst_import(set_id, EXT_INST_SET_NAME)
+            .to_words(&mut self.logical_layout.ext_inst_imports);
+        self.non_semantic_debug = Some(NonSemanticDebugInfo {
+            set_id,
+            source_id: 0,
+            compilation_unit_id: 0,
+            none_id: 0,
+            expression_id: 0,
+            types: Default::default(),
+            numeric_types: Default::default(),
+        });
+
+        // Long sources are split across `DebugSourceContinued` instructions,
+        // like we do for `OpSource`.
+        let chunks = helpers::string_to_byte_chunks(debug_info.source_code, u16::MAX as usize);
+        let mut text_ids = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            let id = self.id_gen.next();
+            self.debug_strings
+                .push(Instruction::string_bytes(chunk, id));
+            text_ids.push(id);
+        }
+        let (first, rest) = text_ids
+            .split_first()
+            .map_or((None, &[][..]), |(first, rest)| (Some(*first), rest));
+        let mut operands = vec![source_file_id];
+        operands.extend(first);
+        let source_id = self.write_debug_instruction(DebugOp::Source, &operands);
+        for &text_id in rest {
+            self.write_debug_instruction(DebugOp::SourceContinued, &[text_id]);
+        }
+
+        let operands = [
+            self.debug_constant(VERSION),
+            self.debug_constant(DWARF_VERSION),
+            source_id,
+            self.debug_constant(debug_info.language as u32),
+        ];
+        let compilation_unit_id = self.write_debug_instruction(DebugOp::CompilationUnit, &operands);
+        let none_id = self.write_debug_instruction(DebugOp::InfoNone, &[]);
+        let expression_id = self.write_debug_instruction(DebugOp::Expression, &[]);
+
+        if let Some(ref mut debug) = self.non_semantic_debug {
+            debug.source_id = source_id;
+            debug.compilation_unit_id = compilation_unit_id;
+            debug.none_id = none_id;
+            debug.expression_id = expression_id;
+        }
+    }
+
+    fn get_debug_numeric_type_id(&mut self, numeric: NumericType) -> Word {
+        if let Some(&id) = self.non_semantic_debug().numeric_types.get(&numeric) {
+            return id;
+        }
+
+        let id = match numeric {
+            NumericType::Scalar(scalar) => {
+                let encoding = match scalar.kind {
+                    crate::ScalarKind::Bool => Encoding::Boolean,
+                    crate::ScalarKind::Sint | crate::ScalarKind::AbstractInt => Encoding::Signed,
+                    crate::ScalarKind::Uint => Encoding::Unsigned,
+                    crate::ScalarKind::Float | crate::ScalarKind::AbstractFloat => Encoding::Float,
+                };
+                let operands = [
+                    self.debug_string(scalar_name(scalar)),
+                    self.debug_constant(scalar.width as u32 * BITS_PER_BYTE),
+                    self.debug_constant(encoding as u32),
+                    self.debug_constant(FLAGS_NONE),
+                ];
+                self.write_debug_instruction(DebugOp::TypeBasic, &operands)
+            }
+            NumericType::Vector { size, scalar } => {
+                let operands = [
+                    self.get_debug_numeric_type_id(NumericType::Scalar(scalar)),
+                    self.debug_constant(size as u32),
+                ];
+                self.write_debug_instruction(DebugOp::TypeVector, &operands)
+            }
+            NumericType::Matrix {
+                columns,
+                rows,
+                scalar,
+            } => {
+                let operands = [
+                    self.get_debug_numeric_type_id(NumericType::Vector { size: rows, scalar }),
+                    self.debug_constant(columns as u32),
+                    self.get_constant_scalar(crate::Literal::Bool(true)),
+                ];
+                self.write_debug_instruction(DebugOp::TypeMatrix, &operands)
+            }
+        };
+
+        if let Some(ref mut debug) = self.non_semantic_debug {
+            debug.numeric_types.insert(numeric, id);
+        }
+        id
+    }
+
+    /// Return the debug type describing `inner`, for types that aren't
+    /// necessarily in the arena.
+    fn get_debug_inner_type_id(
+        &mut self,
+        ir_module: &crate::Module,
+        inner: &crate::TypeInner,
+        source_code: &str,
+    ) -> Word {
+        if let Some(numeric) = NumericType::from_inner(inner) {
+            return self.get_debug_numeric_type_id(numeric);
+        }
+
+        match *inner {
+            crate::TypeInner::Pointer { base, space } => {
+                let operands = [
+                    self.get_debug_type_id(ir_module, base, source_code),
+                    self.debug_constant(helpers::map_storage_class(space) as u32),
+                    self.debug_constant(FLAGS_NONE),
+                ];
+                self.write_debug_instruction(DebugOp::TypePointer, &operands)
+            }
+            _ => self.non_semantic_debug().none_id,
+        }
+    }
+
+    fn get_debug_resolution_type_id(
+        &mut self,
+        ir_module: &crate::Module,
+        resolution: &TypeResolution,
+        source_code: &str,
+    ) -> Word {
+        match *resolution {
+            TypeResolution::Handle(handle) => {
+                self.get_debug_type_id(ir_module, handle, source_code)
+            }
+            TypeResolution::Value(ref inner) => {
+                self.get_debug_inner_type_id(ir_module, inner, source_code)
+            }
+        }
+    }
+
+    /// Return the debug type describing the type `handle`, writing it if
+    /// needed.
+    pub(super) fn get_debug_type_id(
+        &mut self,
+        ir_module: &crate::Module,
+        handle: Handle<crate::Type>,
+        source_code: &str,
+    ) -> Word {
+        if let Some(&id) = self.non_semantic_debug().types.get(&handle) {
+            return id;
+        }
+
+        let ty = &ir_module.types[handle];
+        let id = match ty.inner {
+            crate::TypeInner::Array { base, size, .. }
+            | crate::TypeInner::BindingArray { base, size } => {
+                let count = match size {
+                    crate::ArraySize::Constant(count) => count.get(),
+                    // Runtime-sized arrays are described with a count of zero.
+                    crate::ArraySize::Dynamic => 0,
+                };
+                let operands = [
+                    self.get_debug_type_id(ir_module, base, source_code),
+                    self.debug_constant(count),
+                ];
+                self.write_debug_instruction(DebugOp::TypeArray, &operands)
+            }
+            crate::TypeInner::Struct { ref members, span } => {
+                let name = self.debug_string(ty.name.as_deref().unwrap_or_default());
+                let [source, line, column] =
+                    self.debug_location(ir_module.types.get_span(handle), source_code);
+
+                let mut member_ids = Vec::with_capacity(members.len());
+                for member in members {
+                    let size = ir_module.types[member.ty].inner.size(ir_module.to_ctx());
+                    let operands = [
+                        self.debug_string(member.name.as_deref().unwrap_or_default()),
+                        self.get_debug_type_id(ir_module, member.ty, source_code),
+                        source,
+                        line,
+                        column,
+                        self.debug_constant(member.offset * BITS_PER_BYTE),
+                        self.debug_constant(size * BITS_PER_BYTE),
+                        self.debug_constant(FLAGS_NONE),
+                    ];
+                    member_ids.push(self.write_debug_instruction(DebugOp::TypeMember, &operands));
+                }
+
+                let mut operands = vec![
+                    name,
+                    self.debug_constant(COMPOSITE_STRUCTURE),
+                    source,
+                    line,
+                    column,
+                    self.non_semantic_debug().compilation_unit_id,
+                    name,
+                    self.debug_constant(span * BITS_PER_BYTE),
+                    self.debug_constant(FLAGS_NONE),
+                ];
+                operands.extend(member_ids);
+                self.write_debug_instruction(DebugOp::TypeComposite, &operands)
+            }
+            ref inner => self.get_debug_inner_type_id(ir_module, inner, source_code),
+        };
+
+        if let Some(ref mut debug) = self.non_semantic_debug {
+            debug.types.insert(handle, id);
+        }
+        id
+    }
+
+    pub(super) fn write_debug_global_variable(
+        &mut self,
+        ir_module: &crate::Module,
+        handle: Handle<crate::GlobalVariable>,
+        variable_id: Word,
+        source_code: &str,
+    ) {
+        let var = &ir_module.global_variables[handle];
+        let Some(ref name) = var.name else {
+            return;
+        };
+
+        let name = self.debug_string(name);
+        let [source, line, column] =
+            self.debug_location(ir_module.global_variables.get_span(handle), source_code);
+        let operands = [
+            name,
+            self.get_debug_type_id(ir_module, var.ty, source_code),
+            source,
+            line,
+            column,
+            self.non_semantic_debug().compilation_unit_id,
+            name,
+            variable_id,
+            self.debug_constant(FLAG_IS_DEFINITION),
+        ];
+        self.write_debug_instruction(DebugOp::GlobalVariable, &operands);
+    }
+
+    /// Write the `DebugFunction` describing `ir_function` and return its id.
+    ///
+    /// Entry points are wrapped in a SPIR-V function without parameters or
+    /// results, in which case `entry_point` should be `true`.
+    pub(super) fn write_debug_function(
+        &mut self,
+        ir_module: &crate::Module,
+        ir_function: &crate::Function,
+        entry_point: bool,
+        source_code: &str,
+    ) -> Word {
+        let mut operands = vec![self.debug_constant(FLAGS_NONE)];
+        match ir_function.result {
+            Some(ref result) if !entry_point => {
+                operands.push(self.get_debug_type_id(ir_module, result.ty, source_code));
+            }
+            _ => operands.push(self.void_type),
+        }
+        if !entry_point {
+            for argument in ir_function.arguments.iter() {
+                operands.push(self.get_debug_type_id(ir_module, argument.ty, source_code));
+            }
+        }
+        let type_id = self.write_debug_instruction(DebugOp::TypeFunction, &operands);
+
+        let span = function_span(ir_function);
+        let name = self.debug_string(ir_function.name.as_deref().unwrap_or_default());
+        let [source, line, column] = self.debug_location(span, source_code);
+        let operands = [
+            name,
+            type_id,
+            source,
+            line,
+            column,
+            self.non_semantic_debug().compilation_unit_id,
+            name,
+            self.debug_constant(FLAG_IS_DEFINITION),
+            line,
+        ];
+        self.write_debug_instruction(DebugOp::Function, &operands)
+    }
+
+    /// Write a `DebugLocalVariable` and return its id.
+    ///
+    /// If the variable is a function argument, `arg_number` is its one-based
+    /// position in the argument list.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn write_debug_local_variable(
+        &mut self,
+        ir_module: &crate::Module,
+        name: &str,
+        ty: &TypeResolution,
+        span: Span,
+        scope_id: Word,
+        arg_number: Option<u32>,
+        source_code: &str,
+    ) -> Word {
+        let name = self.debug_string(name);
+        let type_id = self.get_debug_resolution_type_id(ir_module, ty, source_code);
+        let [source, line, column] = self.debug_location(span, source_code);
+        let mut operands = vec![
+            name,
+            type_id,
+            source,
+            line,
+            column,
+            scope_id,
+            self.debug_constant(FLAGS_NONE),
+        ];
+        if let Some(arg_number) = arg_number {
+            operands.push(self.debug_constant(arg_number));
+        }
+        self.write_debug_instruction(DebugOp::LocalVariable, &operands)
+    }
+
+    /// Build a `DebugDeclare` stating that `local_variable_id` lives in the
+    /// `OpVariable` `variable_id`.
+    pub(super) fn debug_declare(
+        &mut self,
+        local_variable_id: Word,
+        variable_id: Word,
+    ) -> Instruction {
+        let expression_id = self.non_semantic_debug().expression_id;
+        self.debug_instruction(
+            DebugOp::Declare,
+            &[local_variable_id, variable_id, expression_id],
+        )
+    }
+
+    /// Build a `DebugValue` stating that `local_variable_id` holds `value_id`.
+    pub(super) fn debug_value(&mut self, local_variable_id: Word, value_id: Word) -> Instruction {
+        let expression_id = self.non_semantic_debug().expression_id;
+        self.debug_instruction(
+            DebugOp::Value,
+            &[local_variable_id, value_id, expression_id],
+        )
+    }
+
+    /// Insert a `DebugScope` at the start of every block of `function` that
+    /// was generated inside a scope.
+    pub(super) fn write_debug_scopes(&mut self, function: &mut super::Function) {
+        for block in function.blocks.iter_mut() {
+            let Some(scope_id) = block.debug_scope else {
+                continue;
+            };
+            // `OpPhi` instructions must come first in a block.
+            let index = block
+                .body
+                .iter()
+                .take_while(|instruction| instruction.op == spirv::Op::Phi)
+                .count();
+            let instruction = self.debug_instruction(DebugOp::Scope, &[scope_id]);
+            block.body.insert(index, instruction);
+        }
+    }
+}
+
+impl BlockContext<'_> {
+    /// Return the `DebugLine` for `span`, if non-semantic debug info is
+    /// enabled.
+    pub(super) fn debug_line(
+        &mut self,
+        span: Span,
+        debug_info: &DebugInfoInner,
+    ) -> Option<Instruction> {
+        self.writer.non_semantic_debug.as_ref()?;
+
+        let [source, line, column] = self.writer.debug_location(span, debug_info.source_code);
+        Some(
+            self.writer
+                .debug_instruction(DebugOp::Line, &[source, line, line, column, column]),
+        )
+    }
+
+    /// Open a `DebugLexicalBlock` for `naga_block`, unless it is the body of
+    /// the function or empty, and return the scope that was current before.
+    pub(super) fn enter_debug_scope(
+        &mut self,
+        naga_block: &crate::Block,
+        debug_info: Option<&DebugInfoInner>,
+    ) -> Option<Word> {
+        let parent_scope = self.function.debug_scope;
+        let (Some(debug_info), Some(parent_scope_id)) = (debug_info, parent_scope) else {
+            return parent_scope;
+        };
+        if std::ptr::eq(naga_block, &self.ir_function.body) {
+            return parent_scope;
+        }
+        let Some(span) = naga_block
+            .span_iter()
+            .map(|(_, span)| *span)
+            .find(Span::is_defined)
+        else {
+            return parent_scope;
+        };
+
+        let [source, line, column] = self.writer.debug_location(span, debug_info.source_code);
+        let scope_id = self.writer.write_debug_instruction(
+            DebugOp::LexicalBlock,
+            &[source, line, column, parent_scope_id],
+        );
+        self.function.debug_scope = Some(scope_id);
+        parent_scope
+    }
+
+    /// Describe the named expression `handle` with a `DebugLocalVariable` and
+    /// a `DebugValue`.
+    pub(super) fn write_debug_named_expression(
+        &mut self,
+        handle: Handle<crate::Expression>,
+        block: &mut Block,
+        debug_info: &DebugInfoInner,
+    ) {
+        let (Some(_), Some(scope_id), Some(name)) = (
+            self.writer.non_semantic_debug.as_ref(),
+            self.function.debug_scope,
+            self.ir_function.named_expressions.get(&handle),
+        ) else {
+            return;
+        };
+        let value_id = self.cached.ids[handle];
+        if value_id == 0 {
+            return;
+        }
+
+        let local_variable_id = self.writer.write_debug_local_variable(
+            self.ir_module,
+            name,
+            &self.fun_info[handle].ty,
+            self.ir_function.expressions.get_span(handle),
+            scope_id,
+            None,
+            debug_info.source_code,
+        );
+        let instruction = self.writer.debug_value(local_variable_id, value_id);
+        block.body.push(instruction);
+    }
+}
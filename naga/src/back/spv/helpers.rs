@@ -116,6 +116,22 @@ pub fn global_needs_wrapper(ir_module: &crate::Module, var: &crate::GlobalVariab
     }
 }
 
+/// Returns true if `ty` is or contains a 16-bit floating-point scalar.
+///
+/// Variables of such types in externally visible storage classes need the
+/// capabilities from `SPV_KHR_16bit_storage`.
+pub fn contains_f16(types: &UniqueArena<crate::Type>, ty: Handle<crate::Type>) -> bool {
+    match types[ty].inner {
+        crate::TypeInner::Struct { ref members, .. } => {
+            members.iter().any(|member| contains_f16(types, member.ty))
+        }
+        crate::TypeInner::Array { base, .. } | crate::TypeInner::BindingArray { base, .. } => {
+            contains_f16(types, base)
+        }
+        ref inner => inner.scalar() == Some(crate::Scalar::F16),
+    }
+}
+
 ///HACK: this is taken from std unstable, remove it when std's floor_char_boundary is stable
 trait U8Internal {
     fn is_utf8_char_boundary(&self) -> bool;
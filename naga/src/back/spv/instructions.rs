@@ -1042,6 +1042,10 @@ impl super::Instruction {
         Self::new(Op::Kill)
     }
 
+    pub(super) const fn demote_to_helper_invocation() -> Self {
+        Self::new(Op::DemoteToHelperInvocation)
+    }
+
     pub(super) const fn return_void() -> Self {
         Self::new(Op::Return)
     }
@@ -1128,6 +1132,7 @@ impl super::Instruction {
         exec_scope_id: Word,
         group_op: Option<spirv::GroupOperation>,
         value: Word,
+        cluster_size: Option<Word>,
     ) -> Self {
         let mut instruction = Self::new(op);
         instruction.set_type(result_type_id);
@@ -1137,6 +1142,9 @@ impl super::Instruction {
             instruction.add_operand(group_op as u32);
         }
         instruction.add_operand(value);
+        if let Some(cluster_size) = cluster_size {
+            instruction.add_operand(cluster_size);
+        }
 
         instruction
     }
@@ -5,6 +5,7 @@ Backend for [SPIR-V][spv] (Standard Portable Intermediate Representation).
 */
 
 mod block;
+mod debug;
 mod helpers;
 mod image;
 mod index;
@@ -73,6 +74,8 @@ pub enum Error {
     Validation(&'static str),
     #[error("overrides should not be present at this stage")]
     Override,
+    #[error("functions taking pointers to global variables must be specialized with `back::pointer_arguments::specialize_pointer_arguments` first")]
+    GlobalPointerArgument,
 }
 
 #[derive(Default)]
@@ -112,6 +115,10 @@ struct Block {
 struct TerminatedBlock {
     label_id: Word,
     body: Vec<Instruction>,
+
+    /// The `DebugScope` the block was generated in, if we are emitting
+    /// non-semantic debug information.
+    debug_scope: Option<Word>,
 }
 
 impl Block {
@@ -181,6 +188,10 @@ struct Function {
 
     blocks: Vec<TerminatedBlock>,
     entry_point_context: Option<EntryPointContext>,
+
+    /// The innermost `DebugFunction` or `DebugLexicalBlock` we are generating
+    /// code for, if we are emitting non-semantic debug information.
+    debug_scope: Option<Word>,
 }
 
 impl Function {
@@ -189,6 +200,7 @@ impl Function {
         self.blocks.push(TerminatedBlock {
             label_id: block.label_id,
             body: block.body,
+            debug_scope: self.debug_scope,
         })
     }
 
@@ -764,6 +776,14 @@ pub struct Writer {
 
     gl450_ext_inst_id: Word,
 
+    /// `OpString` instructions used by the non-semantic debug information.
+    ///
+    /// These must precede any `OpName` in [`debugs`](Writer::debugs).
+    debug_strings: Vec<Instruction>,
+
+    /// State for emitting `NonSemantic.Shader.DebugInfo.100` instructions.
+    non_semantic_debug: Option<debug::NonSemanticDebugInfo>,
+
     // Just a temporary list of SPIR-V ids
     temp_list: Vec<Word>,
 }
@@ -797,6 +817,15 @@ bitflags::bitflags! {
         ///
         /// [`BuiltIn::FragDepth`]: crate::BuiltIn::FragDepth
         const CLAMP_FRAG_DEPTH = 0x10;
+
+        /// Emit `NonSemantic.Shader.DebugInfo.100` instructions describing
+        /// the source's functions, scopes, variables and types.
+        ///
+        /// This only has an effect if [`DEBUG`] is also set and
+        /// [`Options::debug_info`] is provided.
+        ///
+        /// [`DEBUG`]: WriterFlags::DEBUG
+        const NON_SEMANTIC_DEBUG_INFO = 0x20;
     }
 }
 
@@ -1,6 +1,6 @@
 use super::{
     block::DebugInfoInner,
-    helpers::{contains_builtin, global_needs_wrapper, map_storage_class},
+    helpers::{contains_builtin, contains_f16, global_needs_wrapper, map_storage_class},
     Block, BlockContext, CachedConstant, CachedExpressions, DebugInfo, EntryPointContext, Error,
     Function, FunctionArgument, GlobalVariable, IdGenerator, Instruction, LocalType, LocalVariable,
     LogicalLayout, LookupFunctionType, LookupType, NumericType, Options, PhysicalLayout,
@@ -80,6 +80,8 @@ impl Writer {
             binding_map: options.binding_map.clone(),
             saved_cached: CachedExpressions::default(),
             gl450_ext_inst_id,
+            debug_strings: vec![],
+            non_semantic_debug: None,
             temp_list: Vec::new(),
         })
     }
@@ -115,6 +117,7 @@ impl Writer {
             id_gen,
             void_type,
             gl450_ext_inst_id,
+            non_semantic_debug: None,
 
             // Recycled:
             capabilities_used: take(&mut self.capabilities_used).recycle(),
@@ -122,6 +125,7 @@ impl Writer {
             physical_layout: self.physical_layout.clone().recycle(),
             logical_layout: take(&mut self.logical_layout).recycle(),
             debugs: take(&mut self.debugs).recycle(),
+            debug_strings: take(&mut self.debug_strings).recycle(),
             annotations: take(&mut self.annotations).recycle(),
             lookup_type: take(&mut self.lookup_type).recycle(),
             lookup_function: take(&mut self.lookup_function).recycle(),
@@ -179,6 +183,29 @@ impl Writer {
         }
     }
 
+    /// Return `true` if all of `capabilities` are available.
+    ///
+    /// Unlike [`require_any`](Self::require_any), this does not mark anything
+    /// as used; it lets callers fall back to a polyfill instead of failing.
+    pub(super) fn capabilities_are_available(&self, capabilities: &[spirv::Capability]) -> bool {
+        match self.capabilities_available {
+            None => true,
+            Some(ref available) => capabilities.iter().all(|cap| available.contains(cap)),
+        }
+    }
+
+    /// Indicate that the code requires one of `capabilities` from
+    /// `SPV_KHR_16bit_storage`, as for [`require_any`](Self::require_any).
+    fn require_16bit_storage(
+        &mut self,
+        what: &'static str,
+        capabilities: &[spirv::Capability],
+    ) -> Result<(), Error> {
+        self.require_any(what, capabilities)?;
+        self.use_extension("SPV_KHR_16bit_storage");
+        Ok(())
+    }
+
     /// Indicate that the code uses the given extension.
     pub(super) fn use_extension(&mut self, extension: &'static str) {
         self.extensions_used.insert(extension);
@@ -516,6 +543,44 @@ impl Writer {
             function_type,
         ));
 
+        if let (Some(debug_info), true) = (debug_info.as_ref(), self.non_semantic_debug.is_some()) {
+            let is_entry_point = interface.is_some();
+            let scope_id = self.write_debug_function(
+                ir_module,
+                ir_function,
+                is_entry_point,
+                debug_info.source_code,
+            );
+            function.debug_scope = Some(scope_id);
+            let instruction = self.debug_instruction(
+                super::debug::DebugOp::FunctionDefinition,
+                &[scope_id, function_id],
+            );
+            prelude.body.push(instruction);
+
+            for (index, parameter) in function.parameters.iter().enumerate() {
+                let argument = &ir_function.arguments[index];
+                let Some(ref name) = argument.name else {
+                    continue;
+                };
+                let value_id = match parameter.handle_id {
+                    0 => parameter.instruction.result_id.unwrap(),
+                    id => id,
+                };
+                let local_variable_id = self.write_debug_local_variable(
+                    ir_module,
+                    name,
+                    &TypeResolution::Handle(argument.ty),
+                    super::debug::function_span(ir_function),
+                    scope_id,
+                    Some(index as u32 + 1),
+                    debug_info.source_code,
+                );
+                let instruction = self.debug_value(local_variable_id, value_id);
+                prelude.body.push(instruction);
+            }
+        }
+
         if interface.is_some() {
             function.entry_point_context = Some(ep_context);
         }
@@ -633,6 +698,24 @@ impl Writer {
                 .function
                 .variables
                 .insert(handle, LocalVariable { id, instruction });
+
+            if let (Some(debug_info), Some(scope_id), Some(name)) = (
+                debug_info.as_ref(),
+                context.function.debug_scope,
+                variable.name.as_ref(),
+            ) {
+                let local_variable_id = context.writer.write_debug_local_variable(
+                    ir_module,
+                    name,
+                    &TypeResolution::Handle(variable.ty),
+                    ir_function.local_variables.get_span(handle),
+                    scope_id,
+                    None,
+                    debug_info.source_code,
+                );
+                let instruction = context.writer.debug_declare(local_variable_id, id);
+                prelude.body.push(instruction);
+            }
         }
 
         for (handle, expr) in ir_function.expressions.iter() {
@@ -695,6 +778,10 @@ impl Writer {
         self.saved_cached = cached;
         self.temp_list = temp_list;
 
+        if self.non_semantic_debug.is_some() {
+            self.write_debug_scopes(&mut function);
+        }
+
         function.to_words(&mut self.logical_layout.function_definitions);
         Instruction::function_end().to_words(&mut self.logical_layout.function_definitions);
 
@@ -796,8 +883,14 @@ impl Writer {
                 Instruction::type_int(id, bits, signedness)
             }
             Sk::Float => {
-                if bits == 64 {
-                    self.capabilities_used.insert(spirv::Capability::Float64);
+                match bits {
+                    16 => {
+                        self.capabilities_used.insert(spirv::Capability::Float16);
+                    }
+                    64 => {
+                        self.capabilities_used.insert(spirv::Capability::Float64);
+                    }
+                    _ => {}
                 }
                 Instruction::type_float(id, bits)
             }
@@ -1145,6 +1238,9 @@ impl Writer {
                 Instruction::constant_64bit(type_id, id, bits as u32, (bits >> 32) as u32)
             }
             crate::Literal::F32(value) => Instruction::constant_32bit(type_id, id, value.to_bits()),
+            crate::Literal::F16(value) => {
+                Instruction::constant_32bit(type_id, id, value.to_bits() as u32)
+            }
             crate::Literal::U32(value) => Instruction::constant_32bit(type_id, id, value),
             crate::Literal::I32(value) => Instruction::constant_32bit(type_id, id, value as u32),
             crate::Literal::U64(value) => {
@@ -1259,7 +1355,7 @@ impl Writer {
     }
 
     pub(super) fn write_barrier(&mut self, flags: crate::Barrier, block: &mut Block) {
-        let memory_scope = if flags.contains(crate::Barrier::STORAGE) {
+        let memory_scope = if flags.intersects(crate::Barrier::STORAGE | crate::Barrier::TEXTURE) {
             spirv::Scope::Device
         } else {
             spirv::Scope::Workgroup
@@ -1273,6 +1369,10 @@ impl Writer {
             spirv::MemorySemantics::WORKGROUP_MEMORY,
             flags.contains(crate::Barrier::WORK_GROUP),
         );
+        semantics.set(
+            spirv::MemorySemantics::IMAGE_MEMORY,
+            flags.contains(crate::Barrier::TEXTURE),
+        );
         let exec_scope_id = if flags.contains(crate::Barrier::SUB_GROUP) {
             self.get_index_constant(spirv::Scope::Subgroup as u32)
         } else {
@@ -1422,6 +1522,13 @@ impl Writer {
         ty: Handle<crate::Type>,
         binding: &crate::Binding,
     ) -> Result<Word, Error> {
+        if contains_f16(&ir_module.types, ty) {
+            self.require_16bit_storage(
+                "16-bit shader inputs and outputs",
+                &[spirv::Capability::StorageInputOutput16],
+            )?;
+        }
+
         let id = self.id_gen.next();
         let pointer_type_id = self.get_pointer_id(ty, class);
         Instruction::variable(pointer_type_id, id, class, None)
@@ -1443,7 +1550,7 @@ impl Writer {
                 location,
                 interpolation,
                 sampling,
-                second_blend_source,
+                blend_src,
             } => {
                 self.decorate(id, Decoration::Location, &[location]);
 
@@ -1488,8 +1595,8 @@ impl Writer {
                         }
                     }
                 }
-                if second_blend_source {
-                    self.decorate(id, Decoration::Index, &[1]);
+                if let Some(blend_src) = blend_src {
+                    self.decorate(id, Decoration::Index, &[blend_src]);
                 }
             }
             crate::Binding::BuiltIn(built_in) => {
@@ -1641,6 +1748,24 @@ impl Writer {
 
         //self.check(class.required_capabilities())?;
 
+        if contains_f16(&ir_module.types, global_variable.ty) {
+            match global_variable.space {
+                crate::AddressSpace::Uniform => self.require_16bit_storage(
+                    "16-bit uniform buffers",
+                    &[spirv::Capability::UniformAndStorageBuffer16BitAccess],
+                )?,
+                crate::AddressSpace::Storage { .. } => self.require_16bit_storage(
+                    "16-bit storage buffers",
+                    &[spirv::Capability::StorageBuffer16BitAccess],
+                )?,
+                crate::AddressSpace::PushConstant => self.require_16bit_storage(
+                    "16-bit push constants",
+                    &[spirv::Capability::StoragePushConstant16],
+                )?,
+                _ => {}
+            }
+        }
+
         if self.flags.contains(WriterFlags::DEBUG) {
             if let Some(ref name) = global_variable.name {
                 self.debugs.push(Instruction::name(id, name));
@@ -1931,6 +2056,10 @@ impl Writer {
                     0,
                     &debug_info_inner,
                 ));
+
+                if self.flags.contains(WriterFlags::NON_SEMANTIC_DEBUG_INFO) {
+                    self.write_debug_compilation_unit(debug_info, source_file_id);
+                }
             }
         }
 
@@ -1968,6 +2097,16 @@ impl Writer {
                 }
                 _ => {
                     let id = self.write_global_variable(ir_module, var)?;
+                    if let (Some(debug_info), true) =
+                        (debug_info_inner.as_ref(), self.non_semantic_debug.is_some())
+                    {
+                        self.write_debug_global_variable(
+                            ir_module,
+                            handle,
+                            id,
+                            debug_info.source_code,
+                        );
+                    }
                     GlobalVariable::new(id)
                 }
             };
@@ -2036,7 +2175,7 @@ impl Writer {
             .to_words(&mut self.logical_layout.memory_model);
 
         if self.flags.contains(WriterFlags::DEBUG) {
-            for debug in self.debugs.iter() {
+            for debug in self.debug_strings.iter().chain(self.debugs.iter()) {
                 debug.to_words(&mut self.logical_layout.debugs);
             }
         }
@@ -2048,6 +2187,13 @@ impl Writer {
         Ok(())
     }
 
+    /// Write `ir_module` as SPIR-V to `words`.
+    ///
+    /// Overrides must already have been replaced by
+    /// [`process_overrides`](crate::back::pipeline_constants::process_overrides), and
+    /// functions taking pointers to global variables must already have been
+    /// specialized with
+    /// [`specialize_pointer_arguments`](crate::back::pointer_arguments::specialize_pointer_arguments).
     pub fn write(
         &mut self,
         ir_module: &crate::Module,
@@ -2059,6 +2205,9 @@ impl Writer {
         if !ir_module.overrides.is_empty() {
             return Err(Error::Override);
         }
+        if crate::back::pointer_arguments::has_global_pointer_arguments(ir_module) {
+            return Err(Error::GlobalPointerArgument);
+        }
 
         self.reset();
 
@@ -15,10 +15,11 @@ enum Attribute {
     Binding(u32),
     BuiltIn(crate::BuiltIn),
     Group(u32),
+    Id(u16),
     Invariant,
     Interpolate(Option<crate::Interpolation>, Option<crate::Sampling>),
     Location(u32),
-    SecondBlendSource,
+    BlendSrc(u32),
     Stage(ShaderStage),
     WorkGroupSize([u32; 3]),
 }
@@ -68,7 +69,14 @@ pub struct Writer<W> {
     names: crate::FastHashMap<NameKey, String>,
     namer: proc::Namer,
     named_expressions: crate::NamedExpressions,
-    ep_results: Vec<(ShaderStage, Handle<crate::Type>)>,
+
+    /// Predeclared types that the module needs to spell out.
+    ///
+    /// WGSL has no way to name the predeclared result types of builtins like
+    /// `modf` or `atomicCompareExchangeWeak`, but IR from other frontends may
+    /// use them in `Compose` expressions or as variable types. We declare an
+    /// ordinary struct for those instead.
+    named_predeclared_types: crate::FastHashSet<Handle<crate::Type>>,
     required_polyfills: crate::FastIndexSet<InversePolyfill>,
 }
 
@@ -80,7 +88,7 @@ impl<W: Write> Writer<W> {
             names: crate::FastHashMap::default(),
             namer: proc::Namer::default(),
             named_expressions: crate::NamedExpressions::default(),
-            ep_results: vec![],
+            named_predeclared_types: crate::FastHashSet::default(),
             required_polyfills: crate::FastIndexSet::default(),
         }
     }
@@ -90,40 +98,120 @@ impl<W: Write> Writer<W> {
         self.namer.reset(
             module,
             crate::keywords::wgsl::RESERVED,
-            // an identifier must not start with two underscore
-            &[],
+            // Naga's WGSL frontend predeclares these types
+            &["RayDesc", "RayIntersection"],
             &[],
+            // an identifier must not start with two underscore
             &["__", "_naga"],
             &mut self.names,
         );
+        if let Some(ty) = module.special_types.ray_desc {
+            self.names.insert(NameKey::Type(ty), "RayDesc".to_string());
+        }
+        if let Some(ty) = module.special_types.ray_intersection {
+            self.names
+                .insert(NameKey::Type(ty), "RayIntersection".to_string());
+        }
+
+        // Name unnamed entry point result structs after their stage. Go
+        // through the namer, so that they can't clash with other types.
+        let mut ep_results = crate::FastHashSet::default();
+        for ep in &module.entry_points {
+            let Some(ref result) = ep.function.result else {
+                continue;
+            };
+            let ty = &module.types[result.ty];
+            if ty.name.is_some()
+                || !matches!(ty.inner, TypeInner::Struct { .. })
+                || !ep_results.insert(result.ty)
+            {
+                continue;
+            }
+            let name = match ep.stage {
+                ShaderStage::Compute => "ComputeOutput",
+                ShaderStage::Fragment => "FragmentOutput",
+                ShaderStage::Vertex => "VertexOutput",
+            };
+            self.names
+                .insert(NameKey::Type(result.ty), self.namer.call(name));
+        }
+
         self.named_expressions.clear();
-        self.ep_results.clear();
+        self.collect_named_predeclared_types(module);
         self.required_polyfills.clear();
     }
 
     fn is_builtin_wgsl_struct(&self, module: &Module, handle: Handle<crate::Type>) -> bool {
-        module
-            .special_types
-            .predeclared_types
-            .values()
-            .any(|t| *t == handle)
+        let special_types = &module.special_types;
+        special_types.ray_desc == Some(handle)
+            || special_types.ray_intersection == Some(handle)
+            || special_types
+                .predeclared_types
+                .values()
+                .any(|t| *t == handle)
+                && !self.named_predeclared_types.contains(&handle)
     }
 
-    pub fn write(&mut self, module: &Module, info: &valid::ModuleInfo) -> BackendResult {
-        if !module.overrides.is_empty() {
-            return Err(Error::Unimplemented(
-                "Pipeline constants are not yet supported for this back-end".to_string(),
-            ));
+    /// Fill [`named_predeclared_types`](Self::named_predeclared_types).
+    fn collect_named_predeclared_types(&mut self, module: &Module) {
+        self.named_predeclared_types.clear();
+        if module.special_types.predeclared_types.is_empty() {
+            return;
         }
 
-        self.reset(module);
-
-        // Save all ep result types
-        for ep in &module.entry_points {
-            if let Some(ref result) = ep.function.result {
-                self.ep_results.push((ep.stage, result.ty));
+        let mut named = Vec::new();
+        for (_, ty) in module.types.iter() {
+            match ty.inner {
+                TypeInner::Struct { ref members, .. } => {
+                    named.extend(members.iter().map(|member| member.ty))
+                }
+                TypeInner::Array { base, .. }
+                | TypeInner::BindingArray { base, .. }
+                | TypeInner::Pointer { base, .. } => named.push(base),
+                _ => {}
             }
         }
+        named.extend(module.global_variables.iter().map(|(_, var)| var.ty));
+        named.extend(module.constants.iter().map(|(_, constant)| constant.ty));
+
+        fn composed_types(
+            expressions: &crate::Arena<crate::Expression>,
+        ) -> impl Iterator<Item = Handle<crate::Type>> + '_ {
+            expressions.iter().filter_map(|(_, expr)| match *expr {
+                crate::Expression::Compose { ty, .. } | crate::Expression::ZeroValue(ty) => {
+                    Some(ty)
+                }
+                _ => None,
+            })
+        }
+        named.extend(composed_types(&module.global_expressions));
+        let functions = module
+            .functions
+            .iter()
+            .map(|(_, function)| function)
+            .chain(module.entry_points.iter().map(|ep| &ep.function));
+        for function in functions {
+            named.extend(function.arguments.iter().map(|argument| argument.ty));
+            named.extend(function.result.as_ref().map(|result| result.ty));
+            named.extend(function.local_variables.iter().map(|(_, var)| var.ty));
+            named.extend(composed_types(&function.expressions));
+        }
+
+        self.named_predeclared_types
+            .extend(named.into_iter().filter(|ty| {
+                module
+                    .special_types
+                    .predeclared_types
+                    .values()
+                    .any(|t| t == ty)
+            }));
+    }
+
+    pub fn write(&mut self, module: &Module, info: &valid::ModuleInfo) -> BackendResult {
+        self.reset(module);
+
+        // Write all `enable` declarations
+        self.write_enable_declarations(module)?;
 
         // Write all structs
         for (handle, ty) in module.types.iter() {
@@ -151,6 +239,16 @@ impl<W: Write> Writer<W> {
             }
         }
 
+        // Write all overrides
+        for (handle, _) in module.overrides.iter() {
+            self.write_override(module, handle)?;
+        }
+
+        if !module.overrides.is_empty() {
+            // Add extra newline for readability
+            writeln!(self.out)?;
+        }
+
         // Write all globals
         for (ty, global) in module.global_variables.iter() {
             self.write_global(module, global, ty)?;
@@ -217,24 +315,66 @@ impl<W: Write> Writer<W> {
         Ok(())
     }
 
+    /// Write the `enable` directives needed by the types used in `module`.
+    fn write_enable_declarations(&mut self, module: &Module) -> BackendResult {
+        // Checks whether any entry point interface binding matches `pred`.
+        let any_binding = |pred: fn(&crate::Binding) -> bool| {
+            module.types.iter().any(|(_, ty)| match ty.inner {
+                TypeInner::Struct { ref members, .. } => members
+                    .iter()
+                    .any(|member| member.binding.as_ref().is_some_and(pred)),
+                _ => false,
+            }) || module.entry_points.iter().any(|ep| {
+                ep.function
+                    .result
+                    .as_ref()
+                    .and_then(|result| result.binding.as_ref())
+                    .is_some_and(pred)
+            })
+        };
+
+        let needs_f16 = module
+            .types
+            .iter()
+            .any(|(_, ty)| ty.inner.scalar() == Some(crate::Scalar::F16));
+        let needs_clip_distances = any_binding(|binding| {
+            *binding == crate::Binding::BuiltIn(crate::BuiltIn::ClipDistance)
+        });
+        let needs_dual_source_blending = any_binding(|binding| {
+            matches!(
+                *binding,
+                crate::Binding::Location {
+                    blend_src: Some(_),
+                    ..
+                }
+            )
+        });
+
+        let mut any_written = false;
+        if needs_f16 {
+            writeln!(self.out, "enable f16;")?;
+            any_written = true;
+        }
+        if needs_clip_distances {
+            writeln!(self.out, "enable clip_distances;")?;
+            any_written = true;
+        }
+        if needs_dual_source_blending {
+            writeln!(self.out, "enable dual_source_blending;")?;
+            any_written = true;
+        }
+        if any_written {
+            writeln!(self.out)?;
+        }
+
+        Ok(())
+    }
+
     /// Helper method used to write struct name
     ///
     /// # Notes
     /// Adds no trailing or leading whitespace
-    fn write_struct_name(&mut self, module: &Module, handle: Handle<crate::Type>) -> BackendResult {
-        if module.types[handle].name.is_none() {
-            if let Some(&(stage, _)) = self.ep_results.iter().find(|&&(_, ty)| ty == handle) {
-                let name = match stage {
-                    ShaderStage::Compute => "ComputeOutput",
-                    ShaderStage::Fragment => "FragmentOutput",
-                    ShaderStage::Vertex => "VertexOutput",
-                };
-
-                write!(self.out, "{name}")?;
-                return Ok(());
-            }
-        }
-
+    fn write_struct_name(&mut self, handle: Handle<crate::Type>) -> BackendResult {
         write!(self.out, "{}", self.names[&NameKey::Type(handle)])?;
 
         Ok(())
@@ -340,7 +480,7 @@ impl<W: Write> Writer<W> {
         for attribute in attributes {
             match *attribute {
                 Attribute::Location(id) => write!(self.out, "@location({id}) ")?,
-                Attribute::SecondBlendSource => write!(self.out, "@second_blend_source ")?,
+                Attribute::BlendSrc(blend_src) => write!(self.out, "@blend_src({blend_src}) ")?,
                 Attribute::BuiltIn(builtin_attrib) => {
                     let builtin = builtin_str(builtin_attrib)?;
                     write!(self.out, "@builtin({builtin}) ")?;
@@ -362,6 +502,7 @@ impl<W: Write> Writer<W> {
                 }
                 Attribute::Binding(id) => write!(self.out, "@binding({id}) ")?,
                 Attribute::Group(id) => write!(self.out, "@group({id}) ")?,
+                Attribute::Id(id) => write!(self.out, "@id({id}) ")?,
                 Attribute::Invariant => write!(self.out, "@invariant ")?,
                 Attribute::Interpolate(interpolation, sampling) => {
                     if sampling.is_some() && sampling != Some(crate::Sampling::Center) {
@@ -401,7 +542,7 @@ impl<W: Write> Writer<W> {
         members: &[crate::StructMember],
     ) -> BackendResult {
         write!(self.out, "struct ")?;
-        self.write_struct_name(module, handle)?;
+        self.write_struct_name(handle)?;
         write!(self.out, " {{")?;
         writeln!(self.out)?;
         for (index, member) in members.iter().enumerate() {
@@ -432,7 +573,7 @@ impl<W: Write> Writer<W> {
     fn write_type(&mut self, module: &Module, ty: Handle<crate::Type>) -> BackendResult {
         let inner = &module.types[ty].inner;
         match *inner {
-            TypeInner::Struct { .. } => self.write_struct_name(module, ty)?,
+            TypeInner::Struct { .. } => self.write_struct_name(ty)?,
             ref other => self.write_value_type(module, other)?,
         }
 
@@ -570,51 +711,38 @@ impl<W: Write> Writer<W> {
                 }
             }
             TypeInner::ValuePointer {
-                size: None,
+                size,
                 scalar,
                 space,
             } => {
+                // As for `Pointer`, write pointers to handles as the base
+                // type alone.
                 let (address, maybe_access) = address_space_str(space);
                 if let Some(space) = address {
-                    write!(self.out, "ptr<{}, {}", space, scalar_kind_str(scalar))?;
-                    if let Some(access) = maybe_access {
-                        write!(self.out, ", {access}")?;
-                    }
-                    write!(self.out, ">")?;
-                } else {
-                    return Err(Error::Unimplemented(format!(
-                        "ValuePointer to AddressSpace::Handle {inner:?}"
-                    )));
+                    write!(self.out, "ptr<{space}, ")?;
                 }
-            }
-            TypeInner::ValuePointer {
-                size: Some(size),
-                scalar,
-                space,
-            } => {
-                let (address, maybe_access) = address_space_str(space);
-                if let Some(space) = address {
-                    write!(
+                match size {
+                    Some(size) => write!(
                         self.out,
-                        "ptr<{}, vec{}<{}>",
-                        space,
+                        "vec{}<{}>",
                         back::vector_size_str(size),
                         scalar_kind_str(scalar)
-                    )?;
+                    )?,
+                    None => write!(self.out, "{}", scalar_kind_str(scalar))?,
+                }
+                if address.is_some() {
                     if let Some(access) = maybe_access {
                         write!(self.out, ", {access}")?;
                     }
                     write!(self.out, ">")?;
-                } else {
-                    return Err(Error::Unimplemented(format!(
-                        "ValuePointer to AddressSpace::Handle {inner:?}"
-                    )));
                 }
-                write!(self.out, ">")?;
             }
             TypeInner::AccelerationStructure => write!(self.out, "acceleration_structure")?,
-            _ => {
-                return Err(Error::Unimplemented(format!("write_value_type {inner:?}")));
+            TypeInner::RayQuery => write!(self.out, "ray_query")?,
+            TypeInner::Struct { .. } => {
+                return Err(Error::Custom(
+                    "Structs should be written by name, not by their contents".into(),
+                ));
             }
         }
 
@@ -710,7 +838,9 @@ impl<W: Write> Writer<W> {
                 writeln!(self.out, ";")?;
             }
             // TODO: copy-paste from glsl-out
-            Statement::Kill => {
+            // WGSL's `discard` demotes the invocation to a helper, and has no
+            // terminating counterpart.
+            Statement::Kill | Statement::Demote => {
                 write!(self.out, "{level}")?;
                 writeln!(self.out, "discard;")?
             }
@@ -835,14 +965,7 @@ impl<W: Write> Writer<W> {
 
                 let l2 = level.next();
                 let mut new_case = true;
-                for case in cases {
-                    if case.fall_through && !case.body.is_empty() {
-                        // TODO: we could do the same workaround as we did for the HLSL backend
-                        return Err(Error::Unimplemented(
-                            "fall-through switch case block".into(),
-                        ));
-                    }
-
+                for (i, case) in cases.iter().enumerate() {
                     match case.value {
                         crate::SwitchValue::I32(value) => {
                             if new_case {
@@ -868,21 +991,46 @@ impl<W: Write> Writer<W> {
                         }
                     }
 
-                    new_case = !case.fall_through;
+                    // Cases with empty bodies that fall through just share
+                    // the clause of the case they fall into.
+                    new_case = !case.fall_through || !case.body.is_empty();
 
-                    if case.fall_through {
+                    if !new_case {
                         write!(self.out, ", ")?;
-                    } else {
-                        writeln!(self.out, ": {{")?;
+                        continue;
                     }
+                    writeln!(self.out, ": {{")?;
 
-                    for sta in case.body.iter() {
-                        self.write_stmt(module, sta, func_ctx, l2.next())?;
+                    let l3 = l2.next();
+                    if case.fall_through {
+                        // WGSL has no fall-through, so emulate it by
+                        // duplicating the bodies of all the cases this one
+                        // would fall into, like the HLSL backend does. Each
+                        // body gets its own scope, since the same expressions
+                        // may be named in several of them.
+                        let end_case_idx = i
+                            + 1
+                            + cases[i + 1..]
+                                .iter()
+                                .position(|case| !case.fall_through)
+                                .unwrap();
+                        for case in &cases[i..=end_case_idx] {
+                            writeln!(self.out, "{l3}{{")?;
+                            let prev_len = self.named_expressions.len();
+                            for sta in case.body.iter() {
+                                self.write_stmt(module, sta, func_ctx, l3.next())?;
+                            }
+                            // Forget the expressions named in the nested scope.
+                            self.named_expressions.truncate(prev_len);
+                            writeln!(self.out, "{l3}}}")?;
+                        }
+                    } else {
+                        for sta in case.body.iter() {
+                            self.write_stmt(module, sta, func_ctx, l3)?;
+                        }
                     }
 
-                    if !case.fall_through {
-                        writeln!(self.out, "{l2}}}")?;
-                    }
+                    writeln!(self.out, "{l2}}}")?;
                 }
 
                 writeln!(self.out, "{level}}}")?
@@ -943,8 +1091,39 @@ impl<W: Write> Writer<W> {
                 if barrier.contains(crate::Barrier::SUB_GROUP) {
                     writeln!(self.out, "{level}subgroupBarrier();")?;
                 }
+
+                if barrier.contains(crate::Barrier::TEXTURE) {
+                    writeln!(self.out, "{level}textureBarrier();")?;
+                }
             }
-            Statement::RayQuery { .. } => unreachable!(),
+            Statement::RayQuery { query, ref fun } => match *fun {
+                crate::RayQueryFunction::Initialize {
+                    acceleration_structure,
+                    descriptor,
+                } => {
+                    write!(self.out, "{level}rayQueryInitialize(")?;
+                    self.write_expr(module, query, func_ctx)?;
+                    write!(self.out, ", ")?;
+                    self.write_expr(module, acceleration_structure, func_ctx)?;
+                    write!(self.out, ", ")?;
+                    self.write_expr(module, descriptor, func_ctx)?;
+                    writeln!(self.out, ");")?;
+                }
+                crate::RayQueryFunction::Proceed { result } => {
+                    write!(self.out, "{level}")?;
+                    let res_name = Baked(result).to_string();
+                    self.start_named_expr(module, result, func_ctx, &res_name)?;
+                    self.named_expressions.insert(result, res_name);
+                    write!(self.out, "rayQueryProceed(")?;
+                    self.write_expr(module, query, func_ctx)?;
+                    writeln!(self.out, ");")?;
+                }
+                crate::RayQueryFunction::Terminate => {
+                    return Err(Error::Unimplemented(
+                        "terminating ray queries, which WGSL can't express".into(),
+                    ));
+                }
+            },
             Statement::SubgroupBallot { result, predicate } => {
                 write!(self.out, "{level}")?;
                 let res_name = Baked(result).to_string();
@@ -1008,9 +1187,29 @@ impl<W: Write> Writer<W> {
                     (crate::CollectiveOperation::InclusiveScan, crate::SubgroupOperation::Mul) => {
                         write!(self.out, "subgroupInclusiveMul(")?
                     }
+                    (crate::CollectiveOperation::ClusteredReduce(_), op) => {
+                        let fun = match op {
+                            crate::SubgroupOperation::Add => "Add",
+                            crate::SubgroupOperation::Mul => "Mul",
+                            crate::SubgroupOperation::Max => "Max",
+                            crate::SubgroupOperation::Min => "Min",
+                            crate::SubgroupOperation::And => "And",
+                            crate::SubgroupOperation::Or => "Or",
+                            crate::SubgroupOperation::Xor => "Xor",
+                            crate::SubgroupOperation::All | crate::SubgroupOperation::Any => {
+                                unreachable!(
+                                    "validation rejects clustered `All` and `Any` reductions"
+                                )
+                            }
+                        };
+                        write!(self.out, "subgroup{fun}(")?
+                    }
                     _ => unimplemented!(),
                 }
                 self.write_expr(module, argument, func_ctx)?;
+                if let crate::CollectiveOperation::ClusteredReduce(size) = collective_op {
+                    write!(self.out, ", {size}u")?;
+                }
                 writeln!(self.out, ");")?;
             }
             Statement::SubgroupGather {
@@ -1042,15 +1241,24 @@ impl<W: Write> Writer<W> {
                     crate::GatherMode::ShuffleXor(_) => {
                         write!(self.out, "subgroupShuffleXor(")?;
                     }
+                    crate::GatherMode::QuadBroadcast(_) => {
+                        write!(self.out, "quadBroadcast(")?;
+                    }
+                    crate::GatherMode::QuadSwap(direction) => match direction {
+                        crate::Direction::X => write!(self.out, "quadSwapX(")?,
+                        crate::Direction::Y => write!(self.out, "quadSwapY(")?,
+                        crate::Direction::Diagonal => write!(self.out, "quadSwapDiagonal(")?,
+                    },
                 }
                 self.write_expr(module, argument, func_ctx)?;
                 match mode {
-                    crate::GatherMode::BroadcastFirst => {}
+                    crate::GatherMode::BroadcastFirst | crate::GatherMode::QuadSwap(_) => {}
                     crate::GatherMode::Broadcast(index)
                     | crate::GatherMode::Shuffle(index)
                     | crate::GatherMode::ShuffleDown(index)
                     | crate::GatherMode::ShuffleUp(index)
-                    | crate::GatherMode::ShuffleXor(index) => {
+                    | crate::GatherMode::ShuffleXor(index)
+                    | crate::GatherMode::QuadBroadcast(index) => {
                         write!(self.out, ", ")?;
                         self.write_expr(module, index, func_ctx)?;
                     }
@@ -1232,6 +1440,7 @@ impl<W: Write> Writer<W> {
 
         match expressions[expr] {
             Expression::Literal(literal) => match literal {
+                crate::Literal::F16(value) => write!(self.out, "{value}h")?,
                 crate::Literal::F32(value) => write!(self.out, "{value}f")?,
                 crate::Literal::U32(value) => write!(self.out, "{value}u")?,
                 crate::Literal::I32(value) => {
@@ -1271,6 +1480,9 @@ impl<W: Write> Writer<W> {
                     self.write_const_expression(module, constant.init)?;
                 }
             }
+            Expression::Override(handle) => {
+                write!(self.out, "{}", self.names[&NameKey::Override(handle)])?;
+            }
             Expression::ZeroValue(ty) => {
                 self.write_type(module, ty)?;
                 write!(self.out, "()")?;
@@ -1292,7 +1504,44 @@ impl<W: Write> Writer<W> {
                 write_expression(self, value)?;
                 write!(self.out, ")")?;
             }
-            _ => unreachable!(),
+            Expression::Binary { op, left, right } => {
+                write!(self.out, "(")?;
+                write_expression(self, left)?;
+                write!(self.out, " {} ", back::binary_operation_str(op))?;
+                write_expression(self, right)?;
+                write!(self.out, ")")?;
+            }
+            Expression::Unary { op, expr } => {
+                let unary = match op {
+                    crate::UnaryOperator::Negate => "-",
+                    crate::UnaryOperator::LogicalNot => "!",
+                    crate::UnaryOperator::BitwiseNot => "~",
+                };
+
+                write!(self.out, "{unary}(")?;
+                write_expression(self, expr)?;
+                write!(self.out, ")")?;
+            }
+            Expression::Select {
+                condition,
+                accept,
+                reject,
+            } => {
+                write!(self.out, "select(")?;
+                write_expression(self, reject)?;
+                write!(self.out, ", ")?;
+                write_expression(self, accept)?;
+                write!(self.out, ", ")?;
+                write_expression(self, condition)?;
+                write!(self.out, ")")?;
+            }
+            ref other => {
+                // Only override-expressions that couldn't be evaluated
+                // ahead of time end up here.
+                return Err(Error::Unimplemented(format!(
+                    "writing {other:?} in an override-expression"
+                )));
+            }
         }
 
         Ok(())
@@ -1332,9 +1581,13 @@ impl<W: Write> Writer<W> {
         match *expression {
             Expression::Literal(_)
             | Expression::Constant(_)
+            | Expression::Override(_)
             | Expression::ZeroValue(_)
             | Expression::Compose { .. }
-            | Expression::Splat { .. } => {
+            | Expression::Splat { .. }
+            | Expression::Binary { .. }
+            | Expression::Unary { .. }
+            | Expression::Select { .. } => {
                 self.write_possibly_const_expression(
                     module,
                     expr,
@@ -1342,19 +1595,11 @@ impl<W: Write> Writer<W> {
                     |writer, expr| writer.write_expr(module, expr, func_ctx),
                 )?;
             }
-            Expression::Override(_) => unreachable!(),
             Expression::FunctionArgument(pos) => {
                 let name_key = func_ctx.argument_key(pos);
                 let name = &self.names[&name_key];
                 write!(self.out, "{name}")?;
             }
-            Expression::Binary { op, left, right } => {
-                write!(self.out, "(")?;
-                self.write_expr(module, left, func_ctx)?;
-                write!(self.out, " {} ", back::binary_operation_str(op))?;
-                self.write_expr(module, right, func_ctx)?;
-                write!(self.out, ")")?;
-            }
             Expression::Access { base, index } => {
                 self.write_expr_with_indirection(module, base, func_ctx, indirection)?;
                 write!(self.out, "[")?;
@@ -1569,6 +1814,12 @@ impl<W: Write> Writer<W> {
                 convert,
             } => {
                 let inner = func_ctx.resolve_type(expr, &module.types);
+                // WGSL can't `bitcast` booleans, but the only valid bitcast of
+                // a boolean is to itself, which a conversion does just as well.
+                let convert = match kind {
+                    crate::ScalarKind::Bool => Some(crate::BOOL_WIDTH),
+                    _ => convert,
+                };
                 match *inner {
                     TypeInner::Matrix {
                         columns,
@@ -1706,6 +1957,8 @@ impl<W: Write> Writer<W> {
                     Mf::Pow => Function::Regular("pow"),
                     // geometry
                     Mf::Dot => Function::Regular("dot"),
+                    Mf::Dot4I8Packed => Function::Regular("dot4I8Packed"),
+                    Mf::Dot4U8Packed => Function::Regular("dot4U8Packed"),
                     Mf::Cross => Function::Regular("cross"),
                     Mf::Distance => Function::Regular("distance"),
                     Mf::Length => Function::Regular("length"),
@@ -1723,6 +1976,7 @@ impl<W: Write> Writer<W> {
                     Mf::InverseSqrt => Function::Regular("inverseSqrt"),
                     Mf::Transpose => Function::Regular("transpose"),
                     Mf::Determinant => Function::Regular("determinant"),
+                    Mf::QuantizeToF16 => Function::Regular("quantizeToF16"),
                     // bits
                     Mf::CountTrailingZeros => Function::Regular("countTrailingZeros"),
                     Mf::CountLeadingZeros => Function::Regular("countLeadingZeros"),
@@ -1740,6 +1994,8 @@ impl<W: Write> Writer<W> {
                     Mf::Pack2x16float => Function::Regular("pack2x16float"),
                     Mf::Pack4xI8 => Function::Regular("pack4xI8"),
                     Mf::Pack4xU8 => Function::Regular("pack4xU8"),
+                    Mf::Pack4xI8Clamp => Function::Regular("pack4xI8Clamp"),
+                    Mf::Pack4xU8Clamp => Function::Regular("pack4xU8Clamp"),
                     // data unpacking
                     Mf::Unpack4x8snorm => Function::Regular("unpack4x8snorm"),
                     Mf::Unpack4x8unorm => Function::Regular("unpack4x8unorm"),
@@ -1790,32 +2046,6 @@ impl<W: Write> Writer<W> {
                     self.out.write_char(back::COMPONENTS[sc as usize])?;
                 }
             }
-            Expression::Unary { op, expr } => {
-                let unary = match op {
-                    crate::UnaryOperator::Negate => "-",
-                    crate::UnaryOperator::LogicalNot => "!",
-                    crate::UnaryOperator::BitwiseNot => "~",
-                };
-
-                write!(self.out, "{unary}(")?;
-                self.write_expr(module, expr, func_ctx)?;
-
-                write!(self.out, ")")?
-            }
-
-            Expression::Select {
-                condition,
-                accept,
-                reject,
-            } => {
-                write!(self.out, "select(")?;
-                self.write_expr(module, reject, func_ctx)?;
-                write!(self.out, ", ")?;
-                self.write_expr(module, accept, func_ctx)?;
-                write!(self.out, ", ")?;
-                self.write_expr(module, condition, func_ctx)?;
-                write!(self.out, ")")?
-            }
             Expression::Derivative { axis, ctrl, expr } => {
                 use crate::{DerivativeAxis as Axis, DerivativeControl as Ctrl};
                 let op = match (axis, ctrl) {
@@ -1847,8 +2077,21 @@ impl<W: Write> Writer<W> {
 
                 write!(self.out, ")")?
             }
-            // Not supported yet
-            Expression::RayQueryGetIntersection { .. } => unreachable!(),
+            Expression::RayQueryGetIntersection {
+                query,
+                committed: true,
+            } => {
+                write!(self.out, "rayQueryGetCommittedIntersection(")?;
+                self.write_expr(module, query, func_ctx)?;
+                write!(self.out, ")")?;
+            }
+            Expression::RayQueryGetIntersection {
+                committed: false, ..
+            } => {
+                return Err(Error::Unimplemented(
+                    "candidate ray intersections, which WGSL can't express".into(),
+                ));
+            }
             // Nothing to do here, since call expression already cached
             Expression::CallResult(_)
             | Expression::AtomicResult { .. }
@@ -1931,6 +2174,31 @@ impl<W: Write> Writer<W> {
         Ok(())
     }
 
+    /// Helper method used to write pipeline-overridable constants
+    ///
+    /// # Notes
+    /// Ends in a newline
+    fn write_override(
+        &mut self,
+        module: &Module,
+        handle: Handle<crate::Override>,
+    ) -> BackendResult {
+        let override_ = &module.overrides[handle];
+        if let Some(id) = override_.id {
+            self.write_attributes(&[Attribute::Id(id)])?;
+        }
+        let name = &self.names[&NameKey::Override(handle)];
+        write!(self.out, "override {name}: ")?;
+        self.write_type(module, override_.ty)?;
+        if let Some(init) = override_.init {
+            write!(self.out, " = ")?;
+            self.write_const_expression(module, init)?;
+        }
+        writeln!(self.out, ";")?;
+
+        Ok(())
+    }
+
     // See https://github.com/rust-lang/rust-clippy/issues/4979.
     #[allow(clippy::missing_const_for_fn)]
     pub fn finish(self) -> W {
@@ -1960,9 +2228,9 @@ fn builtin_str(built_in: crate::BuiltIn) -> Result<&'static str, Error> {
         Bi::SubgroupId => "subgroup_id",
         Bi::SubgroupSize => "subgroup_size",
         Bi::SubgroupInvocationId => "subgroup_invocation_id",
+        Bi::ClipDistance => "clip_distances",
         Bi::BaseInstance
         | Bi::BaseVertex
-        | Bi::ClipDistance
         | Bi::CullDistance
         | Bi::PointSize
         | Bi::PointCoord
@@ -1995,6 +2263,10 @@ const fn scalar_kind_str(scalar: crate::Scalar) -> &'static str {
             kind: Sk::Float,
             width: 4,
         } => "f32",
+        Scalar {
+            kind: Sk::Float,
+            width: 2,
+        } => "f16",
         Scalar {
             kind: Sk::Sint,
             width: 4,
@@ -2128,7 +2400,7 @@ fn map_binding_to_attribute(binding: &crate::Binding) -> Vec<Attribute> {
             location,
             interpolation,
             sampling,
-            second_blend_source: false,
+            blend_src: None,
         } => vec![
             Attribute::Location(location),
             Attribute::Interpolate(interpolation, sampling),
@@ -2137,10 +2409,10 @@ fn map_binding_to_attribute(binding: &crate::Binding) -> Vec<Attribute> {
             location,
             interpolation,
             sampling,
-            second_blend_source: true,
+            blend_src: Some(blend_src),
         } => vec![
             Attribute::Location(location),
-            Attribute::SecondBlendSource,
+            Attribute::BlendSrc(blend_src),
             Attribute::Interpolate(interpolation, sampling),
         ],
     }
@@ -3,6 +3,7 @@ use crate::arena::{Arena, Handle};
 
 pub struct ExpressionTracer<'tracer> {
     pub constants: &'tracer Arena<crate::Constant>,
+    pub overrides: &'tracer Arena<crate::Override>,
 
     /// The arena in which we are currently tracing expressions.
     pub expressions: &'tracer Arena<crate::Expression>,
@@ -13,6 +14,12 @@ pub struct ExpressionTracer<'tracer> {
     /// The used map for `constants`.
     pub constants_used: &'tracer mut HandleSet<crate::Constant>,
 
+    /// The used map for `overrides`.
+    pub overrides_used: &'tracer mut HandleSet<crate::Override>,
+
+    /// The used map for the module's global variables.
+    pub global_variables_used: &'tracer mut HandleSet<crate::GlobalVariable>,
+
     /// The used set for `arena`.
     ///
     /// This points to whatever arena holds the expressions we are
@@ -68,12 +75,17 @@ impl<'tracer> ExpressionTracer<'tracer> {
                 // Expressions that do not contain handles that need to be traced.
                 Ex::Literal(_)
                 | Ex::FunctionArgument(_)
-                | Ex::GlobalVariable(_)
                 | Ex::LocalVariable(_)
-                | Ex::CallResult(_)
                 | Ex::SubgroupBallotResult
                 | Ex::RayQueryProceedResult => {}
 
+                // The `Call` statement that produced this result has
+                // already marked the function as used.
+                Ex::CallResult(_) => {}
+
+                Ex::GlobalVariable(handle) => {
+                    self.global_variables_used.insert(handle);
+                }
                 Ex::Constant(handle) => {
                     self.constants_used.insert(handle);
                     // Constants and expressions are mutually recursive, which
@@ -89,10 +101,19 @@ impl<'tracer> ExpressionTracer<'tracer> {
                         None => self.expressions_used.insert(init),
                     };
                 }
-                Ex::Override(_) => {
-                    // All overrides are considered used by definition. We mark
-                    // their types and initialization expressions as used in
-                    // `compact::compact`, so we have no more work to do here.
+                Ex::Override(handle) => {
+                    self.overrides_used.insert(handle);
+                    // Overrides may refer to each other through their
+                    // initializers, but as with constants, we can look
+                    // through each override and mark its initializer as
+                    // used, since the validator ensures that it precedes
+                    // `expr` in the arena.
+                    if let Some(init) = self.overrides[handle].init {
+                        match self.global_expressions_used {
+                            Some(ref mut used) => used.insert(init),
+                            None => self.expressions_used.insert(init),
+                        };
+                    }
                 }
                 Ex::ZeroValue(ty) => {
                     self.types_used.insert(ty);
@@ -247,16 +268,14 @@ impl ModuleMap {
             // Expressions that do not contain handles that need to be adjusted.
             Ex::Literal(_)
             | Ex::FunctionArgument(_)
-            | Ex::GlobalVariable(_)
             | Ex::LocalVariable(_)
-            | Ex::CallResult(_)
             | Ex::SubgroupBallotResult
             | Ex::RayQueryProceedResult => {}
 
-            // All overrides are retained, so their handles never change.
-            Ex::Override(_) => {}
-
             // Expressions that contain handles that need to be adjusted.
+            Ex::GlobalVariable(ref mut global) => self.global_variables.adjust(global),
+            Ex::CallResult(ref mut function) => self.functions.adjust(function),
+            Ex::Override(ref mut override_) => self.overrides.adjust(override_),
             Ex::Constant(ref mut constant) => self.constants.adjust(constant),
             Ex::ZeroValue(ref mut ty) => self.types.adjust(ty),
             Ex::Compose {
@@ -4,9 +4,13 @@ use super::{FunctionMap, ModuleMap};
 pub struct FunctionTracer<'a> {
     pub function: &'a crate::Function,
     pub constants: &'a crate::Arena<crate::Constant>,
+    pub overrides: &'a crate::Arena<crate::Override>,
 
     pub types_used: &'a mut HandleSet<crate::Type>,
     pub constants_used: &'a mut HandleSet<crate::Constant>,
+    pub overrides_used: &'a mut HandleSet<crate::Override>,
+    pub global_variables_used: &'a mut HandleSet<crate::GlobalVariable>,
+    pub functions_used: &'a mut HandleSet<crate::Function>,
     pub global_expressions_used: &'a mut HandleSet<crate::Expression>,
 
     /// Function-local expressions used.
@@ -47,10 +51,13 @@ impl<'a> FunctionTracer<'a> {
     fn as_expression(&mut self) -> super::expressions::ExpressionTracer {
         super::expressions::ExpressionTracer {
             constants: self.constants,
+            overrides: self.overrides,
             expressions: &self.function.expressions,
 
             types_used: self.types_used,
             constants_used: self.constants_used,
+            overrides_used: self.overrides_used,
+            global_variables_used: self.global_variables_used,
             expressions_used: &mut self.expressions_used,
             global_expressions_used: Some(&mut self.global_expressions_used),
         }
@@ -100,7 +107,16 @@ impl FunctionMap {
         std::mem::swap(&mut function.named_expressions, reuse);
         assert!(reuse.is_empty());
 
+        // Adjust diagnostic filter scopes, dropping those left empty.
+        for scope in function.diagnostic_filter_scopes.iter_mut() {
+            self.expressions
+                .adjust_range(&mut scope.expressions, &function.expressions);
+        }
+        function
+            .diagnostic_filter_scopes
+            .retain(|scope| scope.expressions.first_and_last().is_some());
+
         // Adjust statements.
-        self.adjust_body(function);
+        self.adjust_body(function, module_map);
     }
 }
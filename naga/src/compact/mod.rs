@@ -30,58 +30,82 @@ use handle_set_map::HandleMap;
 ///
 /// If `module` has not passed validation, this may panic.
 pub fn compact(module: &mut crate::Module) {
+    compact_impl(module, KeepUnused::Yes);
+}
+
+/// Remove everything from `module` that the selected entry points don't use.
+///
+/// Drop every entry point for which `keep` returns `false`. Then
+/// remove all functions, global variables, types, constants,
+/// overrides, and expressions that the remaining entry points don't
+/// use, directly or indirectly, adjusting all handles as necessary.
+/// Unlike [`compact`], this does not treat globals, functions, named
+/// types, named constants, or overrides as used by definition. Only
+/// special types are always retained.
+///
+/// This is meant for modules with many entry points, like uber-shaders,
+/// where each pipeline only uses one or two of them: backends generate
+/// code for everything in the module, so compacting first can shrink
+/// the output considerably.
+///
+/// # Panics
+///
+/// If `module` has not passed validation, this may panic.
+pub fn compact_entry_points<F>(module: &mut crate::Module, keep: F)
+where
+    F: FnMut(&crate::EntryPoint) -> bool,
+{
+    module.entry_points.retain(keep);
+    compact_impl(module, KeepUnused::No);
+}
+
+/// Whether to treat module-scope items as used even if nothing refers to them.
+#[derive(Clone, Copy, PartialEq)]
+enum KeepUnused {
+    /// Only retain the items reachable from the entry points.
+    No,
+
+    /// Treat all globals, functions, named types, named constants, and
+    /// overrides as used.
+    Yes,
+}
+
+fn compact_impl(module: &mut crate::Module, keep_unused: KeepUnused) {
     let mut module_tracer = ModuleTracer::new(module);
 
-    // We treat all globals as used by definition.
-    log::trace!("tracing global variables");
-    {
-        for (_, global) in module.global_variables.iter() {
-            log::trace!("tracing global {:?}", global.name);
-            module_tracer.types_used.insert(global.ty);
-            if let Some(init) = global.init {
-                module_tracer.global_expressions_used.insert(init);
+    if keep_unused == KeepUnused::Yes {
+        // We treat all globals as used by definition.
+        log::trace!("tracing global variables");
+        module_tracer
+            .global_variables_used
+            .insert_iter(module.global_variables.iter().map(|(handle, _)| handle));
+
+        // We treat all named constants as used by definition.
+        for (handle, constant) in module.constants.iter() {
+            if constant.name.is_some() {
+                module_tracer.constants_used.insert(handle);
+                module_tracer.global_expressions_used.insert(constant.init);
             }
         }
-    }
-
-    // We treat all special types as used by definition.
-    module_tracer.trace_special_types(&module.special_types);
 
-    // We treat all named constants as used by definition.
-    for (handle, constant) in module.constants.iter() {
-        if constant.name.is_some() {
-            module_tracer.constants_used.insert(handle);
-            module_tracer.global_expressions_used.insert(constant.init);
+        // We treat all overrides as used by definition.
+        for (handle, override_) in module.overrides.iter() {
+            module_tracer.overrides_used.insert(handle);
+            if let Some(init) = override_.init {
+                module_tracer.global_expressions_used.insert(init);
+            }
         }
-    }
 
-    // We treat all overrides as used by definition.
-    for (_, override_) in module.overrides.iter() {
-        module_tracer.types_used.insert(override_.ty);
-        if let Some(init) = override_.init {
-            module_tracer.global_expressions_used.insert(init);
-        }
+        // We assume that all functions are used.
+        module_tracer
+            .functions_used
+            .insert_iter(module.functions.iter().map(|(handle, _)| handle));
     }
 
-    // We assume that all functions are used.
-    //
-    // Observe which types, constant expressions, constants, and
-    // expressions each function uses, and produce maps for each
-    // function from pre-compaction to post-compaction expression
-    // handles.
-    log::trace!("tracing functions");
-    let function_maps: Vec<FunctionMap> = module
-        .functions
-        .iter()
-        .map(|(_, f)| {
-            log::trace!("tracing function {:?}", f.name);
-            let mut function_tracer = module_tracer.as_function(f);
-            function_tracer.trace();
-            FunctionMap::from(function_tracer)
-        })
-        .collect();
+    // We treat all special types as used by definition.
+    module_tracer.trace_special_types(&module.special_types);
 
-    // Similarly, observe what each entry point actually uses.
+    // Observe what each entry point actually uses.
     log::trace!("tracing entry points");
     let entry_point_maps: Vec<FunctionMap> = module
         .entry_points
@@ -94,26 +118,77 @@ pub fn compact(module: &mut crate::Module) {
         })
         .collect();
 
+    // Similarly, observe which types, constant expressions,
+    // constants, globals, functions, and expressions each used
+    // function uses, and produce maps for each function from
+    // pre-compaction to post-compaction expression handles.
+    //
+    // Functions can only be used by entry points or other functions.
+    // Callees usually precede their callers in the arena, so a single
+    // pass from back to front finds them all, but keep going until
+    // nothing new turns up, to be safe.
+    log::trace!("tracing functions");
+    let mut function_maps: Vec<Option<FunctionMap>> = std::iter::repeat_with(|| None)
+        .take(module.functions.len())
+        .collect();
+    loop {
+        let mut traced_any = false;
+        for (handle, f) in module.functions.iter().rev() {
+            if !module_tracer.functions_used.contains(handle)
+                || function_maps[handle.index()].is_some()
+            {
+                continue;
+            }
+            log::trace!("tracing function {:?}", f.name);
+            let mut function_tracer = module_tracer.as_function(f);
+            function_tracer.trace();
+            function_maps[handle.index()] = Some(FunctionMap::from(function_tracer));
+            traced_any = true;
+        }
+        if !traced_any {
+            break;
+        }
+    }
+
+    // Note the types and initializers of the globals we've found to
+    // be used.
+    for (handle, global) in module.global_variables.iter() {
+        if module_tracer.global_variables_used.contains(handle) {
+            log::trace!("tracing global {:?}", global.name);
+            module_tracer.types_used.insert(global.ty);
+            if let Some(init) = global.init {
+                module_tracer.global_expressions_used.insert(init);
+            }
+        }
+    }
+
     // Given that the above steps have marked all the constant
     // expressions used directly by globals, constants, functions, and
     // entry points, walk the constant expression arena to find all
     // constant expressions used, directly or indirectly.
     module_tracer.as_const_expression().trace_expressions();
 
-    // Constants' initializers are taken care of already, because
-    // expression tracing sees through constants. But we still need to
-    // note type usage.
+    // Constants' and overrides' initializers are taken care of
+    // already, because expression tracing sees through them. But we
+    // still need to note type usage.
     for (handle, constant) in module.constants.iter() {
         if module_tracer.constants_used.contains(handle) {
             module_tracer.types_used.insert(constant.ty);
         }
     }
+    for (handle, override_) in module.overrides.iter() {
+        if module_tracer.overrides_used.contains(handle) {
+            module_tracer.types_used.insert(override_.ty);
+        }
+    }
 
     // Treat all named types as used.
-    for (handle, ty) in module.types.iter() {
-        log::trace!("tracing type {:?}, name {:?}", handle, ty.name);
-        if ty.name.is_some() {
-            module_tracer.types_used.insert(handle);
+    if keep_unused == KeepUnused::Yes {
+        for (handle, ty) in module.types.iter() {
+            log::trace!("tracing type {:?}, name {:?}", handle, ty.name);
+            if ty.name.is_some() {
+                module_tracer.types_used.insert(handle);
+            }
         }
     }
 
@@ -167,34 +242,49 @@ pub fn compact(module: &mut crate::Module) {
         }
     });
 
-    // Adjust override types and initializers.
+    // Drop unused overrides in place, reusing existing storage.
     log::trace!("adjusting overrides");
-    for (_, override_) in module.overrides.iter_mut() {
-        module_map.types.adjust(&mut override_.ty);
-        if let Some(init) = override_.init.as_mut() {
-            module_map.global_expressions.adjust(init);
+    module.overrides.retain_mut(|handle, override_| {
+        if module_map.overrides.used(handle) {
+            module_map.types.adjust(&mut override_.ty);
+            if let Some(ref mut init) = override_.init {
+                module_map.global_expressions.adjust(init);
+            }
+            true
+        } else {
+            false
         }
-    }
+    });
 
-    // Adjust global variables' types and initializers.
+    // Drop unused global variables in place, reusing existing storage.
     log::trace!("adjusting global variables");
-    for (_, global) in module.global_variables.iter_mut() {
-        log::trace!("adjusting global {:?}", global.name);
-        module_map.types.adjust(&mut global.ty);
-        if let Some(ref mut init) = global.init {
-            module_map.global_expressions.adjust(init);
+    module.global_variables.retain_mut(|handle, global| {
+        if module_map.global_variables.used(handle) {
+            log::trace!("adjusting global {:?}", global.name);
+            module_map.types.adjust(&mut global.ty);
+            if let Some(ref mut init) = global.init {
+                module_map.global_expressions.adjust(init);
+            }
+            true
+        } else {
+            false
         }
-    }
+    });
 
     // Temporary storage to help us reuse allocations of existing
     // named expression tables.
     let mut reused_named_expressions = crate::NamedExpressions::default();
 
-    // Compact each function.
-    for ((_, function), map) in module.functions.iter_mut().zip(function_maps.iter()) {
-        log::trace!("compacting function {:?}", function.name);
-        map.compact(function, &module_map, &mut reused_named_expressions);
-    }
+    // Drop unused functions and compact the rest.
+    module.functions.retain_mut(|handle, function| {
+        if let Some(ref map) = function_maps[handle.index()] {
+            log::trace!("compacting function {:?}", function.name);
+            map.compact(function, &module_map, &mut reused_named_expressions);
+            true
+        } else {
+            false
+        }
+    });
 
     // Compact each entry point.
     for (entry, map) in module.entry_points.iter_mut().zip(entry_point_maps.iter()) {
@@ -211,6 +301,9 @@ struct ModuleTracer<'module> {
     module: &'module crate::Module,
     types_used: HandleSet<crate::Type>,
     constants_used: HandleSet<crate::Constant>,
+    overrides_used: HandleSet<crate::Override>,
+    global_variables_used: HandleSet<crate::GlobalVariable>,
+    functions_used: HandleSet<crate::Function>,
     global_expressions_used: HandleSet<crate::Expression>,
 }
 
@@ -220,6 +313,9 @@ impl<'module> ModuleTracer<'module> {
             module,
             types_used: HandleSet::for_arena(&module.types),
             constants_used: HandleSet::for_arena(&module.constants),
+            overrides_used: HandleSet::for_arena(&module.overrides),
+            global_variables_used: HandleSet::for_arena(&module.global_variables),
+            functions_used: HandleSet::for_arena(&module.functions),
             global_expressions_used: HandleSet::for_arena(&module.global_expressions),
         }
     }
@@ -253,8 +349,11 @@ impl<'module> ModuleTracer<'module> {
         expressions::ExpressionTracer {
             expressions: &self.module.global_expressions,
             constants: &self.module.constants,
+            overrides: &self.module.overrides,
             types_used: &mut self.types_used,
             constants_used: &mut self.constants_used,
+            overrides_used: &mut self.overrides_used,
+            global_variables_used: &mut self.global_variables_used,
             expressions_used: &mut self.global_expressions_used,
             global_expressions_used: None,
         }
@@ -267,8 +366,12 @@ impl<'module> ModuleTracer<'module> {
         FunctionTracer {
             function,
             constants: &self.module.constants,
+            overrides: &self.module.overrides,
             types_used: &mut self.types_used,
             constants_used: &mut self.constants_used,
+            overrides_used: &mut self.overrides_used,
+            global_variables_used: &mut self.global_variables_used,
+            functions_used: &mut self.functions_used,
             global_expressions_used: &mut self.global_expressions_used,
             expressions_used: HandleSet::for_arena(&function.expressions),
         }
@@ -278,6 +381,9 @@ impl<'module> ModuleTracer<'module> {
 struct ModuleMap {
     types: HandleMap<crate::Type>,
     constants: HandleMap<crate::Constant>,
+    overrides: HandleMap<crate::Override>,
+    global_variables: HandleMap<crate::GlobalVariable>,
+    functions: HandleMap<crate::Function>,
     global_expressions: HandleMap<crate::Expression>,
 }
 
@@ -286,6 +392,9 @@ impl From<ModuleTracer<'_>> for ModuleMap {
         ModuleMap {
             types: HandleMap::from_set(used.types_used),
             constants: HandleMap::from_set(used.constants_used),
+            overrides: HandleMap::from_set(used.overrides_used),
+            global_variables: HandleMap::from_set(used.global_variables_used),
+            functions: HandleMap::from_set(used.functions_used),
             global_expressions: HandleMap::from_set(used.global_expressions_used),
         }
     }
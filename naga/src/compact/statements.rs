@@ -1,5 +1,5 @@
 use super::functions::FunctionTracer;
-use super::FunctionMap;
+use super::{FunctionMap, ModuleMap};
 use crate::arena::Handle;
 
 impl FunctionTracer<'_> {
@@ -84,10 +84,11 @@ impl FunctionTracer<'_> {
                         self.expressions_used.insert(result);
                     }
                     St::Call {
-                        function: _,
+                        function,
                         ref arguments,
                         result,
                     } => {
+                        self.functions_used.insert(function);
                         for expr in arguments {
                             self.expressions_used.insert(*expr);
                         }
@@ -120,12 +121,13 @@ impl FunctionTracer<'_> {
                         result,
                     } => {
                         match mode {
-                            crate::GatherMode::BroadcastFirst => {}
+                            crate::GatherMode::BroadcastFirst | crate::GatherMode::QuadSwap(_) => {}
                             crate::GatherMode::Broadcast(index)
                             | crate::GatherMode::Shuffle(index)
                             | crate::GatherMode::ShuffleDown(index)
                             | crate::GatherMode::ShuffleUp(index)
-                            | crate::GatherMode::ShuffleXor(index) => {
+                            | crate::GatherMode::ShuffleXor(index)
+                            | crate::GatherMode::QuadBroadcast(index) => {
                                 self.expressions_used.insert(index);
                             }
                         }
@@ -137,6 +139,7 @@ impl FunctionTracer<'_> {
                     St::Break
                     | St::Continue
                     | St::Kill
+                    | St::Demote
                     | St::Barrier(_)
                     | St::Return { value: None } => {}
                 }
@@ -182,7 +185,7 @@ impl FunctionTracer<'_> {
 }
 
 impl FunctionMap {
-    pub fn adjust_body(&self, function: &mut crate::Function) {
+    pub fn adjust_body(&self, function: &mut crate::Function, module_map: &ModuleMap) {
         let block = &mut function.body;
         let mut worklist: Vec<&mut [crate::Statement]> = vec![block];
         let adjust = |handle: &mut Handle<crate::Expression>| {
@@ -269,10 +272,11 @@ impl FunctionMap {
                         adjust(result);
                     }
                     St::Call {
-                        function: _,
+                        ref mut function,
                         ref mut arguments,
                         ref mut result,
                     } => {
+                        module_map.functions.adjust(function);
                         for expr in arguments {
                             adjust(expr);
                         }
@@ -311,12 +315,13 @@ impl FunctionMap {
                         ref mut result,
                     } => {
                         match *mode {
-                            crate::GatherMode::BroadcastFirst => {}
+                            crate::GatherMode::BroadcastFirst | crate::GatherMode::QuadSwap(_) => {}
                             crate::GatherMode::Broadcast(ref mut index)
                             | crate::GatherMode::Shuffle(ref mut index)
                             | crate::GatherMode::ShuffleDown(ref mut index)
                             | crate::GatherMode::ShuffleUp(ref mut index)
-                            | crate::GatherMode::ShuffleXor(ref mut index) => adjust(index),
+                            | crate::GatherMode::ShuffleXor(ref mut index)
+                            | crate::GatherMode::QuadBroadcast(ref mut index) => adjust(index),
                         }
                         adjust(argument);
                         adjust(result);
@@ -326,6 +331,7 @@ impl FunctionMap {
                     St::Break
                     | St::Continue
                     | St::Kill
+                    | St::Demote
                     | St::Barrier(_)
                     | St::Return { value: None } => {}
                 }
@@ -0,0 +1,413 @@
+//! [`DiagnosticFilter`]s and supporting functionality.
+//!
+//! Diagnostic filters let shader authors change the [`Severity`] of the
+//! diagnostics Naga reports for a given [`FilterableTriggeringRule`], either for
+//! an entire module, a single function, or a region of a function's body.
+//!
+//! See also <https://www.w3.org/TR/WGSL/#diagnostics>.
+
+use crate::{Arena, Expression, Handle, Range};
+
+#[cfg(feature = "wgsl-in")]
+use crate::{FastIndexMap, Span};
+
+#[cfg(feature = "arbitrary")]
+use arbitrary::Arbitrary;
+#[cfg(feature = "deserialize")]
+use serde::Deserialize;
+#[cfg(feature = "serialize")]
+use serde::Serialize;
+
+/// A severity set on a [`DiagnosticFilter`].
+///
+/// <https://www.w3.org/TR/WGSL/#diagnostic-severity>
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub enum Severity {
+    Off,
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    const ERROR: &'static str = "error";
+    const WARNING: &'static str = "warning";
+    const INFO: &'static str = "info";
+    const OFF: &'static str = "off";
+
+    /// Convert from a sentinel word in WGSL into its associated [`Severity`], if possible.
+    pub fn from_ident(s: &str) -> Option<Self> {
+        Some(match s {
+            Self::ERROR => Self::Error,
+            Self::WARNING => Self::Warning,
+            Self::INFO => Self::Info,
+            Self::OFF => Self::Off,
+            _ => return None,
+        })
+    }
+
+    /// Maps this [`Severity`] into the sentinel word associated with it in WGSL.
+    pub const fn to_ident(self) -> &'static str {
+        match self {
+            Self::Error => Self::ERROR,
+            Self::Warning => Self::WARNING,
+            Self::Info => Self::INFO,
+            Self::Off => Self::OFF,
+        }
+    }
+
+    /// Report `err` at this severity.
+    ///
+    /// Only [`Severity::Error`] turns `err` into a failure. Naga has no channel
+    /// for reporting lesser diagnostics to its caller yet, so [`Severity::Info`]
+    /// and [`Severity::Warning`] diagnostics are handed to `log_handler` along
+    /// with the [`log::Level`] they should be logged at, and [`Severity::Off`]
+    /// diagnostics are dropped.
+    pub(crate) fn report_diag<E>(
+        self,
+        err: E,
+        log_handler: impl FnOnce(E, log::Level),
+    ) -> Result<(), E> {
+        let log_level = match self {
+            Severity::Off => return Ok(()),
+            Severity::Info => log::Level::Info,
+            Severity::Warning => log::Level::Warn,
+            Severity::Error => return Err(err),
+        };
+        log_handler(err, log_level);
+        Ok(())
+    }
+}
+
+/// A filterable triggering rule in a [`DiagnosticFilter`].
+///
+/// <https://www.w3.org/TR/WGSL/#filterable-triggering-rules>
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub enum FilterableTriggeringRule {
+    /// A rule defined by the WGSL standard, which Naga knows how to trigger.
+    Standard(StandardFilterableTriggeringRule),
+    /// A single-identifier rule that Naga does not recognize.
+    ///
+    /// The WGSL standard requires these to be accepted (with a warning), so
+    /// that shaders remain portable to implementations with more rules.
+    Unknown(Box<str>),
+    /// A rule of the form `namespace.rule`, reserved for implementations.
+    ///
+    /// Naga does not define any of these, so they never match.
+    User(Box<[Box<str>; 2]>),
+}
+
+/// A filterable triggering rule defined by the WGSL standard.
+///
+/// <https://www.w3.org/TR/WGSL/#filterable-triggering-rules>
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub enum StandardFilterableTriggeringRule {
+    /// Derivatives, or operations that compute them implicitly, used in
+    /// non-uniform control flow.
+    DerivativeUniformity,
+}
+
+impl StandardFilterableTriggeringRule {
+    const DERIVATIVE_UNIFORMITY: &'static str = "derivative_uniformity";
+
+    /// Convert from a sentinel word in WGSL into its associated
+    /// [`StandardFilterableTriggeringRule`], if possible.
+    pub fn from_ident(s: &str) -> Option<Self> {
+        Some(match s {
+            Self::DERIVATIVE_UNIFORMITY => Self::DerivativeUniformity,
+            _ => return None,
+        })
+    }
+
+    /// Maps this [`StandardFilterableTriggeringRule`] into the sentinel word
+    /// associated with it in WGSL.
+    pub const fn to_ident(self) -> &'static str {
+        match self {
+            Self::DerivativeUniformity => Self::DERIVATIVE_UNIFORMITY,
+        }
+    }
+
+    /// The severity at which this rule is reported if no [`DiagnosticFilter`]
+    /// applies to it.
+    ///
+    /// The WGSL specification makes `derivative_uniformity` an error by default,
+    /// but Naga's uniformity analysis still rejects too many valid shaders, so
+    /// it stays off unless a filter raises it.
+    pub const fn default_severity(self) -> Severity {
+        match self {
+            Self::DerivativeUniformity => Severity::Off,
+        }
+    }
+}
+
+impl FilterableTriggeringRule {
+    /// Build a [`FilterableTriggeringRule`] from the identifiers of a WGSL
+    /// `diagnostic_rule_name`: either a single identifier, or two identifiers
+    /// separated by a `.`.
+    pub fn from_idents(first: &str, second: Option<&str>) -> Self {
+        match second {
+            Some(second) => Self::User(Box::new([first.into(), second.into()])),
+            None => match StandardFilterableTriggeringRule::from_ident(first) {
+                Some(rule) => Self::Standard(rule),
+                None => Self::Unknown(first.into()),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for FilterableTriggeringRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::Standard(rule) => write!(f, "{}", rule.to_ident()),
+            Self::Unknown(ref name) => write!(f, "{name}"),
+            Self::User(ref names) => {
+                let [ref namespace, ref name] = **names;
+                write!(f, "{namespace}.{name}")
+            }
+        }
+    }
+}
+
+/// A filter that modifies how diagnostics are emitted for shaders.
+///
+/// <https://www.w3.org/TR/WGSL/#diagnostic-filter>
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub struct DiagnosticFilter {
+    pub new_severity: Severity,
+    pub triggering_rule: FilterableTriggeringRule,
+}
+
+/// A map of diagnostic filters to their severity and the span of their first
+/// occurrence, used to detect conflicting filters in a single scope.
+#[cfg(feature = "wgsl-in")]
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DiagnosticFilterMap(FastIndexMap<FilterableTriggeringRule, (Severity, Span)>);
+
+/// Determines whether [`DiagnosticFilterMap::add`] should consider two
+/// filters with the same triggering rule _and_ severity to conflict.
+#[cfg(feature = "wgsl-in")]
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ShouldConflictOnFullDuplicate {
+    /// Repeating a filter verbatim is fine, as with `diagnostic(…)` directives.
+    No,
+    /// Any repetition of a triggering rule is an error, as with
+    /// `@diagnostic(…)` attributes on the same syntactic element.
+    Yes,
+}
+
+#[cfg(feature = "wgsl-in")]
+impl DiagnosticFilterMap {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the spans of the filters in this map.
+    pub(crate) fn spans(&self) -> impl Iterator<Item = Span> + '_ {
+        self.0.values().map(|&(_, span)| span)
+    }
+
+    /// Add the given `diagnostic_filter` parsed at the given `span` to this map.
+    pub(crate) fn add(
+        &mut self,
+        diagnostic_filter: DiagnosticFilter,
+        span: Span,
+        should_conflict_on_full_duplicate: ShouldConflictOnFullDuplicate,
+    ) -> Result<(), ConflictingDiagnosticRuleError> {
+        use indexmap::map::Entry;
+
+        let &mut Self(ref mut diagnostic_filters) = self;
+        let DiagnosticFilter {
+            new_severity,
+            triggering_rule,
+        } = diagnostic_filter;
+
+        match diagnostic_filters.entry(triggering_rule) {
+            Entry::Vacant(entry) => {
+                entry.insert((new_severity, span));
+            }
+            Entry::Occupied(entry) => {
+                let &(first_severity, first_span) = entry.get();
+                let should_conflict_on_full_duplicate = match should_conflict_on_full_duplicate {
+                    ShouldConflictOnFullDuplicate::Yes => true,
+                    ShouldConflictOnFullDuplicate::No => false,
+                };
+                if first_severity != new_severity || should_conflict_on_full_duplicate {
+                    return Err(ConflictingDiagnosticRuleError {
+                        triggering_rule_spans: [first_span, span],
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Append the filters in this map to `arena` as a chain of
+    /// [`DiagnosticFilterNode`]s hanging off of `parent`, and return the new
+    /// leaf.
+    ///
+    /// If this map is empty, `parent` is returned unchanged.
+    pub(crate) fn into_nodes(
+        self,
+        arena: &mut Arena<DiagnosticFilterNode>,
+        parent: Option<Handle<DiagnosticFilterNode>>,
+    ) -> Option<Handle<DiagnosticFilterNode>> {
+        let Self(diagnostic_filters) = self;
+        diagnostic_filters.into_iter().fold(
+            parent,
+            |parent, (triggering_rule, (new_severity, span))| {
+                Some(arena.append(
+                    DiagnosticFilterNode {
+                        inner: DiagnosticFilter {
+                            new_severity,
+                            triggering_rule,
+                        },
+                        parent,
+                    },
+                    span,
+                ))
+            },
+        )
+    }
+}
+
+/// An error returned by [`DiagnosticFilterMap::add`] when it encounters
+/// conflicting rules.
+#[cfg(feature = "wgsl-in")]
+#[derive(Clone, Debug)]
+pub(crate) struct ConflictingDiagnosticRuleError {
+    pub triggering_rule_spans: [Span; 2],
+}
+
+/// Represents a single parent-linking node in a tree of [`DiagnosticFilter`]s
+/// backed by an [`Arena`].
+///
+/// A single element of a _tree_ of diagnostic filter rules stored in
+/// [`crate::Module::diagnostic_filters`]. When nodes are built by a front-end,
+/// module-applicable rules are chained together in runs based on parse site.
+/// For instance, given the following:
+///
+/// - Module-applicable rules `a` and `b`.
+/// - Rules `c` and `d`, applicable to an entry point called `c_and_d_func`.
+/// - Rule `e`, applicable to an entry point called `e_func`.
+///
+/// The tree would be represented as follows:
+///
+/// ```text
+/// a <- b
+///      ^
+///      |- c <- d
+///      |
+///      \- e
+/// ```
+///
+/// ...where:
+///
+/// - `d` is the leaf of `c_and_d_func`'s [`Function::diagnostic_filter_leaf`],
+/// - `e` is the leaf of `e_func`'s [`Function::diagnostic_filter_leaf`], and
+/// - `b` is [`Module::diagnostic_filter_leaf`].
+///
+/// Statement-level filters continue the same chains: their nodes' parents are
+/// the leaves of the enclosing statement, or of the function.
+///
+/// To find the severity of a rule at a given point, start at the leaf that
+/// applies there and walk towards the root; the first filter that matches the
+/// rule decides its severity. See [`DiagnosticFilterNode::search`].
+///
+/// [`Function::diagnostic_filter_leaf`]: crate::Function::diagnostic_filter_leaf
+/// [`Module::diagnostic_filter_leaf`]: crate::Module::diagnostic_filter_leaf
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub struct DiagnosticFilterNode {
+    pub inner: DiagnosticFilter,
+    pub parent: Option<Handle<DiagnosticFilterNode>>,
+}
+
+impl DiagnosticFilterNode {
+    /// Finds the most specific filter rule applicable to `triggering_rule` from
+    /// the chain of diagnostic filter rules in `arena`, starting with `node`,
+    /// and returns its severity. If none is found, return the value of
+    /// [`StandardFilterableTriggeringRule::default_severity`].
+    ///
+    /// When `triggering_rule` is not applicable to this node, its parent is
+    /// consulted recursively.
+    pub fn search(
+        node: Option<Handle<Self>>,
+        arena: &Arena<Self>,
+        triggering_rule: StandardFilterableTriggeringRule,
+    ) -> Severity {
+        let mut next = node;
+        while let Some(handle) = next {
+            let Self { ref inner, parent } = arena[handle];
+            if inner.triggering_rule == FilterableTriggeringRule::Standard(triggering_rule) {
+                return inner.new_severity;
+            }
+            next = parent;
+        }
+        triggering_rule.default_severity()
+    }
+}
+
+/// A run of a [`Function`]'s expressions governed by statement-level
+/// [`DiagnosticFilter`]s, like those introduced by WGSL's
+/// `@diagnostic(…) { … }`.
+///
+/// Scopes may nest. The leaf of an inner scope always has its enclosing
+/// scope's leaf (or the function's leaf) as an ancestor, and is always
+/// appended to [`Module::diagnostic_filters`] after it.
+///
+/// [`Function`]: crate::Function
+/// [`Module::diagnostic_filters`]: crate::Module::diagnostic_filters
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub struct DiagnosticFilterScope {
+    /// The expressions this scope applies to.
+    pub expressions: Range<Expression>,
+    /// The innermost filter applying to [`Self::expressions`].
+    pub leaf: Handle<DiagnosticFilterNode>,
+}
+
+impl DiagnosticFilterScope {
+    /// Find the leaf of the innermost filter chain that applies to `expr`.
+    ///
+    /// `scopes` and `function_leaf` are a [`Function`]'s
+    /// [`diagnostic_filter_scopes`] and [`diagnostic_filter_leaf`]. Since inner
+    /// scopes' leaves are always appended after those of their enclosing scopes,
+    /// the innermost scope containing `expr` is the one with the greatest leaf.
+    ///
+    /// [`Function`]: crate::Function
+    /// [`diagnostic_filter_scopes`]: crate::Function::diagnostic_filter_scopes
+    /// [`diagnostic_filter_leaf`]: crate::Function::diagnostic_filter_leaf
+    pub fn leaf_for(
+        scopes: &[Self],
+        function_leaf: Option<Handle<DiagnosticFilterNode>>,
+        expr: Handle<Expression>,
+    ) -> Option<Handle<DiagnosticFilterNode>> {
+        let index = expr.index() as u32;
+        scopes
+            .iter()
+            .filter(|scope| scope.expressions.index_range().contains(&index))
+            .map(|scope| scope.leaf)
+            .max()
+            .or(function_leaf)
+    }
+}
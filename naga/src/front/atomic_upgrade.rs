@@ -1,11 +1,11 @@
 //! Upgrade the types of scalars observed to be accessed as atomics to [`Atomic`] types.
 //!
-//! In SPIR-V, atomic operations can be applied to any scalar value, but in Naga
-//! IR atomic operations can only be applied to values of type [`Atomic`]. Naga
-//! IR's restriction matches Metal Shading Language and WGSL, so we don't want
-//! to relax that. Instead, when the SPIR-V front end observes a value being
-//! accessed using atomic instructions, it promotes the value's type from
-//! [`Scalar`] to [`Atomic`]. This module implements `Module::upgrade_atomics`,
+//! In SPIR-V and GLSL, atomic operations can be applied to any scalar value,
+//! but in Naga IR atomic operations can only be applied to values of type
+//! [`Atomic`]. Naga IR's restriction matches Metal Shading Language and WGSL,
+//! so we don't want to relax that. Instead, when the SPIR-V or GLSL front ends
+//! observe a value being accessed using atomic operations, they promote the
+//! value's type from [`Scalar`] to [`Atomic`]. This module implements `Module::upgrade_atomics`,
 //! the function that makes that change.
 //!
 //! Atomics can only appear in global variables in the [`Storage`] and
@@ -22,8 +22,6 @@
 //!
 //! - Atomics in structs are not implemented yet.
 //!
-//! - The GLSL front end could use this transformation as well.
-//!
 //! [`Atomic`]: TypeInner::Atomic
 //! [`Scalar`]: TypeInner::Scalar
 //! [`Storage`]: crate::AddressSpace::Storage
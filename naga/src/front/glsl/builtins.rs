@@ -7,9 +7,10 @@ use super::{
     Error, ErrorKind, Frontend, Result,
 };
 use crate::{
-    BinaryOperator, DerivativeAxis as Axis, DerivativeControl as Ctrl, Expression, Handle,
-    ImageClass, ImageDimension as Dim, ImageQuery, MathFunction, Module, RelationalFunction,
-    SampleLevel, Scalar, ScalarKind as Sk, Span, Type, TypeInner, UnaryOperator, VectorSize,
+    AddressSpace, AtomicFunction, BinaryOperator, CollectiveOperation, DerivativeAxis as Axis,
+    DerivativeControl as Ctrl, Expression, Handle, ImageClass, ImageDimension as Dim, ImageQuery,
+    MathFunction, Module, RelationalFunction, SampleLevel, Scalar, ScalarKind as Sk, Span,
+    SubgroupOperation, Type, TypeInner, UnaryOperator, VectorSize,
 };
 
 impl crate::ScalarKind {
@@ -1016,9 +1017,177 @@ fn inject_standard_builtins(
                     .push(module.add_builtin(args, MacroCall::Clamp(size)))
             }
         }
-        "barrier" => declaration
+        "barrier" | "subgroupBarrier" => {
+            let barrier = match name {
+                "subgroupBarrier" => crate::Barrier::SUB_GROUP,
+                _ => crate::Barrier::all(),
+            };
+
+            declaration
+                .overloads
+                .push(module.add_builtin(Vec::new(), MacroCall::Barrier(barrier)))
+        }
+        "memoryBarrier"
+        | "groupMemoryBarrier"
+        | "memoryBarrierShared"
+        | "memoryBarrierBuffer"
+        | "memoryBarrierImage" => declaration
             .overloads
-            .push(module.add_builtin(Vec::new(), MacroCall::Barrier)),
+            .push(module.add_builtin(Vec::new(), MacroCall::MemoryBarrier)),
+        "atomicAdd" | "atomicMin" | "atomicMax" | "atomicAnd" | "atomicOr" | "atomicXor"
+        | "atomicExchange" | "atomicCompSwap" => {
+            let mc = match name {
+                "atomicAdd" => MacroCall::Atomic(AtomicFunction::Add),
+                "atomicMin" => MacroCall::Atomic(AtomicFunction::Min),
+                "atomicMax" => MacroCall::Atomic(AtomicFunction::Max),
+                "atomicAnd" => MacroCall::Atomic(AtomicFunction::And),
+                "atomicOr" => MacroCall::Atomic(AtomicFunction::InclusiveOr),
+                "atomicXor" => MacroCall::Atomic(AtomicFunction::ExclusiveOr),
+                "atomicExchange" => MacroCall::Atomic(AtomicFunction::Exchange { compare: None }),
+                "atomicCompSwap" => MacroCall::AtomicCompSwap,
+                _ => unreachable!(),
+            };
+
+            // bits layout
+            // bit 0 - int/uint
+            for bits in 0..0b10 {
+                let scalar = match bits {
+                    0b0 => Scalar::I32,
+                    _ => Scalar::U32,
+                };
+
+                let args = match mc {
+                    MacroCall::AtomicCompSwap => vec![TypeInner::Scalar(scalar); 3],
+                    _ => vec![TypeInner::Scalar(scalar); 2],
+                };
+
+                let mut overload = module.add_builtin(args, mc);
+                // The memory operand is passed by reference, this also
+                // prevents implicit conversions from being applied to it.
+                overload.parameters_info[0].qualifier = ParameterQualifier::InOut;
+                declaration.overloads.push(overload)
+            }
+        }
+        "subgroupAll" | "subgroupAny" => {
+            let op = match name {
+                "subgroupAll" => SubgroupOperation::All,
+                _ => SubgroupOperation::Any,
+            };
+
+            declaration.overloads.push(module.add_builtin(
+                vec![TypeInner::Scalar(Scalar::BOOL)],
+                MacroCall::SubgroupCollective(op, CollectiveOperation::Reduce),
+            ))
+        }
+        "subgroupBallot" => declaration.overloads.push(module.add_builtin(
+            vec![TypeInner::Scalar(Scalar::BOOL)],
+            MacroCall::SubgroupBallot,
+        )),
+        // Naga's IR only supports scans for additions and multiplications
+        "subgroupAdd"
+        | "subgroupMul"
+        | "subgroupMin"
+        | "subgroupMax"
+        | "subgroupAnd"
+        | "subgroupOr"
+        | "subgroupXor"
+        | "subgroupInclusiveAdd"
+        | "subgroupInclusiveMul"
+        | "subgroupExclusiveAdd"
+        | "subgroupExclusiveMul" => {
+            let (collective_op, op) = match name.strip_prefix("subgroupInclusive") {
+                Some(op) => (CollectiveOperation::InclusiveScan, op),
+                None => match name.strip_prefix("subgroupExclusive") {
+                    Some(op) => (CollectiveOperation::ExclusiveScan, op),
+                    None => (CollectiveOperation::Reduce, &name["subgroup".len()..]),
+                },
+            };
+            let op = match op {
+                "Add" => SubgroupOperation::Add,
+                "Mul" => SubgroupOperation::Mul,
+                "Min" => SubgroupOperation::Min,
+                "Max" => SubgroupOperation::Max,
+                "And" => SubgroupOperation::And,
+                "Or" => SubgroupOperation::Or,
+                _ => SubgroupOperation::Xor,
+            };
+            let bitwise = matches!(
+                op,
+                SubgroupOperation::And | SubgroupOperation::Or | SubgroupOperation::Xor
+            );
+
+            // bits layout
+            // bit 0 through 1 - float/int/uint
+            // bit 2 through 3 - dims
+            for bits in 0..0b10000 {
+                let scalar = match bits & 0b11 {
+                    0b00 if !bitwise => Scalar::F32,
+                    0b01 => Scalar::I32,
+                    0b10 => Scalar::U32,
+                    _ => continue,
+                };
+                let size = match bits >> 2 {
+                    0b00 => None,
+                    0b01 => Some(VectorSize::Bi),
+                    0b10 => Some(VectorSize::Tri),
+                    _ => Some(VectorSize::Quad),
+                };
+
+                let ty = match size {
+                    Some(size) => TypeInner::Vector { size, scalar },
+                    None => TypeInner::Scalar(scalar),
+                };
+
+                declaration.overloads.push(
+                    module.add_builtin(vec![ty], MacroCall::SubgroupCollective(op, collective_op)),
+                )
+            }
+        }
+        "subgroupBroadcastFirst"
+        | "subgroupBroadcast"
+        | "subgroupShuffle"
+        | "subgroupShuffleXor"
+        | "subgroupShuffleUp"
+        | "subgroupShuffleDown" => {
+            let mode = match name {
+                "subgroupBroadcastFirst" => SubgroupGatherMode::BroadcastFirst,
+                "subgroupBroadcast" => SubgroupGatherMode::Broadcast,
+                "subgroupShuffle" => SubgroupGatherMode::Shuffle,
+                "subgroupShuffleXor" => SubgroupGatherMode::ShuffleXor,
+                "subgroupShuffleUp" => SubgroupGatherMode::ShuffleUp,
+                _ => SubgroupGatherMode::ShuffleDown,
+            };
+
+            // bits layout
+            // bit 0 through 1 - float/int/uint
+            // bit 2 through 3 - dims
+            for bits in 0..0b10000 {
+                let scalar = match bits & 0b11 {
+                    0b00 => Scalar::F32,
+                    0b01 => Scalar::I32,
+                    0b10 => Scalar::U32,
+                    _ => continue,
+                };
+                let size = match bits >> 2 {
+                    0b00 => None,
+                    0b01 => Some(VectorSize::Bi),
+                    0b10 => Some(VectorSize::Tri),
+                    _ => Some(VectorSize::Quad),
+                };
+
+                let mut args = vec![match size {
+                    Some(size) => TypeInner::Vector { size, scalar },
+                    None => TypeInner::Scalar(scalar),
+                }];
+                if mode != SubgroupGatherMode::BroadcastFirst {
+                    args.push(TypeInner::Scalar(Scalar::U32));
+                }
+
+                declaration
+                    .overloads
+                    .push(module.add_builtin(args, MacroCall::SubgroupGather(mode)))
+            }
+        }
         // Add common builtins with floats
         _ => inject_common_builtin(declaration, module, name, 4),
     }
@@ -1514,6 +1683,9 @@ fn inject_common_builtin(
     }
 }
 
+/// The extension enabling the basic subgroup builtins
+pub const SUBGROUP_BASIC_EXTENSION: &str = "GL_KHR_shader_subgroup_basic";
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum TextureLevelType {
     None,
@@ -1521,6 +1693,18 @@ pub enum TextureLevelType {
     Grad,
 }
 
+/// The lane selection of a subgroup gather builtin, mirrors [`crate::GatherMode`]
+/// but without the lane index which is only known at the call site.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SubgroupGatherMode {
+    BroadcastFirst,
+    Broadcast,
+    Shuffle,
+    ShuffleXor,
+    ShuffleUp,
+    ShuffleDown,
+}
+
 /// A compiler defined builtin function
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum MacroCall {
@@ -1554,7 +1738,24 @@ pub enum MacroCall {
     Clamp(Option<VectorSize>),
     BitCast(Sk),
     Derivate(Axis, Ctrl),
-    Barrier,
+    Barrier(crate::Barrier),
+    /// A memory barrier that doesn't synchronize execution.
+    ///
+    /// Naga's IR only has [`Statement::Barrier`], which is also an execution
+    /// barrier, and lowering a memory barrier to it could deadlock in
+    /// non-uniform control flow. These calls therefore produce no IR: the
+    /// memory ordering they request is only provided where the shader also
+    /// calls `barrier()`.
+    ///
+    /// [`Statement::Barrier`]: crate::Statement::Barrier
+    MemoryBarrier,
+    /// Atomic operation on the memory location passed as the first argument,
+    /// the compare and swap operation is handled by [`MacroCall::AtomicCompSwap`]
+    Atomic(AtomicFunction),
+    AtomicCompSwap,
+    SubgroupBallot,
+    SubgroupCollective(SubgroupOperation, CollectiveOperation),
+    SubgroupGather(SubgroupGatherMode),
     /// SmoothStep needs a separate variant because it might need it's inputs
     /// to be splatted depending on the overload
     SmoothStep {
@@ -1564,6 +1765,34 @@ pub enum MacroCall {
 }
 
 impl MacroCall {
+    /// Returns the extension that must be enabled for the builtin to be used,
+    /// if any.
+    ///
+    /// Every `GL_KHR_shader_subgroup_*` extension also enables
+    /// [`SUBGROUP_BASIC_EXTENSION`].
+    pub fn required_extension(&self) -> Option<&'static str> {
+        match *self {
+            MacroCall::Barrier(barrier) if barrier == crate::Barrier::SUB_GROUP => {
+                Some(SUBGROUP_BASIC_EXTENSION)
+            }
+            MacroCall::SubgroupCollective(SubgroupOperation::All | SubgroupOperation::Any, _) => {
+                Some("GL_KHR_shader_subgroup_vote")
+            }
+            MacroCall::SubgroupCollective(..) => Some("GL_KHR_shader_subgroup_arithmetic"),
+            MacroCall::SubgroupBallot
+            | MacroCall::SubgroupGather(
+                SubgroupGatherMode::BroadcastFirst | SubgroupGatherMode::Broadcast,
+            ) => Some("GL_KHR_shader_subgroup_ballot"),
+            MacroCall::SubgroupGather(
+                SubgroupGatherMode::Shuffle | SubgroupGatherMode::ShuffleXor,
+            ) => Some("GL_KHR_shader_subgroup_shuffle"),
+            MacroCall::SubgroupGather(
+                SubgroupGatherMode::ShuffleUp | SubgroupGatherMode::ShuffleDown,
+            ) => Some("GL_KHR_shader_subgroup_shuffle_relative"),
+            _ => None,
+        }
+    }
+
     /// Adds the necessary expressions and statements to the passed body and
     /// finally returns the final expression with the correct result
     pub fn call(
@@ -2020,12 +2249,139 @@ impl MacroCall {
                 },
                 Span::default(),
             )?,
-            MacroCall::Barrier => {
+            MacroCall::Barrier(barrier) => {
                 ctx.emit_restart();
-                ctx.body
-                    .push(crate::Statement::Barrier(crate::Barrier::all()), meta);
+                ctx.body.push(crate::Statement::Barrier(barrier), meta);
                 return Ok(None);
             }
+            MacroCall::MemoryBarrier => return Ok(None),
+            MacroCall::Atomic(fun) => {
+                frontend.register_atomic_access(ctx, args[0], meta)?;
+
+                let ty = ctx.resolve_type_handle(args[1], meta)?;
+
+                ctx.emit_end();
+                let result = ctx.add_expression(
+                    Expression::AtomicResult {
+                        ty,
+                        comparison: false,
+                    },
+                    meta,
+                )?;
+                ctx.body.push(
+                    crate::Statement::Atomic {
+                        pointer: args[0],
+                        fun,
+                        value: args[1],
+                        result: Some(result),
+                    },
+                    meta,
+                );
+                ctx.emit_start();
+
+                result
+            }
+            MacroCall::AtomicCompSwap => {
+                frontend.register_atomic_access(ctx, args[0], meta)?;
+
+                let scalar = match *ctx.resolve_type(args[1], meta)? {
+                    TypeInner::Scalar(scalar) => scalar,
+                    _ => unreachable!(),
+                };
+                let ty = ctx.module.generate_predeclared_type(
+                    crate::PredeclaredType::AtomicCompareExchangeWeakResult(scalar),
+                );
+
+                ctx.emit_end();
+                let result = ctx.add_expression(
+                    Expression::AtomicResult {
+                        ty,
+                        comparison: true,
+                    },
+                    meta,
+                )?;
+                // glsl's `atomicCompSwap(mem, compare, data)` returns the
+                // original value, so only the first member of the result is used.
+                ctx.body.push(
+                    crate::Statement::Atomic {
+                        pointer: args[0],
+                        fun: AtomicFunction::Exchange {
+                            compare: Some(args[1]),
+                        },
+                        value: args[2],
+                        result: Some(result),
+                    },
+                    meta,
+                );
+                ctx.emit_start();
+
+                ctx.add_expression(
+                    Expression::AccessIndex {
+                        base: result,
+                        index: 0,
+                    },
+                    meta,
+                )?
+            }
+            MacroCall::SubgroupBallot => {
+                ctx.emit_end();
+                let result = ctx.add_expression(Expression::SubgroupBallotResult, meta)?;
+                ctx.body.push(
+                    crate::Statement::SubgroupBallot {
+                        result,
+                        predicate: Some(args[0]),
+                    },
+                    meta,
+                );
+                ctx.emit_start();
+
+                result
+            }
+            MacroCall::SubgroupCollective(op, collective_op) => {
+                let ty = ctx.resolve_type_handle(args[0], meta)?;
+
+                ctx.emit_end();
+                let result =
+                    ctx.add_expression(Expression::SubgroupOperationResult { ty }, meta)?;
+                ctx.body.push(
+                    crate::Statement::SubgroupCollectiveOperation {
+                        op,
+                        collective_op,
+                        argument: args[0],
+                        result,
+                    },
+                    meta,
+                );
+                ctx.emit_start();
+
+                result
+            }
+            MacroCall::SubgroupGather(mode) => {
+                let ty = ctx.resolve_type_handle(args[0], meta)?;
+                let mode = match mode {
+                    SubgroupGatherMode::BroadcastFirst => crate::GatherMode::BroadcastFirst,
+                    SubgroupGatherMode::Broadcast => crate::GatherMode::Broadcast(args[1]),
+                    SubgroupGatherMode::Shuffle => crate::GatherMode::Shuffle(args[1]),
+                    SubgroupGatherMode::ShuffleXor => crate::GatherMode::ShuffleXor(args[1]),
+                    SubgroupGatherMode::ShuffleUp => crate::GatherMode::ShuffleUp(args[1]),
+                    SubgroupGatherMode::ShuffleDown => crate::GatherMode::ShuffleDown(args[1]),
+                };
+
+                ctx.emit_end();
+                let result =
+                    ctx.add_expression(Expression::SubgroupOperationResult { ty }, meta)?;
+                ctx.body.push(
+                    crate::Statement::SubgroupGather {
+                        mode,
+                        argument: args[0],
+                        result,
+                    },
+                    meta,
+                );
+                ctx.emit_start();
+
+                result
+            }
             MacroCall::SmoothStep { splatted } => {
                 ctx.implicit_splat(&mut args[0], meta, splatted)?;
                 ctx.implicit_splat(&mut args[1], meta, splatted)?;
@@ -2189,6 +2545,40 @@ impl Frontend {
             })
         }
     }
+
+    /// Records the global variable accessed by an atomic builtin so that its
+    /// type can be upgraded to an atomic one once parsing is done.
+    fn register_atomic_access(
+        &mut self,
+        ctx: &mut Context,
+        mut pointer: Handle<Expression>,
+        meta: Span,
+    ) -> Result<()> {
+        loop {
+            match ctx.expressions[pointer] {
+                Expression::Access { base, .. } | Expression::AccessIndex { base, .. } => {
+                    pointer = base
+                }
+                Expression::GlobalVariable(handle) => {
+                    match ctx.module.global_variables[handle].space {
+                        AddressSpace::Storage { .. } | AddressSpace::WorkGroup => {
+                            self.atomic_globals.insert(handle);
+                            return Ok(());
+                        }
+                        _ => break,
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        Err(Error {
+            kind: ErrorKind::SemanticError(
+                "Atomic functions can only operate on buffer or shared variables".into(),
+            ),
+            meta,
+        })
+    }
 }
 
 /// Helper function to cast a expression holding a sampled image to a
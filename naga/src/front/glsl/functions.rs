@@ -1,6 +1,6 @@
 use super::{
     ast::*,
-    builtins::{inject_builtin, sampled_to_depth},
+    builtins::{inject_builtin, sampled_to_depth, MacroCall, SUBGROUP_BASIC_EXTENSION},
     context::{Context, ExprPos, StmtContext},
     error::{Error, ErrorKind},
     types::scalar_components,
@@ -766,7 +766,12 @@ impl Frontend {
         }
 
         let overload = maybe_overload.ok_or_else(|| Error {
-            kind: ErrorKind::SemanticError(format!("Unknown function '{name}'").into()),
+            // Naga IR has no image atomic operations to lower these to.
+            kind: if declaration.overloads.is_empty() && name.starts_with("imageAtomic") {
+                ErrorKind::NotImplemented("image atomic functions")
+            } else {
+                ErrorKind::SemanticError(format!("Unknown function '{name}'").into())
+            },
             meta,
         })?;
 
@@ -775,6 +780,12 @@ impl Frontend {
         let is_void = overload.void;
         let kind = overload.kind;
 
+        if let FunctionKind::Macro(builtin) = kind {
+            if let Some(extension) = builtin.required_extension() {
+                self.check_extension(&name, extension, meta);
+            }
+        }
+
         let mut arguments = Vec::with_capacity(args.len());
         let mut proxy_writes = Vec::new();
 
@@ -788,6 +799,15 @@ impl Frontend {
             let (mut handle, meta) =
                 ctx.lower_expect_inner(stmt, self, *expr, parameter_info.qualifier.as_pos())?;
 
+            // Atomic builtins operate on the memory location itself, so the
+            // pointer is passed as is instead of going through a local.
+            if let FunctionKind::Macro(MacroCall::Atomic(_) | MacroCall::AtomicCompSwap) = kind {
+                if parameter_info.qualifier.is_lhs() {
+                    arguments.push(handle);
+                    continue;
+                }
+            }
+
             if parameter_info.qualifier.is_lhs() {
                 self.process_lhs_argument(
                     ctx,
@@ -864,6 +884,26 @@ impl Frontend {
         }
     }
 
+    /// Reports an error if the builtin `name` is used without enabling the
+    /// `extension` that provides it.
+    fn check_extension(&mut self, name: &str, extension: &str, meta: Span) {
+        let extensions = &self.meta.extensions;
+        let enabled = extensions.contains(extension)
+            || (extension == SUBGROUP_BASIC_EXTENSION
+                && extensions
+                    .iter()
+                    .any(|ext| ext.starts_with("GL_KHR_shader_subgroup_")));
+
+        if !enabled {
+            self.errors.push(Error {
+                kind: ErrorKind::SemanticError(
+                    format!("'{name}' requires the {extension} extension to be enabled").into(),
+                ),
+                meta,
+            })
+        }
+    }
+
     /// Processes a function call argument that appears in place of an output
     /// parameter.
     #[allow(clippy::too_many_arguments)]
@@ -1066,6 +1106,8 @@ impl Frontend {
             expressions,
             named_expressions: crate::NamedExpressions::default(),
             body,
+            diagnostic_filter_leaf: None,
+            diagnostic_filter_scopes: Vec::new(),
         };
 
         'outer: for decl in declaration.overloads.iter_mut() {
@@ -1438,7 +1480,7 @@ impl Context<'_> {
                         location,
                         interpolation,
                         sampling: None,
-                        second_blend_source: false,
+                        blend_src: None,
                     };
                     location += 1;
 
@@ -1474,7 +1516,7 @@ impl Context<'_> {
                                 location,
                                 interpolation,
                                 sampling: None,
-                                second_blend_source: false,
+                                blend_src: None,
                             };
                             location += 1;
                             binding
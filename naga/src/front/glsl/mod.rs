@@ -16,7 +16,10 @@ pub use ast::{Precision, Profile};
 pub use error::{Error, ErrorKind, ExpectedToken, ParseErrors};
 pub use token::TokenValue;
 
-use crate::{proc::Layouter, FastHashMap, FastHashSet, Handle, Module, ShaderStage, Span, Type};
+use crate::{
+    proc::Layouter, FastHashMap, FastHashSet, FastIndexSet, GlobalVariable, Handle, Module,
+    ShaderStage, Span, Type,
+};
 use ast::{EntryArg, FunctionDeclaration, GlobalLookup};
 use parser::ParsingContext;
 
@@ -174,6 +177,10 @@ pub struct Frontend {
 
     layouter: Layouter,
 
+    /// Global variables accessed by atomic builtins, their types are
+    /// upgraded to atomic ones once parsing is done.
+    atomic_globals: FastIndexSet<Handle<GlobalVariable>>,
+
     errors: Vec<Error>,
 }
 
@@ -186,6 +193,7 @@ impl Frontend {
         self.global_variables.clear();
         self.entry_args.clear();
         self.layouter.clear();
+        self.atomic_globals.clear();
     }
 
     /// Parses a shader either outputting a shader [`Module`] or a list of
@@ -178,6 +178,14 @@ impl<'source> ParsingContext<'source> {
             self.parse_external_declaration(frontend, &mut ctx)?;
         }
 
+        // Upgrade the globals used by atomic builtins to atomic types
+        for handle in std::mem::take(&mut frontend.atomic_globals) {
+            ctx.module.upgrade_atomics([handle]).map_err(|err| Error {
+                kind: ErrorKind::SemanticError(err.to_string().into()),
+                meta: ctx.module.global_variables.get_span(handle),
+            })?;
+        }
+
         // Add an `EntryPoint` to `parser.module` for `main`, if a
         // suitable overload exists. Error out if we can't find one.
         if let Some(declaration) = frontend.lookup_function.get("main") {
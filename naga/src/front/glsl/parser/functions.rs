@@ -113,7 +113,7 @@ impl<'source> ParsingContext<'source> {
             }
             TokenValue::Discard => {
                 let meta = self.bump(frontend)?.meta;
-                ctx.body.push(Statement::Kill, meta);
+                ctx.body.push(Statement::Demote, meta);
                 terminator.get_or_insert(ctx.body.len());
 
                 self.expect(frontend, TokenValue::Semicolon)?.meta
@@ -854,3 +854,104 @@ fn expressions() {
         )
         .unwrap();
 }
+
+#[test]
+fn subgroup_extensions() {
+    let mut frontend = Frontend::default();
+
+    frontend
+        .parse(
+            &Options::from(ShaderStage::Compute),
+            r#"
+        #  version 450
+        #extension GL_KHR_shader_subgroup_arithmetic : require
+        layout(local_size_x = 64) in;
+        void main() {
+            subgroupBarrier();
+            uint sum = subgroupAdd(gl_SubgroupInvocationID);
+        }
+        "#,
+        )
+        .unwrap();
+
+    let errors = frontend
+        .parse(
+            &Options::from(ShaderStage::Compute),
+            r#"
+        #  version 450
+        layout(local_size_x = 64) in;
+        void main() {
+            bool any = subgroupAny(true);
+        }
+        "#,
+        )
+        .err()
+        .unwrap()
+        .errors;
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].kind,
+        ErrorKind::SemanticError(
+            "'subgroupAny' requires the GL_KHR_shader_subgroup_vote extension to be enabled".into()
+        )
+    );
+}
+
+#[test]
+fn image_atomics() {
+    let mut frontend = Frontend::default();
+
+    let errors = frontend
+        .parse(
+            &Options::from(ShaderStage::Compute),
+            r#"
+        #  version 450
+        layout(local_size_x = 64) in;
+        layout(set = 0, binding = 0, r32ui) uniform uimage2D image;
+        void main() {
+            uint old = imageAtomicAdd(image, ivec2(0), 1u);
+        }
+        "#,
+        )
+        .err()
+        .unwrap()
+        .errors;
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].kind,
+        ErrorKind::NotImplemented("image atomic functions")
+    );
+}
+
+#[test]
+fn atomics_in_multi_member_block() {
+    let mut frontend = Frontend::default();
+
+    let source = r#"
+        #  version 450
+        layout(local_size_x = 64) in;
+        layout(set = 0, binding = 0) buffer Counters {
+            uint count;
+            uint total;
+        } counters;
+        void main() {
+            atomicAdd(counters.count, 1u);
+        }
+        "#;
+    let errors = frontend
+        .parse(&Options::from(ShaderStage::Compute), source)
+        .err()
+        .unwrap()
+        .errors;
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].kind,
+        ErrorKind::SemanticError(
+            "upgrading structs of more than one member is not yet implemented".into()
+        )
+    );
+    assert_eq!(&source[errors[0].meta.to_range().unwrap()], "Counters");
+}
@@ -203,6 +203,10 @@ impl Frontend {
                     "gl_SampleID" => BuiltIn::SampleIndex,
                     "gl_LocalInvocationIndex" => BuiltIn::LocalInvocationIndex,
                     "gl_DrawID" => BuiltIn::DrawID,
+                    "gl_SubgroupSize" => BuiltIn::SubgroupSize,
+                    "gl_SubgroupInvocationID" => BuiltIn::SubgroupInvocationId,
+                    "gl_NumSubgroups" => BuiltIn::NumSubgroups,
+                    "gl_SubgroupID" => BuiltIn::SubgroupId,
                     _ => return Ok(None),
                 };
 
@@ -454,7 +458,7 @@ impl Frontend {
                         location,
                         interpolation,
                         sampling,
-                        second_blend_source: false,
+                        blend_src: None,
                     },
                     handle,
                     storage,
@@ -43,7 +43,7 @@ impl crate::Binding {
             location: _,
             interpolation: ref mut interpolation @ None,
             ref mut sampling,
-            second_blend_source: _,
+            blend_src: _,
         } = *self
         {
             match ty.scalar_kind() {
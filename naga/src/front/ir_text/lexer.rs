@@ -0,0 +1,230 @@
+use super::ParseError;
+use crate::Span;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Token<'a> {
+    /// A keyword, an enum variant name, or `_`.
+    Word(&'a str),
+
+    /// An integer or floating-point number, possibly negative.
+    ///
+    /// Non-finite floating-point values are words, like `inf` and `NaN`,
+    /// except for `-inf`, which is a number.
+    Number(&'a str),
+
+    /// A string literal, with escapes already processed.
+    String(String),
+
+    /// A handle, like `%3` or `@t0`.
+    ///
+    /// The `char` identifies the arena: `'%'` for function expressions, `'$'`
+    /// for local variables, or the letter following `@`.
+    Handle(char, u32),
+
+    /// Punctuation.
+    Punct(&'static str),
+
+    End,
+}
+
+impl std::fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Token::Word(word) => write!(f, "`{word}`"),
+            Token::Number(number) => write!(f, "`{number}`"),
+            Token::String(ref string) => write!(f, "{string:?}"),
+            Token::Handle(prefix @ ('%' | '$'), index) => write!(f, "`{prefix}{index}`"),
+            Token::Handle(kind, index) => write!(f, "`@{kind}{index}`"),
+            Token::Punct(punct) => write!(f, "`{punct}`"),
+            Token::End => f.write_str("end of input"),
+        }
+    }
+}
+
+const PUNCTUATION: &[&str] = &["..", "->", "(", ")", "{", "}", "<", ">", ",", ":", "=", "|"];
+
+pub(super) struct Lexer<'a> {
+    source: &'a str,
+
+    /// The byte offset of the next character to read.
+    offset: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub(super) const fn new(source: &'a str) -> Self {
+        Lexer { source, offset: 0 }
+    }
+
+    /// Return the next token and its span, without consuming it.
+    pub(super) fn peek(&self) -> Result<(Token<'a>, Span), ParseError> {
+        Lexer {
+            source: self.source,
+            offset: self.offset,
+        }
+        .next()
+    }
+
+    /// Consume the next token and return it, with its span.
+    pub(super) fn next(&mut self) -> Result<(Token<'a>, Span), ParseError> {
+        self.skip_whitespace_and_comments();
+        let start = self.offset;
+        let rest = &self.source[start..];
+        let mut chars = rest.chars();
+        let Some(first) = chars.next() else {
+            return Ok((Token::End, self.span_from(start)));
+        };
+
+        let token = match first {
+            'a'..='z' | 'A'..='Z' | '_' => {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                self.offset += len;
+                Token::Word(&rest[..len])
+            }
+            // Negative numbers, including `-inf`.
+            '0'..='9' | '-'
+                if first != '-' || chars.next().is_some_and(|c| c.is_ascii_digit() || c == 'i') =>
+            {
+                let mut len = first.len_utf8();
+                let mut previous = first;
+                for c in rest[len..].chars() {
+                    let exponent_sign = matches!(c, '+' | '-') && matches!(previous, 'e' | 'E');
+                    if !(c.is_ascii_alphanumeric() || c == '.' || exponent_sign) {
+                        break;
+                    }
+                    // Don't swallow the `..` of a range.
+                    if c == '.' && rest[len + 1..].starts_with('.') {
+                        break;
+                    }
+                    len += c.len_utf8();
+                    previous = c;
+                }
+                self.offset += len;
+                Token::Number(&rest[..len])
+            }
+            '%' | '$' => {
+                self.offset += 1;
+                Token::Handle(first, self.index(start)?)
+            }
+            '@' => {
+                self.offset += 1;
+                let kind = self.source[self.offset..]
+                    .chars()
+                    .next()
+                    .filter(char::is_ascii_lowercase)
+                    .ok_or_else(|| self.error_from(start, "expected a handle after `@`"))?;
+                self.offset += 1;
+                Token::Handle(kind, self.index(start)?)
+            }
+            '"' => Token::String(self.string(start)?),
+            _ => {
+                let punct = PUNCTUATION
+                    .iter()
+                    .find(|&&punct| rest.starts_with(punct))
+                    .ok_or_else(|| {
+                        self.offset += first.len_utf8();
+                        self.error_from(start, format!("unexpected character `{first}`"))
+                    })?;
+                self.offset += punct.len();
+                Token::Punct(punct)
+            }
+        };
+        Ok((token, self.span_from(start)))
+    }
+
+    /// The byte offset of the end of the last token consumed.
+    pub(super) const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        loop {
+            let rest = &self.source[self.offset..];
+            let trimmed = rest.trim_start();
+            self.offset += rest.len() - trimmed.len();
+            if !trimmed.starts_with("//") {
+                break;
+            }
+            self.offset += trimmed.find('\n').unwrap_or(trimmed.len());
+        }
+    }
+
+    /// Read the index of a handle whose prefix starts at `start`.
+    fn index(&mut self, start: usize) -> Result<u32, ParseError> {
+        let rest = &self.source[self.offset..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        self.offset += len;
+        rest[..len]
+            .parse()
+            .map_err(|_| self.error_from(start, "expected a handle index"))
+    }
+
+    /// Read a string literal whose opening quote is at `start`.
+    ///
+    /// This accepts the escapes Rust's `Debug` formatting of strings produces.
+    fn string(&mut self, start: usize) -> Result<String, ParseError> {
+        let mut string = String::new();
+        self.offset = start + 1;
+        loop {
+            let rest = &self.source[self.offset..];
+            let mut chars = rest.chars();
+            let Some(c) = chars.next() else {
+                return Err(self.error_from(start, "unterminated string"));
+            };
+            if c == '"' {
+                self.offset += 1;
+                return Ok(string);
+            }
+            if c != '\\' {
+                self.offset += c.len_utf8();
+                string.push(c);
+                continue;
+            }
+
+            let escape_start = self.offset;
+            let (escaped, len) = match chars.next() {
+                Some('n') => ('\n', 2),
+                Some('r') => ('\r', 2),
+                Some('t') => ('\t', 2),
+                Some('0') => ('\0', 2),
+                Some(c @ ('\\' | '"' | '\'')) => (c, 2),
+                Some('u') => {
+                    let code = rest[2..]
+                        .strip_prefix('{')
+                        .and_then(|rest| rest.split_once('}'))
+                        .and_then(|(digits, _)| {
+                            let c = u32::from_str_radix(digits, 16).ok()?;
+                            Some((char::from_u32(c)?, digits.len() + 4))
+                        });
+                    match code {
+                        Some(code) => code,
+                        None => {
+                            self.offset += 2;
+                            return Err(self.error_from(escape_start, "invalid unicode escape"));
+                        }
+                    }
+                }
+                _ => {
+                    self.offset += 1;
+                    return Err(self.error_from(escape_start, "invalid escape sequence"));
+                }
+            };
+            self.offset += len;
+            string.push(escaped);
+        }
+    }
+
+    const fn span_from(&self, start: usize) -> Span {
+        Span::new(start as u32, self.offset as u32)
+    }
+
+    fn error_from(&self, start: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            message: message.into(),
+            span: self.span_from(start),
+        }
+    }
+}
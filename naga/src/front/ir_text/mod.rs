@@ -0,0 +1,104 @@
+/*!
+Frontend for Naga's textual IR format.
+
+This parses the format written by [`back::ir_text`], which describes it.
+Parsing the output of [`back::ir_text::write_string`] produces a module
+identical to the one written, apart from spans: the parser gives each type,
+expression, statement and so on the span of the text it was parsed from.
+
+The parser only checks the syntax of its input. In particular, it doesn't
+check that handles refer to arena entries that exist; pass the module to the
+[`Validator`] before doing anything else with it.
+
+[`back::ir_text`]: crate::back::ir_text
+[`back::ir_text::write_string`]: crate::back::ir_text::write_string
+[`Validator`]: crate::valid::Validator
+*/
+
+mod lexer;
+mod parser;
+
+use crate::{SourceLocation, Span};
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+use codespan_reporting::files::SimpleFile;
+use codespan_reporting::term;
+use termcolor::{ColorChoice, NoColor, StandardStream};
+
+/// An error encountered while parsing Naga's textual IR format.
+#[derive(Clone, Debug)]
+pub struct ParseError {
+    message: String,
+    span: Span,
+}
+
+impl ParseError {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn diagnostic(&self) -> Diagnostic<()> {
+        Diagnostic::error()
+            .with_message(self.message.clone())
+            .with_labels(
+                self.span
+                    .to_range()
+                    .map(|range| Label::primary((), range))
+                    .into_iter()
+                    .collect(),
+            )
+    }
+
+    /// Emits a summary of the error to standard error stream.
+    pub fn emit_to_stderr(&self, source: &str) {
+        self.emit_to_stderr_with_path(source, "ir")
+    }
+
+    /// Emits a summary of the error to standard error stream.
+    pub fn emit_to_stderr_with_path<P>(&self, source: &str, path: P)
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let path = path.as_ref().display().to_string();
+        let files = SimpleFile::new(path, source);
+        let config = term::Config::default();
+        let writer = StandardStream::stderr(ColorChoice::Auto);
+        term::emit(&mut writer.lock(), &config, &files, &self.diagnostic())
+            .expect("cannot write error");
+    }
+
+    /// Emits a summary of the error to a string.
+    pub fn emit_to_string(&self, source: &str) -> String {
+        self.emit_to_string_with_path(source, "ir")
+    }
+
+    /// Emits a summary of the error to a string.
+    pub fn emit_to_string_with_path<P>(&self, source: &str, path: P) -> String
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let path = path.as_ref().display().to_string();
+        let files = SimpleFile::new(path, source);
+        let config = term::Config::default();
+        let mut writer = NoColor::new(Vec::new());
+        term::emit(&mut writer, &config, &files, &self.diagnostic()).expect("cannot write error");
+        String::from_utf8(writer.into_inner()).unwrap()
+    }
+
+    /// Returns a [`SourceLocation`] for the error.
+    pub fn location(&self, source: &str) -> SourceLocation {
+        self.span.location(source)
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a module written in Naga's textual IR format.
+pub fn parse_str(source: &str) -> Result<crate::Module, ParseError> {
+    parser::Parser::new(source).parse()
+}
@@ -0,0 +1,1718 @@
+use std::str::FromStr;
+
+use super::lexer::{Lexer, Token};
+use super::ParseError;
+use crate::arena::{Arena, Handle, Range};
+use crate::diagnostic_filter::{
+    DiagnosticFilter, DiagnosticFilterNode, DiagnosticFilterScope, FilterableTriggeringRule,
+    Severity, StandardFilterableTriggeringRule,
+};
+use crate::non_max_u32::NonMaxU32;
+use crate::{
+    AddressSpace, ArraySize, AtomicFunction, BinaryOperator, Binding, Block, BuiltIn,
+    CollectiveOperation, ConservativeDepth, DerivativeAxis, DerivativeControl, Direction,
+    EarlyDepthTest, EntryPoint, Expression, Function, FunctionArgument, FunctionResult, GatherMode,
+    ImageClass, ImageDimension, ImageQuery, Interpolation, Literal, LocalVariable, MathFunction,
+    Module, PredeclaredType, RayQueryFunction, RelationalFunction, ResourceBinding, SampleLevel,
+    Sampling, Scalar, ScalarKind, ShaderStage, Span, Statement, StorageFormat, StructMember,
+    SubgroupOperation, SwitchCase, SwitchValue, SwizzleComponent, Type, TypeInner, UnaryOperator,
+    VectorSize,
+};
+
+/// Which arena the expression handles being parsed refer to.
+#[derive(Clone, Copy)]
+enum Operands {
+    /// [`Module::global_expressions`], written as `@eN`.
+    Global,
+
+    /// A function's expressions, written as `%N`.
+    Function,
+}
+
+impl Operands {
+    const fn kind(self) -> char {
+        match self {
+            Self::Global => 'e',
+            Self::Function => '%',
+        }
+    }
+}
+
+pub(super) struct Parser<'a> {
+    lexer: Lexer<'a>,
+    module: Module,
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn new(source: &'a str) -> Self {
+        Parser {
+            lexer: Lexer::new(source),
+            module: Module::default(),
+        }
+    }
+
+    pub(super) fn parse(mut self) -> Result<Module, ParseError> {
+        loop {
+            let (token, span) = self.lexer.peek()?;
+            match token {
+                Token::End => break,
+                Token::Word("type") => self.type_declaration()?,
+                Token::Word("special") => self.special_type()?,
+                Token::Handle('e', _) => self.global_expression()?,
+                Token::Word("const") => self.constant()?,
+                Token::Word("override") => self.override_declaration()?,
+                Token::Word("global") => self.global_variable()?,
+                Token::Word("diagnostic") => self.diagnostic_filter()?,
+                Token::Word("diagnostic_filter") => {
+                    self.lexer.next()?;
+                    self.module.diagnostic_filter_leaf = Some(self.handle('d')?);
+                }
+                Token::Word("fn") => self.function()?,
+                Token::Word("entry_point") => self.entry_point()?,
+                _ => return Err(unexpected(&token, span, "a declaration")),
+            }
+        }
+        Ok(self.module)
+    }
+
+    fn type_declaration(&mut self) -> Result<(), ParseError> {
+        let (_, start) = self.lexer.next()?;
+        self.declare('t', self.module.types.len())?;
+        let name = self.optional_name()?;
+        self.expect("=")?;
+        let inner = self.type_inner()?;
+        let span = self.span_since(start);
+        let handle = self.module.types.insert(Type { name, inner }, span);
+        if handle.index() + 1 != self.module.types.len() {
+            return Err(error(
+                span,
+                format!("this type is identical to `@t{}`", handle.index()),
+            ));
+        }
+        Ok(())
+    }
+
+    fn special_type(&mut self) -> Result<(), ParseError> {
+        self.lexer.next()?;
+        let (word, span) = self.word()?;
+        match word {
+            "ray_desc" => self.module.special_types.ray_desc = Some(self.handle('t')?),
+            "ray_intersection" => {
+                self.module.special_types.ray_intersection = Some(self.handle('t')?)
+            }
+            "predeclared" => {
+                let (name, span) = self.word()?;
+                self.expect("(")?;
+                let predeclared = match name {
+                    "AtomicCompareExchangeWeakResult" => {
+                        PredeclaredType::AtomicCompareExchangeWeakResult(self.scalar()?)
+                    }
+                    "ModfResult" | "FrexpResult" => {
+                        let size = if self.skip_word("_")? {
+                            None
+                        } else {
+                            Some(self.vector_size()?)
+                        };
+                        self.expect(",")?;
+                        let width = self.number()?;
+                        match name {
+                            "ModfResult" => PredeclaredType::ModfResult { size, width },
+                            _ => PredeclaredType::FrexpResult { size, width },
+                        }
+                    }
+                    _ => return Err(error(span, format!("unknown predeclared type `{name}`"))),
+                };
+                self.expect(")")?;
+                let ty = self.handle('t')?;
+                self.module
+                    .special_types
+                    .predeclared_types
+                    .insert(predeclared, ty);
+            }
+            _ => return Err(error(span, format!("unknown special type `{word}`"))),
+        }
+        Ok(())
+    }
+
+    fn global_expression(&mut self) -> Result<(), ParseError> {
+        let start = self.declare('e', self.module.global_expressions.len())?;
+        self.expect("=")?;
+        let expr = self.expression(Operands::Global)?;
+        let span = self.span_since(start);
+        self.module.global_expressions.append(expr, span);
+        Ok(())
+    }
+
+    fn constant(&mut self) -> Result<(), ParseError> {
+        let (_, start) = self.lexer.next()?;
+        self.declare('c', self.module.constants.len())?;
+        let name = self.optional_name()?;
+        self.expect(":")?;
+        let ty = self.type_ref()?;
+        self.expect("=")?;
+        let init = self.handle('e')?;
+        let span = self.span_since(start);
+        self.module
+            .constants
+            .append(crate::Constant { name, ty, init }, span);
+        Ok(())
+    }
+
+    fn override_declaration(&mut self) -> Result<(), ParseError> {
+        let (_, start) = self.lexer.next()?;
+        self.declare('o', self.module.overrides.len())?;
+        let name = self.optional_name()?;
+        let id = if self.skip_word("id")? {
+            self.expect("(")?;
+            let id = self.number()?;
+            self.expect(")")?;
+            Some(id)
+        } else {
+            None
+        };
+        self.expect(":")?;
+        let ty = self.type_ref()?;
+        let init = if self.skip("=")? {
+            Some(self.handle('e')?)
+        } else {
+            None
+        };
+        let span = self.span_since(start);
+        self.module
+            .overrides
+            .append(crate::Override { name, id, ty, init }, span);
+        Ok(())
+    }
+
+    fn global_variable(&mut self) -> Result<(), ParseError> {
+        let (_, start) = self.lexer.next()?;
+        self.declare('g', self.module.global_variables.len())?;
+        let name = self.optional_name()?;
+        let space = self.address_space()?;
+        let binding = if self.skip_word("group")? {
+            self.expect("(")?;
+            let group = self.number()?;
+            self.expect(")")?;
+            self.expect_word("binding")?;
+            self.expect("(")?;
+            let binding = self.number()?;
+            self.expect(")")?;
+            Some(ResourceBinding { group, binding })
+        } else {
+            None
+        };
+        self.expect(":")?;
+        let ty = self.type_ref()?;
+        let init = if self.skip("=")? {
+            Some(self.handle('e')?)
+        } else {
+            None
+        };
+        let span = self.span_since(start);
+        self.module.global_variables.append(
+            crate::GlobalVariable {
+                name,
+                space,
+                binding,
+                ty,
+                init,
+            },
+            span,
+        );
+        Ok(())
+    }
+
+    fn diagnostic_filter(&mut self) -> Result<(), ParseError> {
+        let (_, start) = self.lexer.next()?;
+        self.declare('d', self.module.diagnostic_filters.len())?;
+        self.expect("=")?;
+        let new_severity = self.variant(SEVERITIES, "a severity")?;
+        let triggering_rule = if self.skip_word("unknown")? {
+            FilterableTriggeringRule::Unknown(self.string()?.into_boxed_str())
+        } else if self.skip_word("user")? {
+            let first = self.string()?.into_boxed_str();
+            let second = self.string()?.into_boxed_str();
+            FilterableTriggeringRule::User(Box::new([first, second]))
+        } else {
+            FilterableTriggeringRule::Standard(self.variant(STANDARD_RULES, "a triggering rule")?)
+        };
+        let parent = if self.skip_word("parent")? {
+            Some(self.handle('d')?)
+        } else {
+            None
+        };
+        let span = self.span_since(start);
+        self.module.diagnostic_filters.append(
+            DiagnosticFilterNode {
+                inner: DiagnosticFilter {
+                    new_severity,
+                    triggering_rule,
+                },
+                parent,
+            },
+            span,
+        );
+        Ok(())
+    }
+
+    fn function(&mut self) -> Result<(), ParseError> {
+        let (_, start) = self.lexer.next()?;
+        self.declare('f', self.module.functions.len())?;
+        let function = self.function_rest()?;
+        let span = self.span_since(start);
+        self.module.functions.append(function, span);
+        Ok(())
+    }
+
+    fn entry_point(&mut self) -> Result<(), ParseError> {
+        self.lexer.next()?;
+        let stage = self.variant(SHADER_STAGES, "a shader stage")?;
+        let name = self.string()?;
+        let early_depth_test = if self.skip_word("early_depth_test")? {
+            let conservative = if self.skip("(")? {
+                let conservative = self.variant(CONSERVATIVE_DEPTHS, "a conservative depth")?;
+                self.expect(")")?;
+                Some(conservative)
+            } else {
+                None
+            };
+            Some(EarlyDepthTest { conservative })
+        } else {
+            None
+        };
+        self.expect_word("workgroup_size")?;
+        self.expect("(")?;
+        let x = self.number()?;
+        self.expect(",")?;
+        let y = self.number()?;
+        self.expect(",")?;
+        let z = self.number()?;
+        self.expect(")")?;
+        self.expect_word("fn")?;
+        let function = self.function_rest()?;
+        self.module.entry_points.push(EntryPoint {
+            name,
+            stage,
+            early_depth_test,
+            workgroup_size: [x, y, z],
+            function,
+        });
+        Ok(())
+    }
+
+    /// Parse the rest of a function, following `fn` and its handle, if any.
+    fn function_rest(&mut self) -> Result<Function, ParseError> {
+        let name = self.optional_name()?;
+        let arguments = self.list(|this| {
+            let name = this.name_or_underscore()?;
+            this.expect(":")?;
+            let ty = this.type_ref()?;
+            let binding = this.binding()?;
+            Ok(FunctionArgument { name, ty, binding })
+        })?;
+        let result = if self.skip("->")? {
+            let ty = self.type_ref()?;
+            let binding = self.binding()?;
+            Some(FunctionResult { ty, binding })
+        } else {
+            None
+        };
+        let diagnostic_filter_leaf = if self.skip_word("diagnostic_filter")? {
+            Some(self.handle('d')?)
+        } else {
+            None
+        };
+        self.expect("{")?;
+
+        let mut function = Function {
+            name,
+            arguments,
+            result,
+            diagnostic_filter_leaf,
+            ..Function::default()
+        };
+        loop {
+            let (token, span) = self.lexer.peek()?;
+            match token {
+                Token::Word("local") => {
+                    self.lexer.next()?;
+                    self.declare('$', function.local_variables.len())?;
+                    let name = self.optional_name()?;
+                    self.expect(":")?;
+                    let ty = self.type_ref()?;
+                    let init = if self.skip("=")? {
+                        Some(self.handle('%')?)
+                    } else {
+                        None
+                    };
+                    let span = self.span_since(span);
+                    function
+                        .local_variables
+                        .append(LocalVariable { name, ty, init }, span);
+                }
+                Token::Handle('%', _) => {
+                    self.declare('%', function.expressions.len())?;
+                    self.expect("=")?;
+                    let (_, start) = self.lexer.peek()?;
+                    let expr = self.expression(Operands::Function)?;
+                    if let Expression::FunctionArgument(index) = expr {
+                        if index as usize >= function.arguments.len() {
+                            return Err(error(
+                                self.span_since(start),
+                                format!(
+                                    "argument index must be less than the function's argument count, {}",
+                                    function.arguments.len()
+                                ),
+                            ));
+                        }
+                    }
+                    let span = self.span_since(span);
+                    function.expressions.append(expr, span);
+                }
+                Token::Word("name") => {
+                    self.lexer.next()?;
+                    let handle = self.handle('%')?;
+                    let name = self.string()?;
+                    function.named_expressions.insert(handle, name);
+                }
+                Token::Word("diagnostic_scope") => {
+                    self.lexer.next()?;
+                    let expressions = self.range(&function.expressions)?;
+                    let leaf = self.handle('d')?;
+                    function
+                        .diagnostic_filter_scopes
+                        .push(DiagnosticFilterScope { expressions, leaf });
+                }
+                Token::Word("body") => {
+                    self.lexer.next()?;
+                    function.body = self.block(&function.expressions)?;
+                    break;
+                }
+                _ => {
+                    return Err(unexpected(
+                        &token,
+                        span,
+                        "a local variable, an expression, or `body`",
+                    ))
+                }
+            }
+        }
+        self.expect("}")?;
+        Ok(function)
+    }
+
+    /// Parse a block, braces included.
+    ///
+    /// The `expressions` arena is used to check the ranges of `emit`
+    /// statements.
+    fn block(&mut self, expressions: &Arena<Expression>) -> Result<Block, ParseError> {
+        self.expect("{")?;
+        let mut block = Block::new();
+        while !self.skip("}")? {
+            let (_, start) = self.lexer.peek()?;
+            let stmt = self.statement(expressions)?;
+            block.push(stmt, self.span_since(start));
+        }
+        Ok(block)
+    }
+
+    fn statement(&mut self, expressions: &Arena<Expression>) -> Result<Statement, ParseError> {
+        let result = match self.lexer.peek()?.0 {
+            Token::Handle('%', _) => {
+                let result = self.handle('%')?;
+                self.expect("=")?;
+                Some(result)
+            }
+            _ => None,
+        };
+        let (token, span) = self.lexer.next()?;
+        let Token::Word(word) = token else {
+            return Err(unexpected(&token, span, "a statement"));
+        };
+        let required = |result: Option<Handle<Expression>>| {
+            result.ok_or_else(|| error(span, format!("`{word}` needs a result expression")))
+        };
+        let stmt = match word {
+            "emit" => Statement::Emit(self.range(expressions)?),
+            "block" => Statement::Block(self.block(expressions)?),
+            "if" => {
+                let condition = self.handle('%')?;
+                let accept = self.block(expressions)?;
+                self.expect_word("else")?;
+                let reject = self.block(expressions)?;
+                Statement::If {
+                    condition,
+                    accept,
+                    reject,
+                }
+            }
+            "switch" => {
+                let selector = self.handle('%')?;
+                self.expect("{")?;
+                let mut cases = Vec::new();
+                while !self.skip("}")? {
+                    let (case, span) = self.word()?;
+                    let value = match case {
+                        "case" => match self.word()? {
+                            ("i32", _) => SwitchValue::I32(self.number()?),
+                            ("u32", _) => SwitchValue::U32(self.number()?),
+                            (_, span) => return Err(error(span, "expected `i32` or `u32`")),
+                        },
+                        "default" => SwitchValue::Default,
+                        _ => return Err(error(span, "expected `case`, `default`, or `}`")),
+                    };
+                    let fall_through = self.skip_word("fallthrough")?;
+                    let body = self.block(expressions)?;
+                    cases.push(SwitchCase {
+                        value,
+                        body,
+                        fall_through,
+                    });
+                }
+                Statement::Switch { selector, cases }
+            }
+            "loop" => {
+                let body = self.block(expressions)?;
+                self.expect_word("continuing")?;
+                let continuing = self.block(expressions)?;
+                let break_if = if self.skip_word("break_if")? {
+                    Some(self.handle('%')?)
+                } else {
+                    None
+                };
+                Statement::Loop {
+                    body,
+                    continuing,
+                    break_if,
+                }
+            }
+            "break" => Statement::Break,
+            "continue" => Statement::Continue,
+            "return" => Statement::Return {
+                value: self.optional_operand(Operands::Function)?,
+            },
+            "kill" => Statement::Kill,
+            "demote" => Statement::Demote,
+            "barrier" => Statement::Barrier(self.flags()?),
+            "store" => {
+                let pointer = self.handle('%')?;
+                self.expect(",")?;
+                let value = self.handle('%')?;
+                Statement::Store { pointer, value }
+            }
+            "image_store" => {
+                let image = self.handle('%')?;
+                self.expect(",")?;
+                let coordinate = self.handle('%')?;
+                self.expect(",")?;
+                let value = self.handle('%')?;
+                let array_index = self.keyword_operand("array_index", Operands::Function)?;
+                Statement::ImageStore {
+                    image,
+                    coordinate,
+                    array_index,
+                    value,
+                }
+            }
+            "atomic" => {
+                let fun = if self.skip_word("Exchange")? {
+                    None
+                } else {
+                    Some(self.variant(ATOMIC_FUNCTIONS, "an atomic function")?)
+                };
+                let pointer = self.handle('%')?;
+                self.expect(",")?;
+                let value = self.handle('%')?;
+                let fun = match fun {
+                    Some(fun) => fun,
+                    None => AtomicFunction::Exchange {
+                        compare: self.keyword_operand("compare", Operands::Function)?,
+                    },
+                };
+                Statement::Atomic {
+                    pointer,
+                    fun,
+                    value,
+                    result,
+                }
+            }
+            "workgroup_uniform_load" => Statement::WorkGroupUniformLoad {
+                pointer: self.handle('%')?,
+                result: required(result)?,
+            },
+            "call" => {
+                let function = self.handle('f')?;
+                let arguments = self.list(|this| this.handle('%'))?;
+                Statement::Call {
+                    function,
+                    arguments,
+                    result,
+                }
+            }
+            "ray_query" => {
+                let (fun, span) = self.word()?;
+                let query = self.handle('%')?;
+                let fun = match fun {
+                    "initialize" => {
+                        self.expect(",")?;
+                        let acceleration_structure = self.handle('%')?;
+                        self.expect(",")?;
+                        let descriptor = self.handle('%')?;
+                        RayQueryFunction::Initialize {
+                            acceleration_structure,
+                            descriptor,
+                        }
+                    }
+                    "proceed" => RayQueryFunction::Proceed {
+                        result: required(result)?,
+                    },
+                    "terminate" => RayQueryFunction::Terminate,
+                    _ => return Err(error(span, format!("unknown ray query function `{fun}`"))),
+                };
+                Statement::RayQuery { query, fun }
+            }
+            "subgroup_ballot" => Statement::SubgroupBallot {
+                predicate: self.optional_operand(Operands::Function)?,
+                result: required(result)?,
+            },
+            "subgroup_gather" => {
+                let (name, span) = self.word()?;
+                let mode = match name {
+                    "BroadcastFirst" => GatherMode::BroadcastFirst,
+                    "QuadSwap" => {
+                        self.expect("(")?;
+                        let direction = self.variant(DIRECTIONS, "a direction")?;
+                        self.expect(")")?;
+                        GatherMode::QuadSwap(direction)
+                    }
+                    _ => {
+                        let mode = match name {
+                            "Broadcast" => GatherMode::Broadcast,
+                            "Shuffle" => GatherMode::Shuffle,
+                            "ShuffleDown" => GatherMode::ShuffleDown,
+                            "ShuffleUp" => GatherMode::ShuffleUp,
+                            "ShuffleXor" => GatherMode::ShuffleXor,
+                            "QuadBroadcast" => GatherMode::QuadBroadcast,
+                            _ => return Err(error(span, format!("unknown gather mode `{name}`"))),
+                        };
+                        self.expect("(")?;
+                        let index = self.handle('%')?;
+                        self.expect(")")?;
+                        mode(index)
+                    }
+                };
+                Statement::SubgroupGather {
+                    mode,
+                    argument: self.handle('%')?,
+                    result: required(result)?,
+                }
+            }
+            "subgroup_collective" => {
+                let op = self.variant(SUBGROUP_OPERATIONS, "a subgroup operation")?;
+                let collective_op = if self.skip_word("ClusteredReduce")? {
+                    self.expect("(")?;
+                    let cluster_size = self.number()?;
+                    self.expect(")")?;
+                    CollectiveOperation::ClusteredReduce(cluster_size)
+                } else {
+                    self.variant(COLLECTIVE_OPERATIONS, "a collective operation")?
+                };
+                Statement::SubgroupCollectiveOperation {
+                    op,
+                    collective_op,
+                    argument: self.handle('%')?,
+                    result: required(result)?,
+                }
+            }
+            _ => return Err(error(span, format!("unknown statement `{word}`"))),
+        };
+
+        let takes_result = matches!(
+            stmt,
+            Statement::Atomic { .. }
+                | Statement::WorkGroupUniformLoad { .. }
+                | Statement::Call { .. }
+                | Statement::RayQuery {
+                    fun: RayQueryFunction::Proceed { .. },
+                    ..
+                }
+                | Statement::SubgroupBallot { .. }
+                | Statement::SubgroupGather { .. }
+                | Statement::SubgroupCollectiveOperation { .. }
+        );
+        if result.is_some() && !takes_result {
+            return Err(error(span, format!("`{word}` has no result expression")));
+        }
+        Ok(stmt)
+    }
+
+    fn expression(&mut self, operands: Operands) -> Result<Expression, ParseError> {
+        let (word, span) = self.word()?;
+        let kind = operands.kind();
+        let expr = match word {
+            "literal" => Expression::Literal(self.literal()?),
+            "constant" => Expression::Constant(self.handle('c')?),
+            "override" => Expression::Override(self.handle('o')?),
+            "zero" => Expression::ZeroValue(self.type_ref()?),
+            "compose" => {
+                let ty = self.type_ref()?;
+                let components = self.list(|this| this.handle(kind))?;
+                Expression::Compose { ty, components }
+            }
+            "access" => {
+                let base = self.handle(kind)?;
+                self.expect(",")?;
+                let index = self.handle(kind)?;
+                Expression::Access { base, index }
+            }
+            "access_index" => {
+                let index = self.number()?;
+                let base = self.handle(kind)?;
+                Expression::AccessIndex { base, index }
+            }
+            "splat" => {
+                let size = self.vector_size()?;
+                let value = self.handle(kind)?;
+                Expression::Splat { size, value }
+            }
+            "swizzle" => {
+                let size = self.vector_size()?;
+                let (letters, span) = self.word()?;
+                let mut pattern = [SwizzleComponent::X; 4];
+                if letters.len() != pattern.len() {
+                    return Err(error(span, "a swizzle pattern must have four components"));
+                }
+                for (component, letter) in pattern.iter_mut().zip(letters.chars()) {
+                    *component = match letter {
+                        'x' => SwizzleComponent::X,
+                        'y' => SwizzleComponent::Y,
+                        'z' => SwizzleComponent::Z,
+                        'w' => SwizzleComponent::W,
+                        _ => {
+                            return Err(error(span, format!("invalid swizzle pattern `{letters}`")))
+                        }
+                    };
+                }
+                let vector = self.handle(kind)?;
+                Expression::Swizzle {
+                    size,
+                    vector,
+                    pattern,
+                }
+            }
+            "argument" => Expression::FunctionArgument(self.number()?),
+            "global" => Expression::GlobalVariable(self.handle('g')?),
+            "local" => Expression::LocalVariable(self.handle('$')?),
+            "load" => Expression::Load {
+                pointer: self.handle(kind)?,
+            },
+            "image_sample" => {
+                let image = self.handle(kind)?;
+                self.expect(",")?;
+                let sampler = self.handle(kind)?;
+                self.expect(",")?;
+                let coordinate = self.handle(kind)?;
+                let array_index = self.keyword_operand("array_index", operands)?;
+                let offset = self.keyword_operand("offset", Operands::Global)?;
+                self.expect_word("level")?;
+                let (level, span) = self.word()?;
+                let level = match level {
+                    "auto" => SampleLevel::Auto,
+                    "zero" => SampleLevel::Zero,
+                    "exact" => SampleLevel::Exact(self.handle(kind)?),
+                    "bias" => SampleLevel::Bias(self.handle(kind)?),
+                    "gradient" => {
+                        let x = self.handle(kind)?;
+                        self.expect(",")?;
+                        let y = self.handle(kind)?;
+                        SampleLevel::Gradient { x, y }
+                    }
+                    _ => return Err(error(span, format!("unknown sample level `{level}`"))),
+                };
+                let depth_ref = self.keyword_operand("depth_ref", operands)?;
+                let gather = if self.skip_word("gather")? {
+                    Some(self.variant(SWIZZLE_COMPONENTS, "a component")?)
+                } else {
+                    None
+                };
+                Expression::ImageSample {
+                    image,
+                    sampler,
+                    gather,
+                    coordinate,
+                    array_index,
+                    offset,
+                    level,
+                    depth_ref,
+                }
+            }
+            "image_load" => {
+                let image = self.handle(kind)?;
+                self.expect(",")?;
+                let coordinate = self.handle(kind)?;
+                Expression::ImageLoad {
+                    image,
+                    coordinate,
+                    array_index: self.keyword_operand("array_index", operands)?,
+                    sample: self.keyword_operand("sample", operands)?,
+                    level: self.keyword_operand("level", operands)?,
+                }
+            }
+            "image_query" => {
+                let image = self.handle(kind)?;
+                let (query, span) = self.word()?;
+                let query = match query {
+                    "size" => ImageQuery::Size {
+                        level: self.keyword_operand("level", operands)?,
+                    },
+                    "num_levels" => ImageQuery::NumLevels,
+                    "num_layers" => ImageQuery::NumLayers,
+                    "num_samples" => ImageQuery::NumSamples,
+                    _ => return Err(error(span, format!("unknown image query `{query}`"))),
+                };
+                Expression::ImageQuery { image, query }
+            }
+            "unary" => Expression::Unary {
+                op: self.variant(UNARY_OPERATORS, "a unary operator")?,
+                expr: self.handle(kind)?,
+            },
+            "binary" => {
+                let op = self.variant(BINARY_OPERATORS, "a binary operator")?;
+                let left = self.handle(kind)?;
+                self.expect(",")?;
+                let right = self.handle(kind)?;
+                Expression::Binary { op, left, right }
+            }
+            "select" => {
+                let condition = self.handle(kind)?;
+                self.expect(",")?;
+                let accept = self.handle(kind)?;
+                self.expect(",")?;
+                let reject = self.handle(kind)?;
+                Expression::Select {
+                    condition,
+                    accept,
+                    reject,
+                }
+            }
+            "derivative" => Expression::Derivative {
+                axis: self.variant(DERIVATIVE_AXES, "a derivative axis")?,
+                ctrl: self.variant(DERIVATIVE_CONTROLS, "a derivative control")?,
+                expr: self.handle(kind)?,
+            },
+            "relational" => Expression::Relational {
+                fun: self.variant(RELATIONAL_FUNCTIONS, "a relational function")?,
+                argument: self.handle(kind)?,
+            },
+            "math" => {
+                let fun = self.variant(MATH_FUNCTIONS, "a math function")?;
+                let (_, start) = self.lexer.peek()?;
+                let args = self.list(|this| this.optional_handle(kind))?;
+                let span = self.span_since(start);
+                match *args.as_slice() {
+                    [Some(arg), ..] if args.len() <= 4 => Expression::Math {
+                        fun,
+                        arg,
+                        arg1: args.get(1).copied().flatten(),
+                        arg2: args.get(2).copied().flatten(),
+                        arg3: args.get(3).copied().flatten(),
+                    },
+                    _ => {
+                        return Err(error(
+                            span,
+                            "expected between one and four arguments, the first of them present",
+                        ))
+                    }
+                }
+            }
+            "bitcast" => Expression::As {
+                kind: self.variant(SCALAR_KINDS, "a scalar kind")?,
+                convert: None,
+                expr: self.handle(kind)?,
+            },
+            "convert" => Expression::As {
+                kind: self.variant(SCALAR_KINDS, "a scalar kind")?,
+                convert: Some(self.number()?),
+                expr: self.handle(kind)?,
+            },
+            "call_result" => Expression::CallResult(self.handle('f')?),
+            "atomic_result" => Expression::AtomicResult {
+                ty: self.type_ref()?,
+                comparison: self.skip_word("comparison")?,
+            },
+            "workgroup_uniform_load_result" => Expression::WorkGroupUniformLoadResult {
+                ty: self.type_ref()?,
+            },
+            "array_length" => Expression::ArrayLength(self.handle(kind)?),
+            "ray_query_proceed_result" => Expression::RayQueryProceedResult,
+            "ray_query_get_intersection" => Expression::RayQueryGetIntersection {
+                query: self.handle(kind)?,
+                committed: self.skip_word("committed")?,
+            },
+            "subgroup_ballot_result" => Expression::SubgroupBallotResult,
+            "subgroup_operation_result" => Expression::SubgroupOperationResult {
+                ty: self.type_ref()?,
+            },
+            _ => return Err(error(span, format!("unknown expression `{word}`"))),
+        };
+        Ok(expr)
+    }
+
+    fn literal(&mut self) -> Result<Literal, ParseError> {
+        let (kind, span) = self.word()?;
+        Ok(match kind {
+            "f64" => Literal::F64(self.float()?),
+            "f32" => Literal::F32(self.float()?),
+            "f16" => Literal::F16(half::f16::from_f32(self.float()?)),
+            "u32" => Literal::U32(self.number()?),
+            "i32" => Literal::I32(self.number()?),
+            "u64" => Literal::U64(self.number()?),
+            "i64" => Literal::I64(self.number()?),
+            "bool" => match self.word()? {
+                ("true", _) => Literal::Bool(true),
+                ("false", _) => Literal::Bool(false),
+                (_, span) => return Err(error(span, "expected `true` or `false`")),
+            },
+            "abstract_int" => Literal::AbstractInt(self.number()?),
+            "abstract_float" => Literal::AbstractFloat(self.float()?),
+            _ => return Err(error(span, format!("unknown literal type `{kind}`"))),
+        })
+    }
+
+    /// Parse a floating-point number, including `inf`, `-inf` and `NaN`.
+    fn float<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        match self.lexer.next()? {
+            (Token::Number(text) | Token::Word(text @ ("inf" | "NaN")), span) => text
+                .parse()
+                .map_err(|_| error(span, format!("invalid number `{text}`"))),
+            (token, span) => Err(unexpected(&token, span, "a number")),
+        }
+    }
+
+    /// Parse a reference to a type: a handle, or the definition of a type
+    /// without a name, which must already have been declared.
+    fn type_ref(&mut self) -> Result<Handle<Type>, ParseError> {
+        let (token, start) = self.lexer.peek()?;
+        if let Token::Handle('t', _) = token {
+            return self.handle('t');
+        }
+        let inner = self.type_inner()?;
+        let ty = Type { name: None, inner };
+        self.module.types.get(&ty).ok_or_else(|| {
+            error(
+                self.span_since(start),
+                "types without names must be declared before they are used",
+            )
+        })
+    }
+
+    fn type_inner(&mut self) -> Result<TypeInner, ParseError> {
+        let (word, span) = self.word()?;
+        if let Some(scalar) = scalar_from_word(word) {
+            return Ok(TypeInner::Scalar(scalar));
+        }
+        let inner = match word {
+            "atomic" => {
+                self.expect("<")?;
+                let scalar = self.scalar()?;
+                self.expect(">")?;
+                TypeInner::Atomic(scalar)
+            }
+            "ptr" => {
+                self.expect("<")?;
+                let space = self.address_space()?;
+                self.expect(",")?;
+                let base = self.type_ref()?;
+                self.expect(">")?;
+                TypeInner::Pointer { base, space }
+            }
+            "value_ptr" => {
+                self.expect("<")?;
+                let space = self.address_space()?;
+                self.expect(",")?;
+                let (word, span) = self.word()?;
+                let (size, scalar) = match vector_size_from_word(word) {
+                    Some(size) => (Some(size), self.angle_bracketed_scalar()?),
+                    None => (
+                        None,
+                        scalar_from_word(word)
+                            .ok_or_else(|| error(span, format!("unknown scalar type `{word}`")))?,
+                    ),
+                };
+                self.expect(">")?;
+                TypeInner::ValuePointer {
+                    size,
+                    scalar,
+                    space,
+                }
+            }
+            "array" => {
+                self.expect("<")?;
+                let base = self.type_ref()?;
+                self.expect(",")?;
+                let size = if self.skip_word("stride")? {
+                    ArraySize::Dynamic
+                } else {
+                    let count = self.number()?;
+                    self.expect(",")?;
+                    self.expect_word("stride")?;
+                    ArraySize::Constant(count)
+                };
+                self.expect("=")?;
+                let stride = self.number()?;
+                self.expect(">")?;
+                TypeInner::Array { base, size, stride }
+            }
+            "struct" => {
+                self.expect_word("span")?;
+                self.expect("=")?;
+                let span = self.number()?;
+                self.expect("{")?;
+                let mut members = Vec::new();
+                while !self.skip("}")? {
+                    if !members.is_empty() {
+                        self.expect(",")?;
+                        // Allow a trailing comma.
+                        if self.skip("}")? {
+                            break;
+                        }
+                    }
+                    let name = self.name_or_underscore()?;
+                    self.expect(":")?;
+                    let ty = self.type_ref()?;
+                    self.expect_word("offset")?;
+                    self.expect("=")?;
+                    let offset = self.number()?;
+                    let binding = self.binding()?;
+                    members.push(StructMember {
+                        name,
+                        ty,
+                        binding,
+                        offset,
+                    });
+                }
+                TypeInner::Struct { members, span }
+            }
+            "image" => {
+                self.expect("<")?;
+                let dim = self.variant(IMAGE_DIMENSIONS, "an image dimension")?;
+                let arrayed = self.skip_word("arrayed")?;
+                self.expect(",")?;
+                let (class, span) = self.word()?;
+                let class = match class {
+                    "sampled" => ImageClass::Sampled {
+                        kind: self.variant(SCALAR_KINDS, "a scalar kind")?,
+                        multi: self.skip_word("multi")?,
+                    },
+                    "depth" => ImageClass::Depth {
+                        multi: self.skip_word("multi")?,
+                    },
+                    "storage" => ImageClass::Storage {
+                        format: self.variant(STORAGE_FORMATS, "a storage format")?,
+                        access: self.flags()?,
+                    },
+                    _ => return Err(error(span, format!("unknown image class `{class}`"))),
+                };
+                self.expect(">")?;
+                TypeInner::Image {
+                    dim,
+                    arrayed,
+                    class,
+                }
+            }
+            "sampler" => TypeInner::Sampler { comparison: false },
+            "sampler_comparison" => TypeInner::Sampler { comparison: true },
+            "acceleration_structure" => TypeInner::AccelerationStructure,
+            "ray_query" => TypeInner::RayQuery,
+            "binding_array" => {
+                self.expect("<")?;
+                let base = self.type_ref()?;
+                let size = if self.skip(",")? {
+                    ArraySize::Constant(self.number()?)
+                } else {
+                    ArraySize::Dynamic
+                };
+                self.expect(">")?;
+                TypeInner::BindingArray { base, size }
+            }
+            _ => {
+                if let Some(size) = vector_size_from_word(word) {
+                    TypeInner::Vector {
+                        size,
+                        scalar: self.angle_bracketed_scalar()?,
+                    }
+                } else if let Some((columns, rows)) = matrix_size_from_word(word) {
+                    TypeInner::Matrix {
+                        columns,
+                        rows,
+                        scalar: self.angle_bracketed_scalar()?,
+                    }
+                } else {
+                    return Err(error(span, format!("unknown type `{word}`")));
+                }
+            }
+        };
+        Ok(inner)
+    }
+
+    fn scalar(&mut self) -> Result<Scalar, ParseError> {
+        let (word, span) = self.word()?;
+        scalar_from_word(word).ok_or_else(|| error(span, format!("unknown scalar type `{word}`")))
+    }
+
+    /// Parse a scalar type in angle brackets, as in `vec3<f32>`.
+    fn angle_bracketed_scalar(&mut self) -> Result<Scalar, ParseError> {
+        self.expect("<")?;
+        let scalar = self.scalar()?;
+        self.expect(">")?;
+        Ok(scalar)
+    }
+
+    /// Parse a vector size written as a number, as in `splat 4 %0`.
+    fn vector_size(&mut self) -> Result<VectorSize, ParseError> {
+        let (token, span) = self.lexer.next()?;
+        match token {
+            Token::Number("2") => Ok(VectorSize::Bi),
+            Token::Number("3") => Ok(VectorSize::Tri),
+            Token::Number("4") => Ok(VectorSize::Quad),
+            _ => Err(unexpected(&token, span, "a vector size")),
+        }
+    }
+
+    fn address_space(&mut self) -> Result<AddressSpace, ParseError> {
+        let (word, span) = self.word()?;
+        Ok(match word {
+            "function" => AddressSpace::Function,
+            "private" => AddressSpace::Private,
+            "workgroup" => AddressSpace::WorkGroup,
+            "uniform" => AddressSpace::Uniform,
+            "storage" => AddressSpace::Storage {
+                access: self.flags()?,
+            },
+            "handle" => AddressSpace::Handle,
+            "push_constant" => AddressSpace::PushConstant,
+            _ => return Err(error(span, format!("unknown address space `{word}`"))),
+        })
+    }
+
+    /// Parse a binding, if present.
+    fn binding(&mut self) -> Result<Option<Binding>, ParseError> {
+        if self.skip_word("builtin")? {
+            self.expect("(")?;
+            let built_in = if self.skip_word("Position")? {
+                let invariant = if self.skip(",")? {
+                    self.expect_word("invariant")?;
+                    true
+                } else {
+                    false
+                };
+                BuiltIn::Position { invariant }
+            } else {
+                self.variant(BUILT_INS, "a built-in")?
+            };
+            self.expect(")")?;
+            return Ok(Some(Binding::BuiltIn(built_in)));
+        }
+
+        if !self.skip_word("location")? {
+            return Ok(None);
+        }
+        self.expect("(")?;
+        let location = self.number()?;
+        self.expect(")")?;
+        let mut interpolation = None;
+        let mut sampling = None;
+        let mut blend_src = None;
+        if self.skip_word("interpolate")? {
+            self.expect("(")?;
+            interpolation = Some(self.variant(INTERPOLATIONS, "an interpolation")?);
+            self.expect(")")?;
+        }
+        if self.skip_word("sampling")? {
+            self.expect("(")?;
+            sampling = Some(self.variant(SAMPLINGS, "a sampling")?);
+            self.expect(")")?;
+        }
+        if self.skip_word("blend_src")? {
+            self.expect("(")?;
+            blend_src = Some(self.number()?);
+            self.expect(")")?;
+        }
+        Ok(Some(Binding::Location {
+            location,
+            interpolation,
+            sampling,
+            blend_src,
+        }))
+    }
+
+    /// Parse a set of flags, like `(LOAD | STORE)`.
+    fn flags<F: bitflags::Flags>(&mut self) -> Result<F, ParseError> {
+        self.expect("(")?;
+        let mut flags = F::empty();
+        if self.skip(")")? {
+            return Ok(flags);
+        }
+        loop {
+            let (name, span) = self.word()?;
+            let flag =
+                F::from_name(name).ok_or_else(|| error(span, format!("unknown flag `{name}`")))?;
+            flags.insert(flag);
+            if !self.skip("|")? {
+                break;
+            }
+        }
+        self.expect(")")?;
+        Ok(flags)
+    }
+
+    /// Parse a range of expressions, like `%3..%5`.
+    fn range(&mut self, arena: &Arena<Expression>) -> Result<Range<Expression>, ParseError> {
+        let (start, start_span) = self.index('%')?;
+        self.expect("..")?;
+        let (end, _) = self.index('%')?;
+        let span = self.span_since(start_span);
+        if start > end || end as usize > arena.len() {
+            return Err(error(
+                span,
+                "ranges must be in order and refer to expressions declared earlier",
+            ));
+        }
+        Ok(Range::from_index_range(start..end, arena))
+    }
+
+    /// Parse a handle, or `_` for `None`.
+    fn optional_handle<T>(&mut self, kind: char) -> Result<Option<Handle<T>>, ParseError> {
+        if self.skip_word("_")? {
+            Ok(None)
+        } else {
+            self.handle(kind).map(Some)
+        }
+    }
+
+    /// Parse an operand, if the next token is one.
+    fn optional_operand(
+        &mut self,
+        operands: Operands,
+    ) -> Result<Option<Handle<Expression>>, ParseError> {
+        match self.lexer.peek()?.0 {
+            Token::Handle(kind, _) if kind == operands.kind() => self.handle(kind).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Parse an operand preceded by `keyword`, if the next token is `keyword`.
+    fn keyword_operand(
+        &mut self,
+        keyword: &str,
+        operands: Operands,
+    ) -> Result<Option<Handle<Expression>>, ParseError> {
+        if self.skip_word(keyword)? {
+            self.handle(operands.kind()).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parse a parenthesized, comma-separated list.
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.expect("(")?;
+        let mut items = Vec::new();
+        while !self.skip(")")? {
+            if !items.is_empty() {
+                self.expect(",")?;
+            }
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Parse the handle that a declaration introduces.
+    ///
+    /// Declarations must appear in arena order, so the handle's index must
+    /// be `len`, the number of entries already in the arena.
+    fn declare(&mut self, kind: char, len: usize) -> Result<Span, ParseError> {
+        let (index, span) = self.index(kind)?;
+        if index as usize != len {
+            let expected = Token::Handle(kind, len as u32);
+            return Err(error(
+                span,
+                format!("expected {expected}, since declarations must be in arena order"),
+            ));
+        }
+        Ok(span)
+    }
+
+    fn handle<T>(&mut self, kind: char) -> Result<Handle<T>, ParseError> {
+        let (index, span) = self.index(kind)?;
+        NonMaxU32::new(index)
+            .map(Handle::new)
+            .ok_or_else(|| error(span, "handle index is too large"))
+    }
+
+    /// Parse a handle of the given kind, and return its index.
+    fn index(&mut self, kind: char) -> Result<(u32, Span), ParseError> {
+        match self.lexer.next()? {
+            (Token::Handle(found, index), span) if found == kind => Ok((index, span)),
+            (token, span) => {
+                let expected = match kind {
+                    '%' | '$' => format!("a `{kind}` handle"),
+                    _ => format!("an `@{kind}` handle"),
+                };
+                Err(unexpected(&token, span, &expected))
+            }
+        }
+    }
+
+    /// Parse the name of a fieldless enum variant, as written by `Debug`.
+    fn variant<T: Copy + std::fmt::Debug>(
+        &mut self,
+        variants: &[T],
+        expected: &str,
+    ) -> Result<T, ParseError> {
+        let (word, span) = self.word()?;
+        variants
+            .iter()
+            .copied()
+            .find(|variant| format!("{variant:?}") == word)
+            .ok_or_else(|| error(span, format!("expected {expected}, found `{word}`")))
+    }
+
+    fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        match self.lexer.next()? {
+            (Token::Number(text), span) => text
+                .parse()
+                .map_err(|_| error(span, format!("invalid number `{text}`"))),
+            (token, span) => Err(unexpected(&token, span, "a number")),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        match self.lexer.next()? {
+            (Token::String(string), _) => Ok(string),
+            (token, span) => Err(unexpected(&token, span, "a string")),
+        }
+    }
+
+    /// Parse a name, if the next token is a string.
+    fn optional_name(&mut self) -> Result<Option<String>, ParseError> {
+        match self.lexer.peek()?.0 {
+            Token::String(_) => self.string().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Parse a name, or `_` for `None`.
+    fn name_or_underscore(&mut self) -> Result<Option<String>, ParseError> {
+        if self.skip_word("_")? {
+            Ok(None)
+        } else {
+            self.string().map(Some)
+        }
+    }
+
+    fn word(&mut self) -> Result<(&'a str, Span), ParseError> {
+        match self.lexer.next()? {
+            (Token::Word(word), span) => Ok((word, span)),
+            (token, span) => Err(unexpected(&token, span, "a word")),
+        }
+    }
+
+    fn expect(&mut self, punct: &'static str) -> Result<(), ParseError> {
+        match self.lexer.next()? {
+            (Token::Punct(found), _) if found == punct => Ok(()),
+            (token, span) => Err(unexpected(&token, span, &format!("`{punct}`"))),
+        }
+    }
+
+    fn expect_word(&mut self, word: &str) -> Result<(), ParseError> {
+        match self.lexer.next()? {
+            (Token::Word(found), _) if found == word => Ok(()),
+            (token, span) => Err(unexpected(&token, span, &format!("`{word}`"))),
+        }
+    }
+
+    /// Consume the punctuation `punct`, if it is next.
+    fn skip(&mut self, punct: &str) -> Result<bool, ParseError> {
+        let found = matches!(self.lexer.peek()?.0, Token::Punct(found) if found == punct);
+        if found {
+            self.lexer.next()?;
+        }
+        Ok(found)
+    }
+
+    /// Consume the word `word`, if it is next.
+    fn skip_word(&mut self, word: &str) -> Result<bool, ParseError> {
+        let found = matches!(self.lexer.peek()?.0, Token::Word(found) if found == word);
+        if found {
+            self.lexer.next()?;
+        }
+        Ok(found)
+    }
+
+    /// Return a span from the start of `start` to the end of the last token
+    /// consumed.
+    const fn span_since(&self, start: Span) -> Span {
+        let end = self.lexer.offset() as u32;
+        start.until(&Span::new(end, end))
+    }
+}
+
+fn error(span: Span, message: impl Into<String>) -> ParseError {
+    ParseError {
+        message: message.into(),
+        span,
+    }
+}
+
+fn unexpected(token: &Token, span: Span, expected: &str) -> ParseError {
+    error(span, format!("expected {expected}, found {token}"))
+}
+
+fn scalar_from_word(word: &str) -> Option<Scalar> {
+    match word {
+        "bool" => return Some(Scalar::BOOL),
+        "abstract_int" => return Some(Scalar::ABSTRACT_INT),
+        "abstract_float" => return Some(Scalar::ABSTRACT_FLOAT),
+        _ => {}
+    }
+    let kind = match *word.as_bytes().first()? {
+        b'i' => ScalarKind::Sint,
+        b'u' => ScalarKind::Uint,
+        b'f' => ScalarKind::Float,
+        _ => return None,
+    };
+    let width = match &word[1..] {
+        "8" => 1,
+        "16" => 2,
+        "32" => 4,
+        "64" => 8,
+        _ => return None,
+    };
+    Some(Scalar { kind, width })
+}
+
+fn vector_size_from_word(word: &str) -> Option<VectorSize> {
+    match word {
+        "vec2" => Some(VectorSize::Bi),
+        "vec3" => Some(VectorSize::Tri),
+        "vec4" => Some(VectorSize::Quad),
+        _ => None,
+    }
+}
+
+fn matrix_size_from_word(word: &str) -> Option<(VectorSize, VectorSize)> {
+    let (columns, rows) = word.strip_prefix("mat")?.split_once('x')?;
+    let size = |n| match n {
+        "2" => Some(VectorSize::Bi),
+        "3" => Some(VectorSize::Tri),
+        "4" => Some(VectorSize::Quad),
+        _ => None,
+    };
+    Some((size(columns)?, size(rows)?))
+}
+
+// The variants of the fieldless enums the format spells by name. Variants
+// with fields, like `BuiltIn::Position`, are handled separately.
+
+const SCALAR_KINDS: &[ScalarKind] = &[
+    ScalarKind::Sint,
+    ScalarKind::Uint,
+    ScalarKind::Float,
+    ScalarKind::Bool,
+    ScalarKind::AbstractInt,
+    ScalarKind::AbstractFloat,
+];
+
+const IMAGE_DIMENSIONS: &[ImageDimension] = &[
+    ImageDimension::D1,
+    ImageDimension::D2,
+    ImageDimension::D3,
+    ImageDimension::Cube,
+];
+
+const STORAGE_FORMATS: &[StorageFormat] = {
+    use StorageFormat as Sf;
+    &[
+        Sf::R8Unorm,
+        Sf::R8Snorm,
+        Sf::R8Uint,
+        Sf::R8Sint,
+        Sf::R16Uint,
+        Sf::R16Sint,
+        Sf::R16Float,
+        Sf::Rg8Unorm,
+        Sf::Rg8Snorm,
+        Sf::Rg8Uint,
+        Sf::Rg8Sint,
+        Sf::R32Uint,
+        Sf::R32Sint,
+        Sf::R32Float,
+        Sf::Rg16Uint,
+        Sf::Rg16Sint,
+        Sf::Rg16Float,
+        Sf::Rgba8Unorm,
+        Sf::Rgba8Snorm,
+        Sf::Rgba8Uint,
+        Sf::Rgba8Sint,
+        Sf::Bgra8Unorm,
+        Sf::Rgb10a2Uint,
+        Sf::Rgb10a2Unorm,
+        Sf::Rg11b10Ufloat,
+        Sf::Rg32Uint,
+        Sf::Rg32Sint,
+        Sf::Rg32Float,
+        Sf::Rgba16Uint,
+        Sf::Rgba16Sint,
+        Sf::Rgba16Float,
+        Sf::Rgba32Uint,
+        Sf::Rgba32Sint,
+        Sf::Rgba32Float,
+        Sf::R16Unorm,
+        Sf::R16Snorm,
+        Sf::Rg16Unorm,
+        Sf::Rg16Snorm,
+        Sf::Rgba16Unorm,
+        Sf::Rgba16Snorm,
+    ]
+};
+
+const BUILT_INS: &[BuiltIn] = {
+    use BuiltIn as Bi;
+    &[
+        Bi::ViewIndex,
+        Bi::BaseInstance,
+        Bi::BaseVertex,
+        Bi::ClipDistance,
+        Bi::CullDistance,
+        Bi::InstanceIndex,
+        Bi::PointSize,
+        Bi::VertexIndex,
+        Bi::DrawID,
+        Bi::FragDepth,
+        Bi::PointCoord,
+        Bi::FrontFacing,
+        Bi::PrimitiveIndex,
+        Bi::SampleIndex,
+        Bi::SampleMask,
+        Bi::GlobalInvocationId,
+        Bi::LocalInvocationId,
+        Bi::LocalInvocationIndex,
+        Bi::WorkGroupId,
+        Bi::WorkGroupSize,
+        Bi::NumWorkGroups,
+        Bi::NumSubgroups,
+        Bi::SubgroupId,
+        Bi::SubgroupSize,
+        Bi::SubgroupInvocationId,
+    ]
+};
+
+const INTERPOLATIONS: &[Interpolation] = &[
+    Interpolation::Perspective,
+    Interpolation::Linear,
+    Interpolation::Flat,
+];
+
+const SAMPLINGS: &[Sampling] = &[
+    Sampling::Center,
+    Sampling::Centroid,
+    Sampling::Sample,
+    Sampling::First,
+    Sampling::Either,
+];
+
+const SHADER_STAGES: &[ShaderStage] = &[
+    ShaderStage::Vertex,
+    ShaderStage::Fragment,
+    ShaderStage::Compute,
+];
+
+const CONSERVATIVE_DEPTHS: &[ConservativeDepth] = &[
+    ConservativeDepth::GreaterEqual,
+    ConservativeDepth::LessEqual,
+    ConservativeDepth::Unchanged,
+];
+
+const UNARY_OPERATORS: &[UnaryOperator] = &[
+    UnaryOperator::Negate,
+    UnaryOperator::LogicalNot,
+    UnaryOperator::BitwiseNot,
+];
+
+const BINARY_OPERATORS: &[BinaryOperator] = {
+    use BinaryOperator as Bo;
+    &[
+        Bo::Add,
+        Bo::Subtract,
+        Bo::Multiply,
+        Bo::Divide,
+        Bo::Modulo,
+        Bo::Equal,
+        Bo::NotEqual,
+        Bo::Less,
+        Bo::LessEqual,
+        Bo::Greater,
+        Bo::GreaterEqual,
+        Bo::And,
+        Bo::ExclusiveOr,
+        Bo::InclusiveOr,
+        Bo::LogicalAnd,
+        Bo::LogicalOr,
+        Bo::ShiftLeft,
+        Bo::ShiftRight,
+    ]
+};
+
+/// Atomic functions other than `Exchange`, which has a field.
+const ATOMIC_FUNCTIONS: &[AtomicFunction] = &[
+    AtomicFunction::Add,
+    AtomicFunction::Subtract,
+    AtomicFunction::And,
+    AtomicFunction::ExclusiveOr,
+    AtomicFunction::InclusiveOr,
+    AtomicFunction::Min,
+    AtomicFunction::Max,
+];
+
+const DERIVATIVE_AXES: &[DerivativeAxis] =
+    &[DerivativeAxis::X, DerivativeAxis::Y, DerivativeAxis::Width];
+
+const DERIVATIVE_CONTROLS: &[DerivativeControl] = &[
+    DerivativeControl::Coarse,
+    DerivativeControl::Fine,
+    DerivativeControl::None,
+];
+
+const RELATIONAL_FUNCTIONS: &[RelationalFunction] = &[
+    RelationalFunction::All,
+    RelationalFunction::Any,
+    RelationalFunction::IsNan,
+    RelationalFunction::IsInf,
+];
+
+const MATH_FUNCTIONS: &[MathFunction] = {
+    use MathFunction as Mf;
+    &[
+        Mf::Abs,
+        Mf::Min,
+        Mf::Max,
+        Mf::Clamp,
+        Mf::Saturate,
+        Mf::Cos,
+        Mf::Cosh,
+        Mf::Sin,
+        Mf::Sinh,
+        Mf::Tan,
+        Mf::Tanh,
+        Mf::Acos,
+        Mf::Asin,
+        Mf::Atan,
+        Mf::Atan2,
+        Mf::Asinh,
+        Mf::Acosh,
+        Mf::Atanh,
+        Mf::Radians,
+        Mf::Degrees,
+        Mf::Ceil,
+        Mf::Floor,
+        Mf::Round,
+        Mf::Fract,
+        Mf::Trunc,
+        Mf::Modf,
+        Mf::Frexp,
+        Mf::Ldexp,
+        Mf::Exp,
+        Mf::Exp2,
+        Mf::Log,
+        Mf::Log2,
+        Mf::Pow,
+        Mf::Dot,
+        Mf::Dot4I8Packed,
+        Mf::Dot4U8Packed,
+        Mf::Outer,
+        Mf::Cross,
+        Mf::Distance,
+        Mf::Length,
+        Mf::Normalize,
+        Mf::FaceForward,
+        Mf::Reflect,
+        Mf::Refract,
+        Mf::Sign,
+        Mf::Fma,
+        Mf::Mix,
+        Mf::Step,
+        Mf::SmoothStep,
+        Mf::Sqrt,
+        Mf::InverseSqrt,
+        Mf::Inverse,
+        Mf::Transpose,
+        Mf::Determinant,
+        Mf::QuantizeToF16,
+        Mf::CountTrailingZeros,
+        Mf::CountLeadingZeros,
+        Mf::CountOneBits,
+        Mf::ReverseBits,
+        Mf::ExtractBits,
+        Mf::InsertBits,
+        Mf::FirstTrailingBit,
+        Mf::FirstLeadingBit,
+        Mf::Pack4x8snorm,
+        Mf::Pack4x8unorm,
+        Mf::Pack2x16snorm,
+        Mf::Pack2x16unorm,
+        Mf::Pack2x16float,
+        Mf::Pack4xI8,
+        Mf::Pack4xU8,
+        Mf::Pack4xI8Clamp,
+        Mf::Pack4xU8Clamp,
+        Mf::Unpack4x8snorm,
+        Mf::Unpack4x8unorm,
+        Mf::Unpack2x16snorm,
+        Mf::Unpack2x16unorm,
+        Mf::Unpack2x16float,
+        Mf::Unpack4xI8,
+        Mf::Unpack4xU8,
+    ]
+};
+
+const SWIZZLE_COMPONENTS: &[SwizzleComponent] = &[
+    SwizzleComponent::X,
+    SwizzleComponent::Y,
+    SwizzleComponent::Z,
+    SwizzleComponent::W,
+];
+
+const DIRECTIONS: &[Direction] = &[Direction::X, Direction::Y, Direction::Diagonal];
+
+const SUBGROUP_OPERATIONS: &[SubgroupOperation] = &[
+    SubgroupOperation::All,
+    SubgroupOperation::Any,
+    SubgroupOperation::Add,
+    SubgroupOperation::Mul,
+    SubgroupOperation::Min,
+    SubgroupOperation::Max,
+    SubgroupOperation::And,
+    SubgroupOperation::Or,
+    SubgroupOperation::Xor,
+];
+
+/// Collective operations other than `ClusteredReduce`, which has a field.
+const COLLECTIVE_OPERATIONS: &[CollectiveOperation] = &[
+    CollectiveOperation::Reduce,
+    CollectiveOperation::InclusiveScan,
+    CollectiveOperation::ExclusiveScan,
+];
+
+const SEVERITIES: &[Severity] = &[
+    Severity::Off,
+    Severity::Info,
+    Severity::Warning,
+    Severity::Error,
+];
+
+const STANDARD_RULES: &[StandardFilterableTriggeringRule] =
+    &[StandardFilterableTriggeringRule::DerivativeUniformity];
@@ -5,10 +5,12 @@ Frontend parsers that consume binary and text shaders and load them into [`Modul
 mod interpolator;
 mod type_gen;
 
-#[cfg(feature = "spv-in")]
+#[cfg(any(feature = "spv-in", feature = "glsl-in"))]
 pub mod atomic_upgrade;
 #[cfg(feature = "glsl-in")]
 pub mod glsl;
+#[cfg(feature = "ir-text-in")]
+pub mod ir_text;
 #[cfg(feature = "spv-in")]
 pub mod spv;
 #[cfg(feature = "wgsl-in")]
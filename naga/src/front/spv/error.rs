@@ -102,6 +102,8 @@ pub enum Error {
     InvalidImageBaseType(Handle<crate::Type>),
     #[error("invalid image {0:?}")]
     InvalidImage(Handle<crate::Type>),
+    #[error("invalid gather component %{0}")]
+    InvalidGatherComponent(spirv::Word),
     #[error("invalid as type {0:?}")]
     InvalidAsType(Handle<crate::Type>),
     #[error("invalid vector type {0:?}")]
@@ -130,8 +132,14 @@ pub enum Error {
     InvalidArraySize(spirv::Word),
     #[error("invalid barrier scope %{0}")]
     InvalidBarrierScope(spirv::Word),
+    #[error("invalid cluster size %{0}")]
+    InvalidClusterSize(spirv::Word),
+    #[error("invalid quad swap direction %{0}")]
+    InvalidQuadSwapDirection(spirv::Word),
     #[error("invalid barrier memory semantics %{0}")]
     InvalidBarrierMemorySemantics(spirv::Word),
+    #[error("invalid ray query intersection %{0}")]
+    InvalidRayQueryIntersection(spirv::Word),
     #[error(
         "arrays of images / samplers are supported only through bindings for \
          now (i.e. you can't create an array of images or samplers that doesn't \
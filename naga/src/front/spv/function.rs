@@ -60,6 +60,8 @@ impl<I: Iterator<Item = u32>> super::Frontend<I> {
                 ),
                 named_expressions: crate::NamedExpressions::default(),
                 body: crate::Block::new(),
+                diagnostic_filter_leaf: None,
+                diagnostic_filter_scopes: Vec::new(),
             }
         };
 
@@ -129,6 +131,7 @@ impl<I: Iterator<Item = u32>> super::Frontend<I> {
             global_expressions: &mut module.global_expressions,
             type_arena: &module.types,
             global_arena: &module.global_variables,
+            special_types: &module.special_types,
             arguments: &fun.arguments,
             parameter_sampling: &mut parameters_sampling,
         };
@@ -279,6 +282,28 @@ impl<I: Iterator<Item = u32>> super::Frontend<I> {
 
         fun.body = block_ctx.lower();
 
+        // `OpAtomicCompareExchange` results are created with the scalar type
+        // of the atomic, since `BlockContext` can't add types to the module.
+        for (_, expr) in fun.expressions.iter_mut() {
+            if let crate::Expression::AtomicResult {
+                ref mut ty,
+                comparison: true,
+            } = *expr
+            {
+                let scalar = module.types[*ty]
+                    .inner
+                    .scalar()
+                    .ok_or(Error::UnsupportedType(*ty))?;
+                *ty = module.generate_predeclared_type(
+                    crate::PredeclaredType::AtomicCompareExchangeWeakResult(scalar),
+                );
+            }
+        }
+
+        for special_type in self.predeclared_math_results.drain(..) {
+            module.generate_predeclared_type(special_type);
+        }
+
         // done
         let fun_handle = module.functions.append(fun, self.span_from_with_op(start));
         self.lookup_function.insert(
@@ -311,6 +336,8 @@ impl<I: Iterator<Item = u32>> super::Frontend<I> {
             expressions: Arena::new(),
             named_expressions: crate::NamedExpressions::default(),
             body: crate::Block::new(),
+            diagnostic_filter_leaf: None,
+            diagnostic_filter_scopes: Vec::new(),
         };
 
         // 1. copy the inputs from arguments to privates
@@ -466,6 +493,28 @@ impl<I: Iterator<Item = u32>> super::Frontend<I> {
             }
         }
 
+        // `Index 0` is the default, so outputs are only blend sources if one of
+        // them is decorated with `Index 1`.
+        let dual_source_blending = members.iter().any(|member| {
+            matches!(
+                member.binding,
+                Some(crate::Binding::Location {
+                    blend_src: Some(1),
+                    ..
+                })
+            )
+        });
+        if !dual_source_blending {
+            for member in members.iter_mut() {
+                if let Some(crate::Binding::Location {
+                    ref mut blend_src, ..
+                }) = member.binding
+                {
+                    *blend_src = None;
+                }
+            }
+        }
+
         for (member_index, member) in members.iter().enumerate() {
             match member.binding {
                 Some(crate::Binding::BuiltIn(crate::BuiltIn::Position { .. }))
@@ -43,6 +43,9 @@ pub struct SamplingOptions {
     pub project: bool,
     /// Depth comparison sampling with a reference value.
     pub compare: bool,
+    /// Gathering a single component from four texels. Unless `compare` is
+    /// set, the component is given by an operand following the coordinate.
+    pub gather: bool,
 }
 
 enum ExtraCoordinate {
@@ -460,6 +463,19 @@ impl<I: Iterator<Item = u32>> super::Frontend<I> {
         } else {
             None
         };
+        let gather = match (options.gather, options.compare) {
+            // Depth gathers always return the depth component.
+            (true, true) => Some(crate::SwizzleComponent::X),
+            (true, false) => {
+                let component_id = self.next()?;
+                let component_const = self.lookup_constant.lookup(component_id)?;
+                let component = super::resolve_constant(ctx.gctx(), &component_const.inner)
+                    .and_then(|index| crate::SwizzleComponent::XYZW.get(index as usize))
+                    .ok_or(Error::InvalidGatherComponent(component_id))?;
+                Some(*component)
+            }
+            (false, _) => None,
+        };
 
         let mut image_ops = if words_left != 0 {
             words_left -= 1;
@@ -553,6 +569,10 @@ impl<I: Iterator<Item = u32>> super::Frontend<I> {
             }
             image_ops ^= bit;
         }
+        // SPIR-V gathers don't take a level, but always use the base one.
+        if gather.is_some() {
+            level = crate::SampleLevel::Zero;
+        }
 
         let si_lexp = self.lookup_sampled_image.lookup(sampled_image_id)?;
         let coord_lexp = self.lookup_expression.lookup(coordinate_id)?;
@@ -676,7 +696,7 @@ impl<I: Iterator<Item = u32>> super::Frontend<I> {
         let expr = crate::Expression::ImageSample {
             image: si_lexp.image,
             sampler: si_lexp.sampler,
-            gather: None, //TODO
+            gather,
             coordinate,
             array_index,
             offset,
@@ -684,7 +704,7 @@ impl<I: Iterator<Item = u32>> super::Frontend<I> {
             depth_ref,
         };
         let image_sample_handle = ctx.expressions.append(expr, self.span_from_with_op(start));
-        let handle = if is_depth && depth_ref.is_none() {
+        let handle = if is_depth && depth_ref.is_none() && gather.is_none() {
             let splat_expr = crate::Expression::Splat {
                 size: crate::VectorSize::Quad,
                 value: image_sample_handle,
@@ -728,31 +748,63 @@ impl<I: Iterator<Item = u32>> super::Frontend<I> {
 
         // No need to call get_expr_handle here since only globals/arguments are
         // allowed as images and they are always in the root scope
-        //TODO: handle arrays and cubes
         let image_lexp = self.lookup_expression.lookup(image_id)?;
-
-        let expr = crate::Expression::ImageQuery {
-            image: image_lexp.handle,
-            query: crate::ImageQuery::Size { level },
+        let image = image_lexp.handle;
+        let image_ty = ctx.get_image_expr_ty(image)?;
+        let arrayed = match ctx.type_arena[image_ty].inner {
+            crate::TypeInner::Image { arrayed, .. } => arrayed,
+            _ => return Err(Error::InvalidImage(image_ty)),
         };
 
         let result_type_handle = self.lookup_type.lookup(result_type_id)?.handle;
         let maybe_scalar_kind = ctx.type_arena[result_type_handle].inner.scalar_kind();
+        let span = self.span_from_with_op(start);
 
-        let expr = if maybe_scalar_kind == Some(crate::ScalarKind::Sint) {
-            crate::Expression::As {
-                expr: ctx.expressions.append(expr, self.span_from_with_op(start)),
-                kind: crate::ScalarKind::Sint,
-                convert: Some(4),
-            }
+        let mut components = Vec::with_capacity(2);
+        components.push(crate::Expression::ImageQuery {
+            image,
+            query: crate::ImageQuery::Size { level },
+        });
+        // SPIR-V includes the number of layers in the size of arrayed
+        // images, while Naga IR queries it separately.
+        if arrayed {
+            components.push(crate::Expression::ImageQuery {
+                image,
+                query: crate::ImageQuery::NumLayers,
+            });
+        }
+        let mut components: Vec<_> = components
+            .into_iter()
+            .map(|expr| {
+                let expr = if maybe_scalar_kind == Some(crate::ScalarKind::Sint) {
+                    crate::Expression::As {
+                        expr: ctx.expressions.append(expr, span),
+                        kind: crate::ScalarKind::Sint,
+                        convert: Some(4),
+                    }
+                } else {
+                    expr
+                };
+                ctx.expressions.append(expr, span)
+            })
+            .collect();
+
+        let handle = if components.len() == 1 {
+            components.pop().unwrap()
         } else {
-            expr
+            ctx.expressions.append(
+                crate::Expression::Compose {
+                    ty: result_type_handle,
+                    components,
+                },
+                span,
+            )
         };
 
         self.lookup_expression.insert(
             result_id,
             LookupExpression {
-                handle: ctx.expressions.append(expr, self.span_from_with_op(start)),
+                handle,
                 type_id: result_type_id,
                 block_id,
             },
@@ -69,6 +69,8 @@ pub const SUPPORTED_CAPABILITIES: &[spirv::Capability] = &[
     spirv::Capability::Float64,
     spirv::Capability::Geometry,
     spirv::Capability::MultiView,
+    spirv::Capability::RayQueryKHR,
+    spirv::Capability::DemoteToHelperInvocation,
     // tricky ones
     spirv::Capability::UniformBufferArrayDynamicIndexing,
     spirv::Capability::StorageBufferArrayDynamicIndexing,
@@ -77,6 +79,9 @@ pub const SUPPORTED_EXTENSIONS: &[&str] = &[
     "SPV_KHR_storage_buffer_storage_class",
     "SPV_KHR_vulkan_memory_model",
     "SPV_KHR_multiview",
+    "SPV_KHR_ray_query",
+    "SPV_EXT_demote_to_helper_invocation",
+    "SPV_KHR_terminate_invocation",
 ];
 pub const SUPPORTED_EXT_SETS: &[&str] = &["GLSL.std.450"];
 
@@ -196,6 +201,7 @@ struct Decoration {
     name: Option<String>,
     built_in: Option<spirv::Word>,
     location: Option<spirv::Word>,
+    index: Option<spirv::Word>,
     desc_set: Option<spirv::Word>,
     desc_index: Option<spirv::Word>,
     specialization_constant_id: Option<spirv::Word>,
@@ -240,6 +246,7 @@ impl Decoration {
             Decoration {
                 built_in: None,
                 location: Some(location),
+                index,
                 interpolation,
                 sampling,
                 ..
@@ -247,7 +254,7 @@ impl Decoration {
                 location,
                 interpolation,
                 sampling,
-                second_blend_source: false,
+                blend_src: index,
             }),
             _ => Err(Error::MissingDecoration(spirv::Decoration::Location)),
         }
@@ -555,6 +562,8 @@ struct BlockContext<'function> {
     type_arena: &'function UniqueArena<crate::Type>,
     /// Global arena of the module being processed
     global_arena: &'function Arena<crate::GlobalVariable>,
+    /// Special types of the module being processed
+    special_types: &'function crate::SpecialTypes,
     /// Arguments of the function currently being processed
     arguments: &'function [crate::FunctionArgument],
     /// Metadata about the usage of function parameters as sampling objects
@@ -662,6 +671,10 @@ pub struct Frontend<I> {
     /// glslang declares those by default even though they are never written to
     /// (see <https://github.com/KhronosGroup/glslang/issues/1868>)
     gl_per_vertex_builtin_access: FastHashSet<crate::BuiltIn>,
+
+    /// Predeclared types needed by the `Modf` and `Frexp` expressions of the
+    /// function being parsed, which `BlockContext` can't add to the module.
+    predeclared_math_results: Vec<crate::PredeclaredType>,
 }
 
 impl<I: Iterator<Item = u32>> Frontend<I> {
@@ -696,6 +709,7 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
             options: options.clone(),
             switch_cases: FastIndexMap::default(),
             gl_per_vertex_builtin_access: FastHashSet::default(),
+            predeclared_math_results: Vec::new(),
         }
     }
 
@@ -764,6 +778,10 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                 inst.expect(base_words + 2)?;
                 dec.location = Some(self.next()?);
             }
+            spirv::Decoration::Index => {
+                inst.expect(base_words + 2)?;
+                dec.index = Some(self.next()?);
+            }
             spirv::Decoration::DescriptorSet => {
                 inst.expect(base_words + 2)?;
                 dec.desc_set = Some(self.next()?);
@@ -2158,9 +2176,9 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                     let base_handle = get_expr_handle!(pointer_id, base_lexp);
                     let type_lookup = self.lookup_type.lookup(base_lexp.type_id)?;
                     let handle = match ctx.type_arena[type_lookup.handle].inner {
-                        crate::TypeInner::Image { .. } | crate::TypeInner::Sampler { .. } => {
-                            base_handle
-                        }
+                        crate::TypeInner::Image { .. }
+                        | crate::TypeInner::Sampler { .. }
+                        | crate::TypeInner::AccelerationStructure => base_handle,
                         _ => match self.lookup_load_override.get(&pointer_id) {
                             Some(&LookupLoadOverride::Loaded(handle)) => handle,
                             //Note: we aren't handling `LookupLoadOverride::Pending` properly here
@@ -2457,17 +2475,20 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                     inst.expect(5)?;
                     parse_expr_op!(crate::BinaryOperator::Multiply, BINARY)?;
                 }
-                Op::Transpose => {
+                Op::Transpose | Op::QuantizeToF16 => {
                     inst.expect(4)?;
 
                     let result_type_id = self.next()?;
                     let result_id = self.next()?;
-                    let matrix_id = self.next()?;
-                    let matrix_lexp = self.lookup_expression.lookup(matrix_id)?;
-                    let matrix_handle = get_expr_handle!(matrix_id, matrix_lexp);
+                    let arg_id = self.next()?;
+                    let arg_lexp = self.lookup_expression.lookup(arg_id)?;
+                    let arg_handle = get_expr_handle!(arg_id, arg_lexp);
                     let expr = crate::Expression::Math {
-                        fun: crate::MathFunction::Transpose,
-                        arg: matrix_handle,
+                        fun: match inst.op {
+                            Op::Transpose => crate::MathFunction::Transpose,
+                            _ => crate::MathFunction::QuantizeToF16,
+                        },
+                        arg: arg_handle,
                         arg1: None,
                         arg2: None,
                         arg3: None,
@@ -2764,6 +2785,7 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                     let options = image::SamplingOptions {
                         compare: false,
                         project: false,
+                        gather: false,
                     };
                     self.parse_image_sample(
                         extra,
@@ -2780,6 +2802,7 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                     let options = image::SamplingOptions {
                         compare: false,
                         project: true,
+                        gather: false,
                     };
                     self.parse_image_sample(
                         extra,
@@ -2796,6 +2819,7 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                     let options = image::SamplingOptions {
                         compare: true,
                         project: false,
+                        gather: false,
                     };
                     self.parse_image_sample(
                         extra,
@@ -2812,6 +2836,41 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                     let options = image::SamplingOptions {
                         compare: true,
                         project: true,
+                        gather: false,
+                    };
+                    self.parse_image_sample(
+                        extra,
+                        options,
+                        ctx,
+                        &mut emitter,
+                        &mut block,
+                        block_id,
+                        body_idx,
+                    )?;
+                }
+                Op::ImageGather => {
+                    let extra = inst.expect_at_least(6)?;
+                    let options = image::SamplingOptions {
+                        compare: false,
+                        project: false,
+                        gather: true,
+                    };
+                    self.parse_image_sample(
+                        extra,
+                        options,
+                        ctx,
+                        &mut emitter,
+                        &mut block,
+                        block_id,
+                        body_idx,
+                    )?;
+                }
+                Op::ImageDrefGather => {
+                    let extra = inst.expect_at_least(6)?;
+                    let options = image::SamplingOptions {
+                        compare: true,
+                        project: false,
+                        gather: true,
                     };
                     self.parse_image_sample(
                         extra,
@@ -3178,6 +3237,26 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                         None
                     };
 
+                    if let Mf::Modf | Mf::Frexp = fun {
+                        let result_ty = self.lookup_type.lookup(result_type_id)?.handle;
+                        let member_ty = match ctx.type_arena[result_ty].inner {
+                            crate::TypeInner::Struct { ref members, .. } if !members.is_empty() => {
+                                members[0].ty
+                            }
+                            _ => return Err(Error::UnsupportedType(result_ty)),
+                        };
+                        let (size, width) = match ctx.type_arena[member_ty].inner {
+                            crate::TypeInner::Scalar(scalar) => (None, scalar.width),
+                            crate::TypeInner::Vector { size, scalar } => (Some(size), scalar.width),
+                            _ => return Err(Error::UnsupportedType(member_ty)),
+                        };
+                        self.predeclared_math_results.push(if fun == Mf::Modf {
+                            crate::PredeclaredType::ModfResult { size, width }
+                        } else {
+                            crate::PredeclaredType::FrexpResult { size, width }
+                        });
+                    }
+
                     let expr = crate::Expression::Math {
                         fun,
                         arg,
@@ -3271,10 +3350,16 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                         },
                     );
                 }
-                Op::Kill => {
+                Op::Kill | Op::TerminateInvocation => {
                     inst.expect(1)?;
                     break Some(crate::Statement::Kill);
                 }
+                Op::DemoteToHelperInvocation => {
+                    inst.expect(1)?;
+                    block.extend(emitter.finish(ctx.expressions));
+                    block.push(crate::Statement::Demote, span);
+                    emitter.start(ctx.expressions);
+                }
                 Op::Unreachable => {
                     inst.expect(1)?;
                     break None;
@@ -3827,6 +3912,10 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                                     .bits()
                                 != 0,
                         );
+                        flags.set(
+                            crate::Barrier::TEXTURE,
+                            semantics & spirv::MemorySemantics::IMAGE_MEMORY.bits() != 0,
+                        );
                         block.push(crate::Statement::Barrier(flags), span);
                     } else {
                         log::warn!("Unsupported barrier execution scope: {}", exec_scope);
@@ -3923,37 +4012,47 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                 | Op::GroupNonUniformLogicalOr
                 | Op::GroupNonUniformLogicalXor => {
                     block.extend(emitter.finish(ctx.expressions));
-                    inst.expect(
-                        if matches!(inst.op, Op::GroupNonUniformAll | Op::GroupNonUniformAny) {
-                            5
-                        } else {
-                            6
-                        },
-                    )?;
+                    if matches!(inst.op, Op::GroupNonUniformAll | Op::GroupNonUniformAny) {
+                        inst.expect(5)?;
+                    } else {
+                        inst.expect_at_least(6)?;
+                    }
                     let result_type_id = self.next()?;
                     let result_id = self.next()?;
                     let exec_scope_id = self.next()?;
-                    let collective_op_id = match inst.op {
-                        Op::GroupNonUniformAll | Op::GroupNonUniformAny => {
-                            crate::CollectiveOperation::Reduce
-                        }
-                        _ => {
-                            let group_op_id = self.next()?;
-                            match spirv::GroupOperation::from_u32(group_op_id) {
-                                Some(spirv::GroupOperation::Reduce) => {
-                                    crate::CollectiveOperation::Reduce
-                                }
-                                Some(spirv::GroupOperation::InclusiveScan) => {
-                                    crate::CollectiveOperation::InclusiveScan
-                                }
-                                Some(spirv::GroupOperation::ExclusiveScan) => {
-                                    crate::CollectiveOperation::ExclusiveScan
-                                }
-                                _ => return Err(Error::UnsupportedGroupOperation(group_op_id)),
-                            }
-                        }
+                    let group_op_id = match inst.op {
+                        Op::GroupNonUniformAll | Op::GroupNonUniformAny => None,
+                        _ => Some(self.next()?),
                     };
                     let argument_id = self.next()?;
+                    let collective_op_id = match group_op_id {
+                        None => crate::CollectiveOperation::Reduce,
+                        Some(group_op_id) => match spirv::GroupOperation::from_u32(group_op_id) {
+                            Some(spirv::GroupOperation::Reduce) => {
+                                inst.expect(6)?;
+                                crate::CollectiveOperation::Reduce
+                            }
+                            Some(spirv::GroupOperation::InclusiveScan) => {
+                                inst.expect(6)?;
+                                crate::CollectiveOperation::InclusiveScan
+                            }
+                            Some(spirv::GroupOperation::ExclusiveScan) => {
+                                inst.expect(6)?;
+                                crate::CollectiveOperation::ExclusiveScan
+                            }
+                            Some(spirv::GroupOperation::ClusteredReduce) => {
+                                inst.expect(7)?;
+                                let cluster_size_id = self.next()?;
+                                let cluster_size_const =
+                                    self.lookup_constant.lookup(cluster_size_id)?;
+                                let cluster_size =
+                                    resolve_constant(ctx.gctx(), &cluster_size_const.inner)
+                                        .ok_or(Error::InvalidClusterSize(cluster_size_id))?;
+                                crate::CollectiveOperation::ClusteredReduce(cluster_size)
+                            }
+                            _ => return Err(Error::UnsupportedGroupOperation(group_op_id)),
+                        },
+                    };
 
                     let argument_lookup = self.lookup_expression.lookup(argument_id)?;
                     let argument_handle = get_expr_handle!(argument_id, argument_lookup);
@@ -4023,7 +4122,9 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                 | Op::GroupNonUniformShuffle
                 | Op::GroupNonUniformShuffleDown
                 | Op::GroupNonUniformShuffleUp
-                | Op::GroupNonUniformShuffleXor => {
+                | Op::GroupNonUniformShuffleXor
+                | Op::GroupNonUniformQuadBroadcast
+                | Op::GroupNonUniformQuadSwap => {
                     inst.expect(if matches!(inst.op, Op::GroupNonUniformBroadcastFirst) {
                         5
                     } else {
@@ -4045,6 +4146,16 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
 
                     let mode = if matches!(inst.op, Op::GroupNonUniformBroadcastFirst) {
                         crate::GatherMode::BroadcastFirst
+                    } else if matches!(inst.op, Op::GroupNonUniformQuadSwap) {
+                        let direction_id = self.next()?;
+                        let direction_const = self.lookup_constant.lookup(direction_id)?;
+                        let direction = match resolve_constant(ctx.gctx(), &direction_const.inner) {
+                            Some(0) => crate::Direction::X,
+                            Some(1) => crate::Direction::Y,
+                            Some(2) => crate::Direction::Diagonal,
+                            _ => return Err(Error::InvalidQuadSwapDirection(direction_id)),
+                        };
+                        crate::GatherMode::QuadSwap(direction)
                     } else {
                         let index_id = self.next()?;
                         let index_lookup = self.lookup_expression.lookup(index_id)?;
@@ -4063,6 +4174,9 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                             Op::GroupNonUniformShuffleXor => {
                                 crate::GatherMode::ShuffleXor(index_handle)
                             }
+                            Op::GroupNonUniformQuadBroadcast => {
+                                crate::GatherMode::QuadBroadcast(index_handle)
+                            }
                             _ => unreachable!(),
                         }
                     };
@@ -4156,6 +4270,234 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                     self.upgrade_atomics
                         .insert(ctx.get_contained_global_variable(p_lexp_handle)?);
                 }
+                Op::RayQueryInitializeKHR => {
+                    inst.expect(9)?;
+                    let query_id = self.next()?;
+                    let acceleration_structure_id = self.next()?;
+                    let flags_id = self.next()?;
+                    let cull_mask_id = self.next()?;
+                    let origin_id = self.next()?;
+                    let tmin_id = self.next()?;
+                    let direction_id = self.next()?;
+                    let tmax_id = self.next()?;
+
+                    let query_lexp = self.lookup_expression.lookup(query_id)?;
+                    let query = get_expr_handle!(query_id, query_lexp);
+                    let acceleration_structure_lexp =
+                        self.lookup_expression.lookup(acceleration_structure_id)?;
+                    let acceleration_structure =
+                        get_expr_handle!(acceleration_structure_id, acceleration_structure_lexp);
+
+                    //Note: component order must match `generate_ray_desc_type`
+                    let mut components = Vec::with_capacity(6);
+                    for id in [
+                        flags_id,
+                        cull_mask_id,
+                        tmin_id,
+                        tmax_id,
+                        origin_id,
+                        direction_id,
+                    ] {
+                        let lexp = self.lookup_expression.lookup(id)?;
+                        components.push(get_expr_handle!(id, lexp));
+                    }
+                    let ray_desc = ctx
+                        .special_types
+                        .ray_desc
+                        .ok_or(Error::InvalidId(query_id))?;
+                    let descriptor = ctx.expressions.append(
+                        crate::Expression::Compose {
+                            ty: ray_desc,
+                            components,
+                        },
+                        span,
+                    );
+
+                    block.extend(emitter.finish(ctx.expressions));
+                    block.push(
+                        crate::Statement::RayQuery {
+                            query,
+                            fun: crate::RayQueryFunction::Initialize {
+                                acceleration_structure,
+                                descriptor,
+                            },
+                        },
+                        span,
+                    );
+                    emitter.start(ctx.expressions);
+                }
+                Op::RayQueryProceedKHR => {
+                    inst.expect(4)?;
+                    let result_type_id = self.next()?;
+                    let result_id = self.next()?;
+                    let query_id = self.next()?;
+
+                    let query_lexp = self.lookup_expression.lookup(query_id)?;
+                    let query = get_expr_handle!(query_id, query_lexp);
+
+                    block.extend(emitter.finish(ctx.expressions));
+                    let result = ctx
+                        .expressions
+                        .append(crate::Expression::RayQueryProceedResult, span);
+                    self.lookup_expression.insert(
+                        result_id,
+                        LookupExpression {
+                            handle: result,
+                            type_id: result_type_id,
+                            block_id,
+                        },
+                    );
+                    block.push(
+                        crate::Statement::RayQuery {
+                            query,
+                            fun: crate::RayQueryFunction::Proceed { result },
+                        },
+                        span,
+                    );
+                    emitter.start(ctx.expressions);
+                }
+                Op::RayQueryTerminateKHR => {
+                    inst.expect(2)?;
+                    let query_id = self.next()?;
+
+                    let query_lexp = self.lookup_expression.lookup(query_id)?;
+                    let query = get_expr_handle!(query_id, query_lexp);
+
+                    block.extend(emitter.finish(ctx.expressions));
+                    block.push(
+                        crate::Statement::RayQuery {
+                            query,
+                            fun: crate::RayQueryFunction::Terminate,
+                        },
+                        span,
+                    );
+                    emitter.start(ctx.expressions);
+                }
+                Op::RayQueryGetIntersectionTypeKHR
+                | Op::RayQueryGetIntersectionTKHR
+                | Op::RayQueryGetIntersectionInstanceCustomIndexKHR
+                | Op::RayQueryGetIntersectionInstanceIdKHR
+                | Op::RayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR
+                | Op::RayQueryGetIntersectionGeometryIndexKHR
+                | Op::RayQueryGetIntersectionPrimitiveIndexKHR
+                | Op::RayQueryGetIntersectionBarycentricsKHR
+                | Op::RayQueryGetIntersectionFrontFaceKHR
+                | Op::RayQueryGetIntersectionObjectToWorldKHR
+                | Op::RayQueryGetIntersectionWorldToObjectKHR => {
+                    inst.expect(5)?;
+                    let result_type_id = self.next()?;
+                    let result_id = self.next()?;
+                    let query_id = self.next()?;
+                    let intersection_id = self.next()?;
+
+                    let intersection_const = self.lookup_constant.lookup(intersection_id)?;
+                    let committed = match resolve_constant(ctx.gctx(), &intersection_const.inner) {
+                        Some(value)
+                            if value
+                                == spirv::RayQueryIntersection::RayQueryCommittedIntersectionKHR
+                                    as u32 =>
+                        {
+                            true
+                        }
+                        Some(value)
+                            if value
+                                == spirv::RayQueryIntersection::RayQueryCandidateIntersectionKHR
+                                    as u32 =>
+                        {
+                            false
+                        }
+                        _ => return Err(Error::InvalidRayQueryIntersection(intersection_id)),
+                    };
+
+                    let query_lexp = self.lookup_expression.lookup(query_id)?;
+                    let query = get_expr_handle!(query_id, query_lexp);
+
+                    //Note: member indices must match `generate_ray_intersection_type`
+                    let index = match inst.op {
+                        Op::RayQueryGetIntersectionTypeKHR => 0,
+                        Op::RayQueryGetIntersectionTKHR => 1,
+                        Op::RayQueryGetIntersectionInstanceCustomIndexKHR => 2,
+                        Op::RayQueryGetIntersectionInstanceIdKHR => 3,
+                        Op::RayQueryGetIntersectionInstanceShaderBindingTableRecordOffsetKHR => 4,
+                        Op::RayQueryGetIntersectionGeometryIndexKHR => 5,
+                        Op::RayQueryGetIntersectionPrimitiveIndexKHR => 6,
+                        Op::RayQueryGetIntersectionBarycentricsKHR => 7,
+                        Op::RayQueryGetIntersectionFrontFaceKHR => 8,
+                        Op::RayQueryGetIntersectionObjectToWorldKHR => 9,
+                        _ => 10,
+                    };
+                    let intersection = ctx.expressions.append(
+                        crate::Expression::RayQueryGetIntersection { query, committed },
+                        span,
+                    );
+                    let mut handle = ctx.expressions.append(
+                        crate::Expression::AccessIndex {
+                            base: intersection,
+                            index,
+                        },
+                        span,
+                    );
+
+                    // Committed intersection types match Naga's intersection
+                    // kinds, but SPIR-V numbers candidate types from zero:
+                    // triangles are 0, and bounding boxes are 1.
+                    if !committed && inst.op == Op::RayQueryGetIntersectionTypeKHR {
+                        block.extend(emitter.finish(ctx.expressions));
+                        let [bounding_box, one, zero] =
+                            [crate::back::RayIntersectionType::BoundingBox as u32, 1, 0].map(
+                                |value| {
+                                    ctx.expressions.append(
+                                        crate::Expression::Literal(crate::Literal::U32(value)),
+                                        span,
+                                    )
+                                },
+                            );
+                        emitter.start(ctx.expressions);
+                        let is_bounding_box = ctx.expressions.append(
+                            crate::Expression::Binary {
+                                op: crate::BinaryOperator::Equal,
+                                left: handle,
+                                right: bounding_box,
+                            },
+                            span,
+                        );
+                        handle = ctx.expressions.append(
+                            crate::Expression::Select {
+                                condition: is_bounding_box,
+                                accept: one,
+                                reject: zero,
+                            },
+                            span,
+                        );
+                    }
+
+                    // The integer members are unsigned in Naga, but SPIR-V
+                    // producers commonly declare them as signed.
+                    let result_ty = self.lookup_type.lookup(result_type_id)?;
+                    if let crate::TypeInner::Scalar(crate::Scalar {
+                        kind: crate::ScalarKind::Sint,
+                        ..
+                    }) = ctx.type_arena[result_ty.handle].inner
+                    {
+                        handle = ctx.expressions.append(
+                            crate::Expression::As {
+                                expr: handle,
+                                kind: crate::ScalarKind::Sint,
+                                convert: None,
+                            },
+                            span,
+                        );
+                    }
+
+                    self.lookup_expression.insert(
+                        result_id,
+                        LookupExpression {
+                            handle,
+                            type_id: result_type_id,
+                            block_id,
+                        },
+                    );
+                }
                 Op::AtomicIIncrement | Op::AtomicIDecrement => {
                     inst.expect(6)?;
                     let start = self.data_offset;
@@ -4221,6 +4563,80 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                     self.upgrade_atomics
                         .insert(ctx.get_contained_global_variable(p_exp_h)?);
                 }
+                Op::AtomicCompareExchange => {
+                    inst.expect(9)?;
+                    let start = self.data_offset;
+                    let result_type_id = self.next()?;
+                    let result_id = self.next()?;
+                    let pointer_id = self.next()?;
+                    let _memory_scope_id = self.next()?;
+                    let _equal_memory_semantics_id = self.next()?;
+                    let _unequal_memory_semantics_id = self.next()?;
+                    let value_id = self.next()?;
+                    let comparator_id = self.next()?;
+                    let span = self.span_from_with_op(start);
+
+                    let (p_exp_h, p_base_ty_h) = self.get_exp_and_base_ty_handles(
+                        pointer_id,
+                        ctx,
+                        &mut emitter,
+                        &mut block,
+                        body_idx,
+                    )?;
+
+                    log::trace!("\t\t\tlooking up value expr {:?}", value_id);
+                    let v_lexp = self.lookup_expression.lookup(value_id)?;
+                    let v_lexp_handle = get_expr_handle!(value_id, v_lexp);
+
+                    log::trace!("\t\t\tlooking up comparator expr {:?}", comparator_id);
+                    let c_lexp = self.lookup_expression.lookup(comparator_id)?;
+                    let c_lexp_handle = get_expr_handle!(comparator_id, c_lexp);
+
+                    block.extend(emitter.finish(ctx.expressions));
+                    // Create an expression for our result. Its type is replaced
+                    // by the `__atomic_compare_exchange_result` struct once the
+                    // function has been parsed, as we can't add types here.
+                    let r_lexp_handle = ctx.expressions.append(
+                        crate::Expression::AtomicResult {
+                            ty: p_base_ty_h,
+                            comparison: true,
+                        },
+                        span,
+                    );
+                    emitter.start(ctx.expressions);
+
+                    // Create a statement for the op itself
+                    let stmt = crate::Statement::Atomic {
+                        pointer: p_exp_h,
+                        fun: crate::AtomicFunction::Exchange {
+                            compare: Some(c_lexp_handle),
+                        },
+                        value: v_lexp_handle,
+                        result: Some(r_lexp_handle),
+                    };
+                    block.push(stmt, span);
+
+                    // SPIR-V's result is only the original value
+                    let old_value_handle = ctx.expressions.append(
+                        crate::Expression::AccessIndex {
+                            base: r_lexp_handle,
+                            index: 0,
+                        },
+                        span,
+                    );
+                    self.lookup_expression.insert(
+                        result_id,
+                        LookupExpression {
+                            handle: old_value_handle,
+                            type_id: result_type_id,
+                            block_id,
+                        },
+                    );
+
+                    // Store any associated global variables so we can upgrade their types later
+                    self.upgrade_atomics
+                        .insert(ctx.get_contained_global_variable(p_exp_h)?);
+                }
                 Op::AtomicExchange
                 | Op::AtomicIAdd
                 | Op::AtomicISub
@@ -4373,6 +4789,7 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                 | S::Continue
                 | S::Return { .. }
                 | S::Kill
+                | S::Demote
                 | S::Barrier(_)
                 | S::Store { .. }
                 | S::ImageStore { .. }
@@ -4511,6 +4928,10 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
                 Op::TypeImage => self.parse_type_image(inst, &mut module),
                 Op::TypeSampledImage => self.parse_type_sampled_image(inst),
                 Op::TypeSampler => self.parse_type_sampler(inst, &mut module),
+                Op::TypeAccelerationStructureKHR => {
+                    self.parse_type_acceleration_structure(inst, &mut module)
+                }
+                Op::TypeRayQueryKHR => self.parse_type_ray_query(inst, &mut module),
                 Op::Constant | Op::SpecConstant => self.parse_constant(inst, &mut module),
                 Op::ConstantComposite | Op::SpecConstantComposite => {
                     self.parse_composite_constant(inst, &mut module)
@@ -5434,6 +5855,66 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
         Ok(())
     }
 
+    fn parse_type_acceleration_structure(
+        &mut self,
+        inst: Instruction,
+        module: &mut crate::Module,
+    ) -> Result<(), Error> {
+        let start = self.data_offset;
+        self.switch(ModuleState::Type, inst.op)?;
+        inst.expect(2)?;
+        let id = self.next()?;
+        let decor = self.future_decor.remove(&id).unwrap_or_default();
+        let handle = module.types.insert(
+            crate::Type {
+                name: decor.name,
+                inner: crate::TypeInner::AccelerationStructure,
+            },
+            self.span_from_with_op(start),
+        );
+        self.lookup_type.insert(
+            id,
+            LookupType {
+                handle,
+                base_id: None,
+            },
+        );
+        Ok(())
+    }
+
+    fn parse_type_ray_query(
+        &mut self,
+        inst: Instruction,
+        module: &mut crate::Module,
+    ) -> Result<(), Error> {
+        let start = self.data_offset;
+        self.switch(ModuleState::Type, inst.op)?;
+        inst.expect(2)?;
+        let id = self.next()?;
+        let decor = self.future_decor.remove(&id).unwrap_or_default();
+        let handle = module.types.insert(
+            crate::Type {
+                name: decor.name,
+                inner: crate::TypeInner::RayQuery,
+            },
+            self.span_from_with_op(start),
+        );
+        self.lookup_type.insert(
+            id,
+            LookupType {
+                handle,
+                base_id: None,
+            },
+        );
+
+        // The `OpRayQuery*` instructions are lowered to statements and
+        // expressions operating on these types, and we can't add types to the
+        // module while parsing function bodies.
+        module.generate_ray_desc_type();
+        module.generate_ray_intersection_type();
+        Ok(())
+    }
+
     fn parse_constant(
         &mut self,
         inst: Instruction,
@@ -5484,6 +5965,7 @@ impl<I: Iterator<Item = u32>> Frontend<I> {
             }) => {
                 let low = self.next()?;
                 match width {
+                    2 => crate::Literal::F16(half::f16::from_bits(low as u16)),
                     4 => crate::Literal::F32(f32::from_bits(low)),
                     8 => {
                         inst.expect(5)?;
@@ -5991,4 +6473,11 @@ mod test_atomic {
     fn atomic_i_add_and_sub() {
         atomic_test(include_bytes!("../../../tests/in/spv/atomic_i_add_sub.spv"));
     }
+
+    #[test]
+    fn atomic_compare_exchange() {
+        atomic_test(include_bytes!(
+            "../../../tests/in/spv/atomic_compare_exchange.spv"
+        ));
+    }
 }
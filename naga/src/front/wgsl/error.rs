@@ -1,10 +1,10 @@
+use crate::diagnostic_filter::ConflictingDiagnosticRuleError;
 use crate::front::wgsl::parse::directive::enable_extension::{
     EnableExtension, UnimplementedEnableExtension,
 };
 use crate::front::wgsl::parse::directive::language_extension::{
     LanguageExtension, UnimplementedLanguageExtension,
 };
-use crate::front::wgsl::parse::directive::{DirectiveKind, UnimplementedDirectiveKind};
 use crate::front::wgsl::parse::lexer::Token;
 use crate::front::wgsl::Scalar;
 use crate::proc::{Alignment, ConstantEvaluatorError, ResolveError};
@@ -144,8 +144,6 @@ pub enum NumberError {
     Invalid,
     #[error("numeric literal not representable by target type")]
     NotRepresentable,
-    #[error("unimplemented f16 type")]
-    UnimplementedF16,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -179,12 +177,15 @@ pub(crate) enum Error<'a> {
     /// A break if appeared outside of a continuing block
     InvalidBreakIf(Span),
     InvalidGatherComponent(Span),
+    InvalidClusterSize(Span),
     InvalidConstructorComponentType(Span, i32),
     InvalidIdentifierUnderscore(Span),
     ReservedIdentifierPrefix(Span),
     UnknownAddressSpace(Span),
     RepeatedAttribute(Span),
     UnknownAttribute(Span),
+    /// The non-standard `@second_blend_source` attribute, replaced by `@blend_src`.
+    SecondBlendSource(Span),
     UnknownBuiltin(Span),
     UnknownAccess(Span),
     UnknownIdent(Span, &'a str),
@@ -210,7 +211,6 @@ pub(crate) enum Error<'a> {
     InvalidAtomicPointer(Span),
     InvalidAtomicOperandType(Span),
     InvalidRayQueryPointer(Span),
-    Pointer(&'static str, Span),
     NotPointer(Span),
     NotReference(&'static str, Span),
     InvalidAssignment {
@@ -276,10 +276,6 @@ pub(crate) enum Error<'a> {
     PipelineConstantIDValue(Span),
     NotBool(Span),
     ConstAssertFailed(Span),
-    DirectiveNotYetImplemented {
-        kind: UnimplementedDirectiveKind,
-        span: Span,
-    },
     DirectiveAfterFirstGlobalDecl {
         directive_span: Span,
     },
@@ -295,6 +291,17 @@ pub(crate) enum Error<'a> {
         kind: UnimplementedLanguageExtension,
         span: Span,
     },
+    DiagnosticInvalidSeverity {
+        severity_control_name_span: Span,
+    },
+    DiagnosticDuplicateTriggeringRule(ConflictingDiagnosticRuleError),
+    /// Not an error per se; reported as a warning for unrecognized rule names.
+    UnknownDiagnosticRuleName(Span),
+    DiagnosticAttributeNotSupported {
+        /// What the attributes were written on, in plural form, like `"semicolons"`.
+        on_what_plural: &'static str,
+        spans: Vec<Span>,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -484,6 +491,14 @@ impl<'a> Error<'a> {
                 labels: vec![(bad_span, "invalid component".into())],
                 notes: vec![],
             },
+            Error::InvalidClusterSize(bad_span) => ParseError {
+                message: format!(
+                    "cluster size '{}' must be a power of two",
+                    &source[bad_span]
+                ),
+                labels: vec![(bad_span, "invalid cluster size".into())],
+                notes: vec![],
+            },
             Error::InvalidConstructorComponentType(bad_span, component) => ParseError {
                 message: format!("invalid type for constructor component at index [{component}]"),
                 labels: vec![(bad_span, "invalid component type".into())],
@@ -520,6 +535,16 @@ impl<'a> Error<'a> {
                 labels: vec![(bad_span, "unknown attribute".into())],
                 notes: vec![],
             },
+            Error::SecondBlendSource(bad_span) => ParseError {
+                message: "the `second_blend_source` attribute has been removed".to_string(),
+                labels: vec![(bad_span, "use `@blend_src(1)` instead".into())],
+                notes: vec![concat!(
+                    "dual-source blending now requires `enable dual_source_blending;`, ",
+                    "with the outputs marked `@location(0) @blend_src(0)` and ",
+                    "`@location(0) @blend_src(1)`"
+                )
+                .into()],
+            },
             Error::UnknownBuiltin(bad_span) => ParseError {
                 message: format!("unknown builtin: '{}'", &source[bad_span]),
                 labels: vec![(bad_span, "unknown builtin".into())],
@@ -675,11 +700,6 @@ impl<'a> Error<'a> {
                     notes,
                 }
             }
-            Error::Pointer(what, span) => ParseError {
-                message: format!("{what} must not be a pointer"),
-                labels: vec![(span, "expression is a pointer".into())],
-                notes: vec![],
-            },
             Error::ReservedKeyword(name_span) => ParseError {
                 message: format!("name `{}` is a reserved keyword", &source[name_span]),
                 labels: vec![(
@@ -914,24 +934,6 @@ impl<'a> Error<'a> {
                 labels: vec![(span, "evaluates to false".into())],
                 notes: vec![],
             },
-            Error::DirectiveNotYetImplemented { kind, span } => ParseError {
-                message: format!(
-                    "the `{}` directive is not yet implemented",
-                    DirectiveKind::Unimplemented(kind).to_ident()
-                ),
-                labels: vec![(
-                    span,
-                    "this global directive is standard, but not yet implemented".into(),
-                )],
-                notes: vec![format!(
-                    concat!(
-                        "Let Naga maintainers know that you ran into this at ",
-                        "<https://github.com/gfx-rs/wgpu/issues/{}>, ",
-                        "so they can prioritize it!"
-                    ),
-                    kind.tracking_issue_num()
-                )],
-            },
             Error::DirectiveAfterFirstGlobalDecl { directive_span } => ParseError {
                 message: "expected global declaration, but found a global directive".into(),
                 labels: vec![(
@@ -990,7 +992,10 @@ impl<'a> Error<'a> {
                         kind.tracking_issue_num()
                     )]
                 } else {
-                    vec![]
+                    vec![format!(
+                        "You can enable this extension by adding `enable {};` at the top of the shader.",
+                        kind.to_ident()
+                    )]
                 },
             },
             Error::LanguageExtensionNotYetImplemented { kind, span } => ParseError {
@@ -1008,6 +1013,61 @@ impl<'a> Error<'a> {
                     kind.tracking_issue_num()
                 )],
             },
+            Error::DiagnosticInvalidSeverity {
+                severity_control_name_span,
+            } => ParseError {
+                message: "invalid `diagnostic(…)` severity".into(),
+                labels: vec![(
+                    severity_control_name_span,
+                    "not a valid severity level".into(),
+                )],
+                notes: vec![concat!(
+                    "See available severities at ",
+                    "<https://www.w3.org/TR/WGSL/#diagnostic-severity>."
+                )
+                .into()],
+            },
+            Error::DiagnosticDuplicateTriggeringRule(ConflictingDiagnosticRuleError {
+                triggering_rule_spans,
+            }) => {
+                let [first_span, second_span] = triggering_rule_spans;
+                ParseError {
+                    message: "found conflicting `diagnostic(…)` rule(s)".into(),
+                    labels: vec![
+                        (first_span, "first rule".into()),
+                        (second_span, "second rule".into()),
+                    ],
+                    notes: vec![concat!(
+                        "Multiple `diagnostic(…)` rules with the same rule name ",
+                        "conflict unless they are directives and the severity is the same.",
+                    )
+                    .into()],
+                }
+            }
+            Error::UnknownDiagnosticRuleName(span) => ParseError {
+                message: format!("unknown `diagnostic(…)` rule name `{}`", &source[span]),
+                labels: vec![(span, "not a known rule name".into())],
+                notes: vec![concat!(
+                    "Unknown rule names are ignored. See the list of standard rules at ",
+                    "<https://www.w3.org/TR/WGSL/#filterable-triggering-rules>."
+                )
+                .into()],
+            },
+            Error::DiagnosticAttributeNotSupported {
+                on_what_plural,
+                ref spans,
+            } => ParseError {
+                message: format!(
+                    "`@diagnostic(…)` attribute(s) on {on_what_plural} are not supported",
+                ),
+                labels: spans.iter().map(|&span| (span, "".into())).collect(),
+                notes: vec![concat!(
+                    "`@diagnostic(…)` attributes are only permitted on `fn`s, ",
+                    "compound statements, `if`, `switch`, `loop`, `for` and `while` ",
+                    "statements, and `switch` and `loop` bodies."
+                )
+                .into()],
+            },
         }
     }
 }
@@ -182,6 +182,12 @@ pub struct StatementContext<'source, 'temp, 'out> {
     /// the code for lowering `let` bindings.
     local_expression_kind_tracker: &'temp mut crate::proc::ExpressionKindTracker,
     global_expression_kind_tracker: &'temp mut crate::proc::ExpressionKindTracker,
+
+    /// The innermost diagnostic filter in effect for the statement being
+    /// lowered, in [`Module::diagnostic_filters`].
+    ///
+    /// [`Module::diagnostic_filters`]: crate::Module::diagnostic_filters
+    diagnostic_filter_leaf: Option<Handle<crate::diagnostic_filter::DiagnosticFilterNode>>,
 }
 
 impl<'a, 'temp> StatementContext<'a, 'temp, '_> {
@@ -551,6 +557,41 @@ impl<'source, 'temp, 'out> ExpressionContext<'source, 'temp, 'out> {
         }
     }
 
+    fn cluster_size(
+        &mut self,
+        expr: Handle<crate::Expression>,
+        size_span: Span,
+        op_span: Span,
+    ) -> Result<u32, Error<'source>> {
+        match self.expr_type {
+            ExpressionContextType::Runtime(ref rctx) => {
+                if !rctx.local_expression_kind_tracker.is_const(expr) {
+                    return Err(Error::ExpectedConstExprConcreteIntegerScalar(size_span));
+                }
+
+                let size = self
+                    .module
+                    .to_ctx()
+                    .eval_expr_to_u32_from(expr, &rctx.function.expressions)
+                    .map_err(|err| match err {
+                        crate::proc::U32EvalError::NonConst => {
+                            Error::ExpectedConstExprConcreteIntegerScalar(size_span)
+                        }
+                        crate::proc::U32EvalError::Negative => {
+                            Error::ExpectedNonNegative(size_span)
+                        }
+                    })?;
+                if !size.is_power_of_two() {
+                    return Err(Error::InvalidClusterSize(size_span));
+                }
+                Ok(size)
+            }
+            ExpressionContextType::Constant(_) | ExpressionContextType::Override => {
+                Err(Error::UnexpectedOperationInConstContext(op_span))
+            }
+        }
+    }
+
     /// Determine the type of `handle`, and add it to the module's arena.
     ///
     /// If you just need a `TypeInner` for `handle`'s type, use the
@@ -980,6 +1021,8 @@ enum SubgroupGather {
     ShuffleDown,
     ShuffleUp,
     ShuffleXor,
+    QuadBroadcast,
+    QuadSwap(crate::Direction),
 }
 
 impl SubgroupGather {
@@ -991,6 +1034,10 @@ impl SubgroupGather {
             "subgroupShuffleDown" => Self::ShuffleDown,
             "subgroupShuffleUp" => Self::ShuffleUp,
             "subgroupShuffleXor" => Self::ShuffleXor,
+            "quadBroadcast" => Self::QuadBroadcast,
+            "quadSwapX" => Self::QuadSwap(crate::Direction::X),
+            "quadSwapY" => Self::QuadSwap(crate::Direction::Y),
+            "quadSwapDiagonal" => Self::QuadSwap(crate::Direction::Diagonal),
             _ => return None,
         })
     }
@@ -1013,7 +1060,11 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
         &mut self,
         tu: &'temp ast::TranslationUnit<'source>,
     ) -> Result<crate::Module, Error<'source>> {
-        let mut module = crate::Module::default();
+        let mut module = crate::Module {
+            diagnostic_filters: tu.diagnostic_filters.clone(),
+            diagnostic_filter_leaf: tu.diagnostic_filter_leaf,
+            ..Default::default()
+        };
 
         let mut ctx = GlobalContext {
             ast_expressions: &tu.expressions,
@@ -1280,6 +1331,8 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
             expressions,
             named_expressions: crate::NamedExpressions::default(),
             body: crate::Block::default(),
+            diagnostic_filter_leaf: f.diagnostic_filter_leaf,
+            diagnostic_filter_scopes: Vec::new(),
         };
 
         let mut typifier = Typifier::default();
@@ -1295,6 +1348,7 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
             module: ctx.module,
             local_expression_kind_tracker: &mut local_expression_kind_tracker,
             global_expression_kind_tracker: ctx.global_expression_kind_tracker,
+            diagnostic_filter_leaf: f.diagnostic_filter_leaf,
         };
         let mut body = self.block(&f.body, false, &mut stmt_ctx)?;
         ensure_block_returns(&mut body);
@@ -1360,6 +1414,31 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
                 let block = self.block(block, is_inside_loop, ctx)?;
                 crate::Statement::Block(block)
             }
+            ast::StatementKind::Diagnostic {
+                ref filters,
+                ref statement,
+            } => {
+                let outer_leaf = ctx.diagnostic_filter_leaf;
+                let leaf = filters
+                    .clone()
+                    .into_nodes(&mut ctx.module.diagnostic_filters, outer_leaf);
+                let start = ctx.function.expressions.len();
+
+                ctx.diagnostic_filter_leaf = leaf;
+                let result = self.statement(statement, block, is_inside_loop, ctx);
+                ctx.diagnostic_filter_leaf = outer_leaf;
+                result?;
+
+                if let Some(leaf) = leaf {
+                    let expressions = ctx.function.expressions.range_from(start);
+                    if expressions.first_and_last().is_some() {
+                        ctx.function.diagnostic_filter_scopes.push(
+                            crate::diagnostic_filter::DiagnosticFilterScope { expressions, leaf },
+                        );
+                    }
+                }
+                return Ok(());
+            }
             ast::StatementKind::LocalDecl(ref decl) => match *decl {
                 ast::LocalDecl::Let(ref l) => {
                     let mut emitter = Emitter::default();
@@ -1628,7 +1707,7 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
 
                 crate::Statement::Return { value }
             }
-            ast::StatementKind::Kill => crate::Statement::Kill,
+            ast::StatementKind::Kill => crate::Statement::Demote,
             ast::StatementKind::Call {
                 ref function,
                 ref arguments,
@@ -1831,6 +1910,7 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
         let expr: Typed<crate::Expression> = match *expr {
             ast::Expression::Literal(literal) => {
                 let literal = match literal {
+                    ast::Literal::Number(Number::F16(f)) => crate::Literal::F16(f),
                     ast::Literal::Number(Number::F32(f)) => crate::Literal::F32(f),
                     ast::Literal::Number(Number::I32(i)) => crate::Literal::I32(i),
                     ast::Literal::Number(Number::U32(u)) => crate::Literal::U32(u),
@@ -1926,25 +2006,16 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
                 return Ok(Typed::Plain(handle));
             }
             ast::Expression::Index { base, index } => {
-                let lowered_base = self.expression_for_reference(base, ctx)?;
+                let lowered_base = self.pointer_composite_access(base, ctx)?;
                 let index = self.expression(index, ctx)?;
 
-                if let Typed::Plain(handle) = lowered_base {
-                    if resolve_inner!(ctx, handle).pointer_space().is_some() {
-                        return Err(Error::Pointer(
-                            "the value indexed by a `[]` subscripting expression",
-                            ctx.ast_expressions.get_span(base),
-                        ));
-                    }
-                }
-
                 lowered_base.map(|base| match ctx.const_access(index) {
                     Some(index) => crate::Expression::AccessIndex { base, index },
                     None => crate::Expression::Access { base, index },
                 })
             }
             ast::Expression::Member { base, ref field } => {
-                let lowered_base = self.expression_for_reference(base, ctx)?;
+                let lowered_base = self.pointer_composite_access(base, ctx)?;
 
                 let temp_inner;
                 let composite_type: &crate::TypeInner = match lowered_base {
@@ -1972,18 +2043,7 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
                         }
                     }
 
-                    Typed::Plain(handle) => {
-                        let inner = resolve_inner!(ctx, handle);
-                        if let crate::TypeInner::Pointer { .. }
-                        | crate::TypeInner::ValuePointer { .. } = *inner
-                        {
-                            return Err(Error::Pointer(
-                                "the value accessed by a `.member` expression",
-                                ctx.ast_expressions.get_span(base),
-                            ));
-                        }
-                        inner
-                    }
+                    Typed::Plain(handle) => resolve_inner!(ctx, handle),
                 };
 
                 let access = match *composite_type {
@@ -2045,6 +2105,25 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
         expr.try_map(|handle| ctx.append_expression(handle, span))
     }
 
+    /// Lower `expr`, the base of an indexing or member access expression.
+    ///
+    /// With the WGSL `pointer_composite_access` language extension, `p[i]`
+    /// and `p.field` are shorthand for `(*p)[i]` and `(*p).field` when `p`
+    /// is a pointer, so we treat pointers as references here.
+    fn pointer_composite_access(
+        &mut self,
+        expr: Handle<ast::Expression<'source>>,
+        ctx: &mut ExpressionContext<'source, '_, '_>,
+    ) -> Result<Typed<Handle<crate::Expression>>, Error<'source>> {
+        let lowered = self.expression_for_reference(expr, ctx)?;
+        Ok(match lowered {
+            Typed::Plain(handle) if resolve_inner!(ctx, handle).pointer_space().is_some() => {
+                Typed::Reference(handle)
+            }
+            other => other,
+        })
+    }
+
     fn binary(
         &mut self,
         op: crate::BinaryOperator,
@@ -2365,6 +2444,14 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
                                 .push(crate::Statement::Barrier(crate::Barrier::WORK_GROUP), span);
                             return Ok(None);
                         }
+                        "textureBarrier" => {
+                            ctx.prepare_args(arguments, 0, span).finish()?;
+
+                            let rctx = ctx.runtime_expression_ctx(span)?;
+                            rctx.block
+                                .push(crate::Statement::Barrier(crate::Barrier::TEXTURE), span);
+                            return Ok(None);
+                        }
                         "subgroupBarrier" => {
                             ctx.prepare_args(arguments, 0, span).finish()?;
 
@@ -2569,8 +2656,11 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
                                 committed: true,
                             }
                         }
-                        "RayDesc" => {
-                            let ty = ctx.module.generate_ray_desc_type();
+                        "RayDesc" | "RayIntersection" => {
+                            let ty = match function.name {
+                                "RayDesc" => ctx.module.generate_ray_desc_type(),
+                                _ => ctx.module.generate_ray_intersection_type(),
+                            };
                             let handle = self.construct(
                                 span,
                                 &ast::ConstructorType::Type(ty),
@@ -2815,6 +2905,23 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
         let mut args = ctx.prepare_args(arguments, 1, span);
 
         let argument = self.expression(args.next()?, ctx)?;
+
+        // Reductions other than `subgroupAll` and `subgroupAny` take an
+        // optional constant cluster size.
+        let collective_op = match (collective_op, op) {
+            (
+                crate::CollectiveOperation::Reduce,
+                crate::SubgroupOperation::All | crate::SubgroupOperation::Any,
+            ) => collective_op,
+            (crate::CollectiveOperation::Reduce, _) if arguments.len() == 2 => {
+                let size = args.next()?;
+                let size_span = ctx.ast_expressions.get_span(size);
+                let size = self.expression(size, ctx)?;
+                let size = ctx.cluster_size(size, size_span, span)?;
+                crate::CollectiveOperation::ClusteredReduce(size)
+            }
+            _ => collective_op,
+        };
         args.finish()?;
 
         let ty = ctx.register_type(argument)?;
@@ -2846,17 +2953,20 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
         let argument = self.expression(args.next()?, ctx)?;
 
         use SubgroupGather as Sg;
-        let mode = if let Sg::BroadcastFirst = mode {
-            crate::GatherMode::BroadcastFirst
-        } else {
-            let index = self.expression(args.next()?, ctx)?;
-            match mode {
-                Sg::Broadcast => crate::GatherMode::Broadcast(index),
-                Sg::Shuffle => crate::GatherMode::Shuffle(index),
-                Sg::ShuffleDown => crate::GatherMode::ShuffleDown(index),
-                Sg::ShuffleUp => crate::GatherMode::ShuffleUp(index),
-                Sg::ShuffleXor => crate::GatherMode::ShuffleXor(index),
-                Sg::BroadcastFirst => unreachable!(),
+        let mode = match mode {
+            Sg::BroadcastFirst => crate::GatherMode::BroadcastFirst,
+            Sg::QuadSwap(direction) => crate::GatherMode::QuadSwap(direction),
+            _ => {
+                let index = self.expression(args.next()?, ctx)?;
+                match mode {
+                    Sg::Broadcast => crate::GatherMode::Broadcast(index),
+                    Sg::Shuffle => crate::GatherMode::Shuffle(index),
+                    Sg::ShuffleDown => crate::GatherMode::ShuffleDown(index),
+                    Sg::ShuffleUp => crate::GatherMode::ShuffleUp(index),
+                    Sg::ShuffleXor => crate::GatherMode::ShuffleXor(index),
+                    Sg::QuadBroadcast => crate::GatherMode::QuadBroadcast(index),
+                    Sg::BroadcastFirst | Sg::QuadSwap(_) => unreachable!(),
+                }
             }
         };
 
@@ -3114,13 +3224,18 @@ impl<'source, 'temp> Lowerer<'source, 'temp> {
             Some(ast::Binding::BuiltIn(b)) => Some(crate::Binding::BuiltIn(b)),
             Some(ast::Binding::Location {
                 location,
-                second_blend_source,
+                blend_src,
                 interpolation,
                 sampling,
             }) => {
+                let blend_src = if let Some(blend_src) = blend_src {
+                    Some(self.const_u32(blend_src, &mut ctx.as_const())?.0)
+                } else {
+                    None
+                };
                 let mut binding = crate::Binding::Location {
                     location: self.const_u32(location, &mut ctx.as_const())?.0,
-                    second_blend_source,
+                    blend_src,
                     interpolation,
                     sampling,
                 };
@@ -1,3 +1,4 @@
+use crate::diagnostic_filter::{DiagnosticFilterMap, DiagnosticFilterNode};
 use crate::front::wgsl::parse::directive::enable_extension::EnableExtensions;
 use crate::front::wgsl::parse::number::Number;
 use crate::front::wgsl::Scalar;
@@ -26,6 +27,17 @@ pub struct TranslationUnit<'a> {
     /// These are referred to by `Handle<ast::Type<'a>>` values.
     /// User-defined types are referred to by name until lowering.
     pub types: Arena<Type<'a>>,
+
+    /// Arena for all diagnostic filter rules parsed in this module, including those in functions.
+    ///
+    /// See [`DiagnosticFilterNode`] for details on how the tree is represented and used in
+    /// validation.
+    pub diagnostic_filters: Arena<DiagnosticFilterNode>,
+    /// The leaf of all `diagnostic(…)` directives in this module.
+    ///
+    /// See [`DiagnosticFilterNode`] for details on how the tree is represented and used in
+    /// validation.
+    pub diagnostic_filter_leaf: Option<Handle<DiagnosticFilterNode>>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -121,6 +133,7 @@ pub struct Function<'a> {
     pub arguments: Vec<FunctionArgument<'a>>,
     pub result: Option<FunctionResult<'a>>,
     pub body: Block<'a>,
+    pub diagnostic_filter_leaf: Option<Handle<DiagnosticFilterNode>>,
 }
 
 #[derive(Debug)]
@@ -128,7 +141,7 @@ pub enum Binding<'a> {
     BuiltIn(crate::BuiltIn),
     Location {
         location: Handle<Expression<'a>>,
-        second_blend_source: bool,
+        blend_src: Option<Handle<Expression<'a>>>,
         interpolation: Option<crate::Interpolation>,
         sampling: Option<crate::Sampling>,
     },
@@ -288,6 +301,15 @@ pub enum StatementKind<'a> {
     Decrement(Handle<Expression<'a>>),
     Phony(Handle<Expression<'a>>),
     ConstAssert(Handle<Expression<'a>>),
+    /// A statement with `@diagnostic(…)` attributes.
+    ///
+    /// The `filters` apply to everything `statement` lowers to. Their
+    /// [`DiagnosticFilterNode`]s are created during lowering, since only then
+    /// do we know the enclosing scope's leaf to use as their parent.
+    Diagnostic {
+        filters: DiagnosticFilterMap,
+        statement: Box<Statement<'a>>,
+    },
 }
 
 #[derive(Debug)]
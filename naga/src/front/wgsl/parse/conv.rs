@@ -1,3 +1,4 @@
+use super::directive::enable_extension::{EnableExtensions, ImplementedEnableExtension};
 use super::Error;
 use crate::front::wgsl::Scalar;
 use crate::Span;
@@ -16,13 +17,21 @@ pub fn map_address_space(word: &str, span: Span) -> Result<crate::AddressSpace,
     }
 }
 
-pub fn map_built_in(word: &str, span: Span) -> Result<crate::BuiltIn, Error<'_>> {
+pub fn map_built_in<'a>(
+    enable_extensions: &EnableExtensions,
+    word: &'a str,
+    span: Span,
+) -> Result<crate::BuiltIn, Error<'a>> {
     Ok(match word {
         "position" => crate::BuiltIn::Position { invariant: false },
         // vertex
         "vertex_index" => crate::BuiltIn::VertexIndex,
         "instance_index" => crate::BuiltIn::InstanceIndex,
         "view_index" => crate::BuiltIn::ViewIndex,
+        "clip_distances" => {
+            enable_extensions.require(ImplementedEnableExtension::ClipDistances, span)?;
+            crate::BuiltIn::ClipDistance
+        }
         // fragment
         "front_facing" => crate::BuiltIn::FrontFacing,
         "frag_depth" => crate::BuiltIn::FragDepth,
@@ -111,10 +120,20 @@ pub fn map_storage_format(word: &str, span: Span) -> Result<crate::StorageFormat
     })
 }
 
-pub fn get_scalar_type(word: &str) -> Option<Scalar> {
+pub fn get_scalar_type(
+    enable_extensions: &EnableExtensions,
+    span: Span,
+    word: &str,
+) -> Result<Option<Scalar>, Error<'static>> {
     use crate::ScalarKind as Sk;
-    match word {
-        // "f16" => Some(Scalar { kind: Sk::Float, width: 2 }),
+    let scalar = match word {
+        "f16" => {
+            enable_extensions.require(ImplementedEnableExtension::F16, span)?;
+            Some(Scalar {
+                kind: Sk::Float,
+                width: 2,
+            })
+        }
         "f32" => Some(Scalar {
             kind: Sk::Float,
             width: 4,
@@ -144,7 +163,42 @@ pub fn get_scalar_type(word: &str) -> Option<Scalar> {
             width: crate::BOOL_WIDTH,
         }),
         _ => None,
-    }
+    };
+    Ok(scalar)
+}
+
+/// The shape of a predeclared `f16` vector or matrix alias, like `vec3h`.
+pub enum F16Alias {
+    /// A vector of the given size.
+    Vector(crate::VectorSize),
+    /// A matrix with the given numbers of columns and rows.
+    Matrix(crate::VectorSize, crate::VectorSize),
+}
+
+pub fn get_f16_alias(
+    enable_extensions: &EnableExtensions,
+    span: Span,
+    word: &str,
+) -> Result<Option<F16Alias>, Error<'static>> {
+    use crate::VectorSize as Vs;
+    use F16Alias::{Matrix, Vector};
+    let alias = match word {
+        "vec2h" => Vector(Vs::Bi),
+        "vec3h" => Vector(Vs::Tri),
+        "vec4h" => Vector(Vs::Quad),
+        "mat2x2h" => Matrix(Vs::Bi, Vs::Bi),
+        "mat2x3h" => Matrix(Vs::Bi, Vs::Tri),
+        "mat2x4h" => Matrix(Vs::Bi, Vs::Quad),
+        "mat3x2h" => Matrix(Vs::Tri, Vs::Bi),
+        "mat3x3h" => Matrix(Vs::Tri, Vs::Tri),
+        "mat3x4h" => Matrix(Vs::Tri, Vs::Quad),
+        "mat4x2h" => Matrix(Vs::Quad, Vs::Bi),
+        "mat4x3h" => Matrix(Vs::Quad, Vs::Tri),
+        "mat4x4h" => Matrix(Vs::Quad, Vs::Quad),
+        _ => return Ok(None),
+    };
+    enable_extensions.require(ImplementedEnableExtension::F16, span)?;
+    Ok(Some(alias))
 }
 
 pub fn map_derivative(word: &str) -> Option<(crate::DerivativeAxis, crate::DerivativeControl)> {
@@ -213,6 +267,8 @@ pub fn map_standard_fun(word: &str) -> Option<crate::MathFunction> {
         "pow" => Mf::Pow,
         // geometry
         "dot" => Mf::Dot,
+        "dot4I8Packed" => Mf::Dot4I8Packed,
+        "dot4U8Packed" => Mf::Dot4U8Packed,
         "cross" => Mf::Cross,
         "distance" => Mf::Distance,
         "length" => Mf::Length,
@@ -230,6 +286,7 @@ pub fn map_standard_fun(word: &str) -> Option<crate::MathFunction> {
         "inverseSqrt" => Mf::InverseSqrt,
         "transpose" => Mf::Transpose,
         "determinant" => Mf::Determinant,
+        "quantizeToF16" => Mf::QuantizeToF16,
         // bits
         "countTrailingZeros" => Mf::CountTrailingZeros,
         "countLeadingZeros" => Mf::CountLeadingZeros,
@@ -247,6 +304,8 @@ pub fn map_standard_fun(word: &str) -> Option<crate::MathFunction> {
         "pack2x16float" => Mf::Pack2x16float,
         "pack4xI8" => Mf::Pack4xI8,
         "pack4xU8" => Mf::Pack4xU8,
+        "pack4xI8Clamp" => Mf::Pack4xI8Clamp,
+        "pack4xU8Clamp" => Mf::Pack4xU8Clamp,
         // data unpacking
         "unpack4x8snorm" => Mf::Unpack4x8snorm,
         "unpack4x8unorm" => Mf::Unpack4x8unorm,
@@ -7,12 +7,16 @@ pub(crate) mod language_extension;
 
 /// A parsed sentinel word indicating the type of directive to be parsed next.
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(test, derive(strum::EnumIter))]
 pub(crate) enum DirectiveKind {
+    /// A global diagnostic filter, like `diagnostic(off, derivative_uniformity);`.
+    ///
+    /// See [`crate::diagnostic_filter`].
+    Diagnostic,
     /// An [`enable_extension`].
     Enable,
     /// A [`language_extension`].
     Requires,
-    Unimplemented(UnimplementedDirectiveKind),
 }
 
 impl DirectiveKind {
@@ -23,45 +27,12 @@ impl DirectiveKind {
     /// Convert from a sentinel word in WGSL into its associated [`DirectiveKind`], if possible.
     pub fn from_ident(s: &str) -> Option<Self> {
         Some(match s {
-            Self::DIAGNOSTIC => Self::Unimplemented(UnimplementedDirectiveKind::Diagnostic),
+            Self::DIAGNOSTIC => Self::Diagnostic,
             Self::ENABLE => Self::Enable,
             Self::REQUIRES => Self::Requires,
             _ => return None,
         })
     }
-
-    /// Maps this [`DirectiveKind`] into the sentinel word associated with it in WGSL.
-    pub const fn to_ident(self) -> &'static str {
-        match self {
-            Self::Enable => Self::ENABLE,
-            Self::Requires => Self::REQUIRES,
-            Self::Unimplemented(kind) => match kind {
-                UnimplementedDirectiveKind::Diagnostic => Self::DIAGNOSTIC,
-            },
-        }
-    }
-
-    #[cfg(test)]
-    fn iter() -> impl Iterator<Item = Self> {
-        use strum::IntoEnumIterator;
-
-        UnimplementedDirectiveKind::iter().map(Self::Unimplemented)
-    }
-}
-
-/// A [`DirectiveKind`] that is not yet implemented. See [`DirectiveKind::Unimplemented`].
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
-#[cfg_attr(test, derive(strum::EnumIter))]
-pub(crate) enum UnimplementedDirectiveKind {
-    Diagnostic,
-}
-
-impl UnimplementedDirectiveKind {
-    pub const fn tracking_issue_num(self) -> u16 {
-        match self {
-            Self::Diagnostic => 5320,
-        }
-    }
 }
 
 #[cfg(test)]
@@ -70,32 +41,7 @@ mod test {
 
     use crate::front::wgsl::assert_parse_err;
 
-    use super::{DirectiveKind, UnimplementedDirectiveKind};
-
-    #[test]
-    fn unimplemented_directives() {
-        for unsupported_shader in UnimplementedDirectiveKind::iter() {
-            let shader;
-            let expected_msg;
-            match unsupported_shader {
-                UnimplementedDirectiveKind::Diagnostic => {
-                    shader = "diagnostic(off,derivative_uniformity);";
-                    expected_msg = "\
-error: the `diagnostic` directive is not yet implemented
-  ┌─ wgsl:1:1
-  │
-1 │ diagnostic(off,derivative_uniformity);
-  │ ^^^^^^^^^^ this global directive is standard, but not yet implemented
-  │
-  = note: Let Naga maintainers know that you ran into this at <https://github.com/gfx-rs/wgpu/issues/5320>, so they can prioritize it!
-
-";
-                }
-            };
-
-            assert_parse_err(shader, expected_msg);
-        }
-    }
+    use super::DirectiveKind;
 
     #[test]
     fn directive_after_global_decl() {
@@ -103,7 +49,7 @@ error: the `diagnostic` directive is not yet implemented
             let directive;
             let expected_msg;
             match unsupported_shader {
-                DirectiveKind::Unimplemented(UnimplementedDirectiveKind::Diagnostic) => {
+                DirectiveKind::Diagnostic => {
                     directive = "diagnostic(off,derivative_uniformity)";
                     expected_msg = "\
 error: expected global declaration, but found a global directive
@@ -205,7 +205,6 @@ pub(in crate::front::wgsl) struct Lexer<'a> {
     pub(in crate::front::wgsl) source: &'a str,
     // The byte offset of the end of the last non-trivia token.
     last_end_offset: usize,
-    #[allow(dead_code)]
     pub(in crate::front::wgsl) enable_extensions: EnableExtensions,
 }
 
@@ -397,7 +396,8 @@ impl<'a> Lexer<'a> {
         self.expect_generic_paren('<')?;
         let pair = match self.next() {
             (Token::Word(word), span) => {
-                conv::get_scalar_type(word).ok_or(Error::UnknownScalarType(span))
+                conv::get_scalar_type(&self.enable_extensions, span, word)?
+                    .ok_or(Error::UnknownScalarType(span))
             }
             (_, span) => Err(Error::UnknownScalarType(span)),
         }?;
@@ -413,9 +413,11 @@ impl<'a> Lexer<'a> {
     ) -> Result<(Scalar, Span), Error<'a>> {
         self.expect_generic_paren('<')?;
         let pair = match self.next() {
-            (Token::Word(word), span) => conv::get_scalar_type(word)
-                .map(|scalar| (scalar, span))
-                .ok_or(Error::UnknownScalarType(span)),
+            (Token::Word(word), span) => {
+                conv::get_scalar_type(&self.enable_extensions, span, word)?
+                    .map(|scalar| (scalar, span))
+                    .ok_or(Error::UnknownScalarType(span))
+            }
             (_, span) => Err(Error::UnknownScalarType(span)),
         }?;
         self.expect_generic_paren('>')?;
@@ -477,6 +479,8 @@ fn sub_test(source: &str, expected_tokens: &[Token]) {
 
 #[test]
 fn test_numbers() {
+    use half::f16;
+
     // WGSL spec examples //
 
     // decimal integer
@@ -501,14 +505,14 @@ fn test_numbers() {
             Token::Number(Ok(Number::AbstractFloat(0.01))),
             Token::Number(Ok(Number::AbstractFloat(12.34))),
             Token::Number(Ok(Number::F32(0.))),
-            Token::Number(Err(NumberError::UnimplementedF16)),
+            Token::Number(Ok(Number::F16(f16::from_f32(0.)))),
             Token::Number(Ok(Number::AbstractFloat(0.001))),
             Token::Number(Ok(Number::AbstractFloat(43.75))),
             Token::Number(Ok(Number::F32(16.))),
             Token::Number(Ok(Number::AbstractFloat(0.1875))),
-            Token::Number(Err(NumberError::UnimplementedF16)),
+            Token::Number(Ok(Number::F16(f16::from_f32(12.)))),
             Token::Number(Ok(Number::AbstractFloat(0.12109375))),
-            Token::Number(Err(NumberError::UnimplementedF16)),
+            Token::Number(Ok(Number::F16(f16::from_f32(12.5)))),
         ],
     );
 
@@ -625,6 +629,42 @@ fn test_numbers() {
     );
 }
 
+#[test]
+fn half_floats() {
+    use half::f16;
+
+    sub_test(
+        "0.5h 1e-3h 0x1.8p1h 65504h 6e-8h 0x1p-24h",
+        &[
+            Token::Number(Ok(Number::F16(f16::from_f32(0.5)))),
+            Token::Number(Ok(Number::F16(f16::from_f32(0.001)))),
+            Token::Number(Ok(Number::F16(f16::from_f32(3.0)))),
+            Token::Number(Ok(Number::F16(f16::MAX))),
+            Token::Number(Ok(Number::F16(f16::from_bits(1)))),
+            Token::Number(Ok(Number::F16(f16::from_bits(1)))),
+        ],
+    );
+    sub_test(
+        "65520h 0x1p16h 0x1.001p0h",
+        &[
+            Token::Number(Err(NumberError::NotRepresentable)),
+            Token::Number(Err(NumberError::NotRepresentable)),
+            Token::Number(Err(NumberError::NotRepresentable)),
+        ],
+    );
+    // These round to an `f16` midpoint as `f64`, so they must be rounded from
+    // their exact value instead.
+    sub_test(
+        "1.00048828125000000000000001h 1.00048828124999999999999999h 1.00048828125h 65519.999999999999999h",
+        &[
+            Token::Number(Ok(Number::F16(f16::from_bits(0x3c01)))),
+            Token::Number(Ok(Number::F16(f16::ONE))),
+            Token::Number(Ok(Number::F16(f16::ONE))),
+            Token::Number(Ok(Number::F16(f16::MAX))),
+        ],
+    );
+}
+
 #[test]
 fn double_floats() {
     sub_test(
@@ -1,6 +1,10 @@
+use crate::diagnostic_filter::{
+    self, DiagnosticFilter, DiagnosticFilterMap, FilterableTriggeringRule,
+    ShouldConflictOnFullDuplicate,
+};
 use crate::front::wgsl::error::{Error, ExpectedToken};
 use crate::front::wgsl::parse::directive::enable_extension::{
-    EnableExtension, EnableExtensions, UnimplementedEnableExtension,
+    EnableExtension, EnableExtensions, ImplementedEnableExtension,
 };
 use crate::front::wgsl::parse::directive::language_extension::LanguageExtension;
 use crate::front::wgsl::parse::directive::DirectiveKind;
@@ -168,7 +172,7 @@ impl<T> ParsedAttribute<T> {
 #[derive(Default)]
 struct BindingParser<'a> {
     location: ParsedAttribute<Handle<ast::Expression<'a>>>,
-    second_blend_source: ParsedAttribute<bool>,
+    blend_src: ParsedAttribute<Handle<ast::Expression<'a>>>,
     built_in: ParsedAttribute<crate::BuiltIn>,
     interpolation: ParsedAttribute<crate::Interpolation>,
     sampling: ParsedAttribute<crate::Sampling>,
@@ -194,8 +198,10 @@ impl<'a> BindingParser<'a> {
             "builtin" => {
                 lexer.expect(Token::Paren('('))?;
                 let (raw, span) = lexer.next_ident_with_span()?;
-                self.built_in
-                    .set(conv::map_built_in(raw, span)?, name_span)?;
+                self.built_in.set(
+                    conv::map_built_in(&lexer.enable_extensions, raw, span)?,
+                    name_span,
+                )?;
                 lexer.expect(Token::Paren(')'))?;
             }
             "interpolate" => {
@@ -210,12 +216,19 @@ impl<'a> BindingParser<'a> {
                 }
                 lexer.expect(Token::Paren(')'))?;
             }
-            "second_blend_source" => {
-                self.second_blend_source.set(true, name_span)?;
+            "blend_src" => {
+                lexer
+                    .enable_extensions
+                    .require(ImplementedEnableExtension::DualSourceBlending, name_span)?;
+                lexer.expect(Token::Paren('('))?;
+                self.blend_src
+                    .set(parser.general_expression(lexer, ctx)?, name_span)?;
+                lexer.expect(Token::Paren(')'))?;
             }
             "invariant" => {
                 self.invariant.set(true, name_span)?;
             }
+            "second_blend_source" => return Err(Error::SecondBlendSource(name_span)),
             _ => return Err(Error::UnknownAttribute(name_span)),
         }
         Ok(())
@@ -239,7 +252,7 @@ impl<'a> BindingParser<'a> {
                     location,
                     interpolation,
                     sampling,
-                    second_blend_source: self.second_blend_source.value.unwrap_or(false),
+                    blend_src: self.blend_src.value,
                 }))
             }
             (None, Some(crate::BuiltIn::Position { .. }), None, None, invariant) => {
@@ -312,9 +325,22 @@ impl Parser {
         span: Span,
         ctx: &mut ExpressionContext<'a, '_, '_>,
     ) -> Result<Option<ast::ConstructorType<'a>>, Error<'a>> {
-        if let Some(scalar) = conv::get_scalar_type(word) {
+        if let Some(scalar) = conv::get_scalar_type(&lexer.enable_extensions, span, word)? {
             return Ok(Some(ast::ConstructorType::Scalar(scalar)));
         }
+        if let Some(alias) = conv::get_f16_alias(&lexer.enable_extensions, span, word)? {
+            let ty = ctx.new_scalar(Scalar::F16);
+            let ty_span = Span::UNDEFINED;
+            return Ok(Some(match alias {
+                conv::F16Alias::Vector(size) => ast::ConstructorType::Vector { size, ty, ty_span },
+                conv::F16Alias::Matrix(columns, rows) => ast::ConstructorType::Matrix {
+                    columns,
+                    rows,
+                    ty,
+                    ty_span,
+                },
+            }));
+        }
 
         let partial = match word {
             "vec2" => ast::ConstructorType::PartialVector {
@@ -670,15 +696,12 @@ impl Parser {
             }
             (Token::Number(res), span) => {
                 let _ = lexer.next();
-                let num = res.map_err(|err| match err {
-                    super::error::NumberError::UnimplementedF16 => {
-                        Error::EnableExtensionNotEnabled {
-                            kind: EnableExtension::Unimplemented(UnimplementedEnableExtension::F16),
-                            span,
-                        }
-                    }
-                    err => Error::BadNumber(span, err),
-                })?;
+                let num = res.map_err(|err| Error::BadNumber(span, err))?;
+                if let Number::F16(_) = num {
+                    lexer
+                        .enable_extensions
+                        .require(ImplementedEnableExtension::F16, span)?;
+                }
                 ast::Expression::Literal(ast::Literal::Number(num))
             }
             (Token::Word("RAY_FLAG_NONE"), _) => {
@@ -697,7 +720,16 @@ impl Parser {
                 let start = lexer.start_byte_offset();
                 let _ = lexer.next();
 
-                if let Some(ty) = self.constructor_type(lexer, word, span, ctx)? {
+                // Type names like `vec4f` are only constructors when followed
+                // by arguments or template parameters. Otherwise, they're
+                // ordinary identifiers, like a variable named `vec4f`.
+                let may_construct = matches!(lexer.peek().0, Token::Paren('(' | '<'));
+
+                if let Some(ty) = may_construct
+                    .then(|| self.constructor_type(lexer, word, span, ctx))
+                    .transpose()?
+                    .flatten()
+                {
                     let ty_span = lexer.span_from(start);
                     let components = self.arguments(lexer, ctx)?;
                     ast::Expression::Construct {
@@ -1123,11 +1155,25 @@ impl Parser {
         &mut self,
         lexer: &mut Lexer<'a>,
         word: &'a str,
+        span: Span,
         ctx: &mut ExpressionContext<'a, '_, '_>,
     ) -> Result<Option<ast::Type<'a>>, Error<'a>> {
-        if let Some(scalar) = conv::get_scalar_type(word) {
+        if let Some(scalar) = conv::get_scalar_type(&lexer.enable_extensions, span, word)? {
             return Ok(Some(ast::Type::Scalar(scalar)));
         }
+        if let Some(alias) = conv::get_f16_alias(&lexer.enable_extensions, span, word)? {
+            let ty = ctx.new_scalar(Scalar::F16);
+            let ty_span = Span::UNDEFINED;
+            return Ok(Some(match alias {
+                conv::F16Alias::Vector(size) => ast::Type::Vector { size, ty, ty_span },
+                conv::F16Alias::Matrix(columns, rows) => ast::Type::Matrix {
+                    columns,
+                    rows,
+                    ty,
+                    ty_span,
+                },
+            }));
+        }
 
         Ok(Some(match word {
             "vec2" => {
@@ -1531,7 +1577,7 @@ impl Parser {
 
         let (name, span) = lexer.next_ident_with_span()?;
 
-        let ty = match self.type_decl_impl(lexer, name, ctx)? {
+        let ty = match self.type_decl_impl(lexer, name, span, ctx)? {
             Some(ty) => ty,
             None => {
                 ctx.unresolved.insert(ast::Dependency {
@@ -1695,6 +1741,40 @@ impl Parser {
                 let _ = lexer.next();
                 self.pop_rule_span(lexer);
             }
+            (Token::Attribute, _) => {
+                let filters = self.diagnostic_attributes(lexer)?;
+                match lexer.peek().0 {
+                    Token::Paren('{') | Token::Word("if" | "switch" | "loop" | "for" | "while") => {
+                    }
+                    _ => {
+                        return Err(Error::DiagnosticAttributeNotSupported {
+                            on_what_plural: "statements of this kind",
+                            spans: filters.spans().collect(),
+                        });
+                    }
+                }
+
+                // A `for` statement's initializer is pushed as a separate
+                // statement, so wrap everything we parsed in a block.
+                let mut inner = ast::Block::default();
+                self.statement(lexer, ctx, &mut inner, brace_nesting_level)?;
+                let span = self.pop_rule_span(lexer);
+                let statement = if inner.stmts.len() == 1 {
+                    inner.stmts.pop().unwrap()
+                } else {
+                    ast::Statement {
+                        kind: ast::StatementKind::Block(inner),
+                        span,
+                    }
+                };
+                block.stmts.push(ast::Statement {
+                    kind: ast::StatementKind::Diagnostic {
+                        filters,
+                        statement: Box::new(statement),
+                    },
+                    span,
+                });
+            }
             (Token::Paren('{'), _) => {
                 let (inner, span) = self.block(lexer, ctx, brace_nesting_level)?;
                 block.stmts.push(ast::Statement {
@@ -1848,6 +1928,7 @@ impl Parser {
                     "switch" => {
                         let _ = lexer.next();
                         let selector = self.general_expression(lexer, ctx)?;
+                        let body_filters = self.diagnostic_attributes(lexer)?;
                         let brace_span = lexer.expect_span(Token::Paren('{'))?;
                         let brace_nesting_level =
                             Self::increase_brace_nesting(brace_nesting_level, brace_span)?;
@@ -1899,6 +1980,16 @@ impl Parser {
                             }
                         }
 
+                        if !body_filters.is_empty() {
+                            for case in cases.iter_mut() {
+                                case.body = Self::apply_diagnostic_filters(
+                                    body_filters.clone(),
+                                    std::mem::take(&mut case.body),
+                                    brace_span,
+                                );
+                            }
+                        }
+
                         ast::StatementKind::Switch { selector, cases }
                     }
                     "loop" => self.r#loop(lexer, ctx, brace_nesting_level)?,
@@ -2078,6 +2169,7 @@ impl Parser {
         let mut continuing = ast::Block::default();
         let mut break_if = None;
 
+        let body_filters = self.diagnostic_attributes(lexer)?;
         let brace_span = lexer.expect_span(Token::Paren('{'))?;
         let brace_nesting_level = Self::increase_brace_nesting(brace_nesting_level, brace_span)?;
 
@@ -2139,10 +2231,20 @@ impl Parser {
 
         ctx.local_table.pop_scope();
 
-        Ok(ast::StatementKind::Loop {
+        let kind = ast::StatementKind::Loop {
             body,
             continuing,
             break_if,
+        };
+        if body_filters.is_empty() {
+            return Ok(kind);
+        }
+
+        // A `loop` has nothing outside of its body for the filters to apply to.
+        let span = self.peek_rule_span(lexer);
+        Ok(ast::StatementKind::Diagnostic {
+            filters: body_filters,
+            statement: Box::new(ast::Statement { kind, span }),
         })
     }
 
@@ -2157,6 +2259,7 @@ impl Parser {
 
         ctx.local_table.push_scope();
 
+        let filters = self.diagnostic_attributes(lexer)?;
         let brace_span = lexer.expect_span(Token::Paren('{'))?;
         let brace_nesting_level = Self::increase_brace_nesting(brace_nesting_level, brace_span)?;
         let mut block = ast::Block::default();
@@ -2167,9 +2270,113 @@ impl Parser {
         ctx.local_table.pop_scope();
 
         let span = self.pop_rule_span(lexer);
+        if !filters.is_empty() {
+            block = Self::apply_diagnostic_filters(filters, block, span);
+        }
         Ok((block, span))
     }
 
+    /// Wrap the statements of `block` in a [`Diagnostic`] statement applying
+    /// `filters` to all of them.
+    ///
+    /// [`Diagnostic`]: ast::StatementKind::Diagnostic
+    fn apply_diagnostic_filters(
+        filters: DiagnosticFilterMap,
+        block: ast::Block<'_>,
+        span: Span,
+    ) -> ast::Block<'_> {
+        let statement = ast::Statement {
+            kind: ast::StatementKind::Block(block),
+            span,
+        };
+        ast::Block {
+            stmts: vec![ast::Statement {
+                kind: ast::StatementKind::Diagnostic {
+                    filters,
+                    statement: Box::new(statement),
+                },
+                span,
+            }],
+        }
+    }
+
+    /// Parse the `diagnostic_control` of a `diagnostic` directive or
+    /// `@diagnostic` attribute: a parenthesized severity and rule name, like
+    /// `(off, derivative_uniformity)`.
+    ///
+    /// Return the filter, and the span of the parenthesized part.
+    fn diagnostic_filter<'a>(
+        &self,
+        lexer: &mut Lexer<'a>,
+    ) -> Result<(DiagnosticFilter, Span), Error<'a>> {
+        let start = lexer.start_byte_offset();
+        lexer.expect(Token::Paren('('))?;
+
+        let (severity_control_name, severity_control_name_span) = lexer.next_ident_with_span()?;
+        let new_severity = diagnostic_filter::Severity::from_ident(severity_control_name).ok_or(
+            Error::DiagnosticInvalidSeverity {
+                severity_control_name_span,
+            },
+        )?;
+
+        lexer.expect(Token::Separator(','))?;
+
+        let (rule_name, rule_name_span) = lexer.next_ident_with_span()?;
+        let (rule_name_suffix, rule_name_span) = if lexer.skip(Token::Separator('.')) {
+            let (suffix, suffix_span) = lexer.next_ident_with_span()?;
+            (Some(suffix), rule_name_span.until(&suffix_span))
+        } else {
+            (None, rule_name_span)
+        };
+        let triggering_rule = FilterableTriggeringRule::from_idents(rule_name, rule_name_suffix);
+        if let FilterableTriggeringRule::Unknown(_) = triggering_rule {
+            // The standard requires unknown rule names to be accepted, with a warning.
+            let _ = diagnostic_filter::Severity::Warning.report_diag(
+                Error::UnknownDiagnosticRuleName(rule_name_span),
+                |e, level| {
+                    log::log!(
+                        level,
+                        "{}",
+                        e.as_parse_error(lexer.source).emit_to_string(lexer.source)
+                    );
+                },
+            );
+        }
+
+        lexer.skip(Token::Separator(','));
+        lexer.expect(Token::Paren(')'))?;
+
+        let filter = DiagnosticFilter {
+            new_severity,
+            triggering_rule,
+        };
+        Ok((filter, lexer.span_from(start)))
+    }
+
+    /// Parse zero or more `@diagnostic(…)` attributes, as found before
+    /// statements and compound statements.
+    fn diagnostic_attributes<'a>(
+        &mut self,
+        lexer: &mut Lexer<'a>,
+    ) -> Result<DiagnosticFilterMap, Error<'a>> {
+        let mut filters = DiagnosticFilterMap::new();
+        while lexer.skip(Token::Attribute) {
+            let (name, name_span) = lexer.next_ident_with_span()?;
+            if name != "diagnostic" {
+                return Err(Error::UnknownAttribute(name_span));
+            }
+            let (filter, span) = self.diagnostic_filter(lexer)?;
+            filters
+                .add(
+                    filter,
+                    name_span.until(&span),
+                    ShouldConflictOnFullDuplicate::Yes,
+                )
+                .map_err(Error::DiagnosticDuplicateTriggeringRule)?;
+        }
+        Ok(filters)
+    }
+
     fn varying_binding<'a>(
         &mut self,
         lexer: &mut Lexer<'a>,
@@ -2262,6 +2469,7 @@ impl Parser {
             arguments,
             result,
             body,
+            diagnostic_filter_leaf: None,
         };
 
         // done
@@ -2313,6 +2521,7 @@ impl Parser {
         let (mut bind_index, mut bind_group) =
             (ParsedAttribute::default(), ParsedAttribute::default());
         let mut id = ParsedAttribute::default();
+        let mut diagnostic_filters = DiagnosticFilterMap::new();
 
         let mut dependencies = FastIndexSet::default();
         let mut ctx = ExpressionContext {
@@ -2380,6 +2589,16 @@ impl Parser {
                     };
                     early_depth_test.set(crate::EarlyDepthTest { conservative }, name_span)?;
                 }
+                ("diagnostic", name_span) => {
+                    let (filter, span) = self.diagnostic_filter(lexer)?;
+                    diagnostic_filters
+                        .add(
+                            filter,
+                            name_span.until(&span),
+                            ShouldConflictOnFullDuplicate::Yes,
+                        )
+                        .map_err(Error::DiagnosticDuplicateTriggeringRule)?;
+                }
                 (_, word_span) => return Err(Error::UnknownAttribute(word_span)),
             }
         }
@@ -2397,6 +2616,27 @@ impl Parser {
             (None, None) => {}
         }
 
+        if !diagnostic_filters.is_empty() {
+            let on_what_plural = match lexer.peek().0 {
+                Token::Word("fn") => None,
+                Token::Separator(';') => Some("semicolons"),
+                Token::Word("struct") => Some("`struct` declarations"),
+                Token::Word("alias") => Some("`alias` declarations"),
+                Token::Word("const") => Some("`const` declarations"),
+                Token::Word("override") => Some("`override` declarations"),
+                Token::Word("var") => Some("`var` declarations"),
+                Token::Word("const_assert") => Some("`const_assert`s"),
+                // Let the declaration parser report what's wrong.
+                _ => None,
+            };
+            if let Some(on_what_plural) = on_what_plural {
+                return Err(Error::DiagnosticAttributeNotSupported {
+                    on_what_plural,
+                    spans: diagnostic_filters.spans().collect(),
+                });
+            }
+        }
+
         // read item
         let start = lexer.start_byte_offset();
         let kind = match lexer.next() {
@@ -2464,8 +2704,11 @@ impl Parser {
                 Some(ast::GlobalDeclKind::Var(var))
             }
             (Token::Word("fn"), _) => {
+                let diagnostic_filter_leaf = diagnostic_filters
+                    .into_nodes(&mut out.diagnostic_filters, out.diagnostic_filter_leaf);
                 let function = self.function_decl(lexer, out, &mut dependencies)?;
                 Some(ast::GlobalDeclKind::Fn(ast::Function {
+                    diagnostic_filter_leaf,
                     entry_point: if let Some(stage) = stage.value {
                         if stage == ShaderStage::Compute && workgroup_size.value.is_none() {
                             return Err(Error::MissingWorkgroupSize(compute_span));
@@ -2522,13 +2765,22 @@ impl Parser {
         let mut lexer = Lexer::new(source);
         let mut tu = ast::TranslationUnit::default();
         let mut enable_extensions = EnableExtensions::empty();
+        let mut diagnostic_filters = DiagnosticFilterMap::new();
 
         // Parse directives.
-        while let Ok((ident, span)) = lexer.peek_ident_with_span() {
+        while let Ok((ident, _)) = lexer.peek_ident_with_span() {
             if let Some(kind) = DirectiveKind::from_ident(ident) {
                 self.push_rule_span(Rule::Directive, &mut lexer);
                 let _ = lexer.next_ident_with_span().unwrap();
                 match kind {
+                    DirectiveKind::Diagnostic => {
+                        let (filter, _) = self.diagnostic_filter(&mut lexer)?;
+                        let span = self.peek_rule_span(&lexer);
+                        diagnostic_filters
+                            .add(filter, span, ShouldConflictOnFullDuplicate::No)
+                            .map_err(Error::DiagnosticDuplicateTriggeringRule)?;
+                        lexer.expect(Token::Separator(';'))?;
+                    }
                     DirectiveKind::Enable => {
                         self.directive_ident_list(&mut lexer, |ident, span| {
                             let kind = EnableExtension::from_ident(ident, span)?;
@@ -2562,9 +2814,6 @@ impl Parser {
                             }
                         })?;
                     }
-                    DirectiveKind::Unimplemented(kind) => {
-                        return Err(Error::DirectiveNotYetImplemented { kind, span })
-                    }
                 }
                 self.pop_rule_span(&lexer);
             } else {
@@ -2574,6 +2823,7 @@ impl Parser {
 
         lexer.enable_extensions = enable_extensions.clone();
         tu.enable_extensions = enable_extensions;
+        tu.diagnostic_filter_leaf = diagnostic_filters.into_nodes(&mut tu.diagnostic_filters, None);
 
         loop {
             match self.global_decl(&mut lexer, &mut tu) {
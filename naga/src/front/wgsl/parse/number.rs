@@ -1,5 +1,6 @@
 use crate::front::wgsl::error::NumberError;
 use crate::front::wgsl::parse::lexer::Token;
+use half::f16;
 
 /// When using this type assume no Abstract Int/Float for now
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -16,6 +17,8 @@ pub enum Number {
     I64(i64),
     /// Concrete u64
     U64(u64),
+    /// Concrete f16
+    F16(f16),
     /// Concrete f32
     F32(f32),
     /// Concrete f64
@@ -362,7 +365,17 @@ fn parse_hex_float(input: &str, kind: Option<FloatKind>) -> Result<Number, Numbe
             // can only be ParseHexfErrorKind::Inexact but we can't check since it's private
             _ => Err(NumberError::NotRepresentable),
         },
-        Some(FloatKind::F16) => Err(NumberError::UnimplementedF16),
+        Some(FloatKind::F16) => match hexf_parse::parse_hexf64(input, false) {
+            Ok(num) => {
+                // Hexadecimal literals must be exactly representable.
+                let f16_num = f16::from_f64(num);
+                (f16_num.is_finite() && f64::from(f16_num) == num)
+                    .then_some(Number::F16(f16_num))
+                    .ok_or(NumberError::NotRepresentable)
+            }
+            // can only be ParseHexfErrorKind::Inexact but we can't check since it's private
+            _ => Err(NumberError::NotRepresentable),
+        },
         Some(FloatKind::F32) => match hexf_parse::parse_hexf32(input, false) {
             Ok(num) => Ok(Number::F32(num)),
             // can only be ParseHexfErrorKind::Inexact but we can't check since it's private
@@ -398,10 +411,78 @@ fn parse_dec_float(input: &str, kind: Option<FloatKind>) -> Result<Number, Numbe
                 .then_some(Number::F64(num))
                 .ok_or(NumberError::NotRepresentable)
         }
-        Some(FloatKind::F16) => Err(NumberError::UnimplementedF16),
+        Some(FloatKind::F16) => {
+            let num = parse_dec_f16(input);
+            num.is_finite()
+                .then_some(Number::F16(num))
+                .ok_or(NumberError::NotRepresentable)
+        }
     }
 }
 
+/// Round a decimal floating point literal to the nearest [`f16`].
+///
+/// There is no correctly rounded decimal parser for `f16`, so this parses the
+/// literal as an `f64` and rounds that. Rounding to `f64` first can't move the
+/// value across an `f16` rounding boundary, since those boundaries are exactly
+/// representable as `f64`, but it can land exactly on one. When it does, we
+/// compare the literal's exact decimal value against the boundary instead.
+fn parse_dec_f16(input: &str) -> f16 {
+    let num = input.parse::<f64>().unwrap(); // will never fail
+    let rounded = f16::from_f64(num);
+
+    let (below, above) = if f64::from(rounded) > num {
+        (f16::from_bits(rounded.to_bits() - 1), rounded)
+    } else {
+        (rounded, f16::from_bits(rounded.to_bits() + 1))
+    };
+    // Past `f16::MAX`, values round to infinity from the midpoint to 2^16.
+    let above_value = if above.is_infinite() {
+        65536.0
+    } else {
+        f64::from(above)
+    };
+    let midpoint = (f64::from(below) + above_value) / 2.0;
+    if num != midpoint {
+        return rounded;
+    }
+
+    // The midpoint has at most 25 fractional binary digits, so this prints it
+    // exactly.
+    match cmp_decimals(input, &format!("{midpoint:.25}")) {
+        core::cmp::Ordering::Less => below,
+        core::cmp::Ordering::Equal => rounded,
+        core::cmp::Ordering::Greater => above,
+    }
+}
+
+/// Compare the values of two positive decimal floating point numbers, written
+/// as `digits.digits` with an optional `e` exponent.
+fn cmp_decimals(a: &str, b: &str) -> core::cmp::Ordering {
+    /// Split `input` into its significant digits and the exponent `e` for which
+    /// its value is `0.digits * 10^e`.
+    fn normalize(input: &str) -> (i64, String) {
+        let (mantissa, exponent) = match input.find(['e', 'E']) {
+            Some(i) => (&input[..i], input[i + 1..].parse::<i64>().unwrap()),
+            None => (input, 0),
+        };
+        let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let digits: String = int.chars().chain(frac.chars()).collect();
+        let significant = digits.trim_start_matches('0');
+        let leading_zeros = digits.len() - significant.len();
+        (
+            exponent + int.len() as i64 - leading_zeros as i64,
+            significant.trim_end_matches('0').to_string(),
+        )
+    }
+
+    let (a_exponent, a_digits) = normalize(a);
+    let (b_exponent, b_digits) = normalize(b);
+    a_exponent
+        .cmp(&b_exponent)
+        .then_with(|| a_digits.cmp(&b_digits))
+}
+
 fn parse_int(input: &str, kind: Option<IntKind>, radix: u32) -> Result<Number, NumberError> {
     fn map_err(e: core::num::ParseIntError) -> NumberError {
         match *e.kind() {
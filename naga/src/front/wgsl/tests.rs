@@ -636,3 +636,109 @@ fn parse_missing_workgroup_size() {
         Error::MissingWorkgroupSize(span) if span == Span::new(1, 8)
     ));
 }
+
+#[test]
+fn const_eval_builtins() {
+    for assertion in [
+        // select, bitcast, all and any
+        "select(1, 2, true) == 2",
+        "all(select(vec2(1, 2), vec2(3, 4), vec2(true, false)) == vec2(3, 2))",
+        "all(vec3(true)) && !all(vec2(true, false))",
+        "any(vec2(false, true)) && !any(vec2(false))",
+        "bitcast<u32>(1.0f) == 0x3f800000u",
+        "bitcast<f32>(0x40000000u) == 2.0f",
+        "all(bitcast<vec2<i32>>(vec2(0xffffffffu, 1u)) == vec2(-1i, 1i))",
+        // trigonometry
+        "abs(atan2(1.0, 1.0) - 0.7853982) < 1e-6",
+        "atan2(0.0, -1.0) > 3.14159",
+        // decomposition
+        "modf(-1.5).fract == -0.5 && modf(-1.5).whole == -1.0",
+        "all(modf(vec2(2.25, -0.5)).fract == vec2(0.25, -0.5))",
+        "frexp(8.0).fract == 0.5 && frexp(8.0).exp == 4",
+        "frexp(-0.75).fract == -0.75 && frexp(-0.75).exp == 0",
+        "all(frexp(vec2(1.0, 0.0)).exp == vec2(1, 0))",
+        "ldexp(0.75, 3) == 6.0",
+        "all(ldexp(vec2(1.0, 3.0), vec2(-1, 2)) == vec2(0.5, 12.0))",
+        // geometry
+        "dot(vec3(1, 2, 3), vec3(4, 5, 6)) == 32",
+        "dot(vec2(1u, 2u), vec2(3u, 4u)) == 11u",
+        "dot(vec2(1.5, 2.0), vec2(2.0, 4.0)) == 11.0",
+        "all(cross(vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0)) == vec3(0.0, 0.0, 1.0))",
+        "length(vec2(3.0, 4.0)) == 5.0",
+        "length(-2.0) == 2.0",
+        "distance(vec2(1.0, 1.0), vec2(4.0, 5.0)) == 5.0",
+        "all(normalize(vec2(3.0, 4.0)) == vec2(0.6, 0.8))",
+        "all(faceForward(vec2(1.0, 2.0), vec2(1.0, 0.0), vec2(-1.0, 0.0)) == vec2(1.0, 2.0))",
+        "all(faceForward(vec2(1.0, 2.0), vec2(1.0, 0.0), vec2(1.0, 0.0)) == vec2(-1.0, -2.0))",
+        "all(reflect(vec2(1.0, -1.0), vec2(0.0, 1.0)) == vec2(1.0, 1.0))",
+        "all(refract(vec2(0.0, -1.0), vec2(0.0, 1.0), 1.0) == vec2(0.0, -1.0))",
+        "all(refract(vec2(1.0, 0.0), vec2(0.0, 1.0), 2.0) == vec2(0.0))",
+        // computational
+        "all(mix(vec2(0.0, 10.0), vec2(10.0, 20.0), 0.5) == vec2(5.0, 15.0))",
+        "all(mix(vec2(0.0), vec2(10.0), vec2(0.25, 0.75)) == vec2(2.5, 7.5))",
+        "smoothstep(0.0, 2.0, 1.0) == 0.5",
+        "smoothstep(0.0, 1.0, 2.0) == 1.0 && smoothstep(0.0, 1.0, -1.0) == 0.0",
+        "determinant(mat2x2(1.0, 2.0, 3.0, 4.0)) == -2.0",
+        "determinant(mat3x3(2.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 4.0)) == 24.0",
+        "determinant(mat4x4(0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0)) == -1.0",
+        "all(transpose(mat2x3(1.0, 2.0, 3.0, 4.0, 5.0, 6.0))[2] == vec2(3.0, 6.0))",
+        "quantizeToF16(1.0009765625) == 1.0009765625 && quantizeToF16(1.0001) == 1.0",
+        "all(quantizeToF16(vec2(0.1, 65504.0)) == vec2(0.0999755859375, 65504.0))",
+        // bits
+        "extractBits(0xf0u, 4u, 4u) == 0xfu",
+        "extractBits(0xf0i, 4u, 4u) == -1i",
+        "extractBits(0xffu, 4u, 100u) == 0xfu && extractBits(1u, 40u, 1u) == 0u",
+        "insertBits(0xffu, 0u, 4u, 2u) == 0xcfu",
+        "all(insertBits(vec2(0i), vec2(-1i), 30u, 8u) == vec2(-1073741824i))",
+        // data packing
+        "pack4x8snorm(vec4(1.0, -1.0, 0.5, 0.0)) == 0x0040817fu",
+        "pack4x8unorm(vec4(1.0, 0.0, 0.5, 2.0)) == 0xff8000ffu",
+        "pack2x16snorm(vec2(1.0, -1.0)) == 0x80017fffu",
+        "pack2x16unorm(vec2(1.0, 0.0)) == 0xffffu",
+        "pack2x16float(vec2(1.0, -2.0)) == 0xc0003c00u",
+        // data unpacking
+        "all(unpack4x8snorm(0x0040817fu) == vec4(1.0, -1.0, 64.0 / 127.0, 0.0))",
+        "all(unpack4x8snorm(0x80u) == vec4(-1.0, 0.0, 0.0, 0.0))",
+        "all(unpack4x8unorm(0xff0000ffu) == vec4(1.0, 0.0, 0.0, 1.0))",
+        "all(unpack2x16snorm(0x80017fffu) == vec2(1.0, -1.0))",
+        "all(unpack2x16unorm(0xffffu) == vec2(1.0, 0.0))",
+        "all(unpack2x16float(0xc0003c00u) == vec2(1.0, -2.0))",
+    ] {
+        let source = format!("const_assert {assertion};");
+        if let Err(err) = parse_str(&source) {
+            panic!("{}", err.emit_to_string(&source));
+        }
+    }
+
+    // The results of these must be rejected at shader creation time.
+    for (expression, message) in [
+        ("length(vec2(3.0, 4.0)) == 6.0", "const_assert failure"),
+        (
+            "all(normalize(vec2(0.0)) == vec2(0.0))",
+            "Float literal is NaN",
+        ),
+        (
+            "dot(vec2(0x7fffffff), vec2(2)) == 0",
+            "dot operation overflowed",
+        ),
+        ("quantizeToF16(1e10) == 0.0", "Float literal is infinite"),
+        ("bitcast<f32>(0x7fc00000u) == 0.0", "Float literal is NaN"),
+        ("ldexp(1.0, 200) == 0.0", "Float literal is infinite"),
+        (
+            "pack2x16float(vec2(1e10, 0.0)) == 0u",
+            "Float literal is infinite",
+        ),
+    ] {
+        let source = format!("const_assert {expression};");
+        match parse_str(&source) {
+            Ok(_) => panic!("{source} was accepted"),
+            Err(err) => {
+                let emitted = err.emit_to_string(&source);
+                assert!(
+                    emitted.starts_with(&format!("error: {message}\n")),
+                    "{source} failed with an unexpected error:\n{emitted}"
+                );
+            }
+        }
+    }
+}
@@ -255,11 +255,15 @@ pub mod back;
 mod block;
 #[cfg(feature = "compact")]
 pub mod compact;
+pub mod diagnostic_filter;
 pub mod error;
 pub mod front;
 pub mod keywords;
 mod non_max_u32;
+#[cfg(feature = "opt")]
+pub mod opt;
 pub mod proc;
+pub mod reflect;
 mod span;
 pub mod valid;
 
@@ -871,6 +875,8 @@ pub enum Literal {
     F64(f64),
     /// May not be NaN or infinity.
     F32(f32),
+    /// May not be NaN or infinity.
+    F16(#[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_f16))] half::f16),
     U32(u32),
     I32(i32),
     U64(u64),
@@ -880,6 +886,12 @@ pub enum Literal {
     AbstractFloat(f64),
 }
 
+/// Generate an arbitrary [`half::f16`], which does not implement [`Arbitrary`] itself.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_f16(u: &mut arbitrary::Unstructured) -> arbitrary::Result<half::f16> {
+    Ok(half::f16::from_bits(u.arbitrary()?))
+}
+
 /// Pipeline-overridable constant.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(Serialize))]
@@ -941,8 +953,10 @@ pub enum Binding {
     /// [`Fragment`]: crate::ShaderStage::Fragment
     Location {
         location: u32,
-        /// Indicates the 2nd input to the blender when dual-source blending.
-        second_blend_source: bool,
+        /// Indicates which input to the blender this output is when using
+        /// dual-source blending: `Some(0)` for the first source and `Some(1)`
+        /// for the second.
+        blend_src: Option<u32>,
         interpolation: Option<Interpolation>,
         sampling: Option<Sampling>,
     },
@@ -1179,6 +1193,8 @@ pub enum MathFunction {
     Pow,
     // geometry
     Dot,
+    Dot4I8Packed,
+    Dot4U8Packed,
     Outer,
     Cross,
     Distance,
@@ -1198,6 +1214,7 @@ pub enum MathFunction {
     Inverse,
     Transpose,
     Determinant,
+    QuantizeToF16,
     // bits
     CountTrailingZeros,
     CountLeadingZeros,
@@ -1215,6 +1232,8 @@ pub enum MathFunction {
     Pack2x16float,
     Pack4xI8,
     Pack4xU8,
+    Pack4xI8Clamp,
+    Pack4xU8Clamp,
     // data unpacking
     Unpack4x8snorm,
     Unpack4x8unorm,
@@ -1305,6 +1324,21 @@ pub enum GatherMode {
     ShuffleUp(Handle<Expression>),
     /// Each gathers from their lane xored with the given by the expression
     ShuffleXor(Handle<Expression>),
+    /// All gather from the lane of their quad at the index given by the expression
+    QuadBroadcast(Handle<Expression>),
+    /// Each gathers from the opposite lane of their quad in the given direction
+    QuadSwap(Direction),
+}
+
+/// The direction of a [`GatherMode::QuadSwap`].
+#[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub enum Direction {
+    X = 0,
+    Y = 1,
+    Diagonal = 2,
 }
 
 #[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
@@ -1328,9 +1362,16 @@ pub enum SubgroupOperation {
 #[cfg_attr(feature = "deserialize", derive(Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
 pub enum CollectiveOperation {
-    Reduce = 0,
-    InclusiveScan = 1,
-    ExclusiveScan = 2,
+    Reduce,
+    InclusiveScan,
+    ExclusiveScan,
+    /// Reduce separately over each cluster of consecutive lanes of the given
+    /// size, which must be a power of two no greater than 128.
+    ///
+    /// Not every backend supports every size: the MSL backend only supports
+    /// clusters of 4 lanes, and the HLSL backend rejects clustered reductions
+    /// entirely, since HLSL has no equivalent.
+    ClusteredReduce(u32),
 }
 
 bitflags::bitflags! {
@@ -1346,6 +1387,8 @@ bitflags::bitflags! {
         const WORK_GROUP = 1 << 1;
         /// Barrier synchronizes execution across all invocations within a subgroup that execute this instruction.
         const SUB_GROUP = 1 << 2;
+        /// Barrier affects all [`AddressSpace::Handle`] accesses to storage textures.
+        const TEXTURE = 1 << 3;
     }
 }
 
@@ -1882,12 +1925,31 @@ pub enum Statement {
 
     /// Aborts the current shader execution.
     ///
+    /// This terminates the invocation, like SPIR-V's `OpTerminateInvocation`.
+    /// Derivatives computed by other invocations in the same quad after this
+    /// point are undefined; see [`Demote`] for an alternative that keeps them
+    /// well-defined.
+    ///
     /// `Kill` statements are forbidden within the `continuing` block of a
     /// [`Loop`] statement.
     ///
+    /// [`Demote`]: Statement::Demote
     /// [`Loop`]: Statement::Loop
     Kill,
 
+    /// Demote the current invocation to a helper invocation.
+    ///
+    /// Unlike [`Kill`], this does not end execution: the invocation carries
+    /// on as a helper, so that derivatives computed by the rest of its quad
+    /// remain well-defined, but its writes to memory and its outputs are
+    /// discarded. This is SPIR-V's `OpDemoteToHelperInvocation`.
+    ///
+    /// Backends without a native demote operation lower this to the same
+    /// code as [`Kill`].
+    ///
+    /// [`Kill`]: Statement::Kill
+    Demote,
+
     /// Synchronize invocations within the work group.
     /// The `Barrier` flags control which memory accesses should be synchronized.
     /// If empty, this becomes purely an execution barrier.
@@ -2118,6 +2180,27 @@ pub struct Function {
     pub named_expressions: NamedExpressions,
     /// Block of instructions comprising the body of the function.
     pub body: Block,
+    /// The leaf of all diagnostic filter rules tree (stored in [`Module::diagnostic_filters`])
+    /// parsed on this function.
+    ///
+    /// In WGSL, this corresponds to `@diagnostic(…)` attributes. If this is `None`, the
+    /// module's [`Module::diagnostic_filter_leaf`] applies instead.
+    ///
+    /// See [`DiagnosticFilterNode`] for details on how the tree is represented and used in
+    /// validation.
+    ///
+    /// [`DiagnosticFilterNode`]: crate::diagnostic_filter::DiagnosticFilterNode
+    pub diagnostic_filter_leaf: Option<Handle<diagnostic_filter::DiagnosticFilterNode>>,
+    /// Runs of expressions in this function that are governed by
+    /// statement-level diagnostic filters.
+    ///
+    /// In WGSL, this corresponds to `@diagnostic(…)` attributes on statements
+    /// and compound statements.
+    ///
+    /// See [`DiagnosticFilterScope`] for details.
+    ///
+    /// [`DiagnosticFilterScope`]: crate::diagnostic_filter::DiagnosticFilterScope
+    pub diagnostic_filter_scopes: Vec<diagnostic_filter::DiagnosticFilterScope>,
 }
 
 /// The main function for a pipeline stage.
@@ -2270,4 +2353,19 @@ pub struct Module {
     pub functions: Arena<Function>,
     /// Entry points.
     pub entry_points: Vec<EntryPoint>,
+    /// Arena for all diagnostic filter rules parsed in this module, including those in functions
+    /// and statements.
+    ///
+    /// This arena contains elements of a _tree_ of diagnostic filter rules. When nodes are built
+    /// by a front-end, they refer to the node of the enclosing scope as their parent.
+    pub diagnostic_filters: Arena<diagnostic_filter::DiagnosticFilterNode>,
+    /// The leaf of all diagnostic filter rules tree parsed from directives in this module.
+    ///
+    /// In WGSL, this corresponds to `diagnostic(…);` directives.
+    ///
+    /// See [`DiagnosticFilterNode`] for details on how the tree is represented and used in
+    /// validation.
+    ///
+    /// [`DiagnosticFilterNode`]: crate::diagnostic_filter::DiagnosticFilterNode
+    pub diagnostic_filter_leaf: Option<Handle<diagnostic_filter::DiagnosticFilterNode>>,
 }
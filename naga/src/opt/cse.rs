@@ -0,0 +1,188 @@
+//! Common subexpression elimination.
+
+use std::mem;
+
+use super::handles;
+use crate::arena::HandleVec;
+use crate::{Arena, Block, Expression, FastHashMap, Function, Handle, Statement};
+
+/// Replace expressions that recompute a value already in scope with that value.
+///
+/// An expression is redundant if it is identical to a pure expression emitted
+/// earlier in the same block or an enclosing one. Uses of the redundant
+/// expression are redirected to the earlier one, leaving the redundant
+/// expression unused.
+pub fn eliminate_common_subexpressions(function: &mut Function) {
+    // Diagnostic filter scopes might apply different filters to the two
+    // expressions, so merging them could change what the validator reports.
+    if !function.diagnostic_filter_scopes.is_empty() {
+        return;
+    }
+
+    let mut replacements = HandleVec::with_capacity(function.expressions.len());
+    replacements.resize(function.expressions.len(), None);
+    let mut eliminator = Eliminator {
+        expressions: &mut function.expressions,
+        replacements,
+        scopes: Vec::new(),
+    };
+    eliminator.block(&function.body);
+    let replacements = eliminator.replacements;
+    let replace = |handle: &mut Handle<Expression>| {
+        if let Some(replacement) = replacements[*handle] {
+            *handle = replacement;
+        }
+    };
+
+    // Expressions we visited have had their operands replaced already, but
+    // there may be others, like local variable initializers, that weren't
+    // covered by any `Emit`.
+    for (_, expr) in function.expressions.iter_mut() {
+        handles::for_each_operand_mut(expr, replace);
+    }
+    handles::for_each_statement_mut(&mut function.body, &mut |stmt| {
+        handles::for_each_statement_operand_mut(stmt, replace);
+    });
+    for (_, local) in function.local_variables.iter_mut() {
+        if let Some(ref mut init) = local.init {
+            replace(init);
+        }
+    }
+
+    // Move the names of redundant expressions to their replacements, unless
+    // they already have one.
+    let named_expressions = mem::take(&mut function.named_expressions);
+    let (replaced, kept): (Vec<_>, Vec<_>) = named_expressions
+        .into_iter()
+        .partition(|&(handle, _)| replacements[handle].is_some());
+    function.named_expressions.extend(kept);
+    for (mut handle, name) in replaced {
+        replace(&mut handle);
+        function.named_expressions.entry(handle).or_insert(name);
+    }
+}
+
+/// The key under which we file available expressions.
+///
+/// Equal expressions have the same variant and operands, so this narrows the
+/// candidates for a match to just a few.
+type Key = (mem::Discriminant<Expression>, Option<Handle<Expression>>);
+
+struct Eliminator<'a> {
+    expressions: &'a mut Arena<Expression>,
+
+    /// The expression to use in place of each redundant expression.
+    replacements: HandleVec<Expression, Option<Handle<Expression>>>,
+
+    /// The pure expressions available in each enclosing block, innermost last.
+    scopes: Vec<FastHashMap<Key, Vec<Handle<Expression>>>>,
+}
+
+impl Eliminator<'_> {
+    fn block(&mut self, block: &Block) {
+        self.scopes.push(FastHashMap::default());
+        for stmt in block.iter() {
+            match *stmt {
+                Statement::Emit(ref range) => {
+                    for handle in range.clone() {
+                        self.expression(handle);
+                    }
+                }
+                Statement::Block(ref block) => self.block(block),
+                Statement::If {
+                    ref accept,
+                    ref reject,
+                    ..
+                } => {
+                    self.block(accept);
+                    self.block(reject);
+                }
+                Statement::Switch { ref cases, .. } => {
+                    for case in cases.iter() {
+                        self.block(&case.body);
+                    }
+                }
+                Statement::Loop {
+                    ref body,
+                    ref continuing,
+                    ..
+                } => {
+                    // A `continue` can skip the rest of the body, so the
+                    // continuing block can't rely on anything the body emits.
+                    self.block(body);
+                    self.block(continuing);
+                }
+                _ => {}
+            }
+        }
+        self.scopes.pop();
+    }
+
+    fn expression(&mut self, handle: Handle<Expression>) {
+        let replacements = &self.replacements;
+        handles::for_each_operand_mut(&mut self.expressions[handle], |operand| {
+            if let Some(replacement) = replacements[*operand] {
+                *operand = replacement;
+            }
+        });
+
+        let expr = &self.expressions[handle];
+        if !is_pure(expr) {
+            return;
+        }
+
+        let mut first_operand = None;
+        handles::for_each_operand(expr, |operand| {
+            first_operand.get_or_insert(operand);
+        });
+        let key = (mem::discriminant(expr), first_operand);
+
+        for scope in self.scopes.iter().rev() {
+            let Some(candidates) = scope.get(&key) else {
+                continue;
+            };
+            // Only replace with earlier expressions, so that everything
+            // using `handle` still comes after its operands.
+            if let Some(&earlier) = candidates
+                .iter()
+                .find(|&&candidate| candidate < handle && self.expressions[candidate] == *expr)
+            {
+                self.replacements[handle] = Some(earlier);
+                return;
+            }
+        }
+
+        self.scopes
+            .last_mut()
+            .unwrap()
+            .entry(key)
+            .or_default()
+            .push(handle);
+    }
+}
+
+/// Return true if `expr`'s value depends only on its operands.
+///
+/// Notably, this excludes [`Load`] and image sampling and loading, since
+/// memory may change between two evaluations.
+///
+/// [`Load`]: Expression::Load
+const fn is_pure(expr: &Expression) -> bool {
+    matches!(
+        *expr,
+        Expression::Compose { .. }
+            | Expression::Access { .. }
+            | Expression::AccessIndex { .. }
+            | Expression::Splat { .. }
+            | Expression::Swizzle { .. }
+            | Expression::Unary { .. }
+            | Expression::Binary { .. }
+            | Expression::Select { .. }
+            | Expression::Relational { .. }
+            | Expression::Math { .. }
+            | Expression::As { .. }
+            | Expression::Derivative { .. }
+            | Expression::ImageQuery { .. }
+            | Expression::ArrayLength(_)
+    )
+}
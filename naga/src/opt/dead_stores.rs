@@ -0,0 +1,141 @@
+//! Removal of stores to local variables that are never read, and of unused locals.
+
+use std::mem;
+
+use super::handles;
+use crate::arena::{HandleSet, HandleVec};
+use crate::{Arena, Block, Expression, Function, Handle, LocalVariable, Statement};
+
+/// Remove [`Store`] statements whose target is a local variable nothing reads.
+///
+/// A local is read if a pointer to it, or to any part of it, is used other
+/// than as the target of a [`Store`]: loaded from, passed to a function, and so
+/// on. Named pointer expressions count as reads too, since the name keeps the
+/// expression alive.
+///
+/// [`Store`]: Statement::Store
+pub fn remove_stores(function: &mut Function) {
+    let expressions = &function.expressions;
+    let mut read = HandleSet::for_arena(&function.local_variables);
+
+    for (_, expr) in expressions.iter() {
+        match *expr {
+            // Taking a pointer to part of a local isn't a read in itself;
+            // whatever uses the new pointer decides that. Indices are never
+            // pointers, so they can't refer to locals.
+            Expression::Access { .. } | Expression::AccessIndex { .. } => {}
+            _ => handles::for_each_operand(expr, |operand| {
+                if let Some(local) = root_local(expressions, operand) {
+                    read.insert(local);
+                }
+            }),
+        }
+    }
+    handles::for_each_statement(&function.body, &mut |stmt| {
+        // A `Store`'s value can't be a pointer, so only its target could
+        // refer to a local, and that's not a read.
+        if let Statement::Store { .. } = *stmt {
+            return;
+        }
+        handles::for_each_statement_operand(stmt, |operand| {
+            if let Some(local) = root_local(expressions, operand) {
+                read.insert(local);
+            }
+        });
+    });
+    for &handle in function.named_expressions.keys() {
+        if let Some(local) = root_local(expressions, handle) {
+            read.insert(local);
+        }
+    }
+
+    retain_stores(&mut function.body, &mut |pointer| {
+        root_local(expressions, pointer).map_or(true, |local| read.contains(local))
+    });
+}
+
+/// Remove local variables that no [`LocalVariable`] expression refers to.
+///
+/// Run this after compaction has dropped the expressions left unused by
+/// [`remove_stores`].
+///
+/// [`LocalVariable`]: Expression::LocalVariable
+pub fn remove_locals(function: &mut Function) {
+    let mut used = HandleSet::for_arena(&function.local_variables);
+    for (_, expr) in function.expressions.iter() {
+        if let Expression::LocalVariable(local) = *expr {
+            used.insert(local);
+        }
+    }
+    if used.iter().count() == function.local_variables.len() {
+        return;
+    }
+
+    let mut new_handles = HandleVec::with_capacity(function.local_variables.len());
+    let mut local_variables = Arena::new();
+    for (handle, local, span) in function.local_variables.drain() {
+        let new = used
+            .contains(handle)
+            .then(|| local_variables.append(local, span));
+        new_handles.insert(handle, new);
+    }
+    function.local_variables = local_variables;
+
+    for (_, expr) in function.expressions.iter_mut() {
+        if let Expression::LocalVariable(ref mut local) = *expr {
+            *local = new_handles[*local].unwrap();
+        }
+    }
+}
+
+/// If `pointer` is a pointer into a local variable, return that variable.
+fn root_local(
+    expressions: &Arena<Expression>,
+    mut pointer: Handle<Expression>,
+) -> Option<Handle<LocalVariable>> {
+    loop {
+        match expressions[pointer] {
+            Expression::LocalVariable(local) => return Some(local),
+            Expression::Access { base, .. } | Expression::AccessIndex { base, .. } => {
+                pointer = base
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Drop the [`Store`]s in `block` for whose pointer `keep` returns false.
+///
+/// [`Store`]: Statement::Store
+fn retain_stores(block: &mut Block, keep: &mut impl FnMut(Handle<Expression>) -> bool) {
+    let original = mem::take(block);
+    for (mut stmt, span) in original.span_into_iter() {
+        match stmt {
+            Statement::Store { pointer, .. } if !keep(pointer) => continue,
+            Statement::Block(ref mut block) => retain_stores(block, keep),
+            Statement::If {
+                ref mut accept,
+                ref mut reject,
+                ..
+            } => {
+                retain_stores(accept, keep);
+                retain_stores(reject, keep);
+            }
+            Statement::Switch { ref mut cases, .. } => {
+                for case in cases.iter_mut() {
+                    retain_stores(&mut case.body, keep);
+                }
+            }
+            Statement::Loop {
+                ref mut body,
+                ref mut continuing,
+                ..
+            } => {
+                retain_stores(body, keep);
+                retain_stores(continuing, keep);
+            }
+            _ => {}
+        }
+        block.push(stmt, span);
+    }
+}
@@ -0,0 +1,414 @@
+//! Helpers for visiting the expression handles held by expressions and statements.
+
+use crate::arena::HandleSet;
+use crate::{Block, Expression, Function, Handle, Statement};
+
+/// Call `f` on each function-local expression handle `expr` uses as an operand.
+///
+/// [`ImageSample::offset`] refers to [`Module::global_expressions`], so it is
+/// not visited.
+///
+/// [`ImageSample::offset`]: Expression::ImageSample::offset
+/// [`Module::global_expressions`]: crate::Module::global_expressions
+pub fn for_each_operand_mut(expr: &mut Expression, mut f: impl FnMut(&mut Handle<Expression>)) {
+    match *expr {
+        Expression::Compose {
+            ref mut components,
+            ty: _,
+        } => {
+            for component in components.iter_mut() {
+                f(component);
+            }
+        }
+        Expression::Access {
+            ref mut base,
+            ref mut index,
+        } => {
+            f(base);
+            f(index);
+        }
+        Expression::AccessIndex { ref mut base, .. } => f(base),
+        Expression::Splat { ref mut value, .. } => f(value),
+        Expression::Swizzle { ref mut vector, .. } => f(vector),
+        Expression::Load { ref mut pointer } => f(pointer),
+        Expression::ImageSample {
+            ref mut image,
+            ref mut sampler,
+            ref mut coordinate,
+            ref mut array_index,
+            offset: _,
+            ref mut level,
+            ref mut depth_ref,
+            gather: _,
+        } => {
+            f(image);
+            f(sampler);
+            f(coordinate);
+            if let Some(ref mut array_index) = *array_index {
+                f(array_index);
+            }
+            match *level {
+                crate::SampleLevel::Auto | crate::SampleLevel::Zero => {}
+                crate::SampleLevel::Exact(ref mut expr)
+                | crate::SampleLevel::Bias(ref mut expr) => f(expr),
+                crate::SampleLevel::Gradient {
+                    ref mut x,
+                    ref mut y,
+                } => {
+                    f(x);
+                    f(y);
+                }
+            }
+            if let Some(ref mut depth_ref) = *depth_ref {
+                f(depth_ref);
+            }
+        }
+        Expression::ImageLoad {
+            ref mut image,
+            ref mut coordinate,
+            ref mut array_index,
+            ref mut sample,
+            ref mut level,
+        } => {
+            f(image);
+            f(coordinate);
+            for expr in [array_index, sample, level].into_iter().flatten() {
+                f(expr);
+            }
+        }
+        Expression::ImageQuery {
+            ref mut image,
+            ref mut query,
+        } => {
+            f(image);
+            if let crate::ImageQuery::Size {
+                level: Some(ref mut level),
+            } = *query
+            {
+                f(level);
+            }
+        }
+        Expression::Unary { ref mut expr, .. } => f(expr),
+        Expression::Binary {
+            ref mut left,
+            ref mut right,
+            ..
+        } => {
+            f(left);
+            f(right);
+        }
+        Expression::Select {
+            ref mut condition,
+            ref mut accept,
+            ref mut reject,
+        } => {
+            f(condition);
+            f(accept);
+            f(reject);
+        }
+        Expression::Derivative { ref mut expr, .. } => f(expr),
+        Expression::Relational {
+            ref mut argument, ..
+        } => f(argument),
+        Expression::Math {
+            ref mut arg,
+            ref mut arg1,
+            ref mut arg2,
+            ref mut arg3,
+            fun: _,
+        } => {
+            f(arg);
+            for arg in [arg1, arg2, arg3].into_iter().flatten() {
+                f(arg);
+            }
+        }
+        Expression::As { ref mut expr, .. } => f(expr),
+        Expression::ArrayLength(ref mut expr) => f(expr),
+        Expression::RayQueryGetIntersection { ref mut query, .. } => f(query),
+        Expression::Literal(_)
+        | Expression::Constant(_)
+        | Expression::Override(_)
+        | Expression::ZeroValue(_)
+        | Expression::FunctionArgument(_)
+        | Expression::GlobalVariable(_)
+        | Expression::LocalVariable(_)
+        | Expression::CallResult(_)
+        | Expression::AtomicResult { .. }
+        | Expression::WorkGroupUniformLoadResult { .. }
+        | Expression::RayQueryProceedResult
+        | Expression::SubgroupBallotResult
+        | Expression::SubgroupOperationResult { .. } => {}
+    }
+}
+
+/// Call `f` on each expression handle `expr` uses as an operand.
+pub fn for_each_operand(expr: &Expression, mut f: impl FnMut(Handle<Expression>)) {
+    // Expressions are small, and this keeps the two visitors from drifting apart.
+    let mut expr = expr.clone();
+    for_each_operand_mut(&mut expr, |handle| f(*handle));
+}
+
+/// Call `f` on each expression handle `stmt` uses as an operand.
+///
+/// This doesn't visit [`Emit`] ranges, the statement's result expression (see
+/// [`result_mut`]), or the contents of nested blocks.
+///
+/// [`Emit`]: Statement::Emit
+pub fn for_each_statement_operand_mut(
+    stmt: &mut Statement,
+    mut f: impl FnMut(&mut Handle<Expression>),
+) {
+    match *stmt {
+        Statement::If {
+            ref mut condition, ..
+        } => f(condition),
+        Statement::Switch {
+            ref mut selector, ..
+        } => f(selector),
+        Statement::Loop {
+            ref mut break_if, ..
+        } => {
+            if let Some(ref mut break_if) = *break_if {
+                f(break_if);
+            }
+        }
+        Statement::Return { ref mut value } => {
+            if let Some(ref mut value) = *value {
+                f(value);
+            }
+        }
+        Statement::Store {
+            ref mut pointer,
+            ref mut value,
+        } => {
+            f(pointer);
+            f(value);
+        }
+        Statement::ImageStore {
+            ref mut image,
+            ref mut coordinate,
+            ref mut array_index,
+            ref mut value,
+        } => {
+            f(image);
+            f(coordinate);
+            if let Some(ref mut array_index) = *array_index {
+                f(array_index);
+            }
+            f(value);
+        }
+        Statement::Atomic {
+            ref mut pointer,
+            ref mut fun,
+            ref mut value,
+            result: _,
+        } => {
+            f(pointer);
+            if let crate::AtomicFunction::Exchange {
+                compare: Some(ref mut compare),
+            } = *fun
+            {
+                f(compare);
+            }
+            f(value);
+        }
+        Statement::WorkGroupUniformLoad {
+            ref mut pointer,
+            result: _,
+        } => f(pointer),
+        Statement::Call {
+            ref mut arguments, ..
+        } => {
+            for argument in arguments.iter_mut() {
+                f(argument);
+            }
+        }
+        Statement::RayQuery {
+            ref mut query,
+            ref mut fun,
+        } => {
+            f(query);
+            if let crate::RayQueryFunction::Initialize {
+                ref mut acceleration_structure,
+                ref mut descriptor,
+            } = *fun
+            {
+                f(acceleration_structure);
+                f(descriptor);
+            }
+        }
+        Statement::SubgroupBallot {
+            ref mut predicate,
+            result: _,
+        } => {
+            if let Some(ref mut predicate) = *predicate {
+                f(predicate);
+            }
+        }
+        Statement::SubgroupGather {
+            ref mut mode,
+            ref mut argument,
+            result: _,
+        } => {
+            match *mode {
+                crate::GatherMode::BroadcastFirst | crate::GatherMode::QuadSwap(_) => {}
+                crate::GatherMode::Broadcast(ref mut index)
+                | crate::GatherMode::Shuffle(ref mut index)
+                | crate::GatherMode::ShuffleDown(ref mut index)
+                | crate::GatherMode::ShuffleUp(ref mut index)
+                | crate::GatherMode::ShuffleXor(ref mut index)
+                | crate::GatherMode::QuadBroadcast(ref mut index) => f(index),
+            }
+            f(argument);
+        }
+        Statement::SubgroupCollectiveOperation {
+            ref mut argument, ..
+        } => f(argument),
+        Statement::Emit(_)
+        | Statement::Block(_)
+        | Statement::Break
+        | Statement::Continue
+        | Statement::Kill
+        | Statement::Demote
+        | Statement::Barrier(_) => {}
+    }
+}
+
+/// Call `f` on each expression handle `stmt` uses as an operand.
+///
+/// This visits the same handles as [`for_each_statement_operand_mut`].
+pub fn for_each_statement_operand(stmt: &Statement, mut f: impl FnMut(Handle<Expression>)) {
+    match *stmt {
+        Statement::If { condition, .. } => f(condition),
+        Statement::Switch { selector, .. } => f(selector),
+        Statement::Loop { break_if, .. } => {
+            if let Some(break_if) = break_if {
+                f(break_if);
+            }
+        }
+        Statement::Emit(_) | Statement::Block(_) => {}
+        // The remaining statements have no nested blocks, so they're cheap to clone.
+        _ => {
+            let mut stmt = stmt.clone();
+            for_each_statement_operand_mut(&mut stmt, |handle| f(*handle));
+        }
+    }
+}
+
+/// Return the result expression `stmt` introduces, if any.
+pub fn result_mut(stmt: &mut Statement) -> Option<&mut Handle<Expression>> {
+    match *stmt {
+        Statement::Atomic { ref mut result, .. } | Statement::Call { ref mut result, .. } => {
+            result.as_mut()
+        }
+        Statement::WorkGroupUniformLoad { ref mut result, .. }
+        | Statement::SubgroupBallot { ref mut result, .. }
+        | Statement::SubgroupGather { ref mut result, .. }
+        | Statement::SubgroupCollectiveOperation { ref mut result, .. }
+        | Statement::RayQuery {
+            fun: crate::RayQueryFunction::Proceed { ref mut result },
+            ..
+        } => Some(result),
+        _ => None,
+    }
+}
+
+/// Call `f` on each statement in `block`, including those in nested blocks.
+pub fn for_each_statement(block: &Block, f: &mut impl FnMut(&Statement)) {
+    for stmt in block.iter() {
+        f(stmt);
+        match *stmt {
+            Statement::Block(ref block) => for_each_statement(block, f),
+            Statement::If {
+                ref accept,
+                ref reject,
+                ..
+            } => {
+                for_each_statement(accept, f);
+                for_each_statement(reject, f);
+            }
+            Statement::Switch { ref cases, .. } => {
+                for case in cases.iter() {
+                    for_each_statement(&case.body, f);
+                }
+            }
+            Statement::Loop {
+                ref body,
+                ref continuing,
+                ..
+            } => {
+                for_each_statement(body, f);
+                for_each_statement(continuing, f);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Call `f` on each statement in `block`, including those in nested blocks.
+pub fn for_each_statement_mut(block: &mut Block, f: &mut impl FnMut(&mut Statement)) {
+    for stmt in block.iter_mut() {
+        f(stmt);
+        match *stmt {
+            Statement::Block(ref mut block) => for_each_statement_mut(block, f),
+            Statement::If {
+                ref mut accept,
+                ref mut reject,
+                ..
+            } => {
+                for_each_statement_mut(accept, f);
+                for_each_statement_mut(reject, f);
+            }
+            Statement::Switch { ref mut cases, .. } => {
+                for case in cases.iter_mut() {
+                    for_each_statement_mut(&mut case.body, f);
+                }
+            }
+            Statement::Loop {
+                ref mut body,
+                ref mut continuing,
+                ..
+            } => {
+                for_each_statement_mut(body, f);
+                for_each_statement_mut(continuing, f);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Return true if control never falls off the end of `statements`.
+///
+/// This follows the validator's notion of a block that has finished, which is
+/// what decides whether statements may follow it.
+pub fn block_finishes(statements: &[Statement]) -> bool {
+    match statements.last() {
+        Some(
+            &(Statement::Break | Statement::Continue | Statement::Return { .. } | Statement::Kill),
+        ) => true,
+        Some(&Statement::Block(ref inner)) => block_finishes(inner),
+        _ => false,
+    }
+}
+
+/// Return the set of `function`'s expressions that contribute to an [`Access`] index.
+///
+/// Giving any of these a constant value could let the validator check the
+/// index against the length of the thing indexed, so passes must not make them
+/// constant when they weren't before.
+///
+/// [`Access`]: Expression::Access
+pub fn index_operands(function: &Function) -> HandleSet<Expression> {
+    let mut set = HandleSet::for_arena(&function.expressions);
+    for (handle, expr) in function.expressions.iter().rev() {
+        if let Expression::Access { index, .. } = *expr {
+            set.insert(index);
+        }
+        if set.contains(handle) {
+            for_each_operand(expr, |operand| {
+                set.insert(operand);
+            });
+        }
+    }
+    set
+}
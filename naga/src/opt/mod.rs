@@ -0,0 +1,187 @@
+/*!
+Optional optimization passes over [`Module`]s.
+
+Naga's front ends produce straightforward IR, and its back ends translate it
+fairly literally. The passes here simplify a validated module before it is
+handed to a back end:
+
+- [`inline_functions`] replaces calls with the body of the function called.
+
+- [`propagate_constants`] evaluates expressions whose operands are known,
+  replaces loads from locals that are never written with their initializers,
+  and reduces `if` statements with constant conditions to the branch taken.
+
+- [`eliminate_common_subexpressions`] reuses values already computed in an
+  enclosing block instead of computing them again.
+
+- [`eliminate_dead_stores`] removes stores to local variables that are never
+  read, and the variables themselves.
+
+Each pass leaves a module that passes validation, provided the original did.
+Use [`optimize`] to run a selection of them in a sensible order.
+
+[`Module`]: crate::Module
+*/
+
+mod cse;
+mod dead_stores;
+mod handles;
+mod rebuild;
+
+use rebuild::Actions;
+
+bitflags::bitflags! {
+    /// The passes [`optimize`] should run.
+    #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+    #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct Passes: u32 {
+        /// Inline function calls. See [`inline_functions`].
+        const INLINE = 0x1;
+
+        /// Fold constant expressions and branches. See [`propagate_constants`].
+        const CONSTANT_PROPAGATION = 0x2;
+
+        /// Reuse values already computed. See [`eliminate_common_subexpressions`].
+        const COMMON_SUBEXPRESSIONS = 0x4;
+
+        /// Remove unread local variables. See [`eliminate_dead_stores`].
+        const DEAD_STORES = 0x8;
+    }
+}
+
+impl Default for Passes {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// Run the passes selected by `passes` over `module`, then compact it.
+///
+/// Inlining and constant propagation run first, in a single walk over each
+/// function, so that constant arguments are folded into the inlined bodies.
+/// Common subexpression elimination and dead store elimination follow, and
+/// then [`compact`] removes whatever the passes left unused.
+///
+/// Functions whose every call was inlined are kept, as are unused globals and
+/// overrides, so the module's interface doesn't change. Use
+/// [`compact_entry_points`] to drop them once the entry points are known.
+///
+/// # Panics
+///
+/// If `module` has not passed validation, this may panic.
+///
+/// [`compact`]: crate::compact::compact
+/// [`compact_entry_points`]: crate::compact::compact_entry_points
+pub fn optimize(module: &mut crate::Module, passes: Passes) {
+    let actions = Actions {
+        inline: passes.contains(Passes::INLINE),
+        fold: passes.contains(Passes::CONSTANT_PROPAGATION),
+    };
+    if actions.inline || actions.fold {
+        rebuild::rebuild_functions(module, actions);
+    }
+    if passes.contains(Passes::COMMON_SUBEXPRESSIONS) {
+        eliminate_common_subexpressions(module);
+    }
+    if passes.contains(Passes::DEAD_STORES) {
+        // This compacts the module as part of its work.
+        eliminate_dead_stores(module);
+    } else {
+        crate::compact::compact(module);
+    }
+}
+
+/// Replace calls to suitable functions with the body of the function called.
+///
+/// A function can be inlined if its only [`Return`] statement is the last
+/// statement of its body. Its local variables become locals of the caller;
+/// inside loops, they are explicitly reinitialized at each call site. Calls
+/// are not inlined across different diagnostic filters, or when a constant
+/// argument would become an index the validator checks against the indexed
+/// value's length.
+///
+/// The callees themselves remain in the module.
+///
+/// # Panics
+///
+/// If `module` has not passed validation, this may panic.
+///
+/// [`Return`]: crate::Statement::Return
+pub fn inline_functions(module: &mut crate::Module) {
+    rebuild::rebuild_functions(
+        module,
+        Actions {
+            inline: true,
+            fold: false,
+        },
+    );
+}
+
+/// Evaluate constant expressions in function bodies.
+///
+/// This uses [`ConstantEvaluator`] to fold every expression whose operands
+/// are all constant, replaces loads from local variables that are never
+/// written or otherwise referred to by a pointer with the variable's
+/// initializer, and replaces `if` statements with constant conditions with the
+/// branch taken. Expressions that contribute to indices are left alone, so
+/// the validator's bounds checks see the same expressions as before.
+///
+/// # Panics
+///
+/// If `module` has not passed validation, this may panic.
+///
+/// [`ConstantEvaluator`]: crate::proc::ConstantEvaluator
+pub fn propagate_constants(module: &mut crate::Module) {
+    rebuild::rebuild_functions(
+        module,
+        Actions {
+            inline: false,
+            fold: true,
+        },
+    );
+}
+
+/// Redirect uses of redundant pure expressions to an identical earlier one.
+///
+/// See [`Passes::COMMON_SUBEXPRESSIONS`]. The redundant expressions remain in
+/// the arena, unused, until the module is compacted.
+///
+/// # Panics
+///
+/// If `module` has not passed validation, this may panic.
+pub fn eliminate_common_subexpressions(module: &mut crate::Module) {
+    for (_, function) in module.functions.iter_mut() {
+        cse::eliminate_common_subexpressions(function);
+    }
+    for entry_point in module.entry_points.iter_mut() {
+        cse::eliminate_common_subexpressions(&mut entry_point.function);
+    }
+}
+
+/// Remove stores to local variables that are never read, and unused locals.
+///
+/// Removing the stores leaves the expressions that computed the stored values
+/// unused, so this compacts `module` before dropping the locals that no
+/// remaining expression refers to.
+///
+/// # Panics
+///
+/// If `module` has not passed validation, this may panic.
+pub fn eliminate_dead_stores(module: &mut crate::Module) {
+    for (_, function) in module.functions.iter_mut() {
+        dead_stores::remove_stores(function);
+    }
+    for entry_point in module.entry_points.iter_mut() {
+        dead_stores::remove_stores(&mut entry_point.function);
+    }
+
+    crate::compact::compact(module);
+
+    for (_, function) in module.functions.iter_mut() {
+        dead_stores::remove_locals(function);
+    }
+    for entry_point in module.entry_points.iter_mut() {
+        dead_stores::remove_locals(&mut entry_point.function);
+    }
+}
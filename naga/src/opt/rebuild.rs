@@ -0,0 +1,703 @@
+//! Function inlining and constant propagation.
+//!
+//! Both passes work by rebuilding each function's expression arena and body in
+//! statement order, which lets them splice a callee's statements into its
+//! caller and replace expressions with their folded values as they go, while
+//! producing fresh [`Emit`] statements that cover exactly the expressions that
+//! need them.
+//!
+//! [`Emit`]: crate::Statement::Emit
+
+use std::mem;
+
+use super::handles;
+use crate::arena::{HandleSet, HandleVec};
+use crate::proc::{ConstantEvaluator, Emitter, ExpressionKindTracker};
+use crate::{
+    diagnostic_filter::DiagnosticFilterNode, Arena, Block, Expression, Function, Handle,
+    LocalVariable, Module, NamedExpressions, Span, Statement,
+};
+
+/// What to do while rebuilding functions.
+#[derive(Clone, Copy)]
+pub struct Actions {
+    /// Replace calls to suitable functions with the callee's body.
+    pub inline: bool,
+
+    /// Evaluate constant expressions, and branch on constant conditions.
+    pub fold: bool,
+}
+
+/// Rebuild all of `module`'s functions and entry points as directed by `actions`.
+///
+/// Functions are processed in arena order, which puts callees before their
+/// callers, so calls are inlined using the callee's already-rebuilt body.
+pub fn rebuild_functions(module: &mut Module, actions: Actions) {
+    let mut functions = mem::take(&mut module.functions);
+    let mut callees = HandleVec::with_capacity(functions.len());
+
+    let handles: Vec<_> = functions.iter().map(|(handle, _)| handle).collect();
+    for handle in handles {
+        let function = mem::take(&mut functions[handle]);
+        let function = rebuild_function(module, &functions, &callees, actions, function);
+        callees.insert(handle, Callee::new(module, &function));
+        functions[handle] = function;
+    }
+
+    let mut entry_points = mem::take(&mut module.entry_points);
+    for entry_point in entry_points.iter_mut() {
+        let function = mem::take(&mut entry_point.function);
+        entry_point.function = rebuild_function(module, &functions, &callees, actions, function);
+    }
+
+    module.functions = functions;
+    module.entry_points = entry_points;
+}
+
+fn rebuild_function(
+    module: &mut Module,
+    functions: &Arena<Function>,
+    callees: &HandleVec<Function, Callee>,
+    actions: Actions,
+    function: Function,
+) -> Function {
+    // Diagnostic filter scopes cover ranges of the expression arena, which
+    // we're about to renumber, so leave functions that have them alone.
+    if !function.diagnostic_filter_scopes.is_empty() {
+        return function;
+    }
+
+    let mut rebuilder = Rebuilder {
+        module,
+        functions,
+        callees,
+        actions,
+        diagnostic_filter_leaf: function.diagnostic_filter_leaf,
+        expressions: Arena::new(),
+        local_variables: Arena::new(),
+        named_expressions: NamedExpressions::default(),
+        tracker: ExpressionKindTracker::new(),
+        emitter: Emitter::default(),
+        loop_depth: 0,
+    };
+    let mut frame = Frame::new(&function, None, actions);
+
+    for (handle, local) in function.local_variables.iter() {
+        let new = rebuilder.local_variables.append(
+            LocalVariable {
+                name: local.name.clone(),
+                ty: local.ty,
+                init: None,
+            },
+            function.local_variables.get_span(handle),
+        );
+        frame.local_variables.insert(handle, new);
+    }
+
+    let mut body = Block::with_capacity(function.body.len());
+    rebuilder.emitter.start(&rebuilder.expressions);
+    // Copy initializers first, so that loads of constant locals can refer to
+    // them from any block.
+    for (handle, local) in function.local_variables.iter() {
+        if let Some(init) = local.init {
+            let init = rebuilder.expression(&mut frame, &mut body, init);
+            rebuilder.local_variables[frame.local_variables[handle]].init = Some(init);
+        }
+    }
+    rebuilder.statements(&mut frame, function.body.span_iter(), &mut body);
+    body.extend(rebuilder.emitter.finish(&rebuilder.expressions));
+    rebuilder.name_expressions(&frame, true);
+
+    Function {
+        name: function.name.clone(),
+        arguments: function.arguments.clone(),
+        result: function.result.clone(),
+        local_variables: rebuilder.local_variables,
+        expressions: rebuilder.expressions,
+        named_expressions: rebuilder.named_expressions,
+        body,
+        diagnostic_filter_leaf: function.diagnostic_filter_leaf,
+        diagnostic_filter_scopes: Vec::new(),
+    }
+}
+
+/// What we need to know about a function to decide whether to inline calls to it.
+struct Callee {
+    /// The function's body, with any [`Return`] statements in tail position
+    /// removed if the function returns no value, or `None` if calls to the
+    /// function can't be inlined.
+    ///
+    /// Inlining splices this into the caller, which only works if control
+    /// always reaches its end. So the only [`Return`] statement this may
+    /// contain is its last statement, and it must not otherwise finish.
+    ///
+    /// [`Return`]: Statement::Return
+    body: Option<Block>,
+
+    /// True if some function argument contributes to an [`Access`] index.
+    ///
+    /// Passing a constant for such an argument could produce an index the
+    /// validator would check against the indexed value's length, so we don't
+    /// inline those calls.
+    ///
+    /// [`Access`]: Expression::Access
+    index_sensitive: bool,
+
+    diagnostic_filter_leaf: Option<Handle<DiagnosticFilterNode>>,
+}
+
+impl Callee {
+    fn new(module: &Module, function: &Function) -> Self {
+        let mut body = function.body.clone();
+        // Front ends add a `return` to the end of every path through a
+        // function that returns nothing, but we don't need them.
+        if function.result.is_none() {
+            strip_tail_returns(&mut body);
+        }
+
+        let (statements, _) = split_return(&body);
+        let mut returns = 0;
+        handles::for_each_statement(&body, &mut |stmt| {
+            if let Statement::Return { .. } = *stmt {
+                returns += 1;
+            }
+        });
+        let trailing_returns = statements.len() != body.len();
+
+        let inlinable = function.diagnostic_filter_scopes.is_empty()
+            && returns == usize::from(trailing_returns)
+            && !handles::block_finishes(statements)
+            && function.local_variables.iter().all(|(_, local)| {
+                !matches!(module.types[local.ty].inner, crate::TypeInner::RayQuery)
+            });
+
+        let index_sensitive = handles::index_operands(function).iter().any(|handle| {
+            matches!(
+                function.expressions[handle],
+                Expression::FunctionArgument(_)
+            )
+        });
+
+        Self {
+            body: inlinable.then_some(body),
+            index_sensitive,
+            diagnostic_filter_leaf: function.diagnostic_filter_leaf,
+        }
+    }
+}
+
+/// Remove [`Return`] statements after which control would leave the function anyway.
+///
+/// [`Return`]: Statement::Return
+fn strip_tail_returns(block: &mut Block) {
+    match block.last_mut() {
+        Some(&mut Statement::Return { value: None }) => {
+            block.cull(block.len() - 1..);
+        }
+        Some(&mut Statement::Block(ref mut inner)) => strip_tail_returns(inner),
+        Some(&mut Statement::If {
+            ref mut accept,
+            ref mut reject,
+            ..
+        }) => {
+            strip_tail_returns(accept);
+            strip_tail_returns(reject);
+        }
+        _ => {}
+    }
+}
+
+/// Split `body` into the statements before a trailing [`Return`], and its value.
+///
+/// [`Return`]: Statement::Return
+fn split_return(body: &Block) -> (&[Statement], Option<Handle<Expression>>) {
+    match body.split_last() {
+        Some((&Statement::Return { value }, statements)) => (statements, value),
+        _ => (body, None),
+    }
+}
+
+/// The function whose statements we're currently copying.
+///
+/// When inlining, this is the callee; otherwise, it's the function being rebuilt.
+struct Frame<'f> {
+    function: &'f Function,
+
+    /// The new handle for each of `function`'s expressions, once copied.
+    expressions: HandleVec<Expression, Option<Handle<Expression>>>,
+
+    /// The new handle for each of `function`'s local variables.
+    local_variables: HandleVec<LocalVariable, Handle<LocalVariable>>,
+
+    /// The caller's arguments, if we're inlining `function`.
+    arguments: Option<Vec<Handle<Expression>>>,
+
+    /// Local variables that are only ever loaded from.
+    ///
+    /// Loads from these are replaced with the variable's initializer.
+    constant_locals: HandleSet<LocalVariable>,
+
+    /// Expressions that contribute to [`Access`] indices, which we mustn't fold.
+    ///
+    /// See [`handles::index_operands`].
+    ///
+    /// [`Access`]: Expression::Access
+    index_operands: HandleSet<Expression>,
+}
+
+impl<'f> Frame<'f> {
+    fn new(
+        function: &'f Function,
+        arguments: Option<Vec<Handle<Expression>>>,
+        actions: Actions,
+    ) -> Self {
+        let mut expressions = HandleVec::with_capacity(function.expressions.len());
+        expressions.resize(function.expressions.len(), None);
+
+        let (constant_locals, index_operands) = if actions.fold {
+            let index_operands = handles::index_operands(function);
+            (constant_locals(function, &index_operands), index_operands)
+        } else {
+            (HandleSet::new(), HandleSet::new())
+        };
+
+        Self {
+            function,
+            expressions,
+            local_variables: HandleVec::with_capacity(function.local_variables.len()),
+            arguments,
+            constant_locals,
+            index_operands,
+        }
+    }
+}
+
+/// Return the set of `function`'s local variables that are only ever loaded from.
+fn constant_locals(
+    function: &Function,
+    index_operands: &HandleSet<Expression>,
+) -> HandleSet<LocalVariable> {
+    let mut locals = HandleSet::for_arena(&function.local_variables);
+    locals.insert_iter(function.local_variables.iter().map(|(handle, _)| handle));
+
+    let mut disqualify = |pointer: Handle<Expression>| {
+        if let Expression::LocalVariable(local) = function.expressions[pointer] {
+            locals.remove(local);
+        }
+    };
+
+    for (handle, expr) in function.expressions.iter() {
+        match *expr {
+            Expression::Load { pointer } => {
+                if index_operands.contains(handle) {
+                    disqualify(pointer);
+                }
+            }
+            _ => handles::for_each_operand(expr, &mut disqualify),
+        }
+    }
+    handles::for_each_statement(&function.body, &mut |stmt| {
+        handles::for_each_statement_operand(stmt, &mut disqualify);
+    });
+    for &handle in function.named_expressions.keys() {
+        disqualify(handle);
+    }
+
+    locals
+}
+
+struct Rebuilder<'a> {
+    module: &'a mut Module,
+    functions: &'a Arena<Function>,
+    callees: &'a HandleVec<Function, Callee>,
+    actions: Actions,
+
+    /// The diagnostic filters applying to the function being rebuilt.
+    diagnostic_filter_leaf: Option<Handle<DiagnosticFilterNode>>,
+
+    expressions: Arena<Expression>,
+    local_variables: Arena<LocalVariable>,
+    named_expressions: NamedExpressions,
+    tracker: ExpressionKindTracker,
+    emitter: Emitter,
+
+    /// The number of loops enclosing the statements being copied.
+    loop_depth: usize,
+}
+
+impl Rebuilder<'_> {
+    /// Return the new handle for `frame`'s expression `handle`, copying it if needed.
+    ///
+    /// If the emitter is running, any new expression that needs it is emitted
+    /// into `out`.
+    fn expression(
+        &mut self,
+        frame: &mut Frame,
+        out: &mut Block,
+        handle: Handle<Expression>,
+    ) -> Handle<Expression> {
+        if let Some(new) = frame.expressions[handle] {
+            return new;
+        }
+
+        let span = frame.function.expressions.get_span(handle);
+        let mut expr = frame.function.expressions[handle].clone();
+        let constant_local = match expr {
+            Expression::Load { pointer } if !frame.index_operands.contains(handle) => {
+                self.load_is_constant(frame, pointer)
+            }
+            _ => None,
+        };
+        let new = match expr {
+            Expression::FunctionArgument(index) if frame.arguments.is_some() => {
+                frame.arguments.as_ref().unwrap()[index as usize]
+            }
+            Expression::LocalVariable(local) => self.append_raw(
+                out,
+                Expression::LocalVariable(frame.local_variables[local]),
+                span,
+            ),
+            Expression::Load { .. } if constant_local.is_some() => {
+                let local = &frame.function.local_variables[constant_local.unwrap()];
+                match local.init {
+                    Some(init) => self.expression(frame, out, init),
+                    None => self.append(out, Expression::ZeroValue(local.ty), span),
+                }
+            }
+            _ => {
+                handles::for_each_operand_mut(&mut expr, |operand| {
+                    *operand = self.expression(frame, out, *operand);
+                });
+                if frame.index_operands.contains(handle) {
+                    self.append_raw(out, expr, span)
+                } else {
+                    self.append(out, expr, span)
+                }
+            }
+        };
+
+        frame.expressions[handle] = Some(new);
+        new
+    }
+
+    /// If `pointer` refers to one of `frame`'s constant locals, return it.
+    fn load_is_constant(
+        &self,
+        frame: &Frame,
+        pointer: Handle<Expression>,
+    ) -> Option<Handle<LocalVariable>> {
+        match frame.function.expressions[pointer] {
+            Expression::LocalVariable(local)
+                if self.actions.fold && frame.constant_locals.contains(local) =>
+            {
+                Some(local)
+            }
+            _ => None,
+        }
+    }
+
+    /// Append `expr`, evaluating it first if we're folding constants.
+    fn append(&mut self, out: &mut Block, expr: Expression, span: Span) -> Handle<Expression> {
+        if self.actions.fold {
+            let mut evaluator = ConstantEvaluator::for_wgsl_function(
+                self.module,
+                &mut self.expressions,
+                &mut self.tracker,
+                &mut self.emitter,
+                out,
+                false,
+            );
+            // If evaluation fails, the expression would fail at run time too,
+            // so leave it for then.
+            if let Ok(handle) = evaluator.try_eval_and_append(expr.clone(), span) {
+                return handle;
+            }
+        }
+        self.append_raw(out, expr, span)
+    }
+
+    /// Append `expr` as is, keeping it out of any [`Emit`] range if it must be.
+    ///
+    /// [`Emit`]: Statement::Emit
+    fn append_raw(&mut self, out: &mut Block, expr: Expression, span: Span) -> Handle<Expression> {
+        let kind = self.tracker.type_of_with_expr(&expr);
+        let interrupt = expr.needs_pre_emit() && self.emitter.is_running();
+        if interrupt {
+            out.extend(self.emitter.finish(&self.expressions));
+        }
+        let handle = self.expressions.append(expr, span);
+        if interrupt {
+            self.emitter.start(&self.expressions);
+        }
+        self.tracker.insert(handle, kind);
+        handle
+    }
+
+    /// Copy `statements` from `frame` into `out`.
+    ///
+    /// The emitter must be running on entry, and is left running on exit.
+    /// Return true if `out` has finished, in the validator's sense; in that
+    /// case, the remaining statements are unreachable and are dropped.
+    fn statements<'s>(
+        &mut self,
+        frame: &mut Frame,
+        statements: impl Iterator<Item = (&'s Statement, &'s Span)>,
+        out: &mut Block,
+    ) -> bool {
+        for (stmt, &span) in statements {
+            if self.statement(frame, stmt, span, out) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Copy `block` from `frame` into a new block.
+    ///
+    /// Return the new block, and whether it has finished.
+    fn block(&mut self, frame: &mut Frame, block: &Block) -> (Block, bool) {
+        let mut out = Block::with_capacity(block.len());
+        self.emitter.start(&self.expressions);
+        let finished = self.statements(frame, block.span_iter(), &mut out);
+        out.extend(self.emitter.finish(&self.expressions));
+        (out, finished)
+    }
+
+    /// Copy `stmt` from `frame` into `out`, as for [`Self::statements`].
+    fn statement(
+        &mut self,
+        frame: &mut Frame,
+        stmt: &Statement,
+        span: Span,
+        out: &mut Block,
+    ) -> bool {
+        match *stmt {
+            Statement::Emit(ref range) => {
+                for handle in range.clone() {
+                    self.expression(frame, out, handle);
+                }
+                false
+            }
+            Statement::Block(ref block) => {
+                out.extend(self.emitter.finish(&self.expressions));
+                let (block, finished) = self.block(frame, block);
+                out.push(Statement::Block(block), span);
+                self.emitter.start(&self.expressions);
+                finished
+            }
+            Statement::If {
+                condition,
+                ref accept,
+                ref reject,
+            } => {
+                let condition = self.expression(frame, out, condition);
+                out.extend(self.emitter.finish(&self.expressions));
+                let finished = match self.expressions[condition] {
+                    Expression::Literal(crate::Literal::Bool(value)) if self.actions.fold => {
+                        let (block, finished) =
+                            self.block(frame, if value { accept } else { reject });
+                        if !block.is_empty() {
+                            out.push(Statement::Block(block), span);
+                        }
+                        finished
+                    }
+                    _ => {
+                        let (accept, _) = self.block(frame, accept);
+                        let (reject, _) = self.block(frame, reject);
+                        out.push(
+                            Statement::If {
+                                condition,
+                                accept,
+                                reject,
+                            },
+                            span,
+                        );
+                        false
+                    }
+                };
+                self.emitter.start(&self.expressions);
+                finished
+            }
+            Statement::Switch {
+                selector,
+                ref cases,
+            } => {
+                let selector = self.expression(frame, out, selector);
+                out.extend(self.emitter.finish(&self.expressions));
+                let cases = cases
+                    .iter()
+                    .map(|case| crate::SwitchCase {
+                        value: case.value,
+                        body: self.block(frame, &case.body).0,
+                        fall_through: case.fall_through,
+                    })
+                    .collect();
+                out.push(Statement::Switch { selector, cases }, span);
+                self.emitter.start(&self.expressions);
+                false
+            }
+            Statement::Loop {
+                ref body,
+                ref continuing,
+                break_if,
+            } => {
+                out.extend(self.emitter.finish(&self.expressions));
+                self.loop_depth += 1;
+                let (body, _) = self.block(frame, body);
+                let (continuing, _) = self.block(frame, continuing);
+                self.loop_depth -= 1;
+                let break_if = break_if.map(|break_if| self.expression(frame, out, break_if));
+                out.push(
+                    Statement::Loop {
+                        body,
+                        continuing,
+                        break_if,
+                    },
+                    span,
+                );
+                self.emitter.start(&self.expressions);
+                false
+            }
+            Statement::Call {
+                function,
+                ref arguments,
+                result,
+            } => {
+                let arguments: Vec<_> = arguments
+                    .iter()
+                    .map(|&argument| self.expression(frame, out, argument))
+                    .collect();
+                if self.can_inline(function, &arguments) {
+                    self.inline_call(frame, out, function, arguments, result)
+                } else {
+                    self.simple_statement(frame, stmt, span, out)
+                }
+            }
+            _ => self.simple_statement(frame, stmt, span, out),
+        }
+    }
+
+    /// Copy a statement that has no nested blocks.
+    fn simple_statement(
+        &mut self,
+        frame: &mut Frame,
+        stmt: &Statement,
+        span: Span,
+        out: &mut Block,
+    ) -> bool {
+        let mut stmt = stmt.clone();
+        handles::for_each_statement_operand_mut(&mut stmt, |operand| {
+            *operand = self.expression(frame, out, *operand);
+        });
+        out.extend(self.emitter.finish(&self.expressions));
+        if let Some(result) = handles::result_mut(&mut stmt) {
+            let expr = frame.function.expressions[*result].clone();
+            let span = frame.function.expressions.get_span(*result);
+            let new = self.append_raw(out, expr, span);
+            frame.expressions[*result] = Some(new);
+            *result = new;
+        }
+        let finished = matches!(
+            stmt,
+            Statement::Break | Statement::Continue | Statement::Return { .. } | Statement::Kill
+        );
+        out.push(stmt, span);
+        self.emitter.start(&self.expressions);
+        finished
+    }
+
+    fn can_inline(&self, function: Handle<Function>, arguments: &[Handle<Expression>]) -> bool {
+        let Some(callee) = self.callees.get(function) else {
+            return false;
+        };
+        self.actions.inline
+            && callee.body.is_some()
+            && callee.diagnostic_filter_leaf == self.diagnostic_filter_leaf
+            && !(callee.index_sensitive
+                && arguments
+                    .iter()
+                    .any(|&argument| self.tracker.is_const(argument)))
+    }
+
+    /// Splice the body of `function` into `out` in place of a call to it.
+    ///
+    /// Return true if the spliced statements finish `out`.
+    fn inline_call(
+        &mut self,
+        frame: &mut Frame,
+        out: &mut Block,
+        function: Handle<Function>,
+        arguments: Vec<Handle<Expression>>,
+        result: Option<Handle<Expression>>,
+    ) -> bool {
+        let functions = self.functions;
+        let callee = &functions[function];
+        let body = self.callees[function].body.as_ref().unwrap();
+        let mut callee_frame = Frame::new(callee, Some(arguments), self.actions);
+
+        for (handle, local) in callee.local_variables.iter() {
+            let new = self.local_variables.append(
+                LocalVariable {
+                    name: local.name.clone(),
+                    ty: local.ty,
+                    init: None,
+                },
+                callee.local_variables.get_span(handle),
+            );
+            callee_frame.local_variables.insert(handle, new);
+        }
+
+        // Outside loops, each call site runs at most once, so the callee's
+        // locals can keep their initializers. Inside a loop, we must
+        // reinitialize them on each iteration, as the call would have.
+        let mut stores = Vec::new();
+        for (handle, local) in callee.local_variables.iter() {
+            let new = callee_frame.local_variables[handle];
+            let span = callee.local_variables.get_span(handle);
+            let init = local
+                .init
+                .map(|init| self.expression(&mut callee_frame, out, init));
+            if self.loop_depth == 0 {
+                self.local_variables[new].init = init;
+            } else {
+                let value = init
+                    .unwrap_or_else(|| self.append_raw(out, Expression::ZeroValue(local.ty), span));
+                let pointer = self.append_raw(out, Expression::LocalVariable(new), span);
+                stores.push((Statement::Store { pointer, value }, span));
+            }
+        }
+        if !stores.is_empty() {
+            out.extend(self.emitter.finish(&self.expressions));
+            for (store, span) in stores {
+                out.push(store, span);
+            }
+            self.emitter.start(&self.expressions);
+        }
+
+        let (statements, value) = split_return(body);
+        let spans = body.span_iter().map(|(_, span)| span);
+        if self.statements(&mut callee_frame, statements.iter().zip(spans), out) {
+            return true;
+        }
+
+        if let (Some(result), Some(value)) = (result, value) {
+            frame.expressions[result] = Some(self.expression(&mut callee_frame, out, value));
+        }
+        self.name_expressions(&callee_frame, false);
+        false
+    }
+
+    /// Carry over the names of `frame`'s expressions that we've copied.
+    ///
+    /// If `replace` is false, keep any name the new expression already has.
+    fn name_expressions(&mut self, frame: &Frame, replace: bool) {
+        for (&handle, name) in frame.function.named_expressions.iter() {
+            let Some(new) = frame.expressions[handle] else {
+                continue;
+            };
+            if replace || !self.named_expressions.contains_key(&new) {
+                self.named_expressions.insert(new, name.clone());
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::iter;
 
 use arrayvec::ArrayVec;
+use half::f16;
 
 use crate::{
     arena::{Arena, Handle, HandleVec, UniqueArena},
@@ -198,7 +199,9 @@ gen_component_wise_extractor! {
     component_wise_scalar -> Scalar,
     literals: [
         AbstractFloat => AbstractFloat: f64,
+        F64 => F64: f64,
         F32 => F32: f32,
+        F16 => F16: f16,
         AbstractInt => AbstractInt: i64,
         U32 => U32: u32,
         I32 => I32: i32,
@@ -218,7 +221,9 @@ gen_component_wise_extractor! {
     component_wise_float -> Float,
     literals: [
         AbstractFloat => Abstract: f64,
+        F64 => F64: f64,
         F32 => F32: f32,
+        F16 => F16: f16,
     ],
     scalar_kinds: [
         Float,
@@ -243,7 +248,9 @@ gen_component_wise_extractor! {
     literals: [
         AbstractFloat => AbstractFloat: f64,
         AbstractInt => AbstractInt: i64,
+        F64 => F64: f64,
         F32 => F32: f32,
+        F16 => F16: f16,
         I32 => I32: i32,
     ],
     scalar_kinds: [
@@ -301,6 +308,12 @@ pub struct ConstantEvaluator<'a> {
     /// [`Splat`]: Expression::Splat
     types: &'a mut UniqueArena<Type>,
 
+    /// The module's special types.
+    ///
+    /// We need these to find the result types of built-ins like `modf` and
+    /// `frexp`, which return predeclared structs.
+    special_types: &'a crate::SpecialTypes,
+
     /// The module's constant arena.
     constants: &'a Arena<Constant>,
 
@@ -410,7 +423,7 @@ impl ExpressionKindTracker {
         tracker
     }
 
-    fn type_of_with_expr(&self, expr: &Expression) -> ExpressionKind {
+    pub(crate) fn type_of_with_expr(&self, expr: &Expression) -> ExpressionKind {
         use crate::MathFunction as Mf;
         match *expr {
             Expression::Literal(_) | Expression::ZeroValue(_) | Expression::Constant(_) => {
@@ -453,33 +466,12 @@ impl ExpressionKindTracker {
                     arg3.map(|arg| self.type_of(arg))
                         .unwrap_or(ExpressionKind::Const),
                 )
-                .max(
-                    if matches!(
-                        fun,
-                        Mf::Dot
-                            | Mf::Outer
-                            | Mf::Cross
-                            | Mf::Distance
-                            | Mf::Length
-                            | Mf::Normalize
-                            | Mf::FaceForward
-                            | Mf::Reflect
-                            | Mf::Refract
-                            | Mf::Ldexp
-                            | Mf::Modf
-                            | Mf::Mix
-                            | Mf::Frexp
-                    ) {
-                        ExpressionKind::Const
-                    } else {
-                        ExpressionKind::ImplConst
-                    },
-                ),
-            Expression::As { convert, expr, .. } => self.type_of(expr).max(if convert.is_some() {
-                ExpressionKind::ImplConst
-            } else {
-                ExpressionKind::Const
-            }),
+                .max(if matches!(fun, Mf::Outer | Mf::Inverse) {
+                    ExpressionKind::Const
+                } else {
+                    ExpressionKind::ImplConst
+                }),
+            Expression::As { expr, .. } => self.type_of(expr),
             Expression::Select {
                 condition,
                 accept,
@@ -487,8 +479,7 @@ impl ExpressionKindTracker {
             } => self
                 .type_of(condition)
                 .max(self.type_of(accept))
-                .max(self.type_of(reject))
-                .max(ExpressionKind::Const),
+                .max(self.type_of(reject)),
             Expression::Relational { argument, .. } => self.type_of(argument),
             Expression::ArrayLength(expr) => self.type_of(expr),
             _ => ExpressionKind::Runtime,
@@ -545,6 +536,10 @@ pub enum ConstantEvaluatorError {
     InvalidMathArgCount(crate::MathFunction, usize, usize),
     #[error("value of `low` is greater than `high` for clamp built-in function")]
     InvalidClamp,
+    #[error("Cannot apply {0:?} built-in function to type")]
+    InvalidRelationalArg(crate::RelationalFunction),
+    #[error("Cannot select between the arguments of the select built-in function")]
+    InvalidSelectArgs,
     #[error("Splat is defined only on scalar values")]
     SplatScalarOnly,
     #[error("Can only swizzle vector constants")]
@@ -628,6 +623,7 @@ impl<'a> ConstantEvaluator<'a> {
         Self {
             behavior,
             types: &mut module.types,
+            special_types: &module.special_types,
             constants: &module.constants,
             overrides: &module.overrides,
             expressions: &mut module.global_expressions,
@@ -659,6 +655,7 @@ impl<'a> ConstantEvaluator<'a> {
                 WgslRestrictions::Runtime(local_data)
             }),
             types: &mut module.types,
+            special_types: &module.special_types,
             constants: &module.constants,
             overrides: &module.overrides,
             expressions,
@@ -684,6 +681,7 @@ impl<'a> ConstantEvaluator<'a> {
                 block,
             })),
             types: &mut module.types,
+            special_types: &module.special_types,
             constants: &module.constants,
             overrides: &module.overrides,
             expressions,
@@ -766,18 +764,20 @@ impl<'a> ConstantEvaluator<'a> {
         span: Span,
     ) -> Result<Handle<Expression>, ConstantEvaluatorError> {
         match self.expression_kind_tracker.type_of_with_expr(&expr) {
-            ExpressionKind::ImplConst => self.try_eval_and_append_impl(&expr, span),
-            ExpressionKind::Const => {
+            ExpressionKind::ImplConst | ExpressionKind::Const => {
                 let eval_result = self.try_eval_and_append_impl(&expr, span);
                 // We should be able to evaluate `Const` expressions at this
                 // point. If we failed to, then that probably means we just
-                // haven't implemented that part of constant evaluation. Work
-                // around this by simply emitting it as a run-time expression.
+                // haven't implemented that part of constant evaluation, or
+                // that its operands have the wrong types. Work around this by
+                // simply emitting it as a run-time expression, and leave it to
+                // the validator to report any type errors.
                 if self.behavior.has_runtime_restrictions()
                     && matches!(
                         eval_result,
                         Err(ConstantEvaluatorError::NotImplemented(_)
-                            | ConstantEvaluatorError::InvalidBinaryOpArgs,)
+                            | ConstantEvaluatorError::InvalidBinaryOpArgs
+                            | ConstantEvaluatorError::InvalidMathArg,)
                     )
                 {
                     Ok(self.append_expr(expr, span, ExpressionKind::Runtime))
@@ -909,17 +909,25 @@ impl<'a> ConstantEvaluator<'a> {
 
                 match convert {
                     Some(width) => self.cast(expr, crate::Scalar { kind, width }, span),
-                    None => Err(ConstantEvaluatorError::NotImplemented(
-                        "bitcast built-in function".into(),
-                    )),
+                    None => self.bitcast(expr, kind, span),
                 }
             }
-            Expression::Select { .. } => Err(ConstantEvaluatorError::NotImplemented(
-                "select built-in function".into(),
-            )),
-            Expression::Relational { fun, .. } => Err(ConstantEvaluatorError::NotImplemented(
-                format!("{fun:?} built-in function"),
-            )),
+            Expression::Select {
+                condition,
+                accept,
+                reject,
+            } => {
+                let condition = self.check_and_get(condition)?;
+                let accept = self.check_and_get(accept)?;
+                let reject = self.check_and_get(reject)?;
+
+                self.select(condition, accept, reject, span)
+            }
+            Expression::Relational { fun, argument } => {
+                let argument = self.check_and_get(argument)?;
+
+                self.relational(fun, argument, span)
+            }
             Expression::ArrayLength(expr) => match self.behavior {
                 Behavior::Wgsl(_) => Err(ConstantEvaluatorError::ArrayLength),
                 Behavior::Glsl(_) => {
@@ -1088,6 +1096,8 @@ impl<'a> ConstantEvaluator<'a> {
                 component_wise_scalar(self, span, [arg], |args| match args {
                     Scalar::AbstractFloat([e]) => Ok(Scalar::AbstractFloat([e.abs()])),
                     Scalar::F32([e]) => Ok(Scalar::F32([e.abs()])),
+                    Scalar::F64([e]) => Ok(Scalar::F64([e.abs()])),
+                    Scalar::F16([e]) => Ok(Scalar::F16([e.abs()])),
                     Scalar::AbstractInt([e]) => Ok(Scalar::AbstractInt([e.abs()])),
                     Scalar::I32([e]) => Ok(Scalar::I32([e.wrapping_abs()])),
                     Scalar::U32([e]) => Ok(Scalar::U32([e])), // TODO: just re-use the expression, ezpz
@@ -1120,7 +1130,9 @@ impl<'a> ConstantEvaluator<'a> {
                 )
             }
             crate::MathFunction::Saturate => {
-                component_wise_float!(self, span, [arg], |e| { Ok([e.clamp(0., 1.)]) })
+                component_wise_float!(self, span, [arg], |e| {
+                    Ok([e.clamp(FloatOps::zero(), FloatOps::one())])
+                })
             }
 
             // trigonometry
@@ -1151,6 +1163,11 @@ impl<'a> ConstantEvaluator<'a> {
             crate::MathFunction::Atan => {
                 component_wise_float!(self, span, [arg], |e| { Ok([e.atan()]) })
             }
+            crate::MathFunction::Atan2 => {
+                component_wise_float!(self, span, [arg, arg1.unwrap()], |y, x| {
+                    Ok([y.atan2(x)])
+                })
+            }
             crate::MathFunction::Asinh => {
                 component_wise_float!(self, span, [arg], |e| { Ok([e.asinh()]) })
             }
@@ -1197,7 +1214,9 @@ impl<'a> ConstantEvaluator<'a> {
                 }
                 component_wise_float(self, span, [arg], |e| match e {
                     Float::Abstract([e]) => Ok(Float::Abstract([round_ties_even(e)])),
+                    Float::F64([e]) => Ok(Float::F64([round_ties_even(e)])),
                     Float::F32([e]) => Ok(Float::F32([(round_ties_even(e as f64) as f32)])),
+                    Float::F16([e]) => Ok(Float::F16([f16::from_f64(round_ties_even(e.to_f64()))])),
                 })
             }
             crate::MathFunction::Fract => {
@@ -1210,6 +1229,64 @@ impl<'a> ConstantEvaluator<'a> {
             crate::MathFunction::Trunc => {
                 component_wise_float!(self, span, [arg], |e| { Ok([e.trunc()]) })
             }
+            crate::MathFunction::Modf => {
+                let (scalar, values) = self.float_components(arg, span)?;
+                let fract = values
+                    .iter()
+                    .map(|&e| e - e.trunc())
+                    .collect::<ArrayVec<_, 4>>();
+                let whole = values
+                    .iter()
+                    .map(|&e| e.trunc())
+                    .collect::<ArrayVec<_, 4>>();
+                let ty = self.predeclared_result_type(fun, scalar, values.len())?;
+                let fract = self.register_float_components(scalar, &fract, span)?;
+                let whole = self.register_float_components(scalar, &whole, span)?;
+                let expr = Expression::Compose {
+                    ty,
+                    components: vec![fract, whole],
+                };
+                self.register_evaluated_expr(expr, span)
+            }
+            crate::MathFunction::Frexp => {
+                let (scalar, values) = self.float_components(arg, span)?;
+                let exp_scalar = match scalar {
+                    crate::Scalar::ABSTRACT_FLOAT => crate::Scalar::ABSTRACT_INT,
+                    crate::Scalar::F32 => crate::Scalar::I32,
+                    crate::Scalar::F64 => crate::Scalar::I64,
+                    _ => return Err(ConstantEvaluatorError::InvalidMathArg),
+                };
+                let (fract, exp): (ArrayVec<_, 4>, ArrayVec<_, 4>) =
+                    values.iter().map(|&e| frexp(e)).unzip();
+                let exp = exp
+                    .into_iter()
+                    .map(|e| {
+                        int_literal(exp_scalar, e.into())
+                            .ok_or(ConstantEvaluatorError::InvalidMathArg)
+                    })
+                    .collect::<Result<ArrayVec<_, 4>, _>>()?;
+                let ty = self.predeclared_result_type(fun, scalar, values.len())?;
+                let fract = self.register_float_components(scalar, &fract, span)?;
+                let exp = self.register_components(&exp, span)?;
+                let expr = Expression::Compose {
+                    ty,
+                    components: vec![fract, exp],
+                };
+                self.register_evaluated_expr(expr, span)
+            }
+            crate::MathFunction::Ldexp => {
+                let (scalar, values) = self.float_components(arg, span)?;
+                let exponents = self.literal_components(arg1.unwrap(), span)?;
+                if exponents.len() != values.len() {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                }
+                let values = values
+                    .iter()
+                    .zip(exponents)
+                    .map(|(&e1, e2)| int_value(e2).map(|e2| ldexp(e1, e2)))
+                    .collect::<Result<ArrayVec<_, 4>, _>>()?;
+                self.register_float_components(scalar, &values, span)
+            }
 
             // exponent
             crate::MathFunction::Exp => {
@@ -1230,6 +1307,114 @@ impl<'a> ConstantEvaluator<'a> {
                 })
             }
 
+            // geometry
+            crate::MathFunction::Dot => self.dot(arg, arg1.unwrap(), span),
+            fun @ (crate::MathFunction::Dot4I8Packed | crate::MathFunction::Dot4U8Packed) => {
+                let [a, b] = [arg, arg1.unwrap()].map(|expr| {
+                    match self.literal_components(expr, span)?.as_slice() {
+                        &[Literal::U32(value)] => Ok(value),
+                        _ => Err(ConstantEvaluatorError::InvalidMathArg),
+                    }
+                });
+                let (a, b) = (a?, b?);
+                let bytes = (0..4).map(|i| (a >> (i * 8), b >> (i * 8)));
+                let result = match fun {
+                    crate::MathFunction::Dot4I8Packed => Literal::I32(
+                        bytes
+                            .map(|(a, b)| i32::from(a as i8) * i32::from(b as i8))
+                            .sum(),
+                    ),
+                    _ => Literal::U32(
+                        bytes
+                            .map(|(a, b)| u32::from(a as u8) * u32::from(b as u8))
+                            .sum(),
+                    ),
+                };
+                self.register_evaluated_expr(Expression::Literal(result), span)
+            }
+            crate::MathFunction::Cross => {
+                let (scalar, a) = self.float_components(arg, span)?;
+                let (_, b) = self.float_components(arg1.unwrap(), span)?;
+                let (&[a0, a1, a2], &[b0, b1, b2]) = (a.as_slice(), b.as_slice()) else {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                };
+                let cross = [a1 * b2 - a2 * b1, a2 * b0 - a0 * b2, a0 * b1 - a1 * b0];
+                self.register_float_components(scalar, &cross, span)
+            }
+            crate::MathFunction::Distance => {
+                let (scalar, a) = self.float_components(arg, span)?;
+                let (_, b) = self.float_components(arg1.unwrap(), span)?;
+                if a.len() != b.len() {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                }
+                let difference = a
+                    .iter()
+                    .zip(&b)
+                    .map(|(a, b)| a - b)
+                    .collect::<ArrayVec<_, 4>>();
+                self.register_float_components(scalar, &[length(&difference)], span)
+            }
+            crate::MathFunction::Length => {
+                let (scalar, e) = self.float_components(arg, span)?;
+                self.register_float_components(scalar, &[length(&e)], span)
+            }
+            crate::MathFunction::Normalize => {
+                let (scalar, e) = self.float_components(arg, span)?;
+                let length = length(&e);
+                let normalized = e.iter().map(|e| e / length).collect::<ArrayVec<_, 4>>();
+                self.register_float_components(scalar, &normalized, span)
+            }
+            crate::MathFunction::FaceForward => {
+                let (scalar, e1) = self.float_components(arg, span)?;
+                let (_, e2) = self.float_components(arg1.unwrap(), span)?;
+                let (_, e3) = self.float_components(arg2.unwrap(), span)?;
+                if e1.len() != e2.len() || e1.len() != e3.len() {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                }
+                let sign = if dot(&e2, &e3) < 0.0 { 1.0 } else { -1.0 };
+                let result = e1.iter().map(|e| sign * e).collect::<ArrayVec<_, 4>>();
+                self.register_float_components(scalar, &result, span)
+            }
+            crate::MathFunction::Reflect => {
+                let (scalar, e1) = self.float_components(arg, span)?;
+                let (_, e2) = self.float_components(arg1.unwrap(), span)?;
+                if e1.len() != e2.len() {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                }
+                let d = dot(&e2, &e1);
+                let result = e1
+                    .iter()
+                    .zip(&e2)
+                    .map(|(e1, e2)| e1 - 2.0 * d * e2)
+                    .collect::<ArrayVec<_, 4>>();
+                self.register_float_components(scalar, &result, span)
+            }
+            crate::MathFunction::Refract => {
+                let (scalar, e1) = self.float_components(arg, span)?;
+                let (_, e2) = self.float_components(arg1.unwrap(), span)?;
+                let (_, e3) = self.float_components(arg2.unwrap(), span)?;
+                let &[eta] = e3.as_slice() else {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                };
+                if e1.len() != e2.len() {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                }
+                let d = dot(&e2, &e1);
+                let k = 1.0 - eta * eta * (1.0 - d * d);
+                let result = e1
+                    .iter()
+                    .zip(&e2)
+                    .map(|(e1, e2)| {
+                        if k < 0.0 {
+                            0.0
+                        } else {
+                            eta * e1 - (eta * d + k.sqrt()) * e2
+                        }
+                    })
+                    .collect::<ArrayVec<_, 4>>();
+                self.register_float_components(scalar, &result, span)
+            }
+
             // computational
             crate::MathFunction::Sign => {
                 component_wise_signed!(self, span, [arg], |e| { Ok([e.signum()]) })
@@ -1242,16 +1427,69 @@ impl<'a> ConstantEvaluator<'a> {
                     |e1, e2, e3| { Ok([e1.mul_add(e2, e3)]) }
                 )
             }
+            crate::MathFunction::Mix => {
+                let (scalar, x) = self.float_components(arg, span)?;
+                let (_, y) = self.float_components(arg1.unwrap(), span)?;
+                let (_, a) = self.float_components(arg2.unwrap(), span)?;
+                // The blend factor may be a scalar even if `x` and `y` are vectors.
+                if x.len() != y.len() || (a.len() != 1 && a.len() != x.len()) {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                }
+                let result = x
+                    .iter()
+                    .zip(&y)
+                    .zip(a.iter().cycle())
+                    .map(|((x, y), a)| x * (1.0 - a) + y * a)
+                    .collect::<ArrayVec<_, 4>>();
+                self.register_float_components(scalar, &result, span)
+            }
             crate::MathFunction::Step => {
                 component_wise_float!(self, span, [arg, arg1.unwrap()], |edge, x| {
-                    Ok([if edge <= x { 1.0 } else { 0.0 }])
+                    Ok([if edge <= x {
+                        FloatOps::one()
+                    } else {
+                        FloatOps::zero()
+                    }])
                 })
             }
+            crate::MathFunction::SmoothStep => {
+                component_wise_float!(
+                    self,
+                    span,
+                    [arg, arg1.unwrap(), arg2.unwrap()],
+                    |low, high, x| { Ok([smooth_step(low, high, x)]) }
+                )
+            }
             crate::MathFunction::Sqrt => {
                 component_wise_float!(self, span, [arg], |e| { Ok([e.sqrt()]) })
             }
             crate::MathFunction::InverseSqrt => {
-                component_wise_float!(self, span, [arg], |e| { Ok([1. / e.sqrt()]) })
+                component_wise_float!(self, span, [arg], |e| { Ok([e.sqrt().recip()]) })
+            }
+            crate::MathFunction::Transpose => {
+                let columns = self.matrix_columns(arg, span)?;
+                let transposed = (0..columns[0].len())
+                    .map(|row| columns.iter().map(|column| column[row]).collect())
+                    .collect::<ArrayVec<_, 4>>();
+                self.register_matrix(&transposed, span)
+            }
+            crate::MathFunction::Determinant => {
+                let columns = self.matrix_columns(arg, span)?;
+                if columns.len() != columns[0].len() {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                }
+                let scalar = columns[0][0].scalar();
+                let columns = columns
+                    .into_iter()
+                    .map(|column| column.into_iter().map(float_value).collect())
+                    .collect::<Result<ArrayVec<_, 4>, _>>()?;
+                self.register_float_components(scalar, &[determinant(&columns)], span)
+            }
+            crate::MathFunction::QuantizeToF16 => {
+                component_wise_float(self, span, [arg], |e| match e {
+                    Float::F32([e]) => Ok(Float::F32([f16::from_f32(e).to_f32()])),
+                    _ => Err(ConstantEvaluatorError::InvalidMathArg),
+                })
             }
 
             // bits
@@ -1285,6 +1523,44 @@ impl<'a> ConstantEvaluator<'a> {
             crate::MathFunction::ReverseBits => {
                 component_wise_concrete_int!(self, span, [arg], |e| { Ok([e.reverse_bits()]) })
             }
+            crate::MathFunction::ExtractBits => {
+                let (offset, count) = self.bit_range(arg1.unwrap(), arg2.unwrap(), span)?;
+                component_wise_concrete_int(self, span, [arg], |e| match e {
+                    ConcreteInt::U32([e]) => Ok(ConcreteInt::U32([if count == 0 {
+                        0
+                    } else {
+                        (e >> offset) & (u32::MAX >> (32 - count))
+                    }])),
+                    // Shift the field to the top of the word, and then back down,
+                    // to sign-extend it.
+                    ConcreteInt::I32([e]) => Ok(ConcreteInt::I32([if count == 0 {
+                        0
+                    } else {
+                        (e << (32 - offset - count)) >> (32 - count)
+                    }])),
+                })
+            }
+            crate::MathFunction::InsertBits => {
+                let (offset, count) = self.bit_range(arg2.unwrap(), arg3.unwrap(), span)?;
+                let mask = if count == 0 {
+                    0
+                } else {
+                    (u32::MAX >> (32 - count)) << offset
+                };
+                let insert = move |e: u32, newbits: u32| {
+                    if count == 0 {
+                        e
+                    } else {
+                        (e & !mask) | ((newbits << offset) & mask)
+                    }
+                };
+                component_wise_concrete_int(self, span, [arg, arg1.unwrap()], |e| match e {
+                    ConcreteInt::U32([e, newbits]) => Ok(ConcreteInt::U32([insert(e, newbits)])),
+                    ConcreteInt::I32([e, newbits]) => {
+                        Ok(ConcreteInt::I32([insert(e as u32, newbits as u32) as i32]))
+                    }
+                })
+            }
             crate::MathFunction::FirstTrailingBit => {
                 component_wise_concrete_int(self, span, [arg], |ci| Ok(first_trailing_bit(ci)))
             }
@@ -1292,12 +1568,469 @@ impl<'a> ConstantEvaluator<'a> {
                 component_wise_concrete_int(self, span, [arg], |ci| Ok(first_leading_bit(ci)))
             }
 
+            // data packing
+            fun @ (crate::MathFunction::Pack4x8snorm
+            | crate::MathFunction::Pack4x8unorm
+            | crate::MathFunction::Pack2x16snorm
+            | crate::MathFunction::Pack2x16unorm
+            | crate::MathFunction::Pack2x16float) => {
+                let (scalar, values) = self.float_components(arg, span)?;
+                let (count, bits) = match fun {
+                    crate::MathFunction::Pack4x8snorm | crate::MathFunction::Pack4x8unorm => (4, 8),
+                    _ => (2, 16),
+                };
+                if scalar != crate::Scalar::F32 || values.len() != count {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                }
+                let mut packed = 0;
+                for (i, e) in values.into_iter().enumerate() {
+                    let field = match fun {
+                        crate::MathFunction::Pack4x8snorm => {
+                            u32::from((0.5 + 127.0 * e.clamp(-1.0, 1.0)).floor() as i8 as u8)
+                        }
+                        crate::MathFunction::Pack4x8unorm => {
+                            u32::from((0.5 + 255.0 * e.clamp(0.0, 1.0)).floor() as u8)
+                        }
+                        crate::MathFunction::Pack2x16snorm => {
+                            u32::from((0.5 + 32767.0 * e.clamp(-1.0, 1.0)).floor() as i16 as u16)
+                        }
+                        crate::MathFunction::Pack2x16unorm => {
+                            u32::from((0.5 + 65535.0 * e.clamp(0.0, 1.0)).floor() as u16)
+                        }
+                        _ => {
+                            let half = f16::from_f64(e);
+                            crate::valid::check_literal_value(Literal::F16(half))?;
+                            u32::from(half.to_bits())
+                        }
+                    };
+                    packed |= field << (i * bits);
+                }
+                self.register_evaluated_expr(Expression::Literal(Literal::U32(packed)), span)
+            }
+            fun @ (crate::MathFunction::Pack4xI8
+            | crate::MathFunction::Pack4xU8
+            | crate::MathFunction::Pack4xI8Clamp
+            | crate::MathFunction::Pack4xU8Clamp) => {
+                let components = self.literal_components(arg, span)?;
+                if components.len() != 4 {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                }
+                let mut packed = 0;
+                for (i, component) in components.into_iter().enumerate() {
+                    let byte = match (fun, component) {
+                        (crate::MathFunction::Pack4xI8, Literal::I32(e)) => e as u8,
+                        (crate::MathFunction::Pack4xI8Clamp, Literal::I32(e)) => {
+                            e.clamp(i8::MIN.into(), i8::MAX.into()) as u8
+                        }
+                        (crate::MathFunction::Pack4xU8, Literal::U32(e)) => e as u8,
+                        (crate::MathFunction::Pack4xU8Clamp, Literal::U32(e)) => {
+                            e.min(u8::MAX.into()) as u8
+                        }
+                        _ => return Err(ConstantEvaluatorError::InvalidMathArg),
+                    };
+                    packed |= u32::from(byte) << (i * 8);
+                }
+                self.register_evaluated_expr(Expression::Literal(Literal::U32(packed)), span)
+            }
+
+            // data unpacking
+            fun @ (crate::MathFunction::Unpack4x8snorm
+            | crate::MathFunction::Unpack4x8unorm
+            | crate::MathFunction::Unpack2x16snorm
+            | crate::MathFunction::Unpack2x16unorm
+            | crate::MathFunction::Unpack2x16float
+            | crate::MathFunction::Unpack4xI8
+            | crate::MathFunction::Unpack4xU8) => {
+                let e = match self.literal_components(arg, span)?.as_slice() {
+                    &[Literal::U32(e)] => e,
+                    _ => return Err(ConstantEvaluatorError::InvalidMathArg),
+                };
+                let bytes = (0..4).map(|i| e >> (i * 8));
+                let halves = (0..2).map(|i| e >> (i * 16));
+                let components: ArrayVec<_, 4> = match fun {
+                    crate::MathFunction::Unpack4x8snorm => bytes
+                        .map(|b| Literal::F32((f32::from(b as i8) / 127.0).max(-1.0)))
+                        .collect(),
+                    crate::MathFunction::Unpack4x8unorm => bytes
+                        .map(|b| Literal::F32(f32::from(b as u8) / 255.0))
+                        .collect(),
+                    crate::MathFunction::Unpack2x16snorm => halves
+                        .map(|h| Literal::F32((f32::from(h as i16) / 32767.0).max(-1.0)))
+                        .collect(),
+                    crate::MathFunction::Unpack2x16unorm => halves
+                        .map(|h| Literal::F32(f32::from(h as u16) / 65535.0))
+                        .collect(),
+                    crate::MathFunction::Unpack2x16float => halves
+                        .map(|h| Literal::F32(f16::from_bits(h as u16).to_f32()))
+                        .collect(),
+                    crate::MathFunction::Unpack4xI8 => {
+                        bytes.map(|b| Literal::I32(i32::from(b as i8))).collect()
+                    }
+                    _ => bytes.map(|b| Literal::U32(b & 0xff)).collect(),
+                };
+                self.register_components(&components, span)
+            }
+
             fun => Err(ConstantEvaluatorError::NotImplemented(format!(
                 "{fun:?} built-in function"
             ))),
         }
     }
 
+    /// Return the literal components of `expr`, which must evaluate to a
+    /// scalar or vector literal.
+    fn literal_components(
+        &mut self,
+        expr: Handle<Expression>,
+        span: Span,
+    ) -> Result<ArrayVec<Literal, { crate::VectorSize::MAX }>, ConstantEvaluatorError> {
+        let expr = self.eval_zero_value_and_splat(expr, span)?;
+        let components: ArrayVec<_, { crate::VectorSize::MAX }> = match self.expressions[expr] {
+            Expression::Literal(_) => iter::once(expr).collect(),
+            Expression::Compose { ty, ref components } => {
+                crate::proc::flatten_compose(ty, components, self.expressions, self.types).collect()
+            }
+            _ => return Err(ConstantEvaluatorError::InvalidMathArg),
+        };
+        components
+            .into_iter()
+            .map(|component| {
+                let component = self.eval_zero_value(component, span)?;
+                match self.expressions[component] {
+                    Expression::Literal(literal) => Ok(literal),
+                    _ => Err(ConstantEvaluatorError::InvalidMathArg),
+                }
+            })
+            .collect()
+    }
+
+    /// Return the components of `expr`, which must evaluate to a scalar or
+    /// vector of floats, as `f64` values, along with their scalar type.
+    fn float_components(
+        &mut self,
+        expr: Handle<Expression>,
+        span: Span,
+    ) -> Result<(crate::Scalar, ArrayVec<f64, { crate::VectorSize::MAX }>), ConstantEvaluatorError>
+    {
+        let components = self.literal_components(expr, span)?;
+        let scalar = components
+            .first()
+            .ok_or(ConstantEvaluatorError::InvalidMathArg)?
+            .scalar();
+        let values = components
+            .into_iter()
+            .map(float_value)
+            .collect::<Result<_, _>>()?;
+        Ok((scalar, values))
+    }
+
+    /// Return the literal components of each column of `expr`, which must
+    /// evaluate to a matrix.
+    fn matrix_columns(
+        &mut self,
+        expr: Handle<Expression>,
+        span: Span,
+    ) -> Result<
+        ArrayVec<ArrayVec<Literal, { crate::VectorSize::MAX }>, { crate::VectorSize::MAX }>,
+        ConstantEvaluatorError,
+    > {
+        let expr = self.eval_zero_value(expr, span)?;
+        let (rows, columns) = match self.expressions[expr] {
+            Expression::Compose { ty, ref components } => match self.types[ty].inner {
+                TypeInner::Matrix { rows, .. } => (rows, components.clone()),
+                _ => return Err(ConstantEvaluatorError::InvalidMathArg),
+            },
+            _ => return Err(ConstantEvaluatorError::InvalidMathArg),
+        };
+        columns
+            .into_iter()
+            .map(|column| {
+                let column = self.literal_components(column, span)?;
+                if column.len() != rows as usize {
+                    return Err(ConstantEvaluatorError::InvalidMathArg);
+                }
+                Ok(column)
+            })
+            .collect()
+    }
+
+    /// Return the offset and count arguments of `extractBits` or `insertBits`,
+    /// clamped to the bits of a 32-bit integer.
+    fn bit_range(
+        &mut self,
+        offset: Handle<Expression>,
+        count: Handle<Expression>,
+        span: Span,
+    ) -> Result<(u32, u32), ConstantEvaluatorError> {
+        let [offset, count] =
+            [offset, count].map(
+                |expr| match self.literal_components(expr, span)?.as_slice() {
+                    &[Literal::U32(value)] => Ok(value),
+                    _ => Err(ConstantEvaluatorError::InvalidMathArg),
+                },
+            );
+        let offset = offset?.min(32);
+        Ok((offset, count?.min(32 - offset)))
+    }
+
+    /// Return the predeclared struct type that `fun`, which must be
+    /// [`Modf`] or [`Frexp`], returns for an argument with `components`
+    /// components of type `scalar`.
+    ///
+    /// [`Modf`]: crate::MathFunction::Modf
+    /// [`Frexp`]: crate::MathFunction::Frexp
+    fn predeclared_result_type(
+        &self,
+        fun: crate::MathFunction,
+        scalar: crate::Scalar,
+        components: usize,
+    ) -> Result<Handle<Type>, ConstantEvaluatorError> {
+        let size = match components {
+            1 => None,
+            _ => Some(vector_size(components).ok_or(ConstantEvaluatorError::InvalidMathArg)?),
+        };
+        let width = scalar.width;
+        let predeclared = match fun {
+            crate::MathFunction::Modf => crate::PredeclaredType::ModfResult { size, width },
+            _ => crate::PredeclaredType::FrexpResult { size, width },
+        };
+        self.special_types
+            .predeclared_types
+            .get(&predeclared)
+            .copied()
+            .ok_or_else(|| {
+                ConstantEvaluatorError::NotImplemented(format!(
+                    "{fun:?} built-in function without a predeclared result type"
+                ))
+            })
+    }
+
+    /// Register `literals` as a scalar, or as a vector if there is more than
+    /// one of them.
+    fn register_components(
+        &mut self,
+        literals: &[Literal],
+        span: Span,
+    ) -> Result<Handle<Expression>, ConstantEvaluatorError> {
+        let components = literals
+            .iter()
+            .map(|&literal| self.register_evaluated_expr(Expression::Literal(literal), span))
+            .collect::<Result<Vec<_>, _>>()?;
+        match *components.as_slice() {
+            [component] => Ok(component),
+            _ => {
+                let size =
+                    vector_size(components.len()).ok_or(ConstantEvaluatorError::InvalidMathArg)?;
+                let ty = self.types.insert(
+                    Type {
+                        name: None,
+                        inner: TypeInner::Vector {
+                            size,
+                            scalar: literals[0].scalar(),
+                        },
+                    },
+                    span,
+                );
+                self.register_evaluated_expr(Expression::Compose { ty, components }, span)
+            }
+        }
+    }
+
+    /// Register `values` as a scalar or vector of type `scalar`, rounding
+    /// each value to `scalar`'s precision.
+    fn register_float_components(
+        &mut self,
+        scalar: crate::Scalar,
+        values: &[f64],
+        span: Span,
+    ) -> Result<Handle<Expression>, ConstantEvaluatorError> {
+        let literals = values
+            .iter()
+            .map(|&value| float_literal(scalar, value))
+            .collect::<Result<ArrayVec<_, { crate::VectorSize::MAX }>, _>>()?;
+        self.register_components(&literals, span)
+    }
+
+    /// Register a matrix whose columns have the given literal components.
+    fn register_matrix(
+        &mut self,
+        columns: &[ArrayVec<Literal, { crate::VectorSize::MAX }>],
+        span: Span,
+    ) -> Result<Handle<Expression>, ConstantEvaluatorError> {
+        let components = columns
+            .iter()
+            .map(|column| self.register_components(column, span))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (Some(columns_size), Some(rows)) =
+            (vector_size(columns.len()), vector_size(columns[0].len()))
+        else {
+            return Err(ConstantEvaluatorError::InvalidMathArg);
+        };
+        let ty = self.types.insert(
+            Type {
+                name: None,
+                inner: TypeInner::Matrix {
+                    columns: columns_size,
+                    rows,
+                    scalar: columns[0][0].scalar(),
+                },
+            },
+            span,
+        );
+        self.register_evaluated_expr(Expression::Compose { ty, components }, span)
+    }
+
+    fn dot(
+        &mut self,
+        a: Handle<Expression>,
+        b: Handle<Expression>,
+        span: Span,
+    ) -> Result<Handle<Expression>, ConstantEvaluatorError> {
+        let a = self.literal_components(a, span)?;
+        let b = self.literal_components(b, span)?;
+        if a.len() < 2 || a.len() != b.len() {
+            return Err(ConstantEvaluatorError::InvalidMathArg);
+        }
+        let scalar = a[0].scalar();
+        let result = match scalar.kind {
+            ScalarKind::Float | ScalarKind::AbstractFloat => {
+                let a = a
+                    .into_iter()
+                    .map(float_value)
+                    .collect::<Result<ArrayVec<_, 4>, _>>()?;
+                let b = b
+                    .into_iter()
+                    .map(float_value)
+                    .collect::<Result<ArrayVec<_, 4>, _>>()?;
+                float_literal(scalar, dot(&a, &b))?
+            }
+            ScalarKind::Sint | ScalarKind::Uint | ScalarKind::AbstractInt => {
+                let overflow = || ConstantEvaluatorError::Overflow("dot".into());
+                let mut sum = 0i128;
+                for (a, b) in a.into_iter().zip(b) {
+                    sum = int_value(a)?
+                        .checked_mul(int_value(b)?)
+                        .and_then(|product| sum.checked_add(product))
+                        .ok_or_else(overflow)?;
+                }
+                int_literal(scalar, sum).ok_or_else(overflow)?
+            }
+            ScalarKind::Bool => return Err(ConstantEvaluatorError::InvalidMathArg),
+        };
+        self.register_evaluated_expr(Expression::Literal(result), span)
+    }
+
+    /// Reinterpret the bits of each scalar component of `expr` as `kind`.
+    fn bitcast(
+        &mut self,
+        expr: Handle<Expression>,
+        kind: ScalarKind,
+        span: Span,
+    ) -> Result<Handle<Expression>, ConstantEvaluatorError> {
+        let components = self.literal_components(expr, span)?;
+        let literals = components
+            .into_iter()
+            .map(|literal| {
+                let make_error = || ConstantEvaluatorError::InvalidCastArg {
+                    from: format!("{literal:?}"),
+                    to: format!("bitcast<{kind:?}>"),
+                };
+                let bits = match literal {
+                    Literal::I32(v) => u64::from(v as u32),
+                    Literal::U32(v) => u64::from(v),
+                    Literal::F32(v) => u64::from(v.to_bits()),
+                    Literal::I64(v) => v as u64,
+                    Literal::U64(v) => v,
+                    Literal::F64(v) => v.to_bits(),
+                    _ => return Err(make_error()),
+                };
+                Ok(match (kind, literal.scalar().width) {
+                    (ScalarKind::Sint, 4) => Literal::I32(bits as u32 as i32),
+                    (ScalarKind::Uint, 4) => Literal::U32(bits as u32),
+                    (ScalarKind::Float, 4) => Literal::F32(f32::from_bits(bits as u32)),
+                    (ScalarKind::Sint, 8) => Literal::I64(bits as i64),
+                    (ScalarKind::Uint, 8) => Literal::U64(bits),
+                    (ScalarKind::Float, 8) => Literal::F64(f64::from_bits(bits)),
+                    _ => return Err(make_error()),
+                })
+            })
+            .collect::<Result<ArrayVec<_, { crate::VectorSize::MAX }>, _>>()?;
+        self.register_components(&literals, span)
+    }
+
+    fn select(
+        &mut self,
+        condition: Handle<Expression>,
+        accept: Handle<Expression>,
+        reject: Handle<Expression>,
+        span: Span,
+    ) -> Result<Handle<Expression>, ConstantEvaluatorError> {
+        let conditions = self.literal_components(condition, span)?;
+        // A scalar condition selects between whole values.
+        if let &[Literal::Bool(condition)] = conditions.as_slice() {
+            return Ok(if condition { accept } else { reject });
+        }
+        let accept = self.literal_components(accept, span)?;
+        let reject = self.literal_components(reject, span)?;
+        if accept.len() != conditions.len() || reject.len() != conditions.len() {
+            return Err(ConstantEvaluatorError::InvalidSelectArgs);
+        }
+        let selected = conditions
+            .into_iter()
+            .zip(accept.into_iter().zip(reject))
+            .map(|(condition, (accept, reject))| match condition {
+                Literal::Bool(condition) => Ok(if condition { accept } else { reject }),
+                _ => Err(ConstantEvaluatorError::InvalidSelectArgs),
+            })
+            .collect::<Result<ArrayVec<_, { crate::VectorSize::MAX }>, _>>()?;
+        self.register_components(&selected, span)
+    }
+
+    fn relational(
+        &mut self,
+        fun: crate::RelationalFunction,
+        argument: Handle<Expression>,
+        span: Span,
+    ) -> Result<Handle<Expression>, ConstantEvaluatorError> {
+        use crate::RelationalFunction as Rf;
+
+        let components = self.literal_components(argument, span)?;
+        match fun {
+            Rf::All | Rf::Any => {
+                let values = components
+                    .into_iter()
+                    .map(|component| match component {
+                        Literal::Bool(value) => Ok(value),
+                        _ => Err(ConstantEvaluatorError::InvalidRelationalArg(fun)),
+                    })
+                    .collect::<Result<ArrayVec<_, { crate::VectorSize::MAX }>, _>>()?;
+                let result = if fun == Rf::All {
+                    values.iter().all(|&value| value)
+                } else {
+                    values.iter().any(|&value| value)
+                };
+                self.register_evaluated_expr(Expression::Literal(Literal::Bool(result)), span)
+            }
+            Rf::IsNan | Rf::IsInf => {
+                let results = components
+                    .into_iter()
+                    .map(|component| {
+                        float_value(component)
+                            .map(|value| {
+                                Literal::Bool(if fun == Rf::IsNan {
+                                    value.is_nan()
+                                } else {
+                                    value.is_infinite()
+                                })
+                            })
+                            .map_err(|_| ConstantEvaluatorError::InvalidRelationalArg(fun))
+                    })
+                    .collect::<Result<ArrayVec<_, { crate::VectorSize::MAX }>, _>>()?;
+                self.register_components(&results, span)
+            }
+        }
+    }
+
     fn array_length(
         &mut self,
         array: Handle<Expression>,
@@ -1529,6 +2262,7 @@ impl<'a> ConstantEvaluator<'a> {
                         Literal::I32(v) => v,
                         Literal::U32(v) => v as i32,
                         Literal::F32(v) => v as i32,
+                        Literal::F16(v) => f32::from(v) as i32,
                         Literal::Bool(v) => v as i32,
                         Literal::F64(_) | Literal::I64(_) | Literal::U64(_) => {
                             return make_error();
@@ -1540,6 +2274,7 @@ impl<'a> ConstantEvaluator<'a> {
                         Literal::I32(v) => v as u32,
                         Literal::U32(v) => v,
                         Literal::F32(v) => v as u32,
+                        Literal::F16(v) => f32::from(v) as u32,
                         Literal::Bool(v) => v as u32,
                         Literal::F64(_) | Literal::I64(_) | Literal::U64(_) => {
                             return make_error();
@@ -1551,6 +2286,7 @@ impl<'a> ConstantEvaluator<'a> {
                         Literal::I32(v) => v as i64,
                         Literal::U32(v) => v as i64,
                         Literal::F32(v) => v as i64,
+                        Literal::F16(v) => f32::from(v) as i64,
                         Literal::Bool(v) => v as i64,
                         Literal::F64(v) => v as i64,
                         Literal::I64(v) => v,
@@ -1562,6 +2298,7 @@ impl<'a> ConstantEvaluator<'a> {
                         Literal::I32(v) => v as u64,
                         Literal::U32(v) => v as u64,
                         Literal::F32(v) => v as u64,
+                        Literal::F16(v) => f32::from(v) as u64,
                         Literal::Bool(v) => v as u64,
                         Literal::F64(v) => v as u64,
                         Literal::I64(v) => v as u64,
@@ -1573,6 +2310,7 @@ impl<'a> ConstantEvaluator<'a> {
                         Literal::I32(v) => v as f32,
                         Literal::U32(v) => v as f32,
                         Literal::F32(v) => v,
+                        Literal::F16(v) => f32::from(v),
                         Literal::Bool(v) => v as u32 as f32,
                         Literal::F64(_) | Literal::I64(_) | Literal::U64(_) => {
                             return make_error();
@@ -1580,10 +2318,33 @@ impl<'a> ConstantEvaluator<'a> {
                         Literal::AbstractInt(v) => f32::try_from_abstract(v)?,
                         Literal::AbstractFloat(v) => f32::try_from_abstract(v)?,
                     }),
+                    Sc::F16 => {
+                        let v = match literal {
+                            Literal::I32(v) => f16::from_f64(v as f64),
+                            Literal::U32(v) => f16::from_f64(v as f64),
+                            Literal::F32(v) => f16::from_f32(v),
+                            Literal::F16(v) => v,
+                            Literal::Bool(v) => f16::from_f32(v as u32 as f32),
+                            Literal::F64(_) | Literal::I64(_) | Literal::U64(_) => {
+                                return make_error();
+                            }
+                            Literal::AbstractInt(v) => f16::try_from_abstract(v)?,
+                            Literal::AbstractFloat(v) => f16::try_from_abstract(v)?,
+                        };
+                        // `i32`, `u32` and `f32` values can lie outside the
+                        // finite range of `f16`.
+                        if v.is_infinite() {
+                            return Err(ConstantEvaluatorError::Overflow(
+                                "conversion to f16".into(),
+                            ));
+                        }
+                        Literal::F16(v)
+                    }
                     Sc::F64 => Literal::F64(match literal {
                         Literal::I32(v) => v as f64,
                         Literal::U32(v) => v as f64,
                         Literal::F32(v) => v as f64,
+                        Literal::F16(v) => f64::from(v),
                         Literal::F64(v) => v,
                         Literal::Bool(v) => v as u32 as f64,
                         Literal::I64(_) | Literal::U64(_) => return make_error(),
@@ -1594,6 +2355,7 @@ impl<'a> ConstantEvaluator<'a> {
                         Literal::I32(v) => v != 0,
                         Literal::U32(v) => v != 0,
                         Literal::F32(v) => v != 0.0,
+                        Literal::F16(v) => v != f16::ZERO,
                         Literal::Bool(v) => v,
                         Literal::F64(_)
                         | Literal::I64(_)
@@ -1743,6 +2505,7 @@ impl<'a> ConstantEvaluator<'a> {
                 UnaryOperator::Negate => match value {
                     Literal::I32(v) => Literal::I32(v.wrapping_neg()),
                     Literal::F32(v) => Literal::F32(-v),
+                    Literal::F16(v) => Literal::F16(-v),
                     Literal::AbstractInt(v) => Literal::AbstractInt(v.wrapping_neg()),
                     Literal::AbstractFloat(v) => Literal::AbstractFloat(-v),
                     _ => return Err(ConstantEvaluatorError::InvalidUnaryOpArg),
@@ -1881,6 +2644,14 @@ impl<'a> ConstantEvaluator<'a> {
                             BinaryOperator::Modulo => a % b,
                             _ => return Err(ConstantEvaluatorError::InvalidBinaryOpArgs),
                         }),
+                        (Literal::F16(a), Literal::F16(b)) => Literal::F16(match op {
+                            BinaryOperator::Add => a + b,
+                            BinaryOperator::Subtract => a - b,
+                            BinaryOperator::Multiply => a * b,
+                            BinaryOperator::Divide => a / b,
+                            BinaryOperator::Modulo => a % b,
+                            _ => return Err(ConstantEvaluatorError::InvalidBinaryOpArgs),
+                        }),
                         (Literal::AbstractInt(a), Literal::AbstractInt(b)) => {
                             Literal::AbstractInt(match op {
                                 BinaryOperator::Add => a.checked_add(b).ok_or_else(|| {
@@ -2166,6 +2937,208 @@ impl<'a> ConstantEvaluator<'a> {
     }
 }
 
+const fn vector_size(components: usize) -> Option<crate::VectorSize> {
+    match components {
+        2 => Some(crate::VectorSize::Bi),
+        3 => Some(crate::VectorSize::Tri),
+        4 => Some(crate::VectorSize::Quad),
+        _ => None,
+    }
+}
+
+fn float_value(literal: Literal) -> Result<f64, ConstantEvaluatorError> {
+    match literal {
+        Literal::AbstractFloat(v) | Literal::F64(v) => Ok(v),
+        Literal::F32(v) => Ok(v.into()),
+        Literal::F16(v) => Ok(v.to_f64()),
+        _ => Err(ConstantEvaluatorError::InvalidMathArg),
+    }
+}
+
+/// Return a literal of the floating-point type `scalar` holding `value`,
+/// rounded to `scalar`'s precision.
+fn float_literal(scalar: crate::Scalar, value: f64) -> Result<Literal, ConstantEvaluatorError> {
+    Ok(match scalar {
+        crate::Scalar::ABSTRACT_FLOAT => Literal::AbstractFloat(value),
+        crate::Scalar::F64 => Literal::F64(value),
+        crate::Scalar::F32 => Literal::F32(value as f32),
+        crate::Scalar::F16 => Literal::F16(f16::from_f64(value)),
+        _ => return Err(ConstantEvaluatorError::InvalidMathArg),
+    })
+}
+
+fn int_value(literal: Literal) -> Result<i128, ConstantEvaluatorError> {
+    match literal {
+        Literal::I32(v) => Ok(v.into()),
+        Literal::U32(v) => Ok(v.into()),
+        Literal::I64(v) | Literal::AbstractInt(v) => Ok(v.into()),
+        Literal::U64(v) => Ok(v.into()),
+        _ => Err(ConstantEvaluatorError::InvalidMathArg),
+    }
+}
+
+/// Return a literal of the integer type `scalar` holding `value`, or `None`
+/// if `value` is out of range.
+fn int_literal(scalar: crate::Scalar, value: i128) -> Option<Literal> {
+    Some(match scalar {
+        crate::Scalar::I32 => Literal::I32(value.try_into().ok()?),
+        crate::Scalar::U32 => Literal::U32(value.try_into().ok()?),
+        crate::Scalar::I64 => Literal::I64(value.try_into().ok()?),
+        crate::Scalar::U64 => Literal::U64(value.try_into().ok()?),
+        crate::Scalar::ABSTRACT_INT => Literal::AbstractInt(value.try_into().ok()?),
+        _ => return None,
+    })
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn length(e: &[f64]) -> f64 {
+    dot(e, e).sqrt()
+}
+
+fn smooth_step<T: FloatOps>(low: T, high: T, x: T) -> T {
+    let t = ((x - low) / (high - low)).max(T::zero()).min(T::one());
+    let (two, three) = (T::one() + T::one(), T::one() + T::one() + T::one());
+    t * t * (three - two * t)
+}
+
+/// Arithmetic shared by the floating-point types that built-ins are
+/// evaluated on, for code that is generic over them.
+trait FloatOps:
+    Copy
+    + PartialOrd
+    + core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<Output = Self>
+    + core::ops::Div<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+}
+
+macro_rules! impl_float_ops {
+    ($($ty:ident: $zero:expr, $one:expr;)*) => {
+        $(
+            impl FloatOps for $ty {
+                fn zero() -> Self {
+                    $zero
+                }
+                fn one() -> Self {
+                    $one
+                }
+                fn max(self, other: Self) -> Self {
+                    $ty::max(self, other)
+                }
+                fn min(self, other: Self) -> Self {
+                    $ty::min(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_float_ops! {
+    f64: 0.0, 1.0;
+    f32: 0.0, 1.0;
+    f16: f16::ZERO, f16::ONE;
+}
+
+macro_rules! f16_math {
+    (
+        unary: [$($unary:ident),* $(,)?],
+        binary: [$($binary:ident),* $(,)?],
+    ) => {
+        /// The math functions `f32` and `f64` have as inherent methods, which
+        /// [`f16`] lacks.
+        ///
+        /// These are computed in `f32` and rounded back to `f16`, which is
+        /// exact for `abs` and the rounding functions, and within the
+        /// accuracy WGSL requires for the rest.
+        trait F16Math {
+            $(fn $unary(self) -> Self;)*
+            $(fn $binary(self, other: Self) -> Self;)*
+            fn mul_add(self, a: Self, b: Self) -> Self;
+        }
+
+        impl F16Math for f16 {
+            $(
+                fn $unary(self) -> Self {
+                    f16::from_f32(f32::from(self).$unary())
+                }
+            )*
+            $(
+                fn $binary(self, other: Self) -> Self {
+                    f16::from_f32(f32::from(self).$binary(f32::from(other)))
+                }
+            )*
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                f16::from_f32(f32::from(self).mul_add(f32::from(a), f32::from(b)))
+            }
+        }
+    };
+}
+
+f16_math! {
+    unary: [
+        abs, ceil, floor, trunc, sqrt, recip, exp, exp2, ln, log2, sin, cos, tan, sinh, cosh, tanh,
+        asin, acos, atan, asinh, acosh, atanh, to_radians, to_degrees,
+    ],
+    binary: [atan2, powf],
+}
+
+/// Split `x` into a significand with magnitude in `[0.5, 1)` and the same
+/// sign as `x`, and a power of two.
+fn frexp(x: f64) -> (f64, i32) {
+    if x == 0.0 || !x.is_finite() {
+        return (x, 0);
+    }
+    let bits = x.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    if biased_exponent == 0 {
+        // Scale subnormals up into the normal range first.
+        let (fract, exp) = frexp(x * 2f64.powi(64));
+        return (fract, exp - 64);
+    }
+    let fract = f64::from_bits((bits & !(0x7ff << 52)) | (1022 << 52));
+    (fract, biased_exponent - 1022)
+}
+
+fn ldexp(x: f64, exp: i128) -> f64 {
+    // Scale in two steps, so that neither power of two overflows or
+    // underflows when the result itself is representable.
+    let exp = exp.clamp(-4096, 4096) as i32;
+    x * 2f64.powi(exp / 2) * 2f64.powi(exp - exp / 2)
+}
+
+/// Compute the determinant of a square matrix, given as a list of columns,
+/// by cofactor expansion along the first column.
+fn determinant(columns: &[ArrayVec<f64, { crate::VectorSize::MAX }>]) -> f64 {
+    if columns.len() == 1 {
+        return columns[0][0];
+    }
+    (0..columns.len())
+        .map(|row| {
+            let minor = columns[1..]
+                .iter()
+                .map(|column| {
+                    column
+                        .iter()
+                        .enumerate()
+                        .filter(|&(r, _)| r != row)
+                        .map(|(_, &value)| value)
+                        .collect()
+                })
+                .collect::<ArrayVec<_, { crate::VectorSize::MAX }>>();
+            let sign = if row % 2 == 0 { 1.0 } else { -1.0 };
+            sign * columns[0][row] * determinant(&minor)
+        })
+        .sum()
+}
+
 fn first_trailing_bit(concrete_int: ConcreteInt<1>) -> ConcreteInt<1> {
     // NOTE: Bit indices for this built-in start at 0 at the "right" (or LSB). For example, a value
     // of 1 means the least significant bit is set. Therefore, an input of `0x[80 00…]` would
@@ -2410,6 +3383,32 @@ impl TryFromAbstract<f64> for f32 {
     }
 }
 
+impl TryFromAbstract<i64> for f16 {
+    fn try_from_abstract(value: i64) -> Result<Self, ConstantEvaluatorError> {
+        let f = f16::from_f64(value as f64);
+        if f.is_infinite() {
+            return Err(ConstantEvaluatorError::AutomaticConversionLossy {
+                value: format!("{value:?}"),
+                to_type: "f16",
+            });
+        }
+        Ok(f)
+    }
+}
+
+impl TryFromAbstract<f64> for f16 {
+    fn try_from_abstract(value: f64) -> Result<f16, ConstantEvaluatorError> {
+        let f = f16::from_f64(value);
+        if f.is_infinite() {
+            return Err(ConstantEvaluatorError::AutomaticConversionLossy {
+                value: format!("{value:?}"),
+                to_type: "f16",
+            });
+        }
+        Ok(f)
+    }
+}
+
 impl TryFromAbstract<i64> for f64 {
     fn try_from_abstract(value: i64) -> Result<Self, ConstantEvaluatorError> {
         let f = value as f64;
@@ -2544,6 +3543,7 @@ mod tests {
         let mut solver = ConstantEvaluator {
             behavior: Behavior::Wgsl(WgslRestrictions::Const(None)),
             types: &mut types,
+            special_types: &crate::SpecialTypes::default(),
             constants: &constants,
             overrides: &overrides,
             expressions: &mut global_expressions,
@@ -2630,6 +3630,7 @@ mod tests {
         let mut solver = ConstantEvaluator {
             behavior: Behavior::Wgsl(WgslRestrictions::Const(None)),
             types: &mut types,
+            special_types: &crate::SpecialTypes::default(),
             constants: &constants,
             overrides: &overrides,
             expressions: &mut global_expressions,
@@ -2748,6 +3749,7 @@ mod tests {
         let mut solver = ConstantEvaluator {
             behavior: Behavior::Wgsl(WgslRestrictions::Const(None)),
             types: &mut types,
+            special_types: &crate::SpecialTypes::default(),
             constants: &constants,
             overrides: &overrides,
             expressions: &mut global_expressions,
@@ -2841,6 +3843,7 @@ mod tests {
         let mut solver = ConstantEvaluator {
             behavior: Behavior::Wgsl(WgslRestrictions::Const(None)),
             types: &mut types,
+            special_types: &crate::SpecialTypes::default(),
             constants: &constants,
             overrides: &overrides,
             expressions: &mut global_expressions,
@@ -2923,6 +3926,7 @@ mod tests {
         let mut solver = ConstantEvaluator {
             behavior: Behavior::Wgsl(WgslRestrictions::Const(None)),
             types: &mut types,
+            special_types: &crate::SpecialTypes::default(),
             constants: &constants,
             overrides: &overrides,
             expressions: &mut global_expressions,
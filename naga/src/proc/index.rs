@@ -123,6 +123,12 @@ pub struct BoundsCheckPolicies {
     pub image_load: BoundsCheckPolicy,
 
     /// How should the generated code handle binding array indexes that are out of bounds.
+    ///
+    /// Binding arrays of images and samplers have no zero value to read, so
+    /// under [`ReadZeroSkipWrite`], backends fall back to accessing the first
+    /// element of the array instead.
+    ///
+    /// [`ReadZeroSkipWrite`]: BoundsCheckPolicy::ReadZeroSkipWrite
     #[cfg_attr(feature = "deserialize", serde(default))]
     pub binding_array: BoundsCheckPolicy,
 }
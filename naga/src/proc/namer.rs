@@ -8,6 +8,7 @@ const SEPARATOR: char = '_';
 #[derive(Debug, Eq, Hash, PartialEq)]
 pub enum NameKey {
     Constant(Handle<crate::Constant>),
+    Override(Handle<crate::Override>),
     GlobalVariable(Handle<crate::GlobalVariable>),
     Type(Handle<crate::Type>),
     StructMember(Handle<crate::Type>, u32),
@@ -240,6 +241,11 @@ impl Namer {
             let name = self.call(label);
             output.insert(NameKey::Constant(handle), name);
         }
+
+        for (handle, override_) in module.overrides.iter() {
+            let name = self.call_or(&override_.name, "override");
+            output.insert(NameKey::Override(handle), name);
+        }
     }
 }
 
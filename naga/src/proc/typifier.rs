@@ -705,6 +705,8 @@ impl<'a> ResolveContext<'a> {
                             )))
                         }
                     },
+                    Mf::Dot4I8Packed => TypeResolution::Value(Ti::Scalar(crate::Scalar::I32)),
+                    Mf::Dot4U8Packed => TypeResolution::Value(Ti::Scalar(crate::Scalar::U32)),
                     Mf::Outer => {
                         let arg1 = arg1.ok_or_else(|| {
                             ResolveError::IncompatibleOperands(format!("{fun:?}(_, None)"))
@@ -747,7 +749,8 @@ impl<'a> ResolveContext<'a> {
                     | Mf::Step
                     | Mf::SmoothStep
                     | Mf::Sqrt
-                    | Mf::InverseSqrt => res_arg.clone(),
+                    | Mf::InverseSqrt
+                    | Mf::QuantizeToF16 => res_arg.clone(),
                     Mf::Transpose => match *res_arg.inner_with(types) {
                         Ti::Matrix {
                             columns,
@@ -824,7 +827,9 @@ impl<'a> ResolveContext<'a> {
                     | Mf::Pack2x16unorm
                     | Mf::Pack2x16float
                     | Mf::Pack4xI8
-                    | Mf::Pack4xU8 => TypeResolution::Value(Ti::Scalar(crate::Scalar::U32)),
+                    | Mf::Pack4xU8
+                    | Mf::Pack4xI8Clamp
+                    | Mf::Pack4xU8Clamp => TypeResolution::Value(Ti::Scalar(crate::Scalar::U32)),
                     // data unpacking
                     Mf::Unpack4x8snorm | Mf::Unpack4x8unorm => TypeResolution::Value(Ti::Vector {
                         size: crate::VectorSize::Quad,
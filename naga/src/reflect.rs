@@ -0,0 +1,398 @@
+/*!
+A summary of a module's interface, for generating pipeline layouts.
+
+[`reflect`] collects what a host application needs to know to create
+pipelines for a validated [`Module`]: its entry points and their inputs and
+outputs, the resources it binds, its push constants, and its overrides. With
+the `serialize` feature enabled, the result can be written out as JSON or any
+other format `serde` supports.
+
+[`Module`]: crate::Module
+*/
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::valid::ModuleInfo;
+use crate::{
+    AddressSpace, ArraySize, Binding, ImageClass, ImageDimension, Module, ResourceBinding, Scalar,
+    ShaderStage, StorageAccess, TypeInner,
+};
+
+/// The interface of a [`Module`], as returned by [`reflect`].
+///
+/// [`Module`]: crate::Module
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct Reflection {
+    /// The module's entry points, in the order they appear in the module.
+    pub entry_points: Vec<EntryPoint>,
+
+    /// The module's resource bindings, sorted by group.
+    pub bind_groups: Vec<BindGroup>,
+
+    /// The module's push constant blocks.
+    pub push_constants: Vec<PushConstant>,
+
+    /// The module's pipeline-overridable constants.
+    pub overrides: Vec<Override>,
+}
+
+/// An entry point of the module.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct EntryPoint {
+    pub name: String,
+    pub stage: ShaderStage,
+
+    /// The workgroup size, for compute shaders.
+    pub workgroup_size: Option<[u32; 3]>,
+
+    /// The entry point's inputs, with structs flattened into their members.
+    ///
+    /// For vertex shaders, these are the vertex attributes.
+    pub inputs: Vec<Varying>,
+
+    /// The entry point's outputs, with structs flattened into their members.
+    ///
+    /// For fragment shaders, these are the color attachments, along with any
+    /// built-in outputs like the fragment depth.
+    pub outputs: Vec<Varying>,
+
+    /// The resources this entry point uses, sorted by group and binding.
+    pub resources: Vec<ResourceBinding>,
+}
+
+/// An input or output of an entry point.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct Varying {
+    /// The name of the argument or struct member, if it has one.
+    pub name: Option<String>,
+
+    /// The location or built-in value this is bound to.
+    pub binding: Binding,
+
+    pub ty: TypeInner,
+}
+
+/// The resource bindings in one bind group.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct BindGroup {
+    pub group: u32,
+
+    /// The bindings in this group, sorted by binding number.
+    pub bindings: Vec<BindingEntry>,
+}
+
+/// A resource bound to a global variable.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct BindingEntry {
+    pub binding: u32,
+
+    /// The name of the global variable, if it has one.
+    pub name: Option<String>,
+
+    pub ty: BindingType,
+
+    /// The number of elements, if this is a binding array.
+    pub count: Option<ArraySize>,
+
+    /// The stages of the entry points that use this resource.
+    pub visibility: Vec<ShaderStage>,
+}
+
+/// The kind of resource a [`BindingEntry`] expects.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub enum BindingType {
+    /// A uniform buffer.
+    ///
+    /// The buffer must be at least `min_binding_size` bytes long.
+    UniformBuffer {
+        min_binding_size: u32,
+    },
+
+    /// A storage buffer.
+    ///
+    /// The buffer must be at least `min_binding_size` bytes long. If the
+    /// buffer's contents end with a runtime-sized array, this counts one
+    /// element of that array.
+    StorageBuffer {
+        access: StorageAccess,
+        min_binding_size: u32,
+    },
+
+    /// A sampled, depth, or storage texture.
+    ///
+    /// For storage textures, `class` includes the format and access mode.
+    Texture {
+        dim: ImageDimension,
+        arrayed: bool,
+        class: ImageClass,
+    },
+
+    Sampler {
+        comparison: bool,
+    },
+
+    AccelerationStructure,
+}
+
+/// A global variable in the [`PushConstant`] address space.
+///
+/// [`PushConstant`]: AddressSpace::PushConstant
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct PushConstant {
+    /// The name of the global variable, if it has one.
+    pub name: Option<String>,
+
+    /// The byte range the variable occupies.
+    ///
+    /// An entry point can use at most one push constant variable, which
+    /// always starts at offset zero.
+    pub range: Range<u32>,
+
+    /// The stages of the entry points that use this variable.
+    pub visibility: Vec<ShaderStage>,
+}
+
+/// A pipeline-overridable constant.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
+pub struct Override {
+    pub name: Option<String>,
+
+    /// The pipeline constant ID, if the override has one.
+    pub id: Option<u16>,
+
+    pub ty: Scalar,
+
+    /// The default value, if the override has one that is a literal.
+    ///
+    /// This uses the same representation as the values accepted by
+    /// [`process_overrides`]: booleans are `0.0` or `1.0`. Defaults computed
+    /// from other overrides are `None`, since their value depends on the
+    /// pipeline.
+    ///
+    /// [`process_overrides`]: crate::back::pipeline_constants::process_overrides
+    pub default: Option<f64>,
+}
+
+/// Summarize the interface of `module`.
+///
+/// The `info` argument must be the result of validating `module`.
+///
+/// # Panics
+///
+/// If `module` has not passed validation, this may panic.
+pub fn reflect(module: &Module, info: &ModuleInfo) -> Reflection {
+    let gctx = module.to_ctx();
+
+    let entry_points = module
+        .entry_points
+        .iter()
+        .enumerate()
+        .map(|(index, entry_point)| {
+            let function = &entry_point.function;
+            let mut inputs = Vec::new();
+            for argument in function.arguments.iter() {
+                collect_varyings(
+                    module,
+                    &mut inputs,
+                    argument.name.as_ref(),
+                    argument.binding.as_ref(),
+                    argument.ty,
+                );
+            }
+            let mut outputs = Vec::new();
+            if let Some(ref result) = function.result {
+                collect_varyings(
+                    module,
+                    &mut outputs,
+                    None,
+                    result.binding.as_ref(),
+                    result.ty,
+                );
+            }
+
+            let ep_info = info.get_entry_point(index);
+            let mut resources: Vec<_> = module
+                .global_variables
+                .iter()
+                .filter(|&(handle, _)| !ep_info[handle].is_empty())
+                .filter_map(|(_, var)| var.binding.clone())
+                .collect();
+            resources.sort_by_key(|binding| (binding.group, binding.binding));
+
+            EntryPoint {
+                name: entry_point.name.clone(),
+                stage: entry_point.stage,
+                workgroup_size: (entry_point.stage == ShaderStage::Compute)
+                    .then_some(entry_point.workgroup_size),
+                inputs,
+                outputs,
+                resources,
+            }
+        })
+        .collect();
+
+    let mut groups = BTreeMap::<u32, Vec<BindingEntry>>::new();
+    let mut push_constants = Vec::new();
+    for (handle, var) in module.global_variables.iter() {
+        let visibility = module
+            .entry_points
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| !info.get_entry_point(index)[handle].is_empty())
+            .fold(Vec::new(), |mut stages, (_, entry_point)| {
+                if !stages.contains(&entry_point.stage) {
+                    stages.push(entry_point.stage);
+                }
+                stages
+            });
+
+        if var.space == AddressSpace::PushConstant {
+            push_constants.push(PushConstant {
+                name: var.name.clone(),
+                range: 0..module.types[var.ty].inner.size(gctx),
+                visibility,
+            });
+            continue;
+        }
+
+        let Some(ref binding) = var.binding else {
+            continue;
+        };
+        let (inner, count) = match module.types[var.ty].inner {
+            TypeInner::BindingArray { base, size } => (&module.types[base].inner, Some(size)),
+            ref inner => (inner, None),
+        };
+        let ty = match (var.space, inner) {
+            (AddressSpace::Uniform, _) => BindingType::UniformBuffer {
+                min_binding_size: inner.size(gctx),
+            },
+            (AddressSpace::Storage { access }, _) => BindingType::StorageBuffer {
+                access,
+                min_binding_size: inner.size(gctx),
+            },
+            (
+                _,
+                &TypeInner::Image {
+                    dim,
+                    arrayed,
+                    class,
+                },
+            ) => BindingType::Texture {
+                dim,
+                arrayed,
+                class,
+            },
+            (_, &TypeInner::Sampler { comparison }) => BindingType::Sampler { comparison },
+            (_, &TypeInner::AccelerationStructure) => BindingType::AccelerationStructure,
+            // The validator doesn't allow bindings on anything else.
+            _ => continue,
+        };
+        groups.entry(binding.group).or_default().push(BindingEntry {
+            binding: binding.binding,
+            name: var.name.clone(),
+            ty,
+            count,
+            visibility,
+        });
+    }
+    let bind_groups = groups
+        .into_iter()
+        .map(|(group, mut bindings)| {
+            bindings.sort_by_key(|entry| entry.binding);
+            BindGroup { group, bindings }
+        })
+        .collect();
+
+    let overrides = module
+        .overrides
+        .iter()
+        .map(|(_, o)| Override {
+            name: o.name.clone(),
+            id: o.id,
+            ty: module.types[o.ty]
+                .inner
+                .scalar()
+                .expect("overrides must have scalar types"),
+            default: o
+                .init
+                .and_then(|init| literal_to_f64(&module.global_expressions[init])),
+        })
+        .collect();
+
+    Reflection {
+        entry_points,
+        bind_groups,
+        push_constants,
+        overrides,
+    }
+}
+
+/// Append the entry point input or output of type `ty` to `list`.
+///
+/// If `ty` is a struct, append its members instead.
+fn collect_varyings(
+    module: &Module,
+    list: &mut Vec<Varying>,
+    name: Option<&String>,
+    binding: Option<&Binding>,
+    ty: crate::Handle<crate::Type>,
+) {
+    match module.types[ty].inner {
+        TypeInner::Struct { ref members, .. } => {
+            for member in members {
+                collect_varyings(
+                    module,
+                    list,
+                    member.name.as_ref(),
+                    member.binding.as_ref(),
+                    member.ty,
+                );
+            }
+        }
+        ref inner => {
+            if let Some(binding) = binding {
+                list.push(Varying {
+                    name: name.cloned(),
+                    binding: binding.clone(),
+                    ty: inner.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Return the value of `expr` as an `f64`, if it is a literal.
+fn literal_to_f64(expr: &crate::Expression) -> Option<f64> {
+    use crate::Literal as L;
+
+    let crate::Expression::Literal(literal) = *expr else {
+        return None;
+    };
+    Some(match literal {
+        L::F64(value) | L::AbstractFloat(value) => value,
+        L::F32(value) => value.into(),
+        L::F16(value) => value.into(),
+        L::U32(value) => value.into(),
+        L::I32(value) => value.into(),
+        L::U64(value) => value as f64,
+        L::I64(value) | L::AbstractInt(value) => value as f64,
+        L::Bool(value) => f64::from(u8::from(value)),
+    })
+}
@@ -6,6 +6,9 @@
 //! - expression reference counts
 
 use super::{ExpressionError, FunctionError, ModuleInfo, ShaderStages, ValidationFlags};
+use crate::diagnostic_filter::{
+    DiagnosticFilterNode, DiagnosticFilterScope, Severity, StandardFilterableTriggeringRule,
+};
 use crate::span::{AddSpan as _, WithSpan};
 use crate::{
     arena::{Arena, Handle},
@@ -15,9 +18,28 @@ use std::ops;
 
 pub type NonUniformResult = Option<Handle<crate::Expression>>;
 
-// Remove this once we update our uniformity analysis and
-// add support for the `derivative_uniformity` diagnostic
-const DISABLE_UNIFORMITY_REQ_FOR_FRAGMENT_STAGE: bool = true;
+/// The diagnostic filters applicable to the function being analyzed.
+#[derive(Clone, Copy)]
+struct DiagnosticFilters<'a> {
+    /// The module's [`diagnostic_filters`](crate::Module::diagnostic_filters).
+    arena: &'a Arena<DiagnosticFilterNode>,
+    /// The function's [`diagnostic_filter_leaf`](crate::Function::diagnostic_filter_leaf).
+    function_leaf: Option<Handle<DiagnosticFilterNode>>,
+    /// The function's [`diagnostic_filter_scopes`](crate::Function::diagnostic_filter_scopes).
+    scopes: &'a [DiagnosticFilterScope],
+}
+
+impl DiagnosticFilters<'_> {
+    /// Return the severity at which `triggering_rule` should be reported for `expr`.
+    fn severity_for(
+        &self,
+        expr: Handle<crate::Expression>,
+        triggering_rule: StandardFilterableTriggeringRule,
+    ) -> Severity {
+        let leaf = DiagnosticFilterScope::leaf_for(self.scopes, self.function_leaf, expr);
+        DiagnosticFilterNode::search(leaf, self.arena, triggering_rule)
+    }
+}
 
 bitflags::bitflags! {
     /// Kinds of expressions that require uniform control flow.
@@ -26,8 +48,8 @@ bitflags::bitflags! {
     #[derive(Clone, Copy, Debug, Eq, PartialEq)]
     pub struct UniformityRequirements: u8 {
         const WORK_GROUP_BARRIER = 0x1;
-        const DERIVATIVE = if DISABLE_UNIFORMITY_REQ_FOR_FRAGMENT_STAGE { 0 } else { 0x2 };
-        const IMPLICIT_LEVEL = if DISABLE_UNIFORMITY_REQ_FOR_FRAGMENT_STAGE { 0 } else { 0x4 };
+        const DERIVATIVE = 0x2;
+        const IMPLICIT_LEVEL = 0x4;
     }
 }
 
@@ -820,6 +842,7 @@ impl FunctionInfo {
         other_functions: &[FunctionInfo],
         mut disruptor: Option<UniformityDisruptor>,
         expression_arena: &Arena<crate::Expression>,
+        diagnostic_filters: DiagnosticFilters,
     ) -> Result<FunctionUniformity, WithSpan<FunctionError>> {
         use crate::Statement as S;
 
@@ -836,8 +859,15 @@ impl FunctionInfo {
                             && !req.is_empty()
                         {
                             if let Some(cause) = disruptor {
-                                return Err(FunctionError::NonUniformControlFlow(req, expr, cause)
-                                    .with_span_handle(expr, expression_arena));
+                                let severity = diagnostic_filters.severity_for(
+                                    expr,
+                                    StandardFilterableTriggeringRule::DerivativeUniformity,
+                                );
+                                severity.report_diag(
+                                    FunctionError::NonUniformControlFlow(req, expr, cause)
+                                        .with_span_handle(expr, expression_arena),
+                                    |e, level| log::log!(level, "{e}"),
+                                )?;
                             }
                         }
                         requirements |= req;
@@ -859,6 +889,9 @@ impl FunctionInfo {
                         ExitFlags::empty()
                     },
                 },
+                // Demoted invocations keep executing as helpers, so control
+                // flow stays uniform.
+                S::Demote => FunctionUniformity::new(),
                 S::Barrier(_) => FunctionUniformity {
                     result: Uniformity {
                         non_uniform_result: None,
@@ -895,9 +928,13 @@ impl FunctionInfo {
                         exit: ExitFlags::empty(),
                     }
                 }
-                S::Block(ref b) => {
-                    self.process_block(b, other_functions, disruptor, expression_arena)?
-                }
+                S::Block(ref b) => self.process_block(
+                    b,
+                    other_functions,
+                    disruptor,
+                    expression_arena,
+                    diagnostic_filters,
+                )?,
                 S::If {
                     condition,
                     ref accept,
@@ -911,12 +948,14 @@ impl FunctionInfo {
                         other_functions,
                         branch_disruptor,
                         expression_arena,
+                        diagnostic_filters,
                     )?;
                     let reject_uniformity = self.process_block(
                         reject,
                         other_functions,
                         branch_disruptor,
                         expression_arena,
+                        diagnostic_filters,
                     )?;
                     accept_uniformity | reject_uniformity
                 }
@@ -935,6 +974,7 @@ impl FunctionInfo {
                             other_functions,
                             case_disruptor,
                             expression_arena,
+                            diagnostic_filters,
                         )?;
                         case_disruptor = if case.fall_through {
                             case_disruptor.or(case_uniformity.exit_disruptor())
@@ -950,14 +990,20 @@ impl FunctionInfo {
                     ref continuing,
                     break_if,
                 } => {
-                    let body_uniformity =
-                        self.process_block(body, other_functions, disruptor, expression_arena)?;
+                    let body_uniformity = self.process_block(
+                        body,
+                        other_functions,
+                        disruptor,
+                        expression_arena,
+                        diagnostic_filters,
+                    )?;
                     let continuing_disruptor = disruptor.or(body_uniformity.exit_disruptor());
                     let continuing_uniformity = self.process_block(
                         continuing,
                         other_functions,
                         continuing_disruptor,
                         expression_arena,
+                        diagnostic_filters,
                     )?;
                     if let Some(expr) = break_if {
                         let _ = self.add_ref(expr);
@@ -1059,12 +1105,13 @@ impl FunctionInfo {
                 } => {
                     let _ = self.add_ref(argument);
                     match mode {
-                        crate::GatherMode::BroadcastFirst => {}
+                        crate::GatherMode::BroadcastFirst | crate::GatherMode::QuadSwap(_) => {}
                         crate::GatherMode::Broadcast(index)
                         | crate::GatherMode::Shuffle(index)
                         | crate::GatherMode::ShuffleDown(index)
                         | crate::GatherMode::ShuffleUp(index)
-                        | crate::GatherMode::ShuffleXor(index) => {
+                        | crate::GatherMode::ShuffleXor(index)
+                        | crate::GatherMode::QuadBroadcast(index) => {
                             let _ = self.add_ref(index);
                         }
                     }
@@ -1134,7 +1181,18 @@ impl ModuleInfo {
             }
         }
 
-        let uniformity = info.process_block(&fun.body, &self.functions, None, &fun.expressions)?;
+        let diagnostic_filters = DiagnosticFilters {
+            arena: &module.diagnostic_filters,
+            function_leaf: fun.diagnostic_filter_leaf.or(module.diagnostic_filter_leaf),
+            scopes: &fun.diagnostic_filter_scopes,
+        };
+        let uniformity = info.process_block(
+            &fun.body,
+            &self.functions,
+            None,
+            &fun.expressions,
+            diagnostic_filters,
+        )?;
         info.uniformity = uniformity.result;
         info.may_kill = uniformity.exit.contains(ExitFlags::MAY_KILL);
 
@@ -1225,6 +1283,24 @@ fn uniform_control_flow() {
         sampling: crate::FastHashSet::default(),
         dual_source_blending: false,
     };
+    let mut diagnostic_filter_arena = Arena::new();
+    let derivative_uniformity_error = diagnostic_filter_arena.append(
+        DiagnosticFilterNode {
+            inner: crate::diagnostic_filter::DiagnosticFilter {
+                new_severity: Severity::Error,
+                triggering_rule: crate::diagnostic_filter::FilterableTriggeringRule::Standard(
+                    StandardFilterableTriggeringRule::DerivativeUniformity,
+                ),
+            },
+            parent: None,
+        },
+        Default::default(),
+    );
+    let diagnostic_filters = DiagnosticFilters {
+        arena: &diagnostic_filter_arena,
+        function_leaf: Some(derivative_uniformity_error),
+        scopes: &[],
+    };
     let resolve_context = ResolveContext {
         constants: &Arena::new(),
         overrides: &Arena::new(),
@@ -1270,7 +1346,8 @@ fn uniform_control_flow() {
             &vec![stmt_emit1, stmt_if_uniform].into(),
             &[],
             None,
-            &expressions
+            &expressions,
+            diagnostic_filters,
         ),
         Ok(FunctionUniformity {
             result: Uniformity {
@@ -1302,21 +1379,18 @@ fn uniform_control_flow() {
             &[],
             None,
             &expressions,
+            diagnostic_filters,
         );
-        if DISABLE_UNIFORMITY_REQ_FOR_FRAGMENT_STAGE {
-            assert_eq!(info[derivative_expr].ref_count, 2);
-        } else {
-            assert_eq!(
-                block_info,
-                Err(FunctionError::NonUniformControlFlow(
-                    UniformityRequirements::DERIVATIVE,
-                    derivative_expr,
-                    UniformityDisruptor::Expression(non_uniform_global_expr)
-                )
-                .with_span()),
-            );
-            assert_eq!(info[derivative_expr].ref_count, 1);
-        }
+        assert_eq!(
+            block_info,
+            Err(FunctionError::NonUniformControlFlow(
+                UniformityRequirements::DERIVATIVE,
+                derivative_expr,
+                UniformityDisruptor::Expression(non_uniform_global_expr)
+            )
+            .with_span()),
+        );
+        assert_eq!(info[derivative_expr].ref_count, 1);
     }
     assert_eq!(info[non_uniform_global], GlobalUse::READ);
 
@@ -1329,7 +1403,8 @@ fn uniform_control_flow() {
             &vec![stmt_emit3, stmt_return_non_uniform].into(),
             &[],
             Some(UniformityDisruptor::Return),
-            &expressions
+            &expressions,
+            diagnostic_filters,
         ),
         Ok(FunctionUniformity {
             result: Uniformity {
@@ -1356,7 +1431,8 @@ fn uniform_control_flow() {
             &vec![stmt_emit4, stmt_assign, stmt_kill, stmt_return_pointer].into(),
             &[],
             Some(UniformityDisruptor::Discard),
-            &expressions
+            &expressions,
+            diagnostic_filters,
         ),
         Ok(FunctionUniformity {
             result: Uniformity {
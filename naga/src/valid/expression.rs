@@ -1156,6 +1156,26 @@ impl super::Validator {
                             ));
                         }
                     }
+                    Mf::Dot4I8Packed | Mf::Dot4U8Packed => {
+                        let arg1_ty = match (arg1_ty, arg2_ty, arg3_ty) {
+                            (Some(ty1), None, None) => ty1,
+                            _ => return Err(ExpressionError::WrongArgumentCount(fun)),
+                        };
+                        match *arg_ty {
+                            Ti::Scalar(Sc::U32) => {}
+                            _ => return Err(ExpressionError::InvalidArgumentType(fun, 0, arg)),
+                        }
+                        match *arg1_ty {
+                            Ti::Scalar(Sc::U32) => {}
+                            _ => {
+                                return Err(ExpressionError::InvalidArgumentType(
+                                    fun,
+                                    1,
+                                    arg1.unwrap(),
+                                ))
+                            }
+                        }
+                    }
                     Mf::Outer | Mf::Reflect => {
                         let arg1_ty = match (arg1_ty, arg2_ty, arg3_ty) {
                             (Some(ty1), None, None) => ty1,
@@ -1363,6 +1383,18 @@ impl super::Validator {
                             _ => return Err(ExpressionError::InvalidArgumentType(fun, 0, arg)),
                         }
                     }
+                    Mf::QuantizeToF16 => {
+                        if arg1_ty.is_some() || arg2_ty.is_some() || arg3_ty.is_some() {
+                            return Err(ExpressionError::WrongArgumentCount(fun));
+                        }
+                        match *arg_ty {
+                            Ti::Scalar(Sc::F32)
+                            | Ti::Vector {
+                                scalar: Sc::F32, ..
+                            } => {}
+                            _ => return Err(ExpressionError::InvalidArgumentType(fun, 0, arg)),
+                        }
+                    }
                     // Remove once fixed https://github.com/gfx-rs/wgpu/issues/5276
                     Mf::CountLeadingZeros
                     | Mf::CountTrailingZeros
@@ -1538,10 +1570,10 @@ impl super::Validator {
                             _ => return Err(ExpressionError::InvalidArgumentType(fun, 0, arg)),
                         }
                     }
-                    mf @ (Mf::Pack4xI8 | Mf::Pack4xU8) => {
+                    mf @ (Mf::Pack4xI8 | Mf::Pack4xU8 | Mf::Pack4xI8Clamp | Mf::Pack4xU8Clamp) => {
                         let scalar_kind = match mf {
-                            Mf::Pack4xI8 => Sk::Sint,
-                            Mf::Pack4xU8 => Sk::Uint,
+                            Mf::Pack4xI8 | Mf::Pack4xI8Clamp => Sk::Sint,
+                            Mf::Pack4xU8 | Mf::Pack4xU8Clamp => Sk::Uint,
                             _ => unreachable!(),
                         };
                         if arg1_ty.is_some() || arg2_ty.is_some() || arg3_ty.is_some() {
@@ -1696,6 +1728,7 @@ pub fn check_literal_value(literal: crate::Literal) -> Result<(), LiteralError>
     let is_nan = match literal {
         crate::Literal::F64(v) => v.is_nan(),
         crate::Literal::F32(v) => v.is_nan(),
+        crate::Literal::F16(v) => v.is_nan(),
         _ => false,
     };
     if is_nan {
@@ -1705,6 +1738,7 @@ pub fn check_literal_value(literal: crate::Literal) -> Result<(), LiteralError>
     let is_infinite = match literal {
         crate::Literal::F64(v) => v.is_infinite(),
         crate::Literal::F32(v) => v.is_infinite(),
+        crate::Literal::F16(v) => v.is_infinite(),
         _ => false,
     };
     if is_infinite {
@@ -61,6 +61,14 @@ pub enum AtomicError {
     ResultAlreadyPopulated(Handle<crate::Expression>),
 }
 
+/// The largest cluster size a [`ClusteredReduce`] may use.
+///
+/// This is the largest subgroup size any supported API allows; a cluster can
+/// never span more than one subgroup.
+///
+/// [`ClusteredReduce`]: crate::CollectiveOperation::ClusteredReduce
+const MAX_CLUSTER_SIZE: u32 = 128;
+
 #[derive(Clone, Debug, thiserror::Error)]
 #[cfg_attr(test, derive(PartialEq))]
 pub enum SubgroupError {
@@ -72,6 +80,8 @@ pub enum SubgroupError {
     UnsupportedOperation(super::SubgroupOperationSet),
     #[error("Unknown operation")]
     UnknownOperation,
+    #[error("Cluster size {0} is not a power of two no greater than {MAX_CLUSTER_SIZE}")]
+    InvalidClusterSize(u32),
 }
 
 #[derive(Clone, Debug, thiserror::Error)]
@@ -582,6 +592,13 @@ impl super::Validator {
         };
 
         use crate::CollectiveOperation as co;
+        if let co::ClusteredReduce(size) = *collective_op {
+            if !size.is_power_of_two() || size > MAX_CLUSTER_SIZE {
+                return Err(SubgroupError::InvalidClusterSize(size)
+                    .with_span()
+                    .into_other());
+            }
+        }
         match (*collective_op, *op) {
             (
                 co::Reduce,
@@ -596,6 +613,10 @@ impl super::Validator {
                 | sg::Xor,
             ) => {}
             (co::InclusiveScan | co::ExclusiveScan, sg::Add | sg::Mul) => {}
+            (
+                co::ClusteredReduce(_),
+                sg::Add | sg::Mul | sg::Min | sg::Max | sg::And | sg::Or | sg::Xor,
+            ) => {}
 
             (_, _) => {
                 return Err(SubgroupError::UnknownOperation.with_span().into_other());
@@ -622,12 +643,13 @@ impl super::Validator {
         context: &BlockContext,
     ) -> Result<(), WithSpan<FunctionError>> {
         match *mode {
-            crate::GatherMode::BroadcastFirst => {}
+            crate::GatherMode::BroadcastFirst | crate::GatherMode::QuadSwap(_) => {}
             crate::GatherMode::Broadcast(index)
             | crate::GatherMode::Shuffle(index)
             | crate::GatherMode::ShuffleDown(index)
             | crate::GatherMode::ShuffleUp(index)
-            | crate::GatherMode::ShuffleXor(index) => {
+            | crate::GatherMode::ShuffleXor(index)
+            | crate::GatherMode::QuadBroadcast(index) => {
                 let index_ty = context.resolve_type(index, &self.valid_expression_set)?;
                 match *index_ty {
                     crate::TypeInner::Scalar(crate::Scalar::U32) => {}
@@ -918,6 +940,9 @@ impl super::Validator {
                     stages &= super::ShaderStages::FRAGMENT;
                     finished = true;
                 }
+                S::Demote => {
+                    stages &= super::ShaderStages::FRAGMENT;
+                }
                 S::Barrier(barrier) => {
                     stages &= super::ShaderStages::COMPUTE;
                     if barrier.contains(crate::Barrier::SUB_GROUP) {
@@ -1273,7 +1298,7 @@ impl super::Validator {
                         )
                         .with_span_static(span, "missing capability for this operation"));
                     }
-                    let operation = op.required_operations();
+                    let operation = op.required_operations() | collective_op.required_operations();
                     if !self.subgroup_operations.contains(operation) {
                         return Err(FunctionError::InvalidSubgroup(
                             SubgroupError::UnsupportedOperation(operation),
@@ -1301,6 +1326,13 @@ impl super::Validator {
                         )
                         .with_span_static(span, "support for this operation is not present"));
                     }
+                    if operation.contains(super::SubgroupOperationSet::QUAD_FRAGMENT_COMPUTE)
+                        && !self
+                            .subgroup_operations
+                            .contains(super::SubgroupOperationSet::QUAD_ALL_STAGES)
+                    {
+                        stages &= super::ShaderStages::FRAGMENT | super::ShaderStages::COMPUTE;
+                    }
                     self.validate_subgroup_gather(mode, argument, result, context)?;
                 }
             }
@@ -1379,7 +1411,14 @@ impl super::Validator {
 
         for (index, argument) in fun.arguments.iter().enumerate() {
             match module.types[argument.ty].inner.pointer_space() {
-                Some(crate::AddressSpace::Private | crate::AddressSpace::Function) | None => {}
+                Some(
+                    crate::AddressSpace::Private
+                    | crate::AddressSpace::Function
+                    | crate::AddressSpace::Uniform
+                    | crate::AddressSpace::Storage { .. }
+                    | crate::AddressSpace::WorkGroup,
+                )
+                | None => {}
                 Some(other) => {
                     return Err(FunctionError::InvalidArgumentPointerSpace {
                         index,
@@ -39,6 +39,8 @@ impl super::Validator {
             ref types,
             ref special_types,
             ref global_expressions,
+            ref diagnostic_filters,
+            ref diagnostic_filter_leaf,
         } = module;
 
         // NOTE: Types being first is important. All other forms of validation depend on this.
@@ -76,6 +78,8 @@ impl super::Validator {
         let validate_type = |handle| Self::validate_type_handle(handle, types);
         let validate_const_expr =
             |handle| Self::validate_expression_handle(handle, global_expressions);
+        let validate_diagnostic_filter =
+            |handle| Self::validate_diagnostic_filter_handle(handle, diagnostic_filters);
 
         for (_handle, constant) in constants.iter() {
             let &crate::Constant { name: _, ty, init } = constant;
@@ -119,6 +123,8 @@ impl super::Validator {
                 ref expressions,
                 ref named_expressions,
                 ref body,
+                ref diagnostic_filter_leaf,
+                ref diagnostic_filter_scopes,
             } = function;
 
             for arg in arguments.iter() {
@@ -162,6 +168,19 @@ impl super::Validator {
 
             Self::validate_block_handles(body, expressions, functions)?;
 
+            if let Some(handle) = *diagnostic_filter_leaf {
+                validate_diagnostic_filter(handle)?;
+            }
+
+            for scope in diagnostic_filter_scopes.iter() {
+                let &crate::diagnostic_filter::DiagnosticFilterScope {
+                    expressions: ref scope_expressions,
+                    leaf,
+                } = scope;
+                scope_expressions.check_valid_for(expressions)?;
+                validate_diagnostic_filter(leaf)?;
+            }
+
             Ok(())
         };
 
@@ -173,6 +192,14 @@ impl super::Validator {
             validate_function(Some(function_handle), function)?;
         }
 
+        for (handle, node) in diagnostic_filters.iter() {
+            let &crate::diagnostic_filter::DiagnosticFilterNode { inner: _, parent } = node;
+            handle.check_dep_opt(parent)?;
+        }
+        if let Some(handle) = *diagnostic_filter_leaf {
+            validate_diagnostic_filter(handle)?;
+        }
+
         if let Some(ty) = special_types.ray_desc {
             validate_type(ty)?;
         }
@@ -211,6 +238,13 @@ impl super::Validator {
         handle.check_valid_for(expressions).map(|_| ())
     }
 
+    fn validate_diagnostic_filter_handle(
+        handle: Handle<crate::diagnostic_filter::DiagnosticFilterNode>,
+        diagnostic_filters: &Arena<crate::diagnostic_filter::DiagnosticFilterNode>,
+    ) -> Result<(), InvalidHandleError> {
+        handle.check_valid_for(diagnostic_filters).map(|_| ())
+    }
+
     fn validate_function_handle(
         handle: Handle<crate::Function>,
         functions: &Arena<crate::Function>,
@@ -592,12 +626,13 @@ impl super::Validator {
             } => {
                 validate_expr(argument)?;
                 match mode {
-                    crate::GatherMode::BroadcastFirst => {}
+                    crate::GatherMode::BroadcastFirst | crate::GatherMode::QuadSwap(_) => {}
                     crate::GatherMode::Broadcast(index)
                     | crate::GatherMode::Shuffle(index)
                     | crate::GatherMode::ShuffleDown(index)
                     | crate::GatherMode::ShuffleUp(index)
-                    | crate::GatherMode::ShuffleXor(index) => validate_expr(index)?,
+                    | crate::GatherMode::ShuffleXor(index)
+                    | crate::GatherMode::QuadBroadcast(index) => validate_expr(index)?,
                 }
                 validate_expr(result)?;
                 Ok(())
@@ -605,6 +640,7 @@ impl super::Validator {
             crate::Statement::Break
             | crate::Statement::Continue
             | crate::Statement::Kill
+            | crate::Statement::Demote
             | crate::Statement::Barrier(_) => Ok(()),
         })
     }
@@ -8,6 +8,8 @@ use crate::span::{AddSpan as _, MapErrWithSpan as _, SpanProvider as _, WithSpan
 use bit_set::BitSet;
 
 const MAX_WORKGROUP_SIZE: u32 = 0x4000;
+/// The largest number of clip distances a vertex shader may write.
+const MAX_CLIP_DISTANCES: u32 = 8;
 
 #[derive(Clone, Debug, thiserror::Error)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -67,6 +69,8 @@ pub enum VaryingError {
     MemberMissingBinding(u32),
     #[error("Multiple bindings at location {location} are present")]
     BindingCollision { location: u32 },
+    #[error("Multiple bindings with blend source {blend_src} are present")]
+    BindingCollisionBlendSrc { blend_src: u32 },
     #[error("Built-in {0:?} is present more than once")]
     DuplicateBuiltIn(crate::BuiltIn),
     #[error("Capability {0:?} is not supported")]
@@ -82,8 +86,12 @@ pub enum VaryingError {
         location: u32,
         attribute: &'static str,
     },
+    #[error("The blend source index {blend_src} is out of range, only 0 and 1 are allowed")]
+    InvalidBlendSrcIndex { blend_src: u32 },
     #[error("Workgroup size is multi dimensional, @builtin(subgroup_id) and @builtin(subgroup_invocation_id) are not supported.")]
     InvalidMultiDimensionalSubgroupBuiltIn,
+    #[error("{0} clip distances are written, but at most {MAX_CLIP_DISTANCES} are allowed")]
+    TooManyClipDistances(u32),
 }
 
 #[derive(Clone, Debug, thiserror::Error)]
@@ -119,6 +127,8 @@ pub enum EntryPointError {
         "Invalid locations {location_mask:?} are set while dual source blending. Only location 0 may be set."
     )]
     InvalidLocationsWhileDualSourceBlending { location_mask: BitSet },
+    #[error("Dual source blending requires outputs with both blend source 0 and blend source 1")]
+    IncompleteBlendSrcUsage,
 }
 
 fn storage_usage(access: crate::StorageAccess) -> GlobalUse {
@@ -135,7 +145,7 @@ fn storage_usage(access: crate::StorageAccess) -> GlobalUse {
 struct VaryingContext<'a> {
     stage: crate::ShaderStage,
     output: bool,
-    second_blend_source: bool,
+    blend_src_mask: BitSet,
     types: &'a UniqueArena<crate::Type>,
     type_info: &'a Vec<super::r#type::TypeInfo>,
     location_mask: &'a mut BitSet,
@@ -298,12 +308,24 @@ impl VaryingContext<'_> {
                     log::warn!("Wrong builtin type: {:?}", ty_inner);
                     return Err(VaryingError::InvalidBuiltInType(built_in));
                 }
+                if let (
+                    Bi::ClipDistance,
+                    &Ti::Array {
+                        size: crate::ArraySize::Constant(size),
+                        ..
+                    },
+                ) = (built_in, ty_inner)
+                {
+                    if size.get() > MAX_CLIP_DISTANCES {
+                        return Err(VaryingError::TooManyClipDistances(size.get()));
+                    }
+                }
             }
             crate::Binding::Location {
                 location,
                 interpolation,
                 sampling,
-                second_blend_source,
+                blend_src,
             } => {
                 // Only IO-shareable types may be stored in locations.
                 if !self.type_info[ty.index()]
@@ -313,7 +335,7 @@ impl VaryingContext<'_> {
                     return Err(VaryingError::NotIOShareableType(ty));
                 }
 
-                if second_blend_source {
+                if let Some(blend_src) = blend_src {
                     if !self
                         .capabilities
                         .contains(Capabilities::DUAL_SOURCE_BLENDING)
@@ -324,24 +346,31 @@ impl VaryingContext<'_> {
                     }
                     if self.stage != crate::ShaderStage::Fragment {
                         return Err(VaryingError::InvalidAttributeInStage(
-                            "second_blend_source",
+                            "blend_src",
                             self.stage,
                         ));
                     }
                     if !self.output {
                         return Err(VaryingError::InvalidInputAttributeInStage(
-                            "second_blend_source",
+                            "blend_src",
                             self.stage,
                         ));
                     }
                     if location != 0 {
                         return Err(VaryingError::InvalidLocationAttributeCombination {
                             location,
-                            attribute: "second_blend_source",
+                            attribute: "blend_src",
                         });
                     }
 
-                    self.second_blend_source = true;
+                    if blend_src > 1 {
+                        return Err(VaryingError::InvalidBlendSrcIndex { blend_src });
+                    }
+                    if !self.blend_src_mask.insert(blend_src as usize)
+                        && self.flags.contains(super::ValidationFlags::BINDINGS)
+                    {
+                        return Err(VaryingError::BindingCollisionBlendSrc { blend_src });
+                    }
                 } else if !self.location_mask.insert(location as usize) {
                     if self.flags.contains(super::ValidationFlags::BINDINGS) {
                         return Err(VaryingError::BindingCollision { location });
@@ -662,7 +691,7 @@ impl super::Validator {
             let mut ctx = VaryingContext {
                 stage: ep.stage,
                 output: false,
-                second_blend_source: false,
+                blend_src_mask: BitSet::new(),
                 types: &module.types,
                 type_info: &self.types,
                 location_mask: &mut self.location_mask,
@@ -680,7 +709,7 @@ impl super::Validator {
             let mut ctx = VaryingContext {
                 stage: ep.stage,
                 output: true,
-                second_blend_source: false,
+                blend_src_mask: BitSet::new(),
                 types: &module.types,
                 type_info: &self.types,
                 location_mask: &mut self.location_mask,
@@ -690,16 +719,18 @@ impl super::Validator {
             };
             ctx.validate(ep, fr.ty, fr.binding.as_ref())
                 .map_err_inner(|e| EntryPointError::Result(e).with_span())?;
-            if ctx.second_blend_source {
-                // Only the first location may be used when dual source blending
-                if ctx.location_mask.len() == 1 && ctx.location_mask.contains(0) {
-                    info.dual_source_blending = true;
-                } else {
+            if !ctx.blend_src_mask.is_empty() {
+                // Both blend sources share location 0, no other location may be used
+                if !ctx.location_mask.is_empty() {
                     return Err(EntryPointError::InvalidLocationsWhileDualSourceBlending {
                         location_mask: self.location_mask.clone(),
                     }
                     .with_span());
                 }
+                if ctx.blend_src_mask.len() != 2 {
+                    return Err(EntryPointError::IncompleteBlendSrcUsage.with_span());
+                }
+                info.dual_source_blending = true;
             }
 
             if ep.stage == crate::ShaderStage::Vertex
@@ -26,7 +26,7 @@ pub use analyzer::{ExpressionInfo, FunctionInfo, GlobalUse, Uniformity, Uniformi
 pub use compose::ComposeError;
 pub use expression::{check_literal_value, LiteralError};
 pub use expression::{ConstExpressionError, ExpressionError};
-pub use function::{CallError, FunctionError, LocalVariableError};
+pub use function::{CallError, FunctionError, LocalVariableError, SubgroupError};
 pub use interface::{EntryPointError, GlobalVariableError, VaryingError};
 pub use r#type::{Disalignment, TypeError, TypeFlags, WidthError};
 
@@ -143,6 +143,12 @@ bitflags::bitflags! {
         const SHADER_INT64_ATOMIC_MIN_MAX = 0x80000;
         /// Support for all atomic operations on 64-bit integers.
         const SHADER_INT64_ATOMIC_ALL_OPS = 0x100000;
+        /// Float values with width = 2.
+        const SHADER_FLOAT16 = 0x200000;
+        /// Support for quad subgroup operations in fragment and compute stages.
+        const SUBGROUP_QUAD = 0x400000;
+        /// Support for clustered subgroup reductions.
+        const SUBGROUP_CLUSTERED = 0x800000;
     }
 }
 
@@ -157,7 +163,7 @@ bitflags::bitflags! {
     #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
     #[cfg_attr(feature = "deserialize", derive(serde::Deserialize))]
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
-    pub struct SubgroupOperationSet: u8 {
+    pub struct SubgroupOperationSet: u16 {
         /// Elect, Barrier
         const BASIC = 1 << 0;
         /// Any, All
@@ -170,13 +176,12 @@ bitflags::bitflags! {
         const SHUFFLE = 1 << 4;
         /// shuffle up, down
         const SHUFFLE_RELATIVE = 1 << 5;
-        // We don't support these operations yet
-        // /// Clustered
-        // const CLUSTERED = 1 << 6;
-        // /// Quad supported
-        // const QUAD_FRAGMENT_COMPUTE = 1 << 7;
-        // /// Quad supported in all stages
-        // const QUAD_ALL_STAGES = 1 << 8;
+        /// Clustered
+        const CLUSTERED = 1 << 6;
+        /// Quad supported
+        const QUAD_FRAGMENT_COMPUTE = 1 << 7;
+        /// Quad supported in all stages
+        const QUAD_ALL_STAGES = 1 << 8;
     }
 }
 
@@ -199,6 +204,17 @@ impl super::GatherMode {
             Self::BroadcastFirst | Self::Broadcast(_) => S::BALLOT,
             Self::Shuffle(_) | Self::ShuffleXor(_) => S::SHUFFLE,
             Self::ShuffleUp(_) | Self::ShuffleDown(_) => S::SHUFFLE_RELATIVE,
+            Self::QuadBroadcast(_) | Self::QuadSwap(_) => S::QUAD_FRAGMENT_COMPUTE,
+        }
+    }
+}
+
+impl super::CollectiveOperation {
+    const fn required_operations(&self) -> SubgroupOperationSet {
+        use SubgroupOperationSet as S;
+        match *self {
+            Self::Reduce | Self::InclusiveScan | Self::ExclusiveScan => S::empty(),
+            Self::ClusteredReduce(_) => S::CLUSTERED,
         }
     }
 }
@@ -422,7 +438,17 @@ impl Validator {
     pub fn new(flags: ValidationFlags, capabilities: Capabilities) -> Self {
         let subgroup_operations = if capabilities.contains(Capabilities::SUBGROUP) {
             use SubgroupOperationSet as S;
-            S::BASIC | S::VOTE | S::ARITHMETIC | S::BALLOT | S::SHUFFLE | S::SHUFFLE_RELATIVE
+            let mut operations =
+                S::BASIC | S::VOTE | S::ARITHMETIC | S::BALLOT | S::SHUFFLE | S::SHUFFLE_RELATIVE;
+            operations.set(
+                S::QUAD_FRAGMENT_COMPUTE,
+                capabilities.contains(Capabilities::SUBGROUP_QUAD),
+            );
+            operations.set(
+                S::CLUSTERED,
+                capabilities.contains(Capabilities::SUBGROUP_CLUSTERED),
+            );
+            operations
         } else {
             SubgroupOperationSet::empty()
         };
@@ -537,6 +563,7 @@ impl Validator {
                 crate::Scalar::BOOL
                 | crate::Scalar::I32
                 | crate::Scalar::U32
+                | crate::Scalar::F16
                 | crate::Scalar::F32
                 | crate::Scalar::F64,
             ) => {}
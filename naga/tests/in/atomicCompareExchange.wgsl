@@ -32,3 +32,17 @@ fn test_atomic_compare_exchange_u32() {
         }
     }
 }
+
+var<workgroup> counter: atomic<u32>;
+
+@compute @workgroup_size(64)
+fn test_atomic_compare_exchange_workgroup() {
+    var old = atomicLoad(&counter);
+    loop {
+        let result = atomicCompareExchangeWeak(&counter, old, old + 1u);
+        if result.exchanged {
+            break;
+        }
+        old = result.old_value;
+    }
+}
@@ -17,6 +17,8 @@ fn main() {
     u = pack2x16float(f2);
     u = pack4xI8(i4);
     u = pack4xU8(u4);
+    u = pack4xI8Clamp(i4);
+    u = pack4xU8Clamp(u4);
     f4 = unpack4x8snorm(u);
     f4 = unpack4x8unorm(u);
     f2 = unpack2x16snorm(u);
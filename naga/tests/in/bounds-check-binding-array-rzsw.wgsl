@@ -0,0 +1,21 @@
+// Tests for `naga::back::BoundsCheckPolicy::ReadZeroSkipWrite` for binding arrays.
+
+struct Counter {
+    value: atomic<u32>,
+}
+
+@group(0) @binding(0)
+var textures: binding_array<texture_2d<f32>, 4>;
+@group(0) @binding(1)
+var<storage, read_write> counters: binding_array<Counter, 4>;
+
+@fragment
+fn main(@location(0) @interpolate(flat) index: u32) -> @location(0) vec4<f32> {
+    // Out-of-bounds texture array accesses fall back to the first element.
+    let color = textureLoad(textures[index], vec2(0i), 0i);
+
+    // Out-of-bounds atomics are skipped, and produce zero.
+    let count = atomicAdd(&counters[index].value, 1u);
+
+    return color * f32(count);
+}
@@ -1,8 +1,7 @@
 // Tests for `naga::back::BoundsCheckPolicy::ReadZeroSkipWrite` for atomic types.
 
-// These are separate from `bounds-check-zero.wgsl because SPIR-V does not yet
-// support `ReadZeroSkipWrite` for atomics. Once it does, the test files could
-// be combined.
+// These are separate from `bounds-check-zero.wgsl`, which does not cover
+// the atomic load, store and read-modify-write paths.
 
 struct Globals {
     a: atomic<u32>,
@@ -44,3 +43,14 @@ fn exchange_atomic_dynamic_sized_array_static_index() -> u32 {
    return atomicExchange(&globals.c[1000], 1u);
 }
 
+fn load_atomic_dynamic_sized_array(i: i32) -> u32 {
+   return atomicLoad(&globals.c[i]);
+}
+
+fn store_atomic_dynamic_sized_array(i: i32) {
+   atomicStore(&globals.c[i], 1u);
+}
+
+fn compare_exchange_atomic_dynamic_sized_array(i: i32) -> bool {
+   return atomicCompareExchangeWeak(&globals.c[i], 0u, 1u).exchanged;
+}
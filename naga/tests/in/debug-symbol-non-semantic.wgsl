@@ -0,0 +1,26 @@
+struct Light {
+    position: vec3<f32>,
+    color: vec4<f32>,
+}
+
+@group(0) @binding(0)
+var<storage, read> lights: array<Light>;
+
+fn attenuate(light: Light, point: vec3<f32>) -> f32 {
+    let distance = length(light.position - point);
+    return 1.0 / (1.0 + distance * distance);
+}
+
+@fragment
+fn main(@location(0) world_position: vec3<f32>) -> @location(0) vec4<f32> {
+    var total = vec4<f32>(0.0);
+    for (var i = 0u; i < arrayLength(&lights); i++) {
+        let light = lights[i];
+        if light.color.a > 0.0 {
+            let weight = attenuate(light, world_position);
+            total += light.color * weight;
+        }
+    }
+    let transform = mat2x2<f32>(1.0, 0.0, 0.0, 1.0);
+    return vec4<f32>(transform * total.xy, total.zw);
+}
@@ -0,0 +1,40 @@
+diagnostic(off, derivative_uniformity);
+diagnostic(warning, my_namespace.my_rule);
+
+@group(0) @binding(0) var t: texture_2d<f32>;
+@group(0) @binding(1) var s: sampler;
+
+@diagnostic(warning, derivative_uniformity)
+fn sample_if_positive(x: f32, uv: vec2<f32>) -> vec4<f32> {
+    if x > 0.0 {
+        return textureSample(t, s, uv);
+    }
+    return vec4(0.0);
+}
+
+@fragment
+fn main(@location(0) x: f32, @location(1) uv: vec2<f32>) -> @location(0) vec4<f32> {
+    var color = sample_if_positive(x, uv);
+
+    @diagnostic(info, derivative_uniformity)
+    if x < 1.0 {
+        color += vec4(dpdx(x));
+    }
+
+    loop @diagnostic(error, derivative_uniformity) {
+        if x < 0.5 {
+            break;
+        }
+        color *= 0.5;
+        break;
+    }
+
+    switch i32(x) @diagnostic(off, derivative_uniformity) {
+        case 0 {
+            color.x = fwidth(x);
+        }
+        default {}
+    }
+
+    return color;
+}
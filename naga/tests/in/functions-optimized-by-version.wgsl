@@ -0,0 +1,16 @@
+// Tests that packed integer dot products use the native intrinsics
+// when the target shader model supports them.
+fn test_packed_integer_dot_product(x: u32, y: u32) -> u32 {
+    let c_5: i32 = dot4I8Packed(x, y);
+    let c_6: u32 = dot4U8Packed(x, y);
+
+    // test baking of arguments
+    let c_7: i32 = dot4I8Packed(x + 5u, y + 6u);
+    let c_8: u32 = dot4U8Packed(x + 7u, y + 8u);
+    return c_8;
+}
+
+@compute @workgroup_size(1)
+fn main() {
+    let c = test_packed_integer_dot_product(1u, 2u);
+}
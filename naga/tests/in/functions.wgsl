@@ -22,8 +22,22 @@ fn test_integer_dot_product() -> i32 {
     return c_4;
 }
 
+fn test_packed_integer_dot_product(x: u32, y: u32) -> u32 {
+    let c_5: i32 = dot4I8Packed(x, y);
+    let c_6: u32 = dot4U8Packed(x, y);
+
+    // test baking of arguments
+    let c_7: i32 = dot4I8Packed(x + 5u, y + 6u);
+    let c_8: u32 = dot4U8Packed(x + 7u, y + 8u);
+
+    // test constant evaluation
+    let c_9: i32 = dot4I8Packed(0xFF02u, 0x7F03u);
+    return c_8 + u32(c_9);
+}
+
 @compute @workgroup_size(1)
 fn main() {
     let a = test_fma();
     let b = test_integer_dot_product();
+    let c = test_packed_integer_dot_product(1u, 2u);
 }
@@ -33,4 +33,22 @@ void switchNoLastBreak(int a) {
     return;
 }
 
+int switchFallThrough(int a) {
+    int b = 0;
+    switch (a) {
+        case 0:
+        case 1:
+            b += a * 2;
+        case 2:
+            b += 3;
+            break;
+        case 3:
+            b = a + 1;
+        default:
+            b += 4;
+    }
+
+    return b;
+}
+
 void main() {}
@@ -45,4 +45,12 @@ fn main() {
     let frexp_b = frexp(1.5).fract;
     let frexp_c: i32 = frexp(1.5).exp;
     let frexp_d: i32 = frexp(vec4(1.5, 1.5, 1.5, 1.5)).exp.x;
+    var x = 1.5;
+    let modf_g = modf(x);
+    let frexp_e = frexp(x);
+    let lde_c = ldexp(x, 2);
+    let quantize_a = quantizeToF16(x);
+    let quantize_b = quantizeToF16(vec2(x));
+    let quantize_c = quantizeToF16(vec3(x));
+    let quantize_d = quantizeToF16(vec4(x));
 }
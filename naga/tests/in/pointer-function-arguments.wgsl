@@ -0,0 +1,54 @@
+requires pointer_composite_access, unrestricted_pointer_parameters;
+
+struct Particle {
+    position: vec4<f32>,
+    velocity: vec4<f32>,
+    history: array<vec4<f32>, 4>,
+}
+
+struct Params {
+    gravity: vec4<f32>,
+    damping: array<vec4<f32>, 2>,
+}
+
+@group(0) @binding(0)
+var<storage, read_write> particles: array<Particle>;
+@group(0) @binding(1)
+var<uniform> params: Params;
+@group(0) @binding(2)
+var<storage, read_write> fallback: Particle;
+
+var<workgroup> counters: array<atomic<u32>, 4>;
+var<private> scratch: Particle;
+
+fn read_uniform(p: ptr<uniform, vec4<f32>>) -> f32 {
+    return p.x + (*p).w;
+}
+
+fn apply_gravity(p: ptr<storage, Particle, read_write>, damping: f32) {
+    p.velocity += params.gravity * damping;
+    p.history[1] = p.velocity;
+}
+
+fn step(p: ptr<storage, Particle, read_write>, i: u32) {
+    apply_gravity(p, read_uniform(&params.damping[i % 2u]));
+    p.position += p.velocity;
+}
+
+fn bump(counter: ptr<workgroup, atomic<u32>>) -> u32 {
+    return atomicAdd(counter, 1u);
+}
+
+fn reset(p: ptr<private, Particle>) {
+    p.history[0] = p.position;
+}
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>, @builtin(local_invocation_index) lid: u32) {
+    step(&particles[id.x], id.x);
+    step(&fallback, 0u);
+    apply_gravity(&particles[0], read_uniform(&params.gravity));
+    bump(&counters[lid % 4u]);
+    bump(&counters[3]);
+    reset(&scratch);
+}
@@ -0,0 +1,20 @@
+requires readonly_and_readwrite_storage_textures;
+
+@group(0) @binding(0) var s_r_r: texture_storage_2d<r32float, read>;
+@group(0) @binding(1) var s_rgba_r: texture_storage_2d<rgba8unorm, read>;
+@group(0) @binding(2) var s_r_rw: texture_storage_2d<r32float, read_write>;
+
+@compute @workgroup_size(1)
+fn csLoad(@builtin(global_invocation_id) id: vec3<u32>) {
+    let a = textureLoad(s_r_r, id.xy);
+    let b = textureLoad(s_rgba_r, id.xy);
+    textureStore(s_r_rw, id.xy, a + b.r);
+}
+
+// Filters the texture in place, without a second texture to ping-pong with.
+@compute @workgroup_size(8, 8)
+fn csFilter(@builtin(global_invocation_id) id: vec3<u32>) {
+    let value = textureLoad(s_r_rw, id.xy);
+    textureBarrier();
+    textureStore(s_r_rw, id.xy, value * 0.5);
+}
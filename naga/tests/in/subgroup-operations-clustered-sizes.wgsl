@@ -0,0 +1,12 @@
+// Cluster sizes other than 4, which only SPIR-V and GLSL support.
+
+@compute @workgroup_size(1)
+fn main(@builtin(subgroup_invocation_id) subgroup_invocation_id: u32) {
+    subgroupAdd(subgroup_invocation_id, 1u);
+    subgroupMul(subgroup_invocation_id, 2u);
+    subgroupMin(subgroup_invocation_id, 8u);
+    subgroupMax(subgroup_invocation_id, 16u);
+    subgroupAnd(subgroup_invocation_id, 32u);
+    subgroupOr(subgroup_invocation_id, 64u);
+    subgroupXor(subgroup_invocation_id, 128u);
+}
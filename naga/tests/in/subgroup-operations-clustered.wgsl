@@ -0,0 +1,12 @@
+const CLUSTER_SIZE: u32 = 4u;
+
+@compute @workgroup_size(1)
+fn main(@builtin(subgroup_invocation_id) subgroup_invocation_id: u32) {
+    subgroupAdd(subgroup_invocation_id, 4u);
+    subgroupMul(subgroup_invocation_id, 4u);
+    subgroupMin(subgroup_invocation_id, 4u);
+    subgroupMax(subgroup_invocation_id, 4u);
+    subgroupAnd(subgroup_invocation_id, CLUSTER_SIZE);
+    subgroupOr(subgroup_invocation_id, CLUSTER_SIZE);
+    subgroupXor(subgroup_invocation_id, CLUSTER_SIZE);
+}
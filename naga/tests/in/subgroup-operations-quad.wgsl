@@ -0,0 +1,7 @@
+@compute @workgroup_size(1)
+fn main(@builtin(subgroup_invocation_id) subgroup_invocation_id: u32) {
+    quadBroadcast(subgroup_invocation_id, 2u);
+    quadSwapX(subgroup_invocation_id);
+    quadSwapY(subgroup_invocation_id);
+    quadSwapDiagonal(subgroup_invocation_id);
+}
@@ -0,0 +1,126 @@
+/*!
+Tests for Naga's textual IR format.
+
+The snapshot tests check that every module they produce round-trips through
+the format; these cover what the snapshots don't.
+*/
+#![cfg(all(feature = "ir-text-in", feature = "ir-text-out"))]
+
+fn check(input: &str, snapshot: &str) {
+    let output = naga::front::ir_text::parse_str(input)
+        .expect_err("expected parser error")
+        .emit_to_string(input);
+    if output != snapshot {
+        for diff in diff::lines(snapshot, &output) {
+            match diff {
+                diff::Result::Left(l) => println!("-{l}"),
+                diff::Result::Both(l, _) => println!(" {l}"),
+                diff::Result::Right(r) => println!("+{r}"),
+            }
+        }
+        panic!("Error snapshot failed");
+    }
+}
+
+#[test]
+fn round_trip_escapes_and_special_floats() {
+    let input = r#"type @t0 "a \"quoted\"\nname \u{7f}" = f32
+@e0 = literal f32 -inf
+@e1 = literal f64 NaN
+@e2 = literal abstract_float 1e-300
+@e3 = literal f16 0.33325195
+const @c0: @t0 = @e0
+"#;
+    let module = naga::front::ir_text::parse_str(input).unwrap();
+    assert_eq!(
+        module.types.iter().next().unwrap().1.name.as_deref(),
+        Some("a \"quoted\"\nname \u{7f}")
+    );
+    let output = naga::back::ir_text::write_string(&module).unwrap();
+    assert_eq!(output, input);
+}
+
+#[test]
+fn declarations_out_of_order() {
+    check(
+        "type @t1 = f32",
+        r###"error: expected `@t0`, since declarations must be in arena order
+  ┌─ ir:1:6
+  │
+1 │ type @t1 = f32
+  │      ^^^
+
+"###,
+    );
+}
+
+#[test]
+fn undeclared_inline_type() {
+    check(
+        "global @g0 private: vec2<f32>",
+        r###"error: types without names must be declared before they are used
+  ┌─ ir:1:21
+  │
+1 │ global @g0 private: vec2<f32>
+  │                     ^^^^^^^^^
+
+"###,
+    );
+}
+
+#[test]
+fn emit_out_of_range() {
+    check(
+        "fn @f0() {
+    %0 = literal i32 1
+    body {
+        emit %0..%2
+    }
+}",
+        r###"error: ranges must be in order and refer to expressions declared earlier
+  ┌─ ir:4:14
+  │
+4 │         emit %0..%2
+  │              ^^^^^^
+
+"###,
+    );
+}
+
+#[test]
+fn unexpected_result() {
+    check(
+        "fn @f0() {
+    %0 = literal i32 1
+    body {
+        %0 = return
+    }
+}",
+        r###"error: `return` has no result expression
+  ┌─ ir:4:14
+  │
+4 │         %0 = return
+  │              ^^^^^^
+
+"###,
+    );
+}
+
+#[test]
+fn argument_out_of_range() {
+    check(
+        "type @t0 = f32
+fn @f0(\"x\": @t0) {
+    %0 = argument 1
+    body {
+    }
+}",
+        r###"error: argument index must be less than the function's argument count, 1
+  ┌─ ir:3:10
+  │
+3 │     %0 = argument 1
+  │          ^^^^^^^^^^
+
+"###,
+    );
+}
@@ -0,0 +1,34 @@
+#version 310 es
+
+precision highp float;
+precision highp int;
+
+float value_1 = 0.0;
+
+vec4 color = vec4(0.0);
+
+layout(location = 0) smooth in float _vs2fs_location0;
+layout(location = 0) out vec4 _fs2p_location0;
+
+void main_1() {
+    float _e4 = value_1;
+    if ((_e4 < 0.0)) {
+        discard;
+    }
+    float _e6 = dFdx(_e4);
+    color = vec4(_e6, _e6, _e6, 1.0);
+    if ((_e4 > 1.0)) {
+        discard;
+    }
+    return;
+}
+
+void main() {
+    float value = _vs2fs_location0;
+    value_1 = value;
+    main_1();
+    vec4 _e3 = color;
+    _fs2p_location0 = _e3;
+    return;
+}
+
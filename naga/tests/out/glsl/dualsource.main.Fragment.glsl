@@ -8,7 +8,7 @@ struct FragmentOutput {
     vec4 color;
     vec4 mask;
 };
-layout(location = 0) out vec4 _fs2p_location0;
+layout(location = 0, index = 0) out vec4 _fs2p_location0;
 layout(location = 0, index = 1) out vec4 _fs2p_location1;
 
 void main() {
@@ -20,15 +20,25 @@ int test_integer_dot_product() {
     uvec3 a_3_ = uvec3(1u);
     uvec3 b_3_ = uvec3(1u);
     uint c_3_ = ( + a_3_.x * b_3_.x + a_3_.y * b_3_.y + a_3_.z * b_3_.z);
-    ivec4 _e11 = ivec4(4);
-    ivec4 _e13 = ivec4(2);
-    int c_4_ = ( + _e11.x * _e13.x + _e11.y * _e13.y + _e11.z * _e13.z + _e11.w * _e13.w);
-    return c_4_;
+    return 32;
+}
+
+uint test_packed_integer_dot_product(uint x, uint y) {
+    int c_5_ = (bitfieldExtract(int(x), 0, 8) * bitfieldExtract(int(y), 0, 8) + bitfieldExtract(int(x), 8, 8) * bitfieldExtract(int(y), 8, 8) + bitfieldExtract(int(x), 16, 8) * bitfieldExtract(int(y), 16, 8) + bitfieldExtract(int(x), 24, 8) * bitfieldExtract(int(y), 24, 8));
+    uint c_6_ = (bitfieldExtract(x, 0, 8) * bitfieldExtract(y, 0, 8) + bitfieldExtract(x, 8, 8) * bitfieldExtract(y, 8, 8) + bitfieldExtract(x, 16, 8) * bitfieldExtract(y, 16, 8) + bitfieldExtract(x, 24, 8) * bitfieldExtract(y, 24, 8));
+    uint _e5 = (x + 5u);
+    uint _e7 = (y + 6u);
+    int c_7_ = (bitfieldExtract(int(_e5), 0, 8) * bitfieldExtract(int(_e7), 0, 8) + bitfieldExtract(int(_e5), 8, 8) * bitfieldExtract(int(_e7), 8, 8) + bitfieldExtract(int(_e5), 16, 8) * bitfieldExtract(int(_e7), 16, 8) + bitfieldExtract(int(_e5), 24, 8) * bitfieldExtract(int(_e7), 24, 8));
+    uint _e10 = (x + 7u);
+    uint _e12 = (y + 8u);
+    uint c_8_ = (bitfieldExtract(_e10, 0, 8) * bitfieldExtract(_e12, 0, 8) + bitfieldExtract(_e10, 8, 8) * bitfieldExtract(_e12, 8, 8) + bitfieldExtract(_e10, 16, 8) * bitfieldExtract(_e12, 16, 8) + bitfieldExtract(_e10, 24, 8) * bitfieldExtract(_e12, 24, 8));
+    return (c_8_ + uint(-121));
 }
 
 void main() {
     vec2 _e0 = test_fma();
     int _e1 = test_integer_dot_product();
+    uint _e4 = test_packed_integer_dot_product(1u, 2u);
     return;
 }
 
@@ -55,6 +55,7 @@ _frexp_result_vec4_f32_ naga_frexp(vec4 arg) {
 }
 
 void main() {
+    float x = 1.5;
     vec4 v = vec4(0.0);
     float a = degrees(1.0);
     float b = radians(1.0);
@@ -64,7 +65,6 @@ void main() {
     vec4 g = refract(v, v, 1.0);
     ivec4 sign_b = ivec4(-1, -1, -1, -1);
     vec4 sign_d = vec4(-1.0, -1.0, -1.0, -1.0);
-    int const_dot = ( + ivec2(0).x * ivec2(0).x + ivec2(0).y * ivec2(0).y);
     ivec2 flb_b = ivec2(-1, -1);
     uvec2 flb_c = uvec2(0u, 0u);
     ivec2 ftb_c = ivec2(0, 0);
@@ -75,17 +75,23 @@ void main() {
     ivec2 ctz_h = ivec2(0, 0);
     ivec2 clz_c = ivec2(0, 0);
     uvec2 clz_d = uvec2(31u, 31u);
-    float lde_a = ldexp(1.0, 2);
-    vec2 lde_b = ldexp(vec2(1.0, 2.0), ivec2(3, 4));
-    _modf_result_f32_ modf_a = naga_modf(1.5);
-    float modf_b = naga_modf(1.5).fract_;
-    float modf_c = naga_modf(1.5).whole;
-    _modf_result_vec2_f32_ modf_d = naga_modf(vec2(1.5, 1.5));
-    float modf_e = naga_modf(vec4(1.5, 1.5, 1.5, 1.5)).whole.x;
-    float modf_f = naga_modf(vec2(1.5, 1.5)).fract_.y;
-    _frexp_result_f32_ frexp_a = naga_frexp(1.5);
-    float frexp_b = naga_frexp(1.5).fract_;
-    int frexp_c = naga_frexp(1.5).exp_;
-    int frexp_d = naga_frexp(vec4(1.5, 1.5, 1.5, 1.5)).exp_.x;
+    vec2 lde_b = vec2(8.0, 32.0);
+    _modf_result_f32_ modf_a = _modf_result_f32_(0.5, 1.0);
+    _modf_result_vec2_f32_ modf_d = _modf_result_vec2_f32_(vec2(0.5, 0.5), vec2(1.0, 1.0));
+    _frexp_result_f32_ frexp_a = _frexp_result_f32_(0.75, 1);
+    float _e88 = x;
+    _modf_result_f32_ modf_g = naga_modf(_e88);
+    float _e90 = x;
+    _frexp_result_f32_ frexp_e = naga_frexp(_e90);
+    float _e92 = x;
+    float lde_c = ldexp(_e92, 2);
+    float _e95 = x;
+    float quantize_a = unpackHalf2x16(packHalf2x16(vec2(_e95))).x;
+    float _e97 = x;
+    vec2 quantize_b = unpackHalf2x16(packHalf2x16(vec2(_e97)));
+    float _e100 = x;
+    vec3 quantize_c = vec3(unpackHalf2x16(packHalf2x16(vec3(_e100).xy)), unpackHalf2x16(packHalf2x16(vec3(_e100).zz)).x);
+    float _e103 = x;
+    vec4 quantize_d = vec4(unpackHalf2x16(packHalf2x16(vec4(_e103).xy)), unpackHalf2x16(packHalf2x16(vec4(_e103).zw)));
 }
 
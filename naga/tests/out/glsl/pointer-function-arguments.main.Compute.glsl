@@ -0,0 +1,121 @@
+#version 310 es
+
+precision highp float;
+precision highp int;
+
+layout(local_size_x = 64, local_size_y = 1, local_size_z = 1) in;
+
+struct Particle {
+    vec4 position;
+    vec4 velocity;
+    vec4 history[4];
+};
+struct Params {
+    vec4 gravity;
+    vec4 damping[2];
+};
+layout(std430) buffer type_4_block_0Compute { Particle _group_0_binding_0_cs[]; };
+
+uniform Params_block_1Compute { Params _group_0_binding_1_cs; };
+
+layout(std430) buffer Particle_block_2Compute { Particle _group_0_binding_2_cs; };
+
+shared uint counters[4];
+
+Particle scratch = Particle(vec4(0.0), vec4(0.0), vec4[4](vec4(0.0), vec4(0.0), vec4(0.0), vec4(0.0)));
+
+
+void reset(inout Particle p) {
+    vec4 _e4 = p.position;
+    p.history[0] = _e4;
+    return;
+}
+
+float read_uniform(uint p_index) {
+    float _e5 = _group_0_binding_1_cs.damping[p_index].x;
+    float _e7 = _group_0_binding_1_cs.damping[p_index].w;
+    return (_e5 + _e7);
+}
+
+void apply_gravity(uint p_index_1, float damping) {
+    vec4 _e7 = _group_0_binding_1_cs.gravity;
+    vec4 _e9 = _group_0_binding_0_cs[p_index_1].velocity;
+    _group_0_binding_0_cs[p_index_1].velocity = (_e9 + (_e7 * damping));
+    vec4 _e14 = _group_0_binding_0_cs[p_index_1].velocity;
+    _group_0_binding_0_cs[p_index_1].history[1] = _e14;
+    return;
+}
+
+void step_(uint p_index_2, uint i) {
+    uint _e7 = (i % 2u);
+    float _e9 = read_uniform(_e7);
+    apply_gravity(p_index_2, _e9);
+    vec4 _e12 = _group_0_binding_0_cs[p_index_2].velocity;
+    vec4 _e13 = _group_0_binding_0_cs[p_index_2].position;
+    _group_0_binding_0_cs[p_index_2].position = (_e13 + _e12);
+    return;
+}
+
+void apply_gravity_1(float damping_1) {
+    vec4 _e5 = _group_0_binding_1_cs.gravity;
+    vec4 _e7 = _group_0_binding_2_cs.velocity;
+    _group_0_binding_2_cs.velocity = (_e7 + (_e5 * damping_1));
+    vec4 _e12 = _group_0_binding_2_cs.velocity;
+    _group_0_binding_2_cs.history[1] = _e12;
+    return;
+}
+
+void step_1(uint i_1) {
+    uint _e5 = (i_1 % 2u);
+    float _e7 = read_uniform(_e5);
+    apply_gravity_1(_e7);
+    vec4 _e10 = _group_0_binding_2_cs.velocity;
+    vec4 _e11 = _group_0_binding_2_cs.position;
+    _group_0_binding_2_cs.position = (_e11 + _e10);
+    return;
+}
+
+float read_uniform_1() {
+    float _e3 = _group_0_binding_1_cs.gravity.x;
+    float _e5 = _group_0_binding_1_cs.gravity.w;
+    return (_e3 + _e5);
+}
+
+void apply_gravity_2(float damping_2) {
+    vec4 _e6 = _group_0_binding_1_cs.gravity;
+    vec4 _e8 = _group_0_binding_0_cs[0].velocity;
+    _group_0_binding_0_cs[0].velocity = (_e8 + (_e6 * damping_2));
+    vec4 _e13 = _group_0_binding_0_cs[0].velocity;
+    _group_0_binding_0_cs[0].history[1] = _e13;
+    return;
+}
+
+uint bump(uint counter_index) {
+    uint _e4 = atomicAdd(counters[counter_index], 1u);
+    return _e4;
+}
+
+uint bump_1() {
+    uint _e3 = atomicAdd(counters[3], 1u);
+    return _e3;
+}
+
+void main() {
+    if (gl_LocalInvocationID == uvec3(0u)) {
+        counters = uint[4](0u, 0u, 0u, 0u);
+    }
+    memoryBarrierShared();
+    barrier();
+    uvec3 id = gl_GlobalInvocationID;
+    uint lid = gl_LocalInvocationIndex;
+    step_(id.x, id.x);
+    step_1(0u);
+    float _e12 = read_uniform_1();
+    apply_gravity_2(_e12);
+    uint _e15 = (lid % 4u);
+    uint _e17 = bump(_e15);
+    uint _e20 = bump_1();
+    reset(scratch);
+    return;
+}
+
@@ -0,0 +1,19 @@
+#version 310 es
+
+precision highp float;
+precision highp int;
+
+layout(local_size_x = 8, local_size_y = 8, local_size_z = 1) in;
+
+layout(r32f) uniform highp image2D _group_0_binding_2_cs;
+
+
+void main() {
+    uvec3 id_1 = gl_GlobalInvocationID;
+    vec4 value = imageLoad(_group_0_binding_2_cs, ivec2(id_1.xy));
+    memoryBarrierImage();
+    barrier();
+    imageStore(_group_0_binding_2_cs, ivec2(id_1.xy), (value * 0.5));
+    return;
+}
+
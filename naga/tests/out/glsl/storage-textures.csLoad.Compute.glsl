@@ -0,0 +1,22 @@
+#version 310 es
+
+precision highp float;
+precision highp int;
+
+layout(local_size_x = 1, local_size_y = 1, local_size_z = 1) in;
+
+layout(r32f) readonly uniform highp image2D _group_0_binding_0_cs;
+
+layout(rgba8) readonly uniform highp image2D _group_0_binding_1_cs;
+
+layout(r32f) uniform highp image2D _group_0_binding_2_cs;
+
+
+void main() {
+    uvec3 id = gl_GlobalInvocationID;
+    vec4 a = imageLoad(_group_0_binding_0_cs, ivec2(id.xy));
+    vec4 b = imageLoad(_group_0_binding_1_cs, ivec2(id.xy));
+    imageStore(_group_0_binding_2_cs, ivec2(id.xy), (a + vec4(b.x)));
+    return;
+}
+
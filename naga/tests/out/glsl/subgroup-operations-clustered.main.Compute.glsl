@@ -0,0 +1,26 @@
+#version 430 core
+#extension GL_ARB_compute_shader : require
+#extension GL_KHR_shader_subgroup_basic : require
+#extension GL_KHR_shader_subgroup_vote : require
+#extension GL_KHR_shader_subgroup_arithmetic : require
+#extension GL_KHR_shader_subgroup_ballot : require
+#extension GL_KHR_shader_subgroup_shuffle : require
+#extension GL_KHR_shader_subgroup_shuffle_relative : require
+#extension GL_KHR_shader_subgroup_clustered : require
+layout(local_size_x = 1, local_size_y = 1, local_size_z = 1) in;
+
+const uint CLUSTER_SIZE = 4u;
+
+
+void main() {
+    uint subgroup_invocation_id = gl_SubgroupInvocationID;
+    uint _e1 = subgroupClusteredAdd(subgroup_invocation_id, 4u);
+    uint _e2 = subgroupClusteredMul(subgroup_invocation_id, 4u);
+    uint _e3 = subgroupClusteredMin(subgroup_invocation_id, 4u);
+    uint _e4 = subgroupClusteredMax(subgroup_invocation_id, 4u);
+    uint _e5 = subgroupClusteredAnd(subgroup_invocation_id, 4u);
+    uint _e6 = subgroupClusteredOr(subgroup_invocation_id, 4u);
+    uint _e7 = subgroupClusteredXor(subgroup_invocation_id, 4u);
+    return;
+}
+
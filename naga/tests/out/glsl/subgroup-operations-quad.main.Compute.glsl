@@ -0,0 +1,21 @@
+#version 430 core
+#extension GL_ARB_compute_shader : require
+#extension GL_KHR_shader_subgroup_basic : require
+#extension GL_KHR_shader_subgroup_vote : require
+#extension GL_KHR_shader_subgroup_arithmetic : require
+#extension GL_KHR_shader_subgroup_ballot : require
+#extension GL_KHR_shader_subgroup_shuffle : require
+#extension GL_KHR_shader_subgroup_shuffle_relative : require
+#extension GL_KHR_shader_subgroup_quad : require
+layout(local_size_x = 1, local_size_y = 1, local_size_z = 1) in;
+
+
+void main() {
+    uint subgroup_invocation_id = gl_SubgroupInvocationID;
+    uint _e2 = subgroupQuadBroadcast(subgroup_invocation_id, 2u);
+    uint _e3 = subgroupQuadSwapHorizontal(subgroup_invocation_id);
+    uint _e4 = subgroupQuadSwapVertical(subgroup_invocation_id);
+    uint _e5 = subgroupQuadSwapDiagonal(subgroup_invocation_id);
+    return;
+}
+
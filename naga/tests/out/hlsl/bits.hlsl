@@ -202,103 +202,107 @@ void main()
     u = uint((_e38[0] & 0xFF) | ((_e38[1] & 0xFF) << 8) | ((_e38[2] & 0xFF) << 16) | ((_e38[3] & 0xFF) << 24));
     uint4 _e40 = u4_;
     u = (_e40[0] & 0xFF) | ((_e40[1] & 0xFF) << 8) | ((_e40[2] & 0xFF) << 16) | ((_e40[3] & 0xFF) << 24);
-    uint _e42 = u;
-    f4_ = (float4(int4(_e42 << 24, _e42 << 16, _e42 << 8, _e42) >> 24) / 127.0);
-    uint _e44 = u;
-    f4_ = (float4(_e44 & 0xFF, _e44 >> 8 & 0xFF, _e44 >> 16 & 0xFF, _e44 >> 24) / 255.0);
+    int4 _e42 = i4_;
+    u = uint((clamp(_e42[0], -128, 127) & 0xFF) | ((clamp(_e42[1], -128, 127) & 0xFF) << 8) | ((clamp(_e42[2], -128, 127) & 0xFF) << 16) | ((clamp(_e42[3], -128, 127) & 0xFF) << 24));
+    uint4 _e44 = u4_;
+    u = (clamp(_e44[0], 0u, 255u) & 0xFF) | ((clamp(_e44[1], 0u, 255u) & 0xFF) << 8) | ((clamp(_e44[2], 0u, 255u) & 0xFF) << 16) | ((clamp(_e44[3], 0u, 255u) & 0xFF) << 24);
     uint _e46 = u;
-    f2_ = (float2(int2(_e46 << 16, _e46) >> 16) / 32767.0);
+    f4_ = (float4(int4(_e46 << 24, _e46 << 16, _e46 << 8, _e46) >> 24) / 127.0);
     uint _e48 = u;
-    f2_ = (float2(_e48 & 0xFFFF, _e48 >> 16) / 65535.0);
+    f4_ = (float4(_e48 & 0xFF, _e48 >> 8 & 0xFF, _e48 >> 16 & 0xFF, _e48 >> 24) / 255.0);
     uint _e50 = u;
-    f2_ = float2(f16tof32(_e50), f16tof32((_e50) >> 16));
+    f2_ = (float2(int2(_e50 << 16, _e50) >> 16) / 32767.0);
     uint _e52 = u;
-    i4_ = int4(_e52, _e52 >> 8, _e52 >> 16, _e52 >> 24) << 24 >> 24;
+    f2_ = (float2(_e52 & 0xFFFF, _e52 >> 16) / 65535.0);
     uint _e54 = u;
-    u4_ = uint4(_e54, _e54 >> 8, _e54 >> 16, _e54 >> 24) << 24 >> 24;
-    int _e56 = i;
-    int _e57 = i;
-    i = naga_insertBits(_e56, _e57, 5u, 10u);
-    int2 _e61 = i2_;
-    int2 _e62 = i2_;
-    i2_ = naga_insertBits(_e61, _e62, 5u, 10u);
-    int3 _e66 = i3_;
-    int3 _e67 = i3_;
-    i3_ = naga_insertBits(_e66, _e67, 5u, 10u);
-    int4 _e71 = i4_;
-    int4 _e72 = i4_;
-    i4_ = naga_insertBits(_e71, _e72, 5u, 10u);
-    uint _e76 = u;
-    uint _e77 = u;
-    u = naga_insertBits(_e76, _e77, 5u, 10u);
-    uint2 _e81 = u2_;
-    uint2 _e82 = u2_;
-    u2_ = naga_insertBits(_e81, _e82, 5u, 10u);
-    uint3 _e86 = u3_;
-    uint3 _e87 = u3_;
-    u3_ = naga_insertBits(_e86, _e87, 5u, 10u);
-    uint4 _e91 = u4_;
-    uint4 _e92 = u4_;
-    u4_ = naga_insertBits(_e91, _e92, 5u, 10u);
-    int _e96 = i;
-    i = naga_extractBits(_e96, 5u, 10u);
-    int2 _e100 = i2_;
-    i2_ = naga_extractBits(_e100, 5u, 10u);
-    int3 _e104 = i3_;
-    i3_ = naga_extractBits(_e104, 5u, 10u);
-    int4 _e108 = i4_;
-    i4_ = naga_extractBits(_e108, 5u, 10u);
-    uint _e112 = u;
-    u = naga_extractBits(_e112, 5u, 10u);
-    uint2 _e116 = u2_;
-    u2_ = naga_extractBits(_e116, 5u, 10u);
-    uint3 _e120 = u3_;
-    u3_ = naga_extractBits(_e120, 5u, 10u);
-    uint4 _e124 = u4_;
-    u4_ = naga_extractBits(_e124, 5u, 10u);
-    int _e128 = i;
-    i = asint(firstbitlow(_e128));
-    uint2 _e130 = u2_;
-    u2_ = firstbitlow(_e130);
-    int3 _e132 = i3_;
-    i3_ = asint(firstbithigh(_e132));
-    uint3 _e134 = u3_;
-    u3_ = firstbithigh(_e134);
-    int _e136 = i;
-    i = asint(firstbithigh(_e136));
-    uint _e138 = u;
-    u = firstbithigh(_e138);
+    f2_ = float2(f16tof32(_e54), f16tof32((_e54) >> 16));
+    uint _e56 = u;
+    i4_ = int4(_e56, _e56 >> 8, _e56 >> 16, _e56 >> 24) << 24 >> 24;
+    uint _e58 = u;
+    u4_ = uint4(_e58, _e58 >> 8, _e58 >> 16, _e58 >> 24) << 24 >> 24;
+    int _e60 = i;
+    int _e61 = i;
+    i = naga_insertBits(_e60, _e61, 5u, 10u);
+    int2 _e65 = i2_;
+    int2 _e66 = i2_;
+    i2_ = naga_insertBits(_e65, _e66, 5u, 10u);
+    int3 _e70 = i3_;
+    int3 _e71 = i3_;
+    i3_ = naga_insertBits(_e70, _e71, 5u, 10u);
+    int4 _e75 = i4_;
+    int4 _e76 = i4_;
+    i4_ = naga_insertBits(_e75, _e76, 5u, 10u);
+    uint _e80 = u;
+    uint _e81 = u;
+    u = naga_insertBits(_e80, _e81, 5u, 10u);
+    uint2 _e85 = u2_;
+    uint2 _e86 = u2_;
+    u2_ = naga_insertBits(_e85, _e86, 5u, 10u);
+    uint3 _e90 = u3_;
+    uint3 _e91 = u3_;
+    u3_ = naga_insertBits(_e90, _e91, 5u, 10u);
+    uint4 _e95 = u4_;
+    uint4 _e96 = u4_;
+    u4_ = naga_insertBits(_e95, _e96, 5u, 10u);
+    int _e100 = i;
+    i = naga_extractBits(_e100, 5u, 10u);
+    int2 _e104 = i2_;
+    i2_ = naga_extractBits(_e104, 5u, 10u);
+    int3 _e108 = i3_;
+    i3_ = naga_extractBits(_e108, 5u, 10u);
+    int4 _e112 = i4_;
+    i4_ = naga_extractBits(_e112, 5u, 10u);
+    uint _e116 = u;
+    u = naga_extractBits(_e116, 5u, 10u);
+    uint2 _e120 = u2_;
+    u2_ = naga_extractBits(_e120, 5u, 10u);
+    uint3 _e124 = u3_;
+    u3_ = naga_extractBits(_e124, 5u, 10u);
+    uint4 _e128 = u4_;
+    u4_ = naga_extractBits(_e128, 5u, 10u);
+    int _e132 = i;
+    i = asint(firstbitlow(_e132));
+    uint2 _e134 = u2_;
+    u2_ = firstbitlow(_e134);
+    int3 _e136 = i3_;
+    i3_ = asint(firstbithigh(_e136));
+    uint3 _e138 = u3_;
+    u3_ = firstbithigh(_e138);
     int _e140 = i;
-    i = asint(countbits(asuint(_e140)));
-    int2 _e142 = i2_;
-    i2_ = asint(countbits(asuint(_e142)));
-    int3 _e144 = i3_;
-    i3_ = asint(countbits(asuint(_e144)));
-    int4 _e146 = i4_;
-    i4_ = asint(countbits(asuint(_e146)));
-    uint _e148 = u;
-    u = countbits(_e148);
-    uint2 _e150 = u2_;
-    u2_ = countbits(_e150);
-    uint3 _e152 = u3_;
-    u3_ = countbits(_e152);
-    uint4 _e154 = u4_;
-    u4_ = countbits(_e154);
-    int _e156 = i;
-    i = asint(reversebits(asuint(_e156)));
-    int2 _e158 = i2_;
-    i2_ = asint(reversebits(asuint(_e158)));
-    int3 _e160 = i3_;
-    i3_ = asint(reversebits(asuint(_e160)));
-    int4 _e162 = i4_;
-    i4_ = asint(reversebits(asuint(_e162)));
-    uint _e164 = u;
-    u = reversebits(_e164);
-    uint2 _e166 = u2_;
-    u2_ = reversebits(_e166);
-    uint3 _e168 = u3_;
-    u3_ = reversebits(_e168);
-    uint4 _e170 = u4_;
-    u4_ = reversebits(_e170);
+    i = asint(firstbithigh(_e140));
+    uint _e142 = u;
+    u = firstbithigh(_e142);
+    int _e144 = i;
+    i = asint(countbits(asuint(_e144)));
+    int2 _e146 = i2_;
+    i2_ = asint(countbits(asuint(_e146)));
+    int3 _e148 = i3_;
+    i3_ = asint(countbits(asuint(_e148)));
+    int4 _e150 = i4_;
+    i4_ = asint(countbits(asuint(_e150)));
+    uint _e152 = u;
+    u = countbits(_e152);
+    uint2 _e154 = u2_;
+    u2_ = countbits(_e154);
+    uint3 _e156 = u3_;
+    u3_ = countbits(_e156);
+    uint4 _e158 = u4_;
+    u4_ = countbits(_e158);
+    int _e160 = i;
+    i = asint(reversebits(asuint(_e160)));
+    int2 _e162 = i2_;
+    i2_ = asint(reversebits(asuint(_e162)));
+    int3 _e164 = i3_;
+    i3_ = asint(reversebits(asuint(_e164)));
+    int4 _e166 = i4_;
+    i4_ = asint(reversebits(asuint(_e166)));
+    uint _e168 = u;
+    u = reversebits(_e168);
+    uint2 _e170 = u2_;
+    u2_ = reversebits(_e170);
+    uint3 _e172 = u3_;
+    u3_ = reversebits(_e172);
+    uint4 _e174 = u4_;
+    u4_ = reversebits(_e174);
     return;
 }
@@ -0,0 +1,29 @@
+static float value_1 = (float)0;
+static float4 color = (float4)0;
+
+struct FragmentInput_main {
+    float value_2 : LOC0;
+};
+
+void main_1()
+{
+    float _e4 = value_1;
+    if ((_e4 < 0.0)) {
+        discard;
+    }
+    float _e6 = ddx(_e4);
+    color = float4(_e6, _e6, _e6, 1.0);
+    if ((_e4 > 1.0)) {
+        discard;
+    }
+    return;
+}
+
+float4 main(FragmentInput_main fragmentinput_main) : SV_Target0
+{
+    float value = fragmentinput_main.value_2;
+    value_1 = value;
+    main_1();
+    float4 _e3 = color;
+    return _e3;
+}
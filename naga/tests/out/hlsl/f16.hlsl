@@ -0,0 +1,153 @@
+struct NagaConstants {
+    int first_vertex;
+    int first_instance;
+    uint other;
+};
+ConstantBuffer<NagaConstants> _NagaConstants: register(b0, space1);
+
+struct UniformCompatible {
+    uint val_u32_;
+    int val_i32_;
+    float val_f32_;
+    half val_f16_;
+    half _pad4_0;
+    half2 val_f16_2_;
+    int _pad5_0;
+    half3 val_f16_3_;
+    half _pad6_0;
+    half4 val_f16_4_;
+    half final_value;
+    half _pad8_0;
+    half2 val_mat2x2__0; half2 val_mat2x2__1;
+    int _pad9_0;
+    row_major half4x4 val_mat4x4_;
+};
+
+struct StorageCompatible {
+    half val_f16_array_2_[2];
+};
+
+static const half constant_variable = 15.203125h;
+
+static half private_variable = 1.0h;
+cbuffer input_uniform : register(b0) { UniformCompatible input_uniform; }
+ByteAddressBuffer input_storage : register(t1);
+ByteAddressBuffer input_arrays : register(t2);
+RWByteAddressBuffer output : register(u3);
+RWByteAddressBuffer output_arrays : register(u4);
+
+half2x2 GetMatval_mat2x2_OnUniformCompatible(UniformCompatible obj) {
+    return half2x2(obj.val_mat2x2__0, obj.val_mat2x2__1);
+}
+
+void SetMatval_mat2x2_OnUniformCompatible(UniformCompatible obj, half2x2 mat) {
+    obj.val_mat2x2__0 = mat[0];
+    obj.val_mat2x2__1 = mat[1];
+}
+
+void SetMatVecval_mat2x2_OnUniformCompatible(UniformCompatible obj, half2 vec, uint mat_idx) {
+    switch(mat_idx) {
+    case 0: { obj.val_mat2x2__0 = vec; break; }
+    case 1: { obj.val_mat2x2__1 = vec; break; }
+    }
+}
+
+void SetMatScalarval_mat2x2_OnUniformCompatible(UniformCompatible obj, half scalar, uint mat_idx, uint vec_idx) {
+    switch(mat_idx) {
+    case 0: { obj.val_mat2x2__0[vec_idx] = scalar; break; }
+    case 1: { obj.val_mat2x2__1[vec_idx] = scalar; break; }
+    }
+}
+
+typedef half ret_Constructarray2_half_[2];
+ret_Constructarray2_half_ Constructarray2_half_(half arg0, half arg1) {
+    half ret[2] = { arg0, arg1 };
+    return ret;
+}
+
+half f16_function(half x)
+{
+    half val = 15.203125h;
+
+    half _e4 = val;
+    val = (_e4 + -33344.0h);
+    half _e6 = val;
+    half _e9 = val;
+    val = (_e9 + (_e6 + 5.0h));
+    float _e13 = input_uniform.val_f32_;
+    half _e14 = val;
+    half _e18 = val;
+    val = (_e18 + half((_e13 + float(_e14))));
+    half _e22 = input_uniform.val_f16_;
+    half _e25 = val;
+    val = (_e25 + (_e22).xxx.z);
+    half _e31 = input_uniform.val_f16_;
+    half _e34 = input_storage.Load<half>(12);
+    output.Store(12, (_e31 + _e34));
+    half2 _e40 = input_uniform.val_f16_2_;
+    half2 _e43 = input_storage.Load<half2>(16);
+    output.Store(16, (_e40 + _e43));
+    half3 _e49 = input_uniform.val_f16_3_;
+    half3 _e52 = input_storage.Load<half3>(24);
+    output.Store(24, (_e49 + _e52));
+    half4 _e58 = input_uniform.val_f16_4_;
+    half4 _e61 = input_storage.Load<half4>(32);
+    output.Store(32, (_e58 + _e61));
+    half2x2 _e67 = GetMatval_mat2x2_OnUniformCompatible(input_uniform);
+    half2x2 _e70 = half2x2(input_storage.Load<half2>(44+0), input_storage.Load<half2>(44+4));
+    {
+        half2x2 _value2 = (_e67 + _e70);
+        output.Store(44+0, _value2[0]);
+        output.Store(44+4, _value2[1]);
+    }
+    half4x4 _e76 = input_uniform.val_mat4x4_;
+    half4x4 _e79 = half4x4(input_storage.Load<half4>(56+0), input_storage.Load<half4>(56+8), input_storage.Load<half4>(56+16), input_storage.Load<half4>(56+24));
+    {
+        half4x4 _value2 = (_e76 + _e79);
+        output.Store(56+0, _value2[0]);
+        output.Store(56+8, _value2[1]);
+        output.Store(56+16, _value2[2]);
+        output.Store(56+24, _value2[3]);
+    }
+    half _e85[2] = Constructarray2_half_(input_arrays.Load<half>(0+0), input_arrays.Load<half>(0+2));
+    {
+        half _value2[2] = _e85;
+        output_arrays.Store(0+0, _value2[0]);
+        output_arrays.Store(0+2, _value2[1]);
+    }
+    half _e86 = val;
+    half _e88 = val;
+    val = (_e88 + abs(_e86));
+    half _e90 = val;
+    half _e91 = val;
+    half _e92 = val;
+    half _e94 = val;
+    val = (_e94 + clamp(_e90, _e91, _e92));
+    half _e96 = val;
+    half _e98 = val;
+    half _e101 = val;
+    val = (_e101 + dot((_e96).xx, (_e98).xx));
+    half _e103 = val;
+    half _e104 = val;
+    half _e106 = val;
+    val = (_e106 + max(_e103, _e104));
+    half _e108 = val;
+    half _e109 = val;
+    half _e111 = val;
+    val = (_e111 + min(_e108, _e109));
+    half _e113 = val;
+    half _e115 = val;
+    val = (_e115 + sign(_e113));
+    half _e118 = val;
+    val = (_e118 + 1.0h);
+    half _e120 = val;
+    return _e120;
+}
+
+[numthreads(1, 1, 1)]
+void main()
+{
+    const half _e3 = f16_function(2.0h);
+    output.Store(40, _e3);
+    return;
+}
@@ -0,0 +1,15 @@
+uint test_packed_integer_dot_product(uint x, uint y)
+{
+    int c_5_ = dot4add_i8packed(x, y, 0);
+    uint c_6_ = dot4add_u8packed(x, y, 0u);
+    int c_7_ = dot4add_i8packed((x + 5u), (y + 6u), 0);
+    uint c_8_ = dot4add_u8packed((x + 7u), (y + 8u), 0u);
+    return c_8_;
+}
+
+[numthreads(1, 1, 1)]
+void main()
+{
+    const uint _e2 = test_packed_integer_dot_product(1u, 2u);
+    return;
+}
@@ -14,8 +14,20 @@ int test_integer_dot_product()
     uint3 a_3_ = (1u).xxx;
     uint3 b_3_ = (1u).xxx;
     uint c_3_ = dot(a_3_, b_3_);
-    int c_4_ = dot((4).xxxx, (2).xxxx);
-    return c_4_;
+    return 32;
+}
+
+uint test_packed_integer_dot_product(uint x, uint y)
+{
+    int c_5_ = dot(int4(x, x >> 8, x >> 16, x >> 24) << 24 >> 24, int4(y, y >> 8, y >> 16, y >> 24) << 24 >> 24);
+    uint c_6_ = dot(uint4(x, x >> 8, x >> 16, x >> 24) << 24 >> 24, uint4(y, y >> 8, y >> 16, y >> 24) << 24 >> 24);
+    uint _e5 = (x + 5u);
+    uint _e7 = (y + 6u);
+    int c_7_ = dot(int4(_e5, _e5 >> 8, _e5 >> 16, _e5 >> 24) << 24 >> 24, int4(_e7, _e7 >> 8, _e7 >> 16, _e7 >> 24) << 24 >> 24);
+    uint _e10 = (x + 7u);
+    uint _e12 = (y + 8u);
+    uint c_8_ = dot(uint4(_e10, _e10 >> 8, _e10 >> 16, _e10 >> 24) << 24 >> 24, uint4(_e12, _e12 >> 8, _e12 >> 16, _e12 >> 24) << 24 >> 24);
+    return (c_8_ + uint(-121));
 }
 
 [numthreads(1, 1, 1)]
@@ -23,5 +35,6 @@ void main()
 {
     const float2 _e0 = test_fma();
     const int _e1 = test_integer_dot_product();
+    const uint _e4 = test_packed_integer_dot_product(1u, 2u);
     return;
 }
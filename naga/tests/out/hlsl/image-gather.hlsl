@@ -0,0 +1,29 @@
+Texture2D<float4> tex : register(t0);
+Texture2D<float> depth_tex : register(t2);
+SamplerState samp : register(s1);
+SamplerComparisonState depth_samp : register(s3);
+static float2 uv_1 = (float2)0;
+static float4 color = (float4)0;
+
+struct FragmentInput_main {
+    float2 uv_2 : LOC0;
+};
+
+void main_1()
+{
+    float2 _e7 = uv_1;
+    float4 _e8 = tex.GatherGreen(samp, _e7);
+    float4 _e9 = tex.GatherAlpha(samp, _e7, int2(int2(1, -1)));
+    float4 _e10 = depth_tex.GatherCmp(depth_samp, _e7, 0.5);
+    color = ((_e8 + _e9) + _e10);
+    return;
+}
+
+float4 main(FragmentInput_main fragmentinput_main) : SV_Target0
+{
+    float2 uv = fragmentinput_main.uv_2;
+    uv_1 = uv;
+    main_1();
+    float4 _e3 = color;
+    return _e3;
+}
@@ -0,0 +1,20 @@
+struct _atomic_compare_exchange_resultUint4_ {
+    uint old_value;
+    bool exchanged;
+};
+
+static const int o = 2;
+
+groupshared uint a;
+
+[numthreads(1, 1, 1)]
+void f(uint3 __local_invocation_id : SV_GroupThreadID)
+{
+    if (all(__local_invocation_id == uint3(0u, 0u, 0u))) {
+        a = (uint)0;
+    }
+    GroupMemoryBarrierWithGroupSync();
+    _atomic_compare_exchange_resultUint4_ _e5; InterlockedCompareExchange(a, 2u, 1u, _e5.old_value);
+    _e5.exchanged = (_e5.old_value == 2u);
+    return;
+}
@@ -0,0 +1,15 @@
+RWByteAddressBuffer buf : register(u0);
+
+void double_(uint p_index)
+{
+    float _e3 = asfloat(buf.Load(p_index*4));
+    buf.Store(p_index*4, asuint((_e3 * 2.0)));
+    return;
+}
+
+[numthreads(1, 1, 1)]
+void main(uint index : SV_GroupIndex)
+{
+    double_(index);
+    return;
+}
@@ -0,0 +1,119 @@
+struct Particle {
+    float4 position;
+    float4 velocity;
+    float4 history[4];
+};
+
+struct Params {
+    float4 gravity;
+    float4 damping[2];
+};
+
+RWByteAddressBuffer particles : register(u0);
+cbuffer params : register(b1) { Params params; }
+RWByteAddressBuffer fallback : register(u2);
+groupshared uint counters[4];
+static Particle scratch = (Particle)0;
+
+void reset(inout Particle p)
+{
+    float4 _e4 = p.position;
+    p.history[0] = _e4;
+    return;
+}
+
+float read_uniform(uint p_index)
+{
+    float _e5 = params.damping[p_index].x;
+    float _e7 = params.damping[p_index].w;
+    return (_e5 + _e7);
+}
+
+void apply_gravity(uint p_index_1, float damping)
+{
+    float4 _e7 = params.gravity;
+    float4 _e9 = asfloat(particles.Load4(16+p_index_1*96));
+    particles.Store4(16+p_index_1*96, asuint((_e9 + (_e7 * damping))));
+    float4 _e14 = asfloat(particles.Load4(16+p_index_1*96));
+    particles.Store4(16+32+p_index_1*96, asuint(_e14));
+    return;
+}
+
+void step_(uint p_index_2, uint i)
+{
+    uint _e7 = (i % 2u);
+    const float _e9 = read_uniform(_e7);
+    apply_gravity(p_index_2, _e9);
+    float4 _e12 = asfloat(particles.Load4(16+p_index_2*96));
+    float4 _e13 = asfloat(particles.Load4(0+p_index_2*96));
+    particles.Store4(0+p_index_2*96, asuint((_e13 + _e12)));
+    return;
+}
+
+void apply_gravity_1(float damping_1)
+{
+    float4 _e5 = params.gravity;
+    float4 _e7 = asfloat(fallback.Load4(16));
+    fallback.Store4(16, asuint((_e7 + (_e5 * damping_1))));
+    float4 _e12 = asfloat(fallback.Load4(16));
+    fallback.Store4(16+32, asuint(_e12));
+    return;
+}
+
+void step_1(uint i_1)
+{
+    uint _e5 = (i_1 % 2u);
+    const float _e7 = read_uniform(_e5);
+    apply_gravity_1(_e7);
+    float4 _e10 = asfloat(fallback.Load4(16));
+    float4 _e11 = asfloat(fallback.Load4(0));
+    fallback.Store4(0, asuint((_e11 + _e10)));
+    return;
+}
+
+float read_uniform_1()
+{
+    float _e3 = params.gravity.x;
+    float _e5 = params.gravity.w;
+    return (_e3 + _e5);
+}
+
+void apply_gravity_2(float damping_2)
+{
+    float4 _e6 = params.gravity;
+    float4 _e8 = asfloat(particles.Load4(16+0));
+    particles.Store4(16+0, asuint((_e8 + (_e6 * damping_2))));
+    float4 _e13 = asfloat(particles.Load4(16+0));
+    particles.Store4(16+32+0, asuint(_e13));
+    return;
+}
+
+uint bump(uint counter_index)
+{
+    uint _e4; InterlockedAdd(counters[min(uint(counter_index), 3u)], 1u, _e4);
+    return _e4;
+}
+
+uint bump_1()
+{
+    uint _e3; InterlockedAdd(counters[3], 1u, _e3);
+    return _e3;
+}
+
+[numthreads(64, 1, 1)]
+void main(uint3 id : SV_DispatchThreadID, uint lid : SV_GroupIndex, uint3 __local_invocation_id : SV_GroupThreadID)
+{
+    if (all(__local_invocation_id == uint3(0u, 0u, 0u))) {
+        counters = (uint[4])0;
+    }
+    GroupMemoryBarrierWithGroupSync();
+    step_(id.x, id.x);
+    step_1(0u);
+    const float _e12 = read_uniform_1();
+    apply_gravity_2(_e12);
+    uint _e15 = (lid % 4u);
+    const uint _e17 = bump(_e15);
+    const uint _e20 = bump_1();
+    reset(scratch);
+    return;
+}
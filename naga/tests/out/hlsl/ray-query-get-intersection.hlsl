@@ -0,0 +1,126 @@
+struct RayDesc_ {
+    uint flags;
+    uint cull_mask;
+    float tmin;
+    float tmax;
+    float3 origin;
+    int _pad5_0;
+    float3 dir;
+    int _end_pad_0;
+};
+
+struct RayIntersection {
+    uint kind;
+    float t;
+    uint instance_custom_index;
+    uint instance_id;
+    uint sbt_record_offset;
+    uint geometry_index;
+    uint primitive_index;
+    float2 barycentrics;
+    bool front_face;
+    int _pad9_0;
+    int _pad9_1;
+    row_major float4x3 object_to_world;
+    int _pad10_0;
+    row_major float4x3 world_to_object;
+    int _end_pad_0;
+};
+
+struct Output {
+    uint candidate_type;
+    int candidate_geometry;
+    uint committed_type;
+};
+
+RayDesc naga_rayDesc(RayDesc_ arg0) {
+    RayDesc ret = (RayDesc)0;
+    ret.Origin = arg0.origin;
+    ret.TMin = arg0.tmin;
+    ret.Direction = arg0.dir;
+    ret.TMax = arg0.tmax;
+    return ret;
+}
+
+RayIntersection naga_committedIntersection(RayQuery<RAY_FLAG_NONE> rq) {
+    RayIntersection ret = (RayIntersection)0;
+    ret.kind = 0;
+    if (rq.CommittedStatus() != COMMITTED_NOTHING) {
+        ret.t = rq.CommittedRayT();
+        ret.instance_custom_index = rq.CommittedInstanceID();
+        ret.instance_id = rq.CommittedInstanceIndex();
+        ret.sbt_record_offset = rq.CommittedInstanceContributionToHitGroupIndex();
+        ret.geometry_index = rq.CommittedGeometryIndex();
+        ret.primitive_index = rq.CommittedPrimitiveIndex();
+        if (rq.CommittedStatus() == COMMITTED_TRIANGLE_HIT) {
+            ret.kind = 1;
+            ret.barycentrics = rq.CommittedTriangleBarycentrics();
+            ret.front_face = rq.CommittedTriangleFrontFace();
+        } else {
+            ret.kind = 2;
+        }
+        ret.object_to_world = rq.CommittedObjectToWorld4x3();
+        ret.world_to_object = rq.CommittedWorldToObject4x3();
+    }
+    return ret;
+}
+
+RayIntersection naga_candidateIntersection(RayQuery<RAY_FLAG_NONE> rq) {
+    RayIntersection ret = (RayIntersection)0;
+    ret.instance_custom_index = rq.CandidateInstanceID();
+    ret.instance_id = rq.CandidateInstanceIndex();
+    ret.sbt_record_offset = rq.CandidateInstanceContributionToHitGroupIndex();
+    ret.geometry_index = rq.CandidateGeometryIndex();
+    ret.primitive_index = rq.CandidatePrimitiveIndex();
+    ret.object_to_world = rq.CandidateObjectToWorld4x3();
+    ret.world_to_object = rq.CandidateWorldToObject4x3();
+    if (rq.CandidateType() == CANDIDATE_NON_OPAQUE_TRIANGLE) {
+        ret.kind = 1;
+        ret.t = rq.CandidateTriangleRayT();
+        ret.barycentrics = rq.CandidateTriangleBarycentrics();
+        ret.front_face = rq.CandidateTriangleFrontFace();
+    } else {
+        ret.kind = 4;
+    }
+    return ret;
+}
+
+RaytracingAccelerationStructure acc_struct : register(t0);
+RWByteAddressBuffer output : register(u1);
+
+RayDesc_ ConstructRayDesc_(uint arg0, uint arg1, float arg2, float arg3, float3 arg4, float3 arg5) {
+    RayDesc_ ret = (RayDesc_)0;
+    ret.flags = arg0;
+    ret.cull_mask = arg1;
+    ret.tmin = arg2;
+    ret.tmax = arg3;
+    ret.origin = arg4;
+    ret.dir = arg5;
+    return ret;
+}
+
+void main_1()
+{
+    RayQuery<RAY_FLAG_NONE> rq;
+
+    RayDesc_ _e9 = ConstructRayDesc_(0u, 255u, 0.1, 100.0, float3(0.0, 0.0, 0.0), float3(0.0, 1.0, 0.0));
+    rq.TraceRayInline(acc_struct, _e9.flags, _e9.cull_mask, naga_rayDesc(_e9));
+    while(true) {
+        const bool _e10 = rq.Proceed();
+        if (_e10) {
+            output.Store(0, asuint(((naga_candidateIntersection(rq).kind == 4u) ? 1u : 0u)));
+            output.Store(4, asuint(asint(naga_candidateIntersection(rq).geometry_index)));
+            continue;
+        } else {
+            break;
+        }
+    }
+    output.Store(8, asuint(naga_committedIntersection(rq).kind));
+    return;
+}
+
+[numthreads(1, 1, 1)]
+void main()
+{
+    main_1();
+}
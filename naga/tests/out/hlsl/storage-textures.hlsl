@@ -0,0 +1,21 @@
+RWTexture2D<float> s_r_r : register(u0);
+RWTexture2D<unorm float4> s_rgba_r : register(u1);
+RWTexture2D<float> s_r_rw : register(u2);
+
+[numthreads(1, 1, 1)]
+void csLoad(uint3 id : SV_DispatchThreadID)
+{
+    float4 a = s_r_r.Load(id.xy);
+    float4 b = s_rgba_r.Load(id.xy);
+    s_r_rw[id.xy] = (a + (b.x).xxxx);
+    return;
+}
+
+[numthreads(8, 8, 1)]
+void csFilter(uint3 id_1 : SV_DispatchThreadID)
+{
+    float4 value = s_r_rw.Load(id_1.xy);
+    DeviceMemoryBarrierWithGroupSync();
+    s_r_rw[id_1.xy] = (value * 0.5);
+    return;
+}
@@ -0,0 +1,13 @@
+struct ComputeInput_main {
+};
+
+[numthreads(1, 1, 1)]
+void main(ComputeInput_main computeinput_main)
+{
+    uint subgroup_invocation_id = WaveGetLaneIndex();
+    const uint _e2 = QuadReadLaneAt(subgroup_invocation_id, 2u);
+    const uint _e3 = QuadReadAcrossX(subgroup_invocation_id);
+    const uint _e4 = QuadReadAcrossY(subgroup_invocation_id);
+    const uint _e5 = QuadReadAcrossDiagonal(subgroup_invocation_id);
+    return;
+}
@@ -0,0 +1,27 @@
+struct FragmentInput_main {
+    nointerpolation int index_1 : LOC0;
+};
+
+float4 ZeroValuefloat4() {
+    return (float4)0;
+}
+
+float4 main(FragmentInput_main fragmentinput_main) : SV_Target0
+{
+    int index = fragmentinput_main.index_1;
+    float4 color = ZeroValuefloat4();
+
+    switch(index) {
+        case 1:
+        case 2: {
+            color = (1.0).xxxx;
+            break;
+        }
+        default: {
+            color = (0.5).xxxx;
+            break;
+        }
+    }
+    float4 _e7 = color;
+    return _e7;
+}
@@ -4,6 +4,7 @@ const SIZE: u32 = 128u;
 var<storage, read_write> arr_i32_: array<atomic<i32>, 128>;
 @group(0) @binding(1) 
 var<storage, read_write> arr_u32_: array<atomic<u32>, 128>;
+var<workgroup> counter: atomic<u32>;
 
 @compute @workgroup_size(1, 1, 1) 
 fn test_atomic_compare_exchange_i32_() {
@@ -88,3 +89,21 @@ fn test_atomic_compare_exchange_u32_() {
     }
     return;
 }
+
+@compute @workgroup_size(64, 1, 1) 
+fn test_atomic_compare_exchange_workgroup() {
+    var old_2: u32;
+
+    let _e1 = atomicLoad((&counter));
+    old_2 = _e1;
+    loop {
+        let _e4 = old_2;
+        let _e5 = old_2;
+        let _e8 = atomicCompareExchangeWeak((&counter), _e4, (_e5 + 1u));
+        if _e8.exchanged {
+            break;
+        }
+        old_2 = _e8.old_value;
+    }
+    return;
+}
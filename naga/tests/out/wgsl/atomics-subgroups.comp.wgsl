@@ -0,0 +1,125 @@
+struct Counter {
+    counter: u32,
+}
+
+struct Values {
+    values: array<i32>,
+}
+
+struct Output {
+    ballot: vec4<u32>,
+}
+
+struct Counter_1 {
+    counter: atomic<u32>,
+}
+
+struct Values_1 {
+    values: array<atomic<i32>>,
+}
+
+@group(0) @binding(0) 
+var<storage, read_write> global: Counter_1;
+@group(0) @binding(1) 
+var<storage, read_write> global_1: Values_1;
+@group(0) @binding(2) 
+var<storage, read_write> global_2: Output;
+var<workgroup> shared_max: atomic<u32>;
+var<private> gl_SubgroupInvocationID_1: u32;
+var<private> gl_SubgroupSize_1: u32;
+
+fn main_1() {
+    var index: u32;
+    var old: i32;
+    var swapped: u32;
+    var is_even: bool;
+    var all_even: bool;
+    var any_even: bool;
+    var value: f32;
+    var sum: f32;
+    var product: vec2<f32>;
+    var minimum: i32;
+    var bits: vec3<u32>;
+    var first: f32;
+    var broadcast: f32;
+    var shuffled: f32;
+    var xored: f32;
+    var up: f32;
+    var down: f32;
+
+    let _e10 = atomicAdd((&global.counter), 1u);
+    index = _e10;
+    let _e12 = index;
+    let _e17 = index;
+    let _e21 = atomicMin((&global_1.values[_e17]), -1i);
+    let _e24 = index;
+    let _e25 = atomicMax((&shared_max), _e24);
+    let _e29 = atomicAnd((&global.counter), 255u);
+    let _e33 = atomicOr((&global.counter), 2u);
+    let _e41 = atomicXor((&global_1.values[0i]), 3i);
+    let _e49 = atomicExchange((&global_1.values[1i]), 7i);
+    old = _e49;
+    let _e56 = atomicCompareExchangeWeak((&global.counter), 4u, 5u);
+    swapped = _e56.old_value;
+    storageBarrier();
+    workgroupBarrier();
+    subgroupBarrier();
+    textureBarrier();
+    subgroupBarrier();
+    let _e60 = gl_SubgroupInvocationID_1;
+    is_even = ((_e60 & 1u) == 0u);
+    let _e67 = is_even;
+    let _e68 = subgroupBallot(_e67);
+    global_2.ballot = _e68;
+    let _e70 = is_even;
+    let _e71 = subgroupAll(_e70);
+    all_even = _e71;
+    let _e74 = is_even;
+    let _e75 = subgroupAny(_e74);
+    any_even = _e75;
+    let _e77 = gl_SubgroupInvocationID_1;
+    value = f32(_e77);
+    let _e81 = value;
+    let _e82 = subgroupAdd(_e81);
+    sum = _e82;
+    let _e84 = value;
+    let _e86 = value;
+    let _e88 = subgroupInclusiveMul(vec2(_e86));
+    product = _e88;
+    let _e91 = old;
+    let _e92 = subgroupExclusiveAdd(_e91);
+    minimum = _e92;
+    let _e94 = swapped;
+    let _e96 = swapped;
+    let _e98 = subgroupXor(vec3(_e96));
+    bits = _e98;
+    let _e101 = sum;
+    let _e102 = subgroupBroadcastFirst(_e101);
+    first = _e102;
+    let _e106 = sum;
+    let _e108 = subgroupBroadcast(_e106, 4u);
+    broadcast = _e108;
+    let _e112 = gl_SubgroupSize_1;
+    let _e115 = value;
+    let _e116 = gl_SubgroupSize_1;
+    let _e119 = subgroupShuffle(_e115, (_e116 - 1u));
+    shuffled = _e119;
+    let _e123 = value;
+    let _e125 = subgroupShuffleXor(_e123, 1u);
+    xored = _e125;
+    let _e129 = value;
+    let _e131 = subgroupShuffleUp(_e129, 1u);
+    up = _e131;
+    let _e135 = value;
+    let _e137 = subgroupShuffleDown(_e135, 1u);
+    down = _e137;
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn main(@builtin(subgroup_invocation_id) gl_SubgroupInvocationID: u32, @builtin(subgroup_size) gl_SubgroupSize: u32) {
+    gl_SubgroupInvocationID_1 = gl_SubgroupInvocationID;
+    gl_SubgroupSize_1 = gl_SubgroupSize;
+    main_1();
+    return;
+}
@@ -25,103 +25,107 @@ fn main() {
     u = pack4xI8(_e38);
     let _e40 = u4_;
     u = pack4xU8(_e40);
-    let _e42 = u;
-    f4_ = unpack4x8snorm(_e42);
-    let _e44 = u;
-    f4_ = unpack4x8unorm(_e44);
+    let _e42 = i4_;
+    u = pack4xI8Clamp(_e42);
+    let _e44 = u4_;
+    u = pack4xU8Clamp(_e44);
     let _e46 = u;
-    f2_ = unpack2x16snorm(_e46);
+    f4_ = unpack4x8snorm(_e46);
     let _e48 = u;
-    f2_ = unpack2x16unorm(_e48);
+    f4_ = unpack4x8unorm(_e48);
     let _e50 = u;
-    f2_ = unpack2x16float(_e50);
+    f2_ = unpack2x16snorm(_e50);
     let _e52 = u;
-    i4_ = unpack4xI8(_e52);
+    f2_ = unpack2x16unorm(_e52);
     let _e54 = u;
-    u4_ = unpack4xU8(_e54);
-    let _e56 = i;
-    let _e57 = i;
-    i = insertBits(_e56, _e57, 5u, 10u);
-    let _e61 = i2_;
-    let _e62 = i2_;
-    i2_ = insertBits(_e61, _e62, 5u, 10u);
-    let _e66 = i3_;
-    let _e67 = i3_;
-    i3_ = insertBits(_e66, _e67, 5u, 10u);
-    let _e71 = i4_;
-    let _e72 = i4_;
-    i4_ = insertBits(_e71, _e72, 5u, 10u);
-    let _e76 = u;
-    let _e77 = u;
-    u = insertBits(_e76, _e77, 5u, 10u);
-    let _e81 = u2_;
-    let _e82 = u2_;
-    u2_ = insertBits(_e81, _e82, 5u, 10u);
-    let _e86 = u3_;
-    let _e87 = u3_;
-    u3_ = insertBits(_e86, _e87, 5u, 10u);
-    let _e91 = u4_;
-    let _e92 = u4_;
-    u4_ = insertBits(_e91, _e92, 5u, 10u);
-    let _e96 = i;
-    i = extractBits(_e96, 5u, 10u);
-    let _e100 = i2_;
-    i2_ = extractBits(_e100, 5u, 10u);
-    let _e104 = i3_;
-    i3_ = extractBits(_e104, 5u, 10u);
-    let _e108 = i4_;
-    i4_ = extractBits(_e108, 5u, 10u);
-    let _e112 = u;
-    u = extractBits(_e112, 5u, 10u);
-    let _e116 = u2_;
-    u2_ = extractBits(_e116, 5u, 10u);
-    let _e120 = u3_;
-    u3_ = extractBits(_e120, 5u, 10u);
-    let _e124 = u4_;
-    u4_ = extractBits(_e124, 5u, 10u);
-    let _e128 = i;
-    i = firstTrailingBit(_e128);
-    let _e130 = u2_;
-    u2_ = firstTrailingBit(_e130);
-    let _e132 = i3_;
-    i3_ = firstLeadingBit(_e132);
-    let _e134 = u3_;
-    u3_ = firstLeadingBit(_e134);
-    let _e136 = i;
-    i = firstLeadingBit(_e136);
-    let _e138 = u;
-    u = firstLeadingBit(_e138);
+    f2_ = unpack2x16float(_e54);
+    let _e56 = u;
+    i4_ = unpack4xI8(_e56);
+    let _e58 = u;
+    u4_ = unpack4xU8(_e58);
+    let _e60 = i;
+    let _e61 = i;
+    i = insertBits(_e60, _e61, 5u, 10u);
+    let _e65 = i2_;
+    let _e66 = i2_;
+    i2_ = insertBits(_e65, _e66, 5u, 10u);
+    let _e70 = i3_;
+    let _e71 = i3_;
+    i3_ = insertBits(_e70, _e71, 5u, 10u);
+    let _e75 = i4_;
+    let _e76 = i4_;
+    i4_ = insertBits(_e75, _e76, 5u, 10u);
+    let _e80 = u;
+    let _e81 = u;
+    u = insertBits(_e80, _e81, 5u, 10u);
+    let _e85 = u2_;
+    let _e86 = u2_;
+    u2_ = insertBits(_e85, _e86, 5u, 10u);
+    let _e90 = u3_;
+    let _e91 = u3_;
+    u3_ = insertBits(_e90, _e91, 5u, 10u);
+    let _e95 = u4_;
+    let _e96 = u4_;
+    u4_ = insertBits(_e95, _e96, 5u, 10u);
+    let _e100 = i;
+    i = extractBits(_e100, 5u, 10u);
+    let _e104 = i2_;
+    i2_ = extractBits(_e104, 5u, 10u);
+    let _e108 = i3_;
+    i3_ = extractBits(_e108, 5u, 10u);
+    let _e112 = i4_;
+    i4_ = extractBits(_e112, 5u, 10u);
+    let _e116 = u;
+    u = extractBits(_e116, 5u, 10u);
+    let _e120 = u2_;
+    u2_ = extractBits(_e120, 5u, 10u);
+    let _e124 = u3_;
+    u3_ = extractBits(_e124, 5u, 10u);
+    let _e128 = u4_;
+    u4_ = extractBits(_e128, 5u, 10u);
+    let _e132 = i;
+    i = firstTrailingBit(_e132);
+    let _e134 = u2_;
+    u2_ = firstTrailingBit(_e134);
+    let _e136 = i3_;
+    i3_ = firstLeadingBit(_e136);
+    let _e138 = u3_;
+    u3_ = firstLeadingBit(_e138);
     let _e140 = i;
-    i = countOneBits(_e140);
-    let _e142 = i2_;
-    i2_ = countOneBits(_e142);
-    let _e144 = i3_;
-    i3_ = countOneBits(_e144);
-    let _e146 = i4_;
-    i4_ = countOneBits(_e146);
-    let _e148 = u;
-    u = countOneBits(_e148);
-    let _e150 = u2_;
-    u2_ = countOneBits(_e150);
-    let _e152 = u3_;
-    u3_ = countOneBits(_e152);
-    let _e154 = u4_;
-    u4_ = countOneBits(_e154);
-    let _e156 = i;
-    i = reverseBits(_e156);
-    let _e158 = i2_;
-    i2_ = reverseBits(_e158);
-    let _e160 = i3_;
-    i3_ = reverseBits(_e160);
-    let _e162 = i4_;
-    i4_ = reverseBits(_e162);
-    let _e164 = u;
-    u = reverseBits(_e164);
-    let _e166 = u2_;
-    u2_ = reverseBits(_e166);
-    let _e168 = u3_;
-    u3_ = reverseBits(_e168);
-    let _e170 = u4_;
-    u4_ = reverseBits(_e170);
+    i = firstLeadingBit(_e140);
+    let _e142 = u;
+    u = firstLeadingBit(_e142);
+    let _e144 = i;
+    i = countOneBits(_e144);
+    let _e146 = i2_;
+    i2_ = countOneBits(_e146);
+    let _e148 = i3_;
+    i3_ = countOneBits(_e148);
+    let _e150 = i4_;
+    i4_ = countOneBits(_e150);
+    let _e152 = u;
+    u = countOneBits(_e152);
+    let _e154 = u2_;
+    u2_ = countOneBits(_e154);
+    let _e156 = u3_;
+    u3_ = countOneBits(_e156);
+    let _e158 = u4_;
+    u4_ = countOneBits(_e158);
+    let _e160 = i;
+    i = reverseBits(_e160);
+    let _e162 = i2_;
+    i2_ = reverseBits(_e162);
+    let _e164 = i3_;
+    i3_ = reverseBits(_e164);
+    let _e166 = i4_;
+    i4_ = reverseBits(_e166);
+    let _e168 = u;
+    u = reverseBits(_e168);
+    let _e170 = u2_;
+    u2_ = reverseBits(_e170);
+    let _e172 = u3_;
+    u3_ = reverseBits(_e172);
+    let _e174 = u4_;
+    u4_ = reverseBits(_e174);
     return;
 }
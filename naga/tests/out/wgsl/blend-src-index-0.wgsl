@@ -0,0 +1,13 @@
+var<private> global: vec4<f32>;
+
+fn function() {
+    global = vec4<f32>(1f, 1f, 1f, 1f);
+    return;
+}
+
+@fragment 
+fn main() -> @location(0) vec4<f32> {
+    function();
+    let _e1 = global;
+    return _e1;
+}
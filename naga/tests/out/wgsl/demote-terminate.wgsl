@@ -0,0 +1,23 @@
+var<private> value_1: f32;
+var<private> color: vec4<f32>;
+
+fn main_1() {
+    let _e4 = value_1;
+    if (_e4 < 0f) {
+        discard;
+    }
+    let _e6 = dpdx(_e4);
+    color = vec4<f32>(_e6, _e6, _e6, 1f);
+    if (_e4 > 1f) {
+        discard;
+    }
+    return;
+}
+
+@fragment 
+fn main(@location(0) value: f32) -> @location(0) vec4<f32> {
+    value_1 = value;
+    main_1();
+    let _e3 = color;
+    return _e3;
+}
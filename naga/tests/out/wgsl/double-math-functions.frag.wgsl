@@ -25,9 +25,9 @@ fn main_1() {
     var distanceOut: f64;
     var stepOut: vec4<f64>;
     var ldexpOut: f64;
-    var smoothStepScalar: f64;
-    var smoothStepVector: vec4<f64>;
-    var smoothStepMixed: vec4<f64>;
+    var smoothStepScalar: f64 = 0.5lf;
+    var smoothStepVector: vec4<f64> = vec4<f64>(0.5lf, 0.5lf, 0.5lf, 0.5lf);
+    var smoothStepMixed: vec4<f64> = vec4<f64>(0.5lf, 0.5lf, 0.5lf, 0.5lf);
 
     let _e8 = a;
     let _e9 = b;
@@ -92,10 +92,6 @@ fn main_1() {
     let _e150 = a;
     let _e152 = i;
     ldexpOut = ldexp(_e150.x, _e152);
-    smoothStepScalar = f64(smoothstep(0f, 1f, 0.5f));
-    smoothStepVector = smoothstep(vec4(0.0lf), vec4(1.0lf), vec4(0.5lf));
-    smoothStepMixed = smoothstep(vec4(0.0lf), vec4(1.0lf), vec4(0.5lf));
-    return;
 }
 
 @fragment 
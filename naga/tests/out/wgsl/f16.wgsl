@@ -0,0 +1,103 @@
+enable f16;
+
+struct UniformCompatible {
+    val_u32_: u32,
+    val_i32_: i32,
+    val_f32_: f32,
+    val_f16_: f16,
+    val_f16_2_: vec2<f16>,
+    val_f16_3_: vec3<f16>,
+    val_f16_4_: vec4<f16>,
+    final_value: f16,
+    val_mat2x2_: mat2x2<f16>,
+    val_mat4x4_: mat4x4<f16>,
+}
+
+struct StorageCompatible {
+    val_f16_array_2_: array<f16, 2>,
+}
+
+const constant_variable: f16 = 15.203125h;
+
+var<private> private_variable: f16 = 1h;
+@group(0) @binding(0) 
+var<uniform> input_uniform: UniformCompatible;
+@group(0) @binding(1) 
+var<storage> input_storage: UniformCompatible;
+@group(0) @binding(2) 
+var<storage> input_arrays: StorageCompatible;
+@group(0) @binding(3) 
+var<storage, read_write> output: UniformCompatible;
+@group(0) @binding(4) 
+var<storage, read_write> output_arrays: StorageCompatible;
+
+fn f16_function(x: f16) -> f16 {
+    var val: f16 = 15.203125h;
+
+    let _e4 = val;
+    val = (_e4 + -33344h);
+    let _e6 = val;
+    let _e9 = val;
+    val = (_e9 + (_e6 + 5h));
+    let _e13 = input_uniform.val_f32_;
+    let _e14 = val;
+    let _e18 = val;
+    val = (_e18 + f16((_e13 + f32(_e14))));
+    let _e22 = input_uniform.val_f16_;
+    let _e25 = val;
+    val = (_e25 + vec3(_e22).z);
+    let _e31 = input_uniform.val_f16_;
+    let _e34 = input_storage.val_f16_;
+    output.val_f16_ = (_e31 + _e34);
+    let _e40 = input_uniform.val_f16_2_;
+    let _e43 = input_storage.val_f16_2_;
+    output.val_f16_2_ = (_e40 + _e43);
+    let _e49 = input_uniform.val_f16_3_;
+    let _e52 = input_storage.val_f16_3_;
+    output.val_f16_3_ = (_e49 + _e52);
+    let _e58 = input_uniform.val_f16_4_;
+    let _e61 = input_storage.val_f16_4_;
+    output.val_f16_4_ = (_e58 + _e61);
+    let _e67 = input_uniform.val_mat2x2_;
+    let _e70 = input_storage.val_mat2x2_;
+    output.val_mat2x2_ = (_e67 + _e70);
+    let _e76 = input_uniform.val_mat4x4_;
+    let _e79 = input_storage.val_mat4x4_;
+    output.val_mat4x4_ = (_e76 + _e79);
+    let _e85 = input_arrays.val_f16_array_2_;
+    output_arrays.val_f16_array_2_ = _e85;
+    let _e86 = val;
+    let _e88 = val;
+    val = (_e88 + abs(_e86));
+    let _e90 = val;
+    let _e91 = val;
+    let _e92 = val;
+    let _e94 = val;
+    val = (_e94 + clamp(_e90, _e91, _e92));
+    let _e96 = val;
+    let _e98 = val;
+    let _e101 = val;
+    val = (_e101 + dot(vec2(_e96), vec2(_e98)));
+    let _e103 = val;
+    let _e104 = val;
+    let _e106 = val;
+    val = (_e106 + max(_e103, _e104));
+    let _e108 = val;
+    let _e109 = val;
+    let _e111 = val;
+    val = (_e111 + min(_e108, _e109));
+    let _e113 = val;
+    let _e115 = val;
+    val = (_e115 + sign(_e113));
+    let _e118 = val;
+    val = (_e118 + 1h);
+    let _e120 = val;
+    return _e120;
+}
+
+@compute @workgroup_size(1, 1, 1) 
+fn main() {
+    let _e3 = f16_function(2h);
+    output.final_value = _e3;
+    return;
+}
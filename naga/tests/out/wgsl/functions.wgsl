@@ -12,13 +12,21 @@ fn test_integer_dot_product() -> i32 {
     const a_3_ = vec3(1u);
     const b_3_ = vec3(1u);
     let c_3_ = dot(a_3_, b_3_);
-    let c_4_ = dot(vec4(4i), vec4(2i));
-    return c_4_;
+    return 32i;
+}
+
+fn test_packed_integer_dot_product(x: u32, y: u32) -> u32 {
+    let c_5_ = dot4I8Packed(x, y);
+    let c_6_ = dot4U8Packed(x, y);
+    let c_7_ = dot4I8Packed((x + 5u), (y + 6u));
+    let c_8_ = dot4U8Packed((x + 7u), (y + 8u));
+    return (c_8_ + u32(-121i));
 }
 
 @compute @workgroup_size(1, 1, 1) 
 fn main() {
     let _e0 = test_fma();
     let _e1 = test_integer_dot_product();
+    let _e4 = test_packed_integer_dot_product(1u, 2u);
     return;
 }
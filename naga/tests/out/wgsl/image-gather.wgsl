@@ -0,0 +1,27 @@
+@group(0) @binding(0) 
+var tex: texture_2d<f32>;
+@group(0) @binding(2) 
+var depth_tex: texture_depth_2d;
+@group(0) @binding(1) 
+var samp: sampler;
+@group(0) @binding(3) 
+var depth_samp: sampler_comparison;
+var<private> uv_1: vec2<f32>;
+var<private> color: vec4<f32>;
+
+fn main_1() {
+    let _e7 = uv_1;
+    let _e8 = textureGather(1, tex, samp, _e7);
+    let _e9 = textureGather(3, tex, samp, _e7, vec2<i32>(1i, -1i));
+    let _e10 = textureGatherCompare(depth_tex, depth_samp, _e7, 0.5f);
+    color = ((_e8 + _e9) + _e10);
+    return;
+}
+
+@fragment 
+fn main(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
+    uv_1 = uv;
+    main_1();
+    let _e3 = color;
+    return _e3;
+}
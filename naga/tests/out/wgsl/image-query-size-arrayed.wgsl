@@ -0,0 +1,24 @@
+struct type_4 {
+    member: vec3<u32>,
+    member_1: vec3<i32>,
+}
+
+@group(0) @binding(0) 
+var global: texture_2d_array<f32>;
+@group(0) @binding(1) 
+var<storage, read_write> global_1: type_4;
+
+fn function() {
+    let _e3 = textureDimensions(global, 0i);
+    let _e4 = textureNumLayers(global);
+    global_1.member = vec3<u32>(_e3, _e4);
+    let _e7 = textureDimensions(global, 0i);
+    let _e9 = textureNumLayers(global);
+    global_1.member_1 = vec3<i32>(vec2<i32>(_e7), i32(_e9));
+    return;
+}
+
+@compute @workgroup_size(1, 1, 1) 
+fn main() {
+    function();
+}
@@ -1,5 +1,22 @@
+struct _modf_result_f32_ {
+    fract: f32,
+    whole: f32,
+}
+
+struct _modf_result_vec2_f32_ {
+    fract: vec2<f32>,
+    whole: vec2<f32>,
+}
+
+struct _frexp_result_f32_ {
+    fract: f32,
+    exp: i32,
+}
+
 @fragment 
 fn main() {
+    var x: f32 = 1.5f;
+
     const v = vec4(0f);
     let a = degrees(1f);
     let b = radians(1f);
@@ -9,7 +26,6 @@ fn main() {
     let g = refract(v, v, 1f);
     const sign_b = vec4<i32>(-1i, -1i, -1i, -1i);
     const sign_d = vec4<f32>(-1f, -1f, -1f, -1f);
-    let const_dot = dot(vec2<i32>(), vec2<i32>());
     const flb_b = vec2<i32>(-1i, -1i);
     const flb_c = vec2<u32>(0u, 0u);
     const ftb_c = vec2<i32>(0i, 0i);
@@ -20,16 +36,22 @@ fn main() {
     const ctz_h = vec2<i32>(0i, 0i);
     const clz_c = vec2<i32>(0i, 0i);
     const clz_d = vec2<u32>(31u, 31u);
-    let lde_a = ldexp(1f, 2i);
-    let lde_b = ldexp(vec2<f32>(1f, 2f), vec2<i32>(3i, 4i));
-    let modf_a = modf(1.5f);
-    let modf_b = modf(1.5f).fract;
-    let modf_c = modf(1.5f).whole;
-    let modf_d = modf(vec2<f32>(1.5f, 1.5f));
-    let modf_e = modf(vec4<f32>(1.5f, 1.5f, 1.5f, 1.5f)).whole.x;
-    let modf_f = modf(vec2<f32>(1.5f, 1.5f)).fract.y;
-    let frexp_a = frexp(1.5f);
-    let frexp_b = frexp(1.5f).fract;
-    let frexp_c = frexp(1.5f).exp;
-    let frexp_d = frexp(vec4<f32>(1.5f, 1.5f, 1.5f, 1.5f)).exp.x;
+    const lde_b = vec2<f32>(8f, 32f);
+    const modf_a = _modf_result_f32_(0.5f, 1f);
+    const modf_d = _modf_result_vec2_f32_(vec2<f32>(0.5f, 0.5f), vec2<f32>(1f, 1f));
+    const frexp_a = _frexp_result_f32_(0.75f, 1i);
+    let _e88 = x;
+    let modf_g = modf(_e88);
+    let _e90 = x;
+    let frexp_e = frexp(_e90);
+    let _e92 = x;
+    let lde_c = ldexp(_e92, 2i);
+    let _e95 = x;
+    let quantize_a = quantizeToF16(_e95);
+    let _e97 = x;
+    let quantize_b = quantizeToF16(vec2(_e97));
+    let _e100 = x;
+    let quantize_c = quantizeToF16(vec3(_e100));
+    let _e103 = x;
+    let quantize_d = quantizeToF16(vec4(_e103));
 }
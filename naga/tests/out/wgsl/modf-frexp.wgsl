@@ -0,0 +1,26 @@
+struct type_3 {
+    member: f32,
+    member_1: f32,
+    member_2: vec2<f32>,
+    member_3: vec2<i32>,
+}
+
+@group(0) @binding(0) 
+var<storage, read_write> global: type_3;
+
+fn function() {
+    let _e2 = global.member;
+    let _e3 = modf(_e2);
+    global.member_1 = _e3.fract;
+    global.member = _e3.whole;
+    let _e8 = global.member_2;
+    let _e9 = frexp(_e8);
+    global.member_2 = _e9.fract;
+    global.member_3 = _e9.exp;
+    return;
+}
+
+@compute @workgroup_size(1, 1, 1) 
+fn main() {
+    function();
+}
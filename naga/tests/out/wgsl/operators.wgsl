@@ -4,15 +4,14 @@ const v_f32_half: vec4<f32> = vec4<f32>(0.5f, 0.5f, 0.5f, 0.5f);
 const v_i32_one: vec4<i32> = vec4<i32>(1i, 1i, 1i, 1i);
 
 fn builtins() -> vec4<f32> {
-    let s1_ = select(0i, 1i, true);
-    let s2_ = select(v_f32_zero, v_f32_one, true);
-    let s3_ = select(v_f32_one, v_f32_zero, vec4<bool>(false, false, false, false));
-    let m1_ = mix(v_f32_zero, v_f32_one, v_f32_half);
-    let m2_ = mix(v_f32_zero, v_f32_one, 0.1f);
-    let b1_ = bitcast<f32>(1i);
-    let b2_ = bitcast<vec4<f32>>(v_i32_one);
+    const s1_ = select(0i, 1i, true);
+    const s2_ = select(v_f32_zero, v_f32_one, true);
+    const s3_ = vec4<f32>(1f, 1f, 1f, 1f);
+    const m1_ = vec4<f32>(0.5f, 0.5f, 0.5f, 0.5f);
+    const m2_ = vec4<f32>(0.1f, 0.1f, 0.1f, 0.1f);
+    const b2_ = vec4<f32>(0.000000000000000000000000000000000000000000001f, 0.000000000000000000000000000000000000000000001f, 0.000000000000000000000000000000000000000000001f, 0.000000000000000000000000000000000000000000001f);
     const v_i32_zero = vec4<i32>(0i, 0i, 0i, 0i);
-    return (((((vec4<f32>((vec4(s1_) + v_i32_zero)) + s2_) + m1_) + m2_) + vec4(b1_)) + b2_);
+    return (((((vec4<f32>((vec4(s1_) + v_i32_zero)) + s2_) + m1_) + m2_) + vec4(0.000000000000000000000000000000000000000000001f)) + b2_);
 }
 
 fn splat(m: f32, n: i32) -> vec4<f32> {
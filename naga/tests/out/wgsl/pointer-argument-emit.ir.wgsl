@@ -0,0 +1,14 @@
+@group(0) @binding(0) 
+var<storage, read_write> buf: array<f32, 4>;
+
+fn double(p: ptr<storage, f32, read_write>) {
+    let _e1 = (*p);
+    (*p) = (_e1 * 2f);
+    return;
+}
+
+@compute @workgroup_size(1, 1, 1) 
+fn main(@builtin(local_invocation_index) index: u32) {
+    double((&buf[index]));
+    return;
+}
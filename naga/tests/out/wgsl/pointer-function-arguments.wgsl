@@ -0,0 +1,66 @@
+struct Particle {
+    position: vec4<f32>,
+    velocity: vec4<f32>,
+    history: array<vec4<f32>, 4>,
+}
+
+struct Params {
+    gravity: vec4<f32>,
+    damping: array<vec4<f32>, 2>,
+}
+
+@group(0) @binding(0) 
+var<storage, read_write> particles: array<Particle>;
+@group(0) @binding(1) 
+var<uniform> params: Params;
+@group(0) @binding(2) 
+var<storage, read_write> fallback: Particle;
+var<workgroup> counters: array<atomic<u32>, 4>;
+var<private> scratch: Particle;
+
+fn read_uniform(p: ptr<uniform, vec4<f32>>) -> f32 {
+    let _e2 = (*p).x;
+    let _e4 = (*p).w;
+    return (_e2 + _e4);
+}
+
+fn apply_gravity(p_1: ptr<storage, Particle, read_write>, damping: f32) {
+    let _e5 = params.gravity;
+    let _e7 = (*p_1).velocity;
+    (*p_1).velocity = (_e7 + (_e5 * damping));
+    let _e12 = (*p_1).velocity;
+    (*p_1).history[1] = _e12;
+    return;
+}
+
+fn step(p_2: ptr<storage, Particle, read_write>, i: u32) {
+    let _e7 = read_uniform((&params.damping[(i % 2u)]));
+    apply_gravity(p_2, _e7);
+    let _e10 = (*p_2).velocity;
+    let _e11 = (*p_2).position;
+    (*p_2).position = (_e11 + _e10);
+    return;
+}
+
+fn bump(counter: ptr<workgroup, atomic<u32>>) -> u32 {
+    let _e2 = atomicAdd(counter, 1u);
+    return _e2;
+}
+
+fn reset(p_3: ptr<private, Particle>) {
+    let _e4 = (*p_3).position;
+    (*p_3).history[0] = _e4;
+    return;
+}
+
+@compute @workgroup_size(64, 1, 1) 
+fn main(@builtin(global_invocation_id) id: vec3<u32>, @builtin(local_invocation_index) lid: u32) {
+    step((&particles[id.x]), id.x);
+    step((&fallback), 0u);
+    let _e12 = read_uniform((&params.gravity));
+    apply_gravity((&particles[0]), _e12);
+    let _e17 = bump((&counters[(lid % 4u)]));
+    let _e20 = bump((&counters[3]));
+    reset((&scratch));
+    return;
+}
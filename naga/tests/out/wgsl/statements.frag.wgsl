@@ -53,6 +53,47 @@ fn switchNoLastBreak(a_6: i32) {
     return;
 }
 
+fn switchFallThrough(a_8: i32) -> i32 {
+    var a_9: i32;
+    var b_1: i32 = 0i;
+
+    a_9 = a_8;
+    let _e4 = a_9;
+    switch _e4 {
+        case 0, 1: {
+            {
+                let _e5 = b_1;
+                let _e6 = a_9;
+                b_1 = (_e5 + (_e6 * 2i));
+            }
+            {
+                let _e10 = b_1;
+                b_1 = (_e10 + 3i);
+            }
+        }
+        case 2: {
+            let _e10 = b_1;
+            b_1 = (_e10 + 3i);
+        }
+        case 3: {
+            {
+                let _e13 = a_9;
+                b_1 = (_e13 + 1i);
+            }
+            {
+                let _e16 = b_1;
+                b_1 = (_e16 + 4i);
+            }
+        }
+        default: {
+            let _e16 = b_1;
+            b_1 = (_e16 + 4i);
+        }
+    }
+    let _e19 = b_1;
+    return _e19;
+}
+
 fn main_1() {
     return;
 }
@@ -0,0 +1,22 @@
+@group(0) @binding(0) 
+var s_r_r: texture_storage_2d<r32float,read>;
+@group(0) @binding(1) 
+var s_rgba_r: texture_storage_2d<rgba8unorm,read>;
+@group(0) @binding(2) 
+var s_r_rw: texture_storage_2d<r32float,read_write>;
+
+@compute @workgroup_size(1, 1, 1) 
+fn csLoad(@builtin(global_invocation_id) id: vec3<u32>) {
+    let a = textureLoad(s_r_r, id.xy);
+    let b = textureLoad(s_rgba_r, id.xy);
+    textureStore(s_r_rw, id.xy, (a + vec4(b.x)));
+    return;
+}
+
+@compute @workgroup_size(8, 8, 1) 
+fn csFilter(@builtin(global_invocation_id) id_1: vec3<u32>) {
+    let value = textureLoad(s_r_rw, id_1.xy);
+    textureBarrier();
+    textureStore(s_r_rw, id_1.xy, (value * 0.5f));
+    return;
+}
@@ -0,0 +1,11 @@
+@compute @workgroup_size(1, 1, 1) 
+fn main(@builtin(subgroup_invocation_id) subgroup_invocation_id: u32) {
+    let _e1 = subgroupAdd(subgroup_invocation_id, 1u);
+    let _e2 = subgroupMul(subgroup_invocation_id, 2u);
+    let _e3 = subgroupMin(subgroup_invocation_id, 8u);
+    let _e4 = subgroupMax(subgroup_invocation_id, 16u);
+    let _e5 = subgroupAnd(subgroup_invocation_id, 32u);
+    let _e6 = subgroupOr(subgroup_invocation_id, 64u);
+    let _e7 = subgroupXor(subgroup_invocation_id, 128u);
+    return;
+}
@@ -0,0 +1,13 @@
+const CLUSTER_SIZE: u32 = 4u;
+
+@compute @workgroup_size(1, 1, 1) 
+fn main(@builtin(subgroup_invocation_id) subgroup_invocation_id: u32) {
+    let _e1 = subgroupAdd(subgroup_invocation_id, 4u);
+    let _e2 = subgroupMul(subgroup_invocation_id, 4u);
+    let _e3 = subgroupMin(subgroup_invocation_id, 4u);
+    let _e4 = subgroupMax(subgroup_invocation_id, 4u);
+    let _e5 = subgroupAnd(subgroup_invocation_id, 4u);
+    let _e6 = subgroupOr(subgroup_invocation_id, 4u);
+    let _e7 = subgroupXor(subgroup_invocation_id, 4u);
+    return;
+}
@@ -0,0 +1,8 @@
+@compute @workgroup_size(1, 1, 1) 
+fn main(@builtin(subgroup_invocation_id) subgroup_invocation_id: u32) {
+    let _e2 = quadBroadcast(subgroup_invocation_id, 2u);
+    let _e3 = quadSwapX(subgroup_invocation_id);
+    let _e4 = quadSwapY(subgroup_invocation_id);
+    let _e5 = quadSwapDiagonal(subgroup_invocation_id);
+    return;
+}
@@ -0,0 +1,15 @@
+@fragment 
+fn main(@location(0) @interpolate(flat) index: i32) -> @location(0) vec4<f32> {
+    var color: vec4<f32> = vec4<f32>();
+
+    switch index {
+        case 1, 2: {
+            color = vec4(1f);
+        }
+        default: {
+            color = vec4(0.5f);
+        }
+    }
+    let _e7 = color;
+    return _e7;
+}
@@ -0,0 +1,262 @@
+/*!
+Tests for the module summary produced by `naga::reflect`.
+*/
+
+#![cfg(feature = "wgsl-in")]
+
+use naga::reflect::{BindingType, Reflection};
+use naga::{ShaderStage, StorageAccess};
+
+fn reflect_wgsl(source: &str) -> Reflection {
+    let module = naga::front::wgsl::parse_str(source).unwrap_or_else(|e| {
+        panic!(
+            "expected WGSL to parse successfully:\n{}",
+            e.emit_to_string(source)
+        );
+    });
+    let info = naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::all(),
+    )
+    .validate(&module)
+    .expect("validation failed");
+    naga::reflect::reflect(&module, &info)
+}
+
+#[test]
+fn entry_points() {
+    let reflection = reflect_wgsl(
+        "
+        struct VertexInput {
+            @location(0) position: vec3<f32>,
+            @location(1) @interpolate(flat) index: u32,
+            @builtin(vertex_index) vertex: u32,
+        }
+
+        @vertex
+        fn vs(input: VertexInput) -> @builtin(position) vec4<f32> {
+            return vec4(input.position, f32(input.index + input.vertex));
+        }
+
+        struct FragmentOutput {
+            @location(0) color: vec4<f32>,
+            @builtin(frag_depth) depth: f32,
+        }
+
+        @fragment
+        fn fs() -> FragmentOutput {
+            return FragmentOutput(vec4(1.0), 0.5);
+        }
+
+        @compute @workgroup_size(8, 4)
+        fn cs() {}
+        ",
+    );
+
+    let [ref vs, ref fs, ref cs] = reflection.entry_points[..] else {
+        panic!("expected three entry points");
+    };
+    assert_eq!(vs.stage, ShaderStage::Vertex);
+    assert_eq!(vs.workgroup_size, None);
+    let inputs: Vec<_> = vs
+        .inputs
+        .iter()
+        .map(|varying| (varying.name.as_deref(), &varying.binding))
+        .collect();
+    assert_eq!(
+        inputs,
+        [
+            (
+                Some("position"),
+                &naga::Binding::Location {
+                    location: 0,
+                    blend_src: None,
+                    interpolation: Some(naga::Interpolation::Perspective),
+                    sampling: Some(naga::Sampling::Center),
+                }
+            ),
+            (
+                Some("index"),
+                &naga::Binding::Location {
+                    location: 1,
+                    blend_src: None,
+                    interpolation: Some(naga::Interpolation::Flat),
+                    sampling: None,
+                }
+            ),
+            (
+                Some("vertex"),
+                &naga::Binding::BuiltIn(naga::BuiltIn::VertexIndex)
+            ),
+        ]
+    );
+    assert_eq!(vs.outputs.len(), 1);
+
+    assert_eq!(fs.stage, ShaderStage::Fragment);
+    assert!(fs.inputs.is_empty());
+    let outputs: Vec<_> = fs.outputs.iter().map(|v| v.name.as_deref()).collect();
+    assert_eq!(outputs, [Some("color"), Some("depth")]);
+
+    assert_eq!(cs.workgroup_size, Some([8, 4, 1]));
+}
+
+#[test]
+fn bindings() {
+    let reflection = reflect_wgsl(
+        "
+        struct Particles {
+            count: u32,
+            data: array<vec4<f32>>,
+        }
+
+        @group(1) @binding(0) var<storage, read> particles: Particles;
+        @group(1) @binding(1) var<storage, read_write> counter: atomic<u32>;
+        @group(0) @binding(2) var<uniform> transform: mat4x4<f32>;
+        @group(0) @binding(0) var color: texture_2d_array<f32>;
+        @group(0) @binding(1) var shadow: sampler_comparison;
+        @group(0) @binding(3) var depth: texture_depth_2d;
+        @group(2) @binding(0) var unused: sampler;
+
+        @vertex
+        fn vs() -> @builtin(position) vec4<f32> {
+            return transform * particles.data[particles.count];
+        }
+
+        @fragment
+        fn fs() -> @location(0) vec4<f32> {
+            let lit = textureSampleCompare(depth, shadow, vec2(0.5), 0.5);
+            return textureLoad(color, vec2(0), 0, 0) * transform[0] * lit;
+        }
+
+        @compute @workgroup_size(1)
+        fn cs() {
+            atomicAdd(&counter, 1u);
+        }
+        ",
+    );
+
+    let groups: Vec<_> = reflection
+        .bind_groups
+        .iter()
+        .map(|group| {
+            let bindings: Vec<_> = group
+                .bindings
+                .iter()
+                .map(|entry| (entry.binding, entry.name.as_deref().unwrap()))
+                .collect();
+            (group.group, bindings)
+        })
+        .collect();
+    assert_eq!(
+        groups,
+        [
+            (
+                0,
+                vec![(0, "color"), (1, "shadow"), (2, "transform"), (3, "depth")]
+            ),
+            (1, vec![(0, "particles"), (1, "counter")]),
+            (2, vec![(0, "unused")]),
+        ]
+    );
+
+    let group0 = &reflection.bind_groups[0].bindings;
+    assert_eq!(
+        group0[0].ty,
+        BindingType::Texture {
+            dim: naga::ImageDimension::D2,
+            arrayed: true,
+            class: naga::ImageClass::Sampled {
+                kind: naga::ScalarKind::Float,
+                multi: false,
+            },
+        }
+    );
+    assert_eq!(group0[1].ty, BindingType::Sampler { comparison: true });
+    assert_eq!(
+        group0[2].ty,
+        BindingType::UniformBuffer {
+            min_binding_size: 64
+        }
+    );
+    assert_eq!(
+        group0[2].visibility,
+        [ShaderStage::Vertex, ShaderStage::Fragment]
+    );
+
+    let group1 = &reflection.bind_groups[1].bindings;
+    // The runtime-sized array counts one element.
+    assert_eq!(
+        group1[0].ty,
+        BindingType::StorageBuffer {
+            access: StorageAccess::LOAD,
+            min_binding_size: 32,
+        }
+    );
+    assert_eq!(group1[0].visibility, [ShaderStage::Vertex]);
+    assert_eq!(
+        group1[1].ty,
+        BindingType::StorageBuffer {
+            access: StorageAccess::LOAD | StorageAccess::STORE,
+            min_binding_size: 4,
+        }
+    );
+    assert_eq!(group1[1].visibility, [ShaderStage::Compute]);
+
+    assert!(reflection.bind_groups[2].bindings[0].visibility.is_empty());
+
+    let resources: Vec<_> = reflection.entry_points[1]
+        .resources
+        .iter()
+        .map(|binding| (binding.group, binding.binding))
+        .collect();
+    assert_eq!(resources, [(0, 0), (0, 1), (0, 2), (0, 3)]);
+}
+
+#[test]
+fn push_constants_and_overrides() {
+    let reflection = reflect_wgsl(
+        "
+        struct Constants {
+            offset: vec2<f32>,
+            scale: f32,
+        }
+
+        var<push_constant> constants: Constants;
+
+        @id(7) override gain: f32 = 1.5;
+        override enabled: bool = true;
+        override count: u32;
+        override doubled = gain * 2.0;
+
+        @fragment
+        fn fs() -> @location(0) vec4<f32> {
+            if enabled {
+                return vec4(constants.offset, constants.scale * doubled, f32(count));
+            }
+            return vec4(0.0);
+        }
+        ",
+    );
+
+    let [ref constants] = reflection.push_constants[..] else {
+        panic!("expected one push constant variable");
+    };
+    assert_eq!(constants.name.as_deref(), Some("constants"));
+    assert_eq!(constants.range, 0..16);
+    assert_eq!(constants.visibility, [ShaderStage::Fragment]);
+
+    let overrides: Vec<_> = reflection
+        .overrides
+        .iter()
+        .map(|o| (o.name.as_deref().unwrap(), o.id, o.ty, o.default))
+        .collect();
+    assert_eq!(
+        overrides,
+        [
+            ("gain", Some(7), naga::Scalar::F32, Some(1.5)),
+            ("enabled", None, naga::Scalar::BOOL, Some(1.0)),
+            ("count", None, naga::Scalar::U32, None),
+            ("doubled", None, naga::Scalar::F32, None),
+        ]
+    );
+}
@@ -1,4 +1,6 @@
 mod example_wgsl;
+mod ir_text;
+mod reflect;
 mod snapshots;
 mod spirv_capabilities;
 mod validation;
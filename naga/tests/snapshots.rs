@@ -46,6 +46,8 @@ struct SpirvOutParameters {
     #[serde(default)]
     debug: bool,
     #[serde(default)]
+    non_semantic_debug_info: bool,
+    #[serde(default)]
     adjust_coordinate_space: bool,
     #[serde(default)]
     force_point_size: bool,
@@ -300,6 +302,33 @@ fn check_targets(
         }
     }
 
+    #[cfg(ir_text_out)]
+    {
+        if targets.contains(Targets::IR) {
+            let text = naga::back::ir_text::write_string(module).unwrap();
+            input.write_output_file("ir", "ir", &text);
+
+            // The textual IR must parse back to the same module.
+            #[cfg(all(feature = "ir-text-in", feature = "serialize"))]
+            {
+                let reparsed = naga::front::ir_text::parse_str(&text).unwrap_or_else(|err| {
+                    panic!(
+                        "Textual IR of test `{}` failed to parse:\n{}",
+                        name.display(),
+                        err.emit_to_string(&text)
+                    )
+                });
+                let config = ron::ser::PrettyConfig::default().new_line("\n".to_string());
+                assert_eq!(
+                    ron::ser::to_string_pretty(&reparsed, config.clone()).unwrap(),
+                    ron::ser::to_string_pretty(module, config).unwrap(),
+                    "Textual IR of test `{}` did not round-trip",
+                    name.display(),
+                );
+            }
+        }
+    }
+
     let validation_flags = if targets.contains(Targets::NO_VALIDATION) {
         naga::valid::ValidationFlags::empty()
     } else {
@@ -454,6 +483,10 @@ fn write_output_spv(
 
     let mut flags = spv::WriterFlags::LABEL_VARYINGS;
     flags.set(spv::WriterFlags::DEBUG, params.debug);
+    flags.set(
+        spv::WriterFlags::NON_SEMANTIC_DEBUG_INFO,
+        params.non_semantic_debug_info,
+    );
     flags.set(
         spv::WriterFlags::ADJUST_COORDINATE_SPACE,
         params.adjust_coordinate_space,
@@ -476,8 +509,11 @@ fn write_output_spv(
     };
 
     let (module, info) =
-        naga::back::pipeline_constants::process_overrides(module, info, pipeline_constants)
+        naga::back::pipeline_constants::process_overrides(module, info, None, pipeline_constants)
             .expect("override evaluation failed");
+    let (module, info) =
+        naga::back::pointer_arguments::specialize_pointer_arguments(&module, &info)
+            .expect("pointer argument specialization failed");
 
     if params.separate_entry_points {
         for ep in module.entry_points.iter() {
@@ -512,9 +548,16 @@ fn write_output_spv_inner(
     use rspirv::binary::Disassemble;
     println!("Generating SPIR-V for {:?}", input.file_name);
     let spv = spv::write_vec(module, info, options, pipeline_options).unwrap();
-    let dis = rspirv::dr::load_words(spv)
-        .expect("Produced invalid SPIR-V")
-        .disassemble();
+    let dis = if options
+        .flags
+        .contains(spv::WriterFlags::NON_SEMANTIC_DEBUG_INFO)
+    {
+        disassemble_instructions(&spv)
+    } else {
+        rspirv::dr::load_words(spv)
+            .expect("Produced invalid SPIR-V")
+            .disassemble()
+    };
     // HACK escape CR/LF if source code is in side.
     let dis = if options.debug_info.is_some() {
         let dis = dis.replace("\\r", "\r");
@@ -525,6 +568,40 @@ fn write_output_spv_inner(
     input.write_output_file("spv", extension, dis);
 }
 
+/// Disassemble `words` one instruction at a time.
+///
+/// `rspirv`'s module loader doesn't accept the module-level `OpExtInst`
+/// instructions used by `NonSemantic.Shader.DebugInfo.100`, so we can't build
+/// an `rspirv::dr::Module` for SPIR-V that uses it.
+#[cfg(spv_out)]
+fn disassemble_instructions(words: &[u32]) -> String {
+    use rspirv::binary::{Consumer, Disassemble, ParseAction};
+
+    #[derive(Default)]
+    struct Lines(Vec<String>);
+
+    impl Consumer for Lines {
+        fn initialize(&mut self) -> ParseAction {
+            ParseAction::Continue
+        }
+        fn finalize(&mut self) -> ParseAction {
+            ParseAction::Continue
+        }
+        fn consume_header(&mut self, header: rspirv::dr::ModuleHeader) -> ParseAction {
+            self.0.push(header.disassemble());
+            ParseAction::Continue
+        }
+        fn consume_instruction(&mut self, inst: rspirv::dr::Instruction) -> ParseAction {
+            self.0.push(inst.disassemble());
+            ParseAction::Continue
+        }
+    }
+
+    let mut lines = Lines::default();
+    rspirv::binary::parse_words(words, &mut lines).expect("Produced invalid SPIR-V");
+    lines.0.join("\n")
+}
+
 #[cfg(msl_out)]
 fn write_output_msl(
     input: &Input,
@@ -540,7 +617,7 @@ fn write_output_msl(
     println!("generating MSL");
 
     let (module, info) =
-        naga::back::pipeline_constants::process_overrides(module, info, pipeline_constants)
+        naga::back::pipeline_constants::process_overrides(module, info, None, pipeline_constants)
             .expect("override evaluation failed");
 
     let mut options = options.clone();
@@ -582,8 +659,11 @@ fn write_output_glsl(
 
     let mut buffer = String::new();
     let (module, info) =
-        naga::back::pipeline_constants::process_overrides(module, info, pipeline_constants)
+        naga::back::pipeline_constants::process_overrides(module, info, None, pipeline_constants)
             .expect("override evaluation failed");
+    let (module, info) =
+        naga::back::pointer_arguments::specialize_pointer_arguments(&module, &info)
+            .expect("pointer argument specialization failed");
     let mut writer = glsl::Writer::new(
         &mut buffer,
         &module,
@@ -614,8 +694,11 @@ fn write_output_hlsl(
     println!("generating HLSL");
 
     let (module, info) =
-        naga::back::pipeline_constants::process_overrides(module, info, pipeline_constants)
+        naga::back::pipeline_constants::process_overrides(module, info, None, pipeline_constants)
             .expect("override evaluation failed");
+    let (module, info) =
+        naga::back::pointer_arguments::specialize_pointer_arguments(&module, &info)
+            .expect("pointer argument specialization failed");
 
     let mut buffer = String::new();
     let mut writer = hlsl::Writer::new(&mut buffer, options);
@@ -742,6 +825,7 @@ fn convert_wgsl() {
             "functions",
             Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
         ),
+        ("functions-optimized-by-version", Targets::HLSL),
         (
             "fragment-output",
             Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
@@ -775,7 +859,7 @@ fn convert_wgsl() {
         ),
         (
             "atomicCompareExchange",
-            Targets::SPIRV | Targets::METAL | Targets::WGSL,
+            Targets::SPIRV | Targets::METAL | Targets::HLSL | Targets::WGSL,
         ),
         (
             "padding",
@@ -791,9 +875,13 @@ fn convert_wgsl() {
         ),
         (
             "atomicCompareExchange-int64",
-            Targets::SPIRV | Targets::WGSL,
+            Targets::SPIRV | Targets::HLSL | Targets::WGSL,
         ),
         ("pointers", Targets::SPIRV | Targets::WGSL),
+        (
+            "pointer-function-arguments",
+            Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
+        ),
         (
             "control-flow",
             Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
@@ -812,7 +900,7 @@ fn convert_wgsl() {
             Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
         ),
         ("bounds-check-zero", Targets::SPIRV | Targets::METAL),
-        ("bounds-check-zero-atomic", Targets::METAL),
+        ("bounds-check-zero-atomic", Targets::SPIRV | Targets::METAL),
         ("bounds-check-restrict", Targets::SPIRV | Targets::METAL),
         (
             "bounds-check-image-restrict",
@@ -822,6 +910,7 @@ fn convert_wgsl() {
             "bounds-check-image-rzsw",
             Targets::SPIRV | Targets::METAL | Targets::GLSL,
         ),
+        ("bounds-check-binding-array-rzsw", Targets::SPIRV),
         ("policy-mix", Targets::SPIRV | Targets::METAL),
         (
             "texture-arg",
@@ -860,6 +949,10 @@ fn convert_wgsl() {
             "workgroup-var-init",
             Targets::WGSL | Targets::GLSL | Targets::SPIRV | Targets::HLSL | Targets::METAL,
         ),
+        (
+            "storage-textures",
+            Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
+        ),
         (
             "workgroup-uniform-load",
             Targets::WGSL | Targets::GLSL | Targets::SPIRV | Targets::HLSL | Targets::METAL,
@@ -868,7 +961,7 @@ fn convert_wgsl() {
         ("sprite", Targets::SPIRV),
         ("force_point_size_vertex_shader_webgl", Targets::GLSL),
         ("invariant", Targets::GLSL),
-        ("ray-query", Targets::SPIRV | Targets::METAL),
+        ("ray-query", Targets::SPIRV | Targets::METAL | Targets::HLSL),
         ("hlsl-keyword", Targets::HLSL),
         (
             "constructors",
@@ -885,6 +978,14 @@ fn convert_wgsl() {
             "struct-layout",
             Targets::WGSL | Targets::GLSL | Targets::SPIRV | Targets::HLSL | Targets::METAL,
         ),
+        (
+            "clip-distances",
+            Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
+        ),
+        (
+            "f16",
+            Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
+        ),
         (
             "f64",
             Targets::SPIRV | Targets::GLSL | Targets::HLSL | Targets::WGSL,
@@ -909,6 +1010,18 @@ fn convert_wgsl() {
             "subgroup-operations",
             Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
         ),
+        (
+            "subgroup-operations-quad",
+            Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
+        ),
+        (
+            "subgroup-operations-clustered",
+            Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::WGSL,
+        ),
+        (
+            "subgroup-operations-clustered-sizes",
+            Targets::SPIRV | Targets::GLSL | Targets::WGSL,
+        ),
         (
             "overrides",
             Targets::IR
@@ -916,15 +1029,16 @@ fn convert_wgsl() {
                 | Targets::SPIRV
                 | Targets::METAL
                 | Targets::HLSL
-                | Targets::GLSL,
+                | Targets::GLSL
+                | Targets::WGSL,
         ),
         (
             "overrides-atomicCompareExchangeWeak",
-            Targets::IR | Targets::SPIRV | Targets::METAL,
+            Targets::IR | Targets::SPIRV | Targets::METAL | Targets::HLSL,
         ),
         (
             "overrides-ray-query",
-            Targets::IR | Targets::SPIRV | Targets::METAL,
+            Targets::IR | Targets::SPIRV | Targets::METAL | Targets::HLSL,
         ),
         ("vertex-pulling-transform", Targets::METAL),
         (
@@ -937,6 +1051,7 @@ fn convert_wgsl() {
         ),
         ("6220-break-from-loop", Targets::SPIRV),
         ("index-by-value", Targets::SPIRV | Targets::IR),
+        ("diagnostic-filter", Targets::IR),
         (
             "6438-conflicting-idents",
             Targets::SPIRV | Targets::METAL | Targets::GLSL | Targets::HLSL | Targets::WGSL,
@@ -961,6 +1076,7 @@ fn convert_wgsl() {
             ("debug-symbol-simple", Targets::SPIRV),
             ("debug-symbol-terrain", Targets::SPIRV),
             ("debug-symbol-large-source", Targets::SPIRV),
+            ("debug-symbol-non-semantic", Targets::SPIRV),
         ];
         for &(name, targets) in inputs.iter() {
             // WGSL shaders lives in root dir as a privileged.
@@ -1073,6 +1189,38 @@ fn convert_spv_all() {
         false,
         Targets::IR | Targets::SPIRV | Targets::METAL | Targets::HLSL | Targets::WGSL,
     );
+    convert_spv(
+        "image-gather",
+        false,
+        Targets::METAL | Targets::HLSL | Targets::WGSL,
+    );
+    convert_spv(
+        "demote-terminate",
+        false,
+        Targets::IR
+            | Targets::SPIRV
+            | Targets::METAL
+            | Targets::GLSL
+            | Targets::HLSL
+            | Targets::WGSL,
+    );
+    convert_spv(
+        "ray-query-shadow",
+        false,
+        Targets::IR | Targets::SPIRV | Targets::METAL | Targets::HLSL,
+    );
+    convert_spv(
+        "image-query-size-arrayed",
+        false,
+        Targets::IR | Targets::WGSL,
+    );
+    convert_spv("modf-frexp", false, Targets::IR | Targets::WGSL);
+    convert_spv("blend-src-index-0", false, Targets::IR | Targets::WGSL);
+    convert_spv(
+        "ray-query-get-intersection",
+        false,
+        Targets::IR | Targets::HLSL,
+    );
 }
 
 #[cfg(feature = "glsl-in")]
@@ -1139,3 +1287,279 @@ fn convert_glsl_folder() {
         }
     }
 }
+
+#[cfg(feature = "ir-text-in")]
+#[test]
+fn convert_ir_folder() {
+    let _ = env_logger::try_init();
+
+    for input in Input::files_in_dir("ir") {
+        let input = Input {
+            keep_input_extension: true,
+            ..input
+        };
+        if input.file_name.extension().and_then(|s| s.to_str()) != Some("ir") {
+            continue;
+        }
+
+        let source = input.read_source();
+        let mut module = naga::front::ir_text::parse_str(&source).unwrap_or_else(|err| {
+            panic!(
+                "Failed to parse `{}`:\n{}",
+                input.file_name.display(),
+                err.emit_to_string(&source)
+            )
+        });
+        check_targets(
+            &input,
+            &mut module,
+            Targets::WGSL | Targets::SPIRV | Targets::METAL | Targets::HLSL,
+            None,
+            None,
+        );
+    }
+}
+
+/// Write `module` as WGSL, and make sure that the result parses, validates and
+/// can be written as WGSL again.
+#[cfg(all(feature = "wgsl-in", wgsl_out))]
+fn check_wgsl_round_trip(module: &naga::Module) -> Result<(), String> {
+    use naga::{back, front, valid};
+
+    let validate = |module: &naga::Module| {
+        valid::Validator::new(valid::ValidationFlags::all(), valid::Capabilities::all())
+            .validate(module)
+            .map_err(|err| format!("validation failed: {err:?}"))
+    };
+
+    let info = validate(module)?;
+    let wgsl = back::wgsl::write_string(module, &info, back::wgsl::WriterFlags::empty())
+        .map_err(|err| format!("WGSL write failed: {err}"))?;
+    let module = front::wgsl::parse_str(&wgsl)
+        .map_err(|err| format!("WGSL parse failed: {}", err.emit_to_string(&wgsl)))?;
+    let info = validate(&module).map_err(|err| format!("after WGSL, {err}"))?;
+    back::wgsl::write_string(&module, &info, back::wgsl::WriterFlags::empty())
+        .map_err(|err| format!("second WGSL write failed: {err}"))?;
+    Ok(())
+}
+
+/// Write every module in the WGSL, SPIR-V and GLSL corpora as WGSL, and make
+/// sure that the result parses and validates again. WGSL inputs go through
+/// the SPIR-V backend and frontend first.
+///
+/// The SPIR-V and GLSL frontends produce IR that the WGSL frontend never
+/// would, so this checks that the WGSL backend can express everything Naga
+/// accepts.
+#[cfg(all(
+    feature = "wgsl-in",
+    feature = "spv-in",
+    feature = "glsl-in",
+    spv_out,
+    wgsl_out
+))]
+#[test]
+fn convert_wgsl_through_spv() {
+    use naga::{back, front, valid};
+
+    let _ = env_logger::try_init();
+
+    // Inputs that can't survive the trip for reasons outside the WGSL backend.
+    const SKIP: &[(&str, &str)] = &[
+        (
+            "atomicOps",
+            "spv-in can't upgrade multi-member structs to atomics",
+        ),
+        (
+            "atomicOps-int64",
+            "spv-in can't upgrade multi-member structs to atomics",
+        ),
+        (
+            "atomicOps-int64-min-max",
+            "spv-in can't upgrade multi-member structs to atomics",
+        ),
+        (
+            "binding-arrays",
+            "spv-in doesn't support SPV_EXT_descriptor_indexing",
+        ),
+        (
+            "binding-buffer-arrays",
+            "spv-in doesn't support SPV_EXT_descriptor_indexing",
+        ),
+        (
+            "bounds-check-binding-array-rzsw",
+            "spv-in doesn't support SPV_EXT_descriptor_indexing",
+        ),
+        (
+            "bounds-check-zero-atomic",
+            "spv-in can't upgrade multi-member structs to atomics",
+        ),
+        (
+            "diagnostic-filter",
+            "SPIR-V doesn't carry diagnostic filters",
+        ),
+        ("f16", "spv-in doesn't support SPV_KHR_16bit_storage"),
+    ];
+
+    let validate = |module: &naga::Module| {
+        valid::Validator::new(valid::ValidationFlags::all(), valid::Capabilities::all())
+            .validate(module)
+    };
+
+    let mut failures = Vec::new();
+    let input_directory = Path::new(CRATE_ROOT).join(BASE_DIR_IN);
+    for entry in fs::read_dir(input_directory).expect("error reading directory") {
+        let path = entry.expect("error reading directory").path();
+        if path.extension().and_then(|s| s.to_str()) != Some("wgsl") {
+            continue;
+        }
+        let stem = path.file_stem().unwrap().to_str().unwrap();
+        if SKIP.iter().any(|&(skipped, _)| skipped == stem) {
+            continue;
+        }
+        let input = Input::new(None, stem, "wgsl");
+        let name = input.file_name.display().to_string();
+        let params = input.read_parameters();
+
+        let result = (|| {
+            let module = front::wgsl::parse_str(&input.read_source())
+                .map_err(|err| format!("WGSL parse failed: {err}"))?;
+            let info = validate(&module).map_err(|err| format!("validation failed: {err:?}"))?;
+            let (module, info) = back::pipeline_constants::process_overrides(
+                &module,
+                &info,
+                None,
+                &params.pipeline_constants,
+            )
+            .map_err(|err| format!("override processing failed: {err}"))?;
+            let (module, info) =
+                back::pointer_arguments::specialize_pointer_arguments(&module, &info)
+                    .map_err(|err| format!("pointer argument specialization failed: {err}"))?;
+            let options = back::spv::Options {
+                lang_version: (params.spv.version.0, params.spv.version.1),
+                // spv-in can't upgrade the polyfill's zero stores to
+                // workgroup atomics.
+                zero_initialize_workgroup_memory:
+                    back::spv::ZeroInitializeWorkgroupMemoryMode::None,
+                ..Default::default()
+            };
+            let words = back::spv::write_vec(&module, &info, &options, None)
+                .map_err(|err| format!("SPIR-V write failed: {err}"))?;
+            let module = front::spv::Frontend::new(words.into_iter(), &Default::default())
+                .parse()
+                .map_err(|err| format!("SPIR-V parse failed: {err}"))?;
+            check_wgsl_round_trip(&module).map_err(|err| format!("after SPIR-V, {err}"))
+        })();
+        if let Err(err) = result {
+            failures.push(format!("{name}: {err}"));
+        }
+    }
+
+    // SPIR-V inputs using ray query operations that WGSL can't express.
+    const SKIP_SPV: &[(&str, &str)] = &[
+        (
+            "ray-query-get-intersection",
+            "WGSL has no candidate intersections",
+        ),
+        ("ray-query-shadow", "WGSL can't terminate ray queries"),
+    ];
+
+    for input in Input::files_in_dir("spv") {
+        if input.file_name.extension().and_then(|s| s.to_str()) != Some("spv") {
+            continue;
+        }
+        let stem = input.file_name.file_stem().unwrap().to_str().unwrap();
+        if SKIP_SPV.iter().any(|&(skipped, _)| skipped == stem) {
+            continue;
+        }
+        let input = Input {
+            keep_input_extension: true,
+            ..input
+        };
+        let name = input.file_name.display().to_string();
+        let result = front::spv::parse_u8_slice(&input.read_bytes(), &Default::default())
+            .map_err(|err| format!("SPIR-V parse failed: {err}"))
+            .and_then(|module| check_wgsl_round_trip(&module));
+        if let Err(err) = result {
+            failures.push(format!("{name}: {err}"));
+        }
+    }
+
+    for input in Input::files_in_dir("glsl") {
+        let input = Input {
+            keep_input_extension: true,
+            ..input
+        };
+        let stage = match input.file_name.extension().and_then(|s| s.to_str()) {
+            Some("vert") => naga::ShaderStage::Vertex,
+            Some("frag") => naga::ShaderStage::Fragment,
+            Some("comp") => naga::ShaderStage::Compute,
+            _ => continue,
+        };
+        let name = input.file_name.display().to_string();
+        let options = front::glsl::Options {
+            stage,
+            defines: Default::default(),
+        };
+        let result = front::glsl::Frontend::default()
+            .parse(&options, &input.read_source())
+            .map_err(|err| format!("GLSL parse failed: {err}"))
+            .and_then(|module| check_wgsl_round_trip(&module));
+        if let Err(err) = result {
+            failures.push(format!("{name}: {err}"));
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[cfg(all(feature = "wgsl-in", feature = "opt"))]
+#[test]
+fn optimize_wgsl() {
+    use naga::{front, opt::Passes, valid};
+
+    let _ = env_logger::try_init();
+
+    let passes = [
+        Passes::INLINE,
+        Passes::CONSTANT_PROPAGATION,
+        Passes::COMMON_SUBEXPRESSIONS,
+        Passes::DEAD_STORES,
+        Passes::all(),
+    ];
+
+    let mut failures = Vec::new();
+    let input_directory = Path::new(CRATE_ROOT).join(BASE_DIR_IN);
+    for entry in fs::read_dir(input_directory).expect("error reading directory") {
+        let path = entry.expect("error reading directory").path();
+        if path.extension().and_then(|s| s.to_str()) != Some("wgsl") {
+            continue;
+        }
+        let stem = path.file_stem().unwrap().to_str().unwrap();
+        let input = Input::new(None, stem, "wgsl");
+        let name = input.file_name.display().to_string();
+        let validate = |module: &naga::Module| {
+            valid::Validator::new(valid::ValidationFlags::all(), valid::Capabilities::all())
+                .validate(module)
+        };
+
+        let Ok(module) = front::wgsl::parse_str(&input.read_source()) else {
+            continue;
+        };
+        // Optimization passes may assume their input is valid.
+        if validate(&module).is_err() {
+            continue;
+        }
+
+        for passes in passes {
+            let mut optimized = module.clone();
+            naga::opt::optimize(&mut optimized, passes);
+            if let Err(err) = validate(&optimized) {
+                failures.push(format!(
+                    "{name}: validation after {passes:?} failed: {err:?}"
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -7,6 +7,13 @@ Test SPIR-V backend capability checks.
 use spirv::Capability as Ca;
 
 fn capabilities_used(source: &str) -> naga::FastIndexSet<Ca> {
+    capabilities_used_with_options(source, &naga::back::spv::Options::default())
+}
+
+fn capabilities_used_with_options(
+    source: &str,
+    options: &naga::back::spv::Options,
+) -> naga::FastIndexSet<Ca> {
     use naga::back::spv;
     use naga::valid;
 
@@ -22,7 +29,7 @@ fn capabilities_used(source: &str) -> naga::FastIndexSet<Ca> {
         .expect("validation failed");
 
     let mut words = vec![];
-    let mut writer = spv::Writer::new(&spv::Options::default()).unwrap();
+    let mut writer = spv::Writer::new(options).unwrap();
     writer
         .write(&module, &info, None, &None, &mut words)
         .unwrap();
@@ -208,3 +215,65 @@ fn int64() {
         "#,
     );
 }
+
+#[test]
+fn packed_integer_dot_product() {
+    let source = "
+        fn f(a: u32, b: u32) -> i32 {
+            return dot4I8Packed(a, b) + i32(dot4U8Packed(a, b));
+        }
+    ";
+    let dot_product_caps = [Ca::DotProduct, Ca::DotProductInput4x8BitPacked];
+
+    // SPIR-V 1.6 has the instructions built in.
+    let options = naga::back::spv::Options {
+        lang_version: (1, 6),
+        ..Default::default()
+    };
+    let caps_used = capabilities_used_with_options(source, &options);
+    assert!(dot_product_caps.iter().all(|cap| caps_used.contains(cap)));
+
+    // Earlier versions fall back to a polyfill.
+    require_and_forbid(&[], &dot_product_caps, source);
+}
+
+#[test]
+fn demote_to_helper_invocation() {
+    let source = "
+        @fragment
+        fn main() {
+            discard;
+        }
+    ";
+
+    let opcodes = |options: &naga::back::spv::Options| {
+        let module = naga::front::wgsl::parse_str(source).unwrap();
+        let info = naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::all(),
+        )
+        .validate(&module)
+        .expect("validation failed");
+        let words = naga::back::spv::write_vec(&module, &info, options, None).unwrap();
+        rspirv::dr::load_words(words)
+            .unwrap()
+            .all_inst_iter()
+            .map(|inst| inst.class.opcode)
+            .collect::<Vec<_>>()
+    };
+
+    // `discard` demotes the invocation when the capability is available...
+    let opcodes_used = opcodes(&naga::back::spv::Options::default());
+    assert!(opcodes_used.contains(&rspirv::spirv::Op::DemoteToHelperInvocationEXT));
+    assert!(!opcodes_used.contains(&rspirv::spirv::Op::Kill));
+    require(&[Ca::DemoteToHelperInvocation], source);
+
+    // ...and falls back to terminating it otherwise.
+    let options = naga::back::spv::Options {
+        capabilities: Some([Ca::Shader].into_iter().collect()),
+        ..Default::default()
+    };
+    let opcodes_used = opcodes(&options);
+    assert!(!opcodes_used.contains(&rspirv::spirv::Op::DemoteToHelperInvocationEXT));
+    assert!(opcodes_used.contains(&rspirv::spirv::Op::Kill));
+}
@@ -606,3 +606,473 @@ fn binding_arrays_cannot_hold_scalars() {
 
     assert!(t.validator.validate(&t.module).is_err());
 }
+
+/// GLSL has no diagnostic filters, so derivatives in non-uniform control flow
+/// must stay valid, as they were before WGSL's `derivative_uniformity` rule was
+/// enforced.
+#[cfg(feature = "glsl-in")]
+#[test]
+fn glsl_derivatives_in_non_uniform_control_flow() {
+    let source = r#"
+        #version 450
+        layout(location = 0) in vec2 uv;
+        layout(location = 0) out vec4 color;
+        layout(set = 0, binding = 0) uniform texture2D tex;
+        layout(set = 0, binding = 1) uniform sampler samp;
+
+        void main() {
+            color = vec4(0.0);
+            if (uv.x > 0.5) {
+                color = texture(sampler2D(tex, samp), uv) + vec4(dFdx(uv.y));
+            }
+        }
+    "#;
+    let module = naga::front::glsl::Frontend::default()
+        .parse(
+            &naga::front::glsl::Options::from(naga::ShaderStage::Fragment),
+            source,
+        )
+        .unwrap();
+    naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::default(),
+    )
+    .validate(&module)
+    .expect("derivatives in non-uniform control flow should validate");
+}
+
+#[cfg(feature = "wgsl-in")]
+const GLOBAL_POINTER_ARGUMENT: &str = r#"
+    struct Buf { items: array<vec4<f32>, 4> }
+    @group(0) @binding(0) var<storage, read_write> buf: Buf;
+
+    fn f(p: ptr<storage, vec4<f32>, read_write>) { (*p).x = 1.0; }
+
+    @compute @workgroup_size(1)
+    fn main() { f(&buf.items[1]); }
+"#;
+
+#[cfg(feature = "wgsl-in")]
+fn global_pointer_argument_module() -> (naga::Module, naga::valid::ModuleInfo) {
+    let module = naga::front::wgsl::parse_str(GLOBAL_POINTER_ARGUMENT).unwrap();
+    let info = naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::default(),
+    )
+    .validate(&module)
+    .unwrap();
+    (module, info)
+}
+
+/// Backends that can't pass pointers to globals reject modules that haven't
+/// been through `specialize_pointer_arguments`, and accept them afterwards.
+#[cfg(all(feature = "wgsl-in", feature = "hlsl-out"))]
+#[test]
+fn hlsl_requires_specialized_pointer_arguments() {
+    use naga::back::hlsl;
+
+    let (module, info) = global_pointer_argument_module();
+    let options = hlsl::Options::default();
+    let result = hlsl::Writer::new(String::new(), &options).write(&module, &info, None);
+    assert!(matches!(result, Err(hlsl::Error::GlobalPointerArgument)));
+
+    let (module, info) =
+        naga::back::pointer_arguments::specialize_pointer_arguments(&module, &info).unwrap();
+    hlsl::Writer::new(String::new(), &options)
+        .write(&module, &info, None)
+        .unwrap();
+}
+
+#[cfg(all(feature = "wgsl-in", feature = "spv-out"))]
+#[test]
+fn spv_requires_specialized_pointer_arguments() {
+    use naga::back::spv;
+
+    let (module, info) = global_pointer_argument_module();
+    let options = spv::Options::default();
+    let err = spv::write_vec(&module, &info, &options, None).unwrap_err();
+    assert!(matches!(err, spv::Error::GlobalPointerArgument));
+
+    let (module, info) =
+        naga::back::pointer_arguments::specialize_pointer_arguments(&module, &info).unwrap();
+    spv::write_vec(&module, &info, &options, None).unwrap();
+}
+
+#[cfg(all(feature = "wgsl-in", feature = "glsl-out"))]
+#[test]
+fn glsl_requires_specialized_pointer_arguments() {
+    use naga::back::glsl;
+
+    let (module, info) = global_pointer_argument_module();
+    let options = glsl::Options::default();
+    let pipeline_options = glsl::PipelineOptions {
+        shader_stage: naga::ShaderStage::Compute,
+        entry_point: "main".to_string(),
+        multiview: None,
+    };
+    let err = glsl::Writer::new(
+        String::new(),
+        &module,
+        &info,
+        &options,
+        &pipeline_options,
+        Default::default(),
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(err, glsl::Error::GlobalPointerArgument));
+
+    let (module, info) =
+        naga::back::pointer_arguments::specialize_pointer_arguments(&module, &info).unwrap();
+    glsl::Writer::new(
+        String::new(),
+        &module,
+        &info,
+        &options,
+        &pipeline_options,
+        Default::default(),
+    )
+    .unwrap()
+    .write()
+    .unwrap();
+}
+
+#[cfg(feature = "wgsl-in")]
+fn clustered_reduce_module(cluster_size: u32) -> (naga::Module, naga::valid::ModuleInfo) {
+    let source = format!(
+        "
+        @compute @workgroup_size(1)
+        fn main(@builtin(subgroup_invocation_id) id: u32) {{
+            subgroupAdd(id, {cluster_size}u);
+        }}
+        "
+    );
+    let module = naga::front::wgsl::parse_str(&source).unwrap();
+    let info = naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::SUBGROUP | naga::valid::Capabilities::SUBGROUP_CLUSTERED,
+    )
+    .validate(&module)
+    .unwrap();
+    (module, info)
+}
+
+/// Metal only has quad reductions, so other cluster sizes must be rejected
+/// rather than miscompiled.
+#[cfg(all(feature = "wgsl-in", feature = "msl-out"))]
+#[test]
+fn msl_clustered_reduce_sizes() {
+    use naga::back::msl;
+
+    let options = msl::Options {
+        lang_version: (2, 4),
+        ..Default::default()
+    };
+    let pipeline_options = msl::PipelineOptions::default();
+
+    let (module, info) = clustered_reduce_module(4);
+    msl::write_string(&module, &info, &options, &pipeline_options).unwrap();
+
+    let (module, info) = clustered_reduce_module(8);
+    let result = msl::write_string(&module, &info, &options, &pipeline_options);
+    assert!(matches!(result, Err(msl::Error::FeatureNotImplemented(_))));
+}
+
+/// HLSL has no clustered subgroup operations at all.
+#[cfg(all(feature = "wgsl-in", feature = "hlsl-out"))]
+#[test]
+fn hlsl_rejects_clustered_reduce() {
+    use naga::back::hlsl;
+
+    let (module, info) = clustered_reduce_module(4);
+    let options = hlsl::Options {
+        shader_model: hlsl::ShaderModel::V6_0,
+        ..Default::default()
+    };
+    let result = hlsl::Writer::new(String::new(), &options).write(&module, &info, None);
+    assert!(matches!(result, Err(hlsl::Error::Custom(_))));
+}
+
+#[cfg(feature = "wgsl-in")]
+const MULTIPLE_ENTRY_POINTS: &str = r#"
+    struct Unused { x: f32 }
+    struct Params { scale: f32 }
+
+    const SHARED: f32 = 2.0;
+    const UNUSED_CONST: i32 = 7;
+    override shared_override: f32 = 1.0;
+    override vs_only: f32;
+
+    @group(0) @binding(0) var<uniform> params: Params;
+    @group(0) @binding(1) var<storage, read_write> output: array<f32>;
+    var<private> unused_private: vec4<f32>;
+
+    fn helper(x: f32) -> f32 { return x * SHARED * shared_override; }
+    fn helper_caller(x: f32) -> f32 { return helper(x) + 1.0; }
+    fn vs_helper() -> vec4<f32> { return vec4(vs_only); }
+    fn unused_fn() -> f32 { return unused_private.x; }
+
+    @vertex
+    fn vs_main() -> @builtin(position) vec4<f32> { return vs_helper(); }
+
+    @compute @workgroup_size(1)
+    fn cs_main() { output[0] = helper_caller(params.scale); }
+"#;
+
+#[cfg(feature = "wgsl-in")]
+#[test]
+fn compact_entry_points() {
+    let mut module = naga::front::wgsl::parse_str(MULTIPLE_ENTRY_POINTS).unwrap();
+    naga::compact::compact_entry_points(&mut module, |ep| ep.name == "cs_main");
+
+    let entry_points: Vec<_> = module.entry_points.iter().map(|ep| &*ep.name).collect();
+    assert_eq!(entry_points, ["cs_main"]);
+
+    let functions: Vec<_> = module
+        .functions
+        .iter()
+        .map(|(_, f)| f.name.as_deref().unwrap())
+        .collect();
+    assert_eq!(functions, ["helper", "helper_caller"]);
+
+    let globals: Vec<_> = module
+        .global_variables
+        .iter()
+        .map(|(_, g)| g.name.as_deref().unwrap())
+        .collect();
+    assert_eq!(globals, ["params", "output"]);
+
+    let constants: Vec<_> = module
+        .constants
+        .iter()
+        .filter_map(|(_, c)| c.name.as_deref())
+        .collect();
+    assert_eq!(constants, ["SHARED"]);
+
+    let overrides: Vec<_> = module
+        .overrides
+        .iter()
+        .map(|(_, o)| o.name.as_deref().unwrap())
+        .collect();
+    assert_eq!(overrides, ["shared_override"]);
+
+    assert!(module
+        .types
+        .iter()
+        .all(|(_, ty)| ty.name.as_deref() != Some("Unused")));
+
+    naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::default(),
+    )
+    .validate(&module)
+    .expect("compacted module should validate");
+}
+
+/// Overrides that the selected entry point doesn't use don't need values.
+#[cfg(all(feature = "wgsl-in", feature = "spv-out"))]
+#[test]
+fn process_overrides_for_entry_point() {
+    use naga::back::pipeline_constants::{process_overrides, PipelineConstantError};
+
+    let module = naga::front::wgsl::parse_str(MULTIPLE_ENTRY_POINTS).unwrap();
+    let info = naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::default(),
+    )
+    .validate(&module)
+    .unwrap();
+
+    let constants = Default::default();
+    assert!(matches!(
+        process_overrides(&module, &info, None, &constants),
+        Err(PipelineConstantError::MissingValue(name)) if name == "vs_only"
+    ));
+
+    let (compute, _) = process_overrides(
+        &module,
+        &info,
+        Some((naga::ShaderStage::Compute, "cs_main")),
+        &constants,
+    )
+    .unwrap();
+    assert_eq!(compute.entry_points.len(), 1);
+    assert_eq!(compute.functions.len(), 2);
+    assert!(compute.overrides.is_empty());
+}
+
+/// Parse and validate `source`, run `passes` over it, and validate the result.
+#[cfg(all(feature = "wgsl-in", feature = "opt"))]
+fn optimize_wgsl(source: &str, passes: naga::opt::Passes) -> naga::Module {
+    let validate = |module: &naga::Module| {
+        naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::default(),
+        )
+        .validate(module)
+    };
+
+    let mut module = naga::front::wgsl::parse_str(source).unwrap();
+    validate(&module).unwrap();
+    naga::opt::optimize(&mut module, passes);
+    validate(&module).expect("optimized module should validate");
+    module
+}
+
+#[cfg(all(feature = "wgsl-in", feature = "opt"))]
+#[test]
+fn optimize_inline() {
+    let module = optimize_wgsl(
+        "
+        var<private> total: f32;
+
+        fn add(x: f32) {
+            if x > 0.0 {
+                total += x;
+            }
+        }
+
+        fn twice(x: f32) -> f32 {
+            var y = x;
+            add(y);
+            return y * 2.0;
+        }
+
+        @compute @workgroup_size(1)
+        fn main() {
+            for (var i = 0; i < 4; i++) {
+                total = twice(f32(i));
+            }
+        }
+        ",
+        naga::opt::Passes::INLINE,
+    );
+
+    let mut calls = 0;
+    let mut blocks = vec![&module.entry_points[0].function.body];
+    while let Some(block) = blocks.pop() {
+        for stmt in block.iter() {
+            match *stmt {
+                naga::Statement::Call { .. } => calls += 1,
+                naga::Statement::Block(ref block) => blocks.push(block),
+                naga::Statement::If {
+                    ref accept,
+                    ref reject,
+                    ..
+                } => blocks.extend([accept, reject]),
+                naga::Statement::Loop {
+                    ref body,
+                    ref continuing,
+                    ..
+                } => blocks.extend([body, continuing]),
+                _ => {}
+            }
+        }
+    }
+    assert_eq!(calls, 0);
+    // `twice`'s local `y` moved into the entry point.
+    assert_eq!(module.entry_points[0].function.local_variables.len(), 2);
+}
+
+#[cfg(all(feature = "wgsl-in", feature = "opt"))]
+#[test]
+fn optimize_constant_propagation() {
+    let module = optimize_wgsl(
+        "
+        fn scaled(x: i32) -> i32 {
+            return x * 3;
+        }
+
+        @compute @workgroup_size(1)
+        fn main() {
+            var k = 2;
+            var out = 0;
+            if scaled(k) == 6 {
+                out = scaled(k) + 1;
+            }
+        }
+        ",
+        naga::opt::Passes::INLINE | naga::opt::Passes::CONSTANT_PROPAGATION,
+    );
+
+    let function = &module.entry_points[0].function;
+    // The condition was folded away, leaving a store of the folded sum.
+    assert!(!function
+        .body
+        .iter()
+        .any(|stmt| matches!(*stmt, naga::Statement::If { .. })));
+    assert!(function
+        .expressions
+        .iter()
+        .any(|(_, expr)| matches!(*expr, naga::Expression::Literal(naga::Literal::I32(7)))));
+    assert!(!function
+        .expressions
+        .iter()
+        .any(|(_, expr)| matches!(*expr, naga::Expression::Binary { .. })));
+}
+
+#[cfg(all(feature = "wgsl-in", feature = "opt"))]
+#[test]
+fn optimize_common_subexpressions() {
+    let module = optimize_wgsl(
+        "
+        @fragment
+        fn main(@location(0) v: vec4<f32>) -> @location(0) vec4<f32> {
+            let a = v * v.x;
+            var b = vec4(0.0);
+            if v.y > 0.0 {
+                b = v * v.x;
+            }
+            return a + b + v * v.x;
+        }
+        ",
+        naga::opt::Passes::COMMON_SUBEXPRESSIONS,
+    );
+
+    let function = &module.entry_points[0].function;
+    let multiplications = function
+        .expressions
+        .iter()
+        .filter(|&(_, expr)| {
+            matches!(
+                *expr,
+                naga::Expression::Binary {
+                    op: naga::BinaryOperator::Multiply,
+                    ..
+                }
+            )
+        })
+        .count();
+    assert_eq!(multiplications, 1);
+}
+
+#[cfg(all(feature = "wgsl-in", feature = "opt"))]
+#[test]
+fn optimize_dead_stores() {
+    let module = optimize_wgsl(
+        "
+        fn helper(p: ptr<function, f32>) -> f32 {
+            return *p;
+        }
+
+        @fragment
+        fn main(@location(0) v: f32) -> @location(0) f32 {
+            var never_read = 1.0;
+            never_read = v * 2.0;
+            var passed = v;
+            var read = 0.0;
+            read = v + 1.0;
+            return read + helper(&passed);
+        }
+        ",
+        naga::opt::Passes::DEAD_STORES,
+    );
+
+    let locals: Vec<_> = module.entry_points[0]
+        .function
+        .local_variables
+        .iter()
+        .map(|(_, local)| local.name.as_deref().unwrap())
+        .collect();
+    assert_eq!(locals, ["passed", "read"]);
+}
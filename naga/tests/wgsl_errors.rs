@@ -276,6 +276,24 @@ fn bad_for_initializer() {
     );
 }
 
+#[test]
+fn invalid_cluster_size() {
+    check(
+        r#"
+            fn x(v: u32) {
+                let y = subgroupAdd(v, 3u);
+            }
+        "#,
+        r#"error: cluster size '3u' must be a power of two
+  ┌─ wgsl:3:40
+  │
+3 │                 let y = subgroupAdd(v, 3u);
+  │                                        ^^ invalid cluster size
+
+"#,
+    );
+}
+
 #[test]
 fn unknown_storage_class() {
     check(
@@ -590,11 +608,11 @@ fn postfix_pointers() {
                 let a = *pv[3]; // Problematic line
             }
         "#,
-        r#"error: the value indexed by a `[]` subscripting expression must not be a pointer
-  ┌─ wgsl:5:26
+        r#"error: the operand of the `*` operator must be a pointer
+  ┌─ wgsl:5:25
   │
 5 │                 let a = *pv[3]; // Problematic line
-  │                          ^^ expression is a pointer
+  │                         ^^^^^^ expression is not a pointer
 
 "#,
     );
@@ -608,11 +626,11 @@ fn postfix_pointers() {
                 let a = *ps.m; // Problematic line
             }
         "#,
-        r#"error: the value accessed by a `.member` expression must not be a pointer
-  ┌─ wgsl:6:26
+        r#"error: the operand of the `*` operator must be a pointer
+  ┌─ wgsl:6:25
   │
 6 │                 let a = *ps.m; // Problematic line
-  │                          ^^ expression is a pointer
+  │                         ^^^^^ expression is not a pointer
 
 "#,
     );
@@ -966,6 +984,259 @@ fn int64_capability() {
     }
 }
 
+#[test]
+fn f16_capability() {
+    check_validation! {
+        "enable f16; var input: f16;",
+        "enable f16; var input: vec2<f16>;",
+        "enable f16; var input: mat2x2<f16>;":
+        Err(naga::valid::ValidationError::Type {
+            source: naga::valid::TypeError::WidthError(naga::valid::WidthError::MissingCapability {flag: "SHADER_FLOAT16",..}),
+            ..
+        })
+    }
+}
+
+#[test]
+fn cluster_size_too_large() {
+    check_validation! {
+        "fn x(v: u32) { let y = subgroupAdd(v, 256u); }":
+        Err(naga::valid::ValidationError::Function {
+            source: naga::valid::FunctionError::InvalidSubgroup(
+                naga::valid::SubgroupError::InvalidClusterSize(256)
+            ),
+            ..
+        }),
+        naga::valid::Capabilities::SUBGROUP | naga::valid::Capabilities::SUBGROUP_CLUSTERED
+    }
+}
+
+#[test]
+fn f16_not_enabled() {
+    check(
+        "var<private> input: f16;",
+        r###"error: `f16` enable-extension is not enabled
+  ┌─ wgsl:1:21
+  │
+1 │ var<private> input: f16;
+  │                     ^^^ the `f16` enable-extension is needed for this functionality, but it is not currently enabled
+  │
+  = note: You can enable this extension by adding `enable f16;` at the top of the shader.
+
+"###,
+    );
+
+    check(
+        "const x = 1.5h;",
+        r###"error: `f16` enable-extension is not enabled
+  ┌─ wgsl:1:11
+  │
+1 │ const x = 1.5h;
+  │           ^^^^ the `f16` enable-extension is needed for this functionality, but it is not currently enabled
+  │
+  = note: You can enable this extension by adding `enable f16;` at the top of the shader.
+
+"###,
+    );
+}
+
+#[test]
+fn f16_conversion_overflow() {
+    check(
+        "enable f16; const x = f16(70000i);",
+        r###"error: conversion to f16 operation overflowed
+  ┌─ wgsl:1:23
+  │
+1 │ enable f16; const x = f16(70000i);
+  │                       ^^^^^^^^^^^ see msg
+
+"###,
+    );
+
+    check(
+        "enable f16; const x = f16(1e10f);",
+        r###"error: conversion to f16 operation overflowed
+  ┌─ wgsl:1:23
+  │
+1 │ enable f16; const x = f16(1e10f);
+  │                       ^^^^^^^^^^ see msg
+
+"###,
+    );
+}
+
+#[test]
+fn too_many_clip_distances() {
+    check_validation! {
+        "
+        enable clip_distances;
+        struct VertexOutput {
+            @builtin(position) position: vec4<f32>,
+            @builtin(clip_distances) clip_distances: array<f32, 9>,
+        }
+        @vertex
+        fn main() -> VertexOutput {
+            return VertexOutput(vec4(0.0), array<f32, 9>());
+        }
+        ":
+        Err(naga::valid::ValidationError::EntryPoint {
+            source: naga::valid::EntryPointError::Result(
+                naga::valid::VaryingError::TooManyClipDistances(9)
+            ),
+            ..
+        }),
+        naga::valid::Capabilities::CLIP_DISTANCE
+    }
+}
+
+#[test]
+fn second_blend_source_removed() {
+    check(
+        "struct FragmentOutput { @location(0) @second_blend_source b: vec4<f32> }",
+        r###"error: the `second_blend_source` attribute has been removed
+  ┌─ wgsl:1:39
+  │
+1 │ struct FragmentOutput { @location(0) @second_blend_source b: vec4<f32> }
+  │                                       ^^^^^^^^^^^^^^^^^^^ use `@blend_src(1)` instead
+  │
+  = note: dual-source blending now requires `enable dual_source_blending;`, with the outputs marked `@location(0) @blend_src(0)` and `@location(0) @blend_src(1)`
+
+"###,
+    );
+}
+
+#[test]
+fn clip_distances_not_enabled() {
+    check(
+        r#"
+        struct VertexOutput {
+            @builtin(position) position: vec4<f32>,
+            @builtin(clip_distances) clip_distances: array<f32, 1>,
+        }
+        "#,
+        r###"error: `clip_distances` enable-extension is not enabled
+  ┌─ wgsl:4:22
+  │
+4 │             @builtin(clip_distances) clip_distances: array<f32, 1>,
+  │                      ^^^^^^^^^^^^^^ the `clip_distances` enable-extension is needed for this functionality, but it is not currently enabled
+  │
+  = note: You can enable this extension by adding `enable clip_distances;` at the top of the shader.
+
+"###,
+    );
+}
+
+#[test]
+fn dual_source_blending_not_enabled() {
+    check(
+        r#"
+        struct FragmentOutput {
+            @location(0) @blend_src(0) color: vec4<f32>,
+            @location(0) @blend_src(1) mask: vec4<f32>,
+        }
+        "#,
+        r###"error: `dual_source_blending` enable-extension is not enabled
+  ┌─ wgsl:3:27
+  │
+3 │             @location(0) @blend_src(0) color: vec4<f32>,
+  │                           ^^^^^^^^^ the `dual_source_blending` enable-extension is needed for this functionality, but it is not currently enabled
+  │
+  = note: You can enable this extension by adding `enable dual_source_blending;` at the top of the shader.
+
+"###,
+    );
+}
+
+#[test]
+fn invalid_blend_src() {
+    check_validation! {
+        "
+        enable dual_source_blending;
+        struct FragmentOutput {
+            @location(0) @blend_src(0) color: vec4<f32>,
+            @location(0) @blend_src(2) mask: vec4<f32>,
+        }
+        @fragment
+        fn main() -> FragmentOutput {
+            return FragmentOutput(vec4<f32>(), vec4<f32>());
+        }
+        ":
+        Err(naga::valid::ValidationError::EntryPoint {
+            stage: naga::ShaderStage::Fragment,
+            source: naga::valid::EntryPointError::Result(
+                naga::valid::VaryingError::InvalidBlendSrcIndex { blend_src: 2 },
+            ),
+            ..
+        }),
+        naga::valid::Capabilities::DUAL_SOURCE_BLENDING
+    }
+
+    check_validation! {
+        "
+        enable dual_source_blending;
+        struct FragmentOutput {
+            @location(0) @blend_src(0) color: vec4<f32>,
+        }
+        @fragment
+        fn main() -> FragmentOutput {
+            return FragmentOutput(vec4<f32>());
+        }
+        ":
+        Err(naga::valid::ValidationError::EntryPoint {
+            stage: naga::ShaderStage::Fragment,
+            source: naga::valid::EntryPointError::IncompleteBlendSrcUsage,
+            ..
+        }),
+        naga::valid::Capabilities::DUAL_SOURCE_BLENDING
+    }
+
+    check_validation! {
+        "
+        enable dual_source_blending;
+        struct FragmentOutput {
+            @location(0) @blend_src(0) color: vec4<f32>,
+            @location(0) @blend_src(1) mask: vec4<f32>,
+            @location(1) other: vec4<f32>,
+        }
+        @fragment
+        fn main() -> FragmentOutput {
+            return FragmentOutput(vec4<f32>(), vec4<f32>(), vec4<f32>());
+        }
+        ":
+        Err(naga::valid::ValidationError::EntryPoint {
+            stage: naga::ShaderStage::Fragment,
+            source: naga::valid::EntryPointError::InvalidLocationsWhileDualSourceBlending { .. },
+            ..
+        }),
+        naga::valid::Capabilities::DUAL_SOURCE_BLENDING
+    }
+
+    check_validation! {
+        "
+        enable dual_source_blending;
+        struct FragmentOutput {
+            @location(1) @blend_src(0) color: vec4<f32>,
+            @location(1) @blend_src(1) mask: vec4<f32>,
+        }
+        @fragment
+        fn main() -> FragmentOutput {
+            return FragmentOutput(vec4<f32>(), vec4<f32>());
+        }
+        ":
+        Err(naga::valid::ValidationError::EntryPoint {
+            stage: naga::ShaderStage::Fragment,
+            source: naga::valid::EntryPointError::Result(
+                naga::valid::VaryingError::InvalidLocationAttributeCombination {
+                    location: 1,
+                    attribute: "blend_src",
+                },
+            ),
+            ..
+        }),
+        naga::valid::Capabilities::DUAL_SOURCE_BLENDING
+    }
+}
+
 #[test]
 fn invalid_arrays() {
     check_validation! {
@@ -1153,43 +1424,25 @@ fn invalid_functions() {
 
     // Pointers of these address spaces cannot be passed as arguments.
     check_validation! {
-        "fn unacceptable_ptr_space(arg: ptr<storage, array<f32>>) { }":
+        "fn unacceptable_ptr_space(arg: ptr<push_constant, f32>) { }":
         Err(naga::valid::ValidationError::Function {
             name: function_name,
             source: naga::valid::FunctionError::InvalidArgumentPointerSpace {
                 index: 0,
                 name: argument_name,
-                space: naga::AddressSpace::Storage { .. },
-            },
-            ..
-        })
-        if function_name == "unacceptable_ptr_space" && argument_name == "arg"
-    }
-    check_validation! {
-        "fn unacceptable_ptr_space(arg: ptr<uniform, f32>) { }":
-        Err(naga::valid::ValidationError::Function {
-            name: function_name,
-            source: naga::valid::FunctionError::InvalidArgumentPointerSpace {
-                index: 0,
-                name: argument_name,
-                space: naga::AddressSpace::Uniform,
+                space: naga::AddressSpace::PushConstant,
             },
             ..
         })
         if function_name == "unacceptable_ptr_space" && argument_name == "arg"
     }
+
+    // With `unrestricted_pointer_parameters`, these can.
     check_validation! {
-        "fn unacceptable_ptr_space(arg: ptr<workgroup, f32>) { }":
-        Err(naga::valid::ValidationError::Function {
-            name: function_name,
-            source: naga::valid::FunctionError::InvalidArgumentPointerSpace {
-                index: 0,
-                name: argument_name,
-                space: naga::AddressSpace::WorkGroup,
-            },
-            ..
-        })
-        if function_name == "unacceptable_ptr_space" && argument_name == "arg"
+        "fn acceptable_ptr_space(arg: ptr<storage, array<f32>>) { }",
+        "fn acceptable_ptr_space(arg: ptr<uniform, f32>) { }",
+        "fn acceptable_ptr_space(arg: ptr<workgroup, f32>) { }":
+        Ok(_)
     }
 
     check_validation! {
@@ -2429,3 +2682,170 @@ fn const_assert_failed() {
 "###,
     );
 }
+
+#[test]
+fn diagnostic_filter_invalid_severity() {
+    check(
+        "diagnostic(loud, derivative_uniformity);",
+        r###"error: invalid `diagnostic(…)` severity
+  ┌─ wgsl:1:12
+  │
+1 │ diagnostic(loud, derivative_uniformity);
+  │            ^^^^ not a valid severity level
+  │
+  = note: See available severities at <https://www.w3.org/TR/WGSL/#diagnostic-severity>.
+
+"###,
+    );
+}
+
+#[test]
+fn diagnostic_filter_conflicting_rules() {
+    check(
+        "
+        diagnostic(off, derivative_uniformity);
+        diagnostic(warning, derivative_uniformity);
+        ",
+        r###"error: found conflicting `diagnostic(…)` rule(s)
+  ┌─ wgsl:2:9
+  │
+2 │         diagnostic(off, derivative_uniformity);
+  │         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ first rule
+3 │         diagnostic(warning, derivative_uniformity);
+  │         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ second rule
+  │
+  = note: Multiple `diagnostic(…)` rules with the same rule name conflict unless they are directives and the severity is the same.
+
+"###,
+    );
+
+    check(
+        "
+        @diagnostic(off, derivative_uniformity)
+        @diagnostic(off, derivative_uniformity)
+        fn main() {}
+        ",
+        r###"error: found conflicting `diagnostic(…)` rule(s)
+  ┌─ wgsl:2:10
+  │
+2 │         @diagnostic(off, derivative_uniformity)
+  │          ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ first rule
+3 │         @diagnostic(off, derivative_uniformity)
+  │          ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ second rule
+  │
+  = note: Multiple `diagnostic(…)` rules with the same rule name conflict unless they are directives and the severity is the same.
+
+"###,
+    );
+}
+
+#[test]
+fn diagnostic_filter_attribute_not_supported() {
+    check(
+        "
+        @diagnostic(off, derivative_uniformity)
+        var<private> x: f32;
+        ",
+        r###"error: `@diagnostic(…)` attribute(s) on `var` declarations are not supported
+  ┌─ wgsl:2:10
+  │
+2 │         @diagnostic(off, derivative_uniformity)
+  │          ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+  │
+  = note: `@diagnostic(…)` attributes are only permitted on `fn`s, compound statements, `if`, `switch`, `loop`, `for` and `while` statements, and `switch` and `loop` bodies.
+
+"###,
+    );
+}
+
+#[test]
+fn derivative_uniformity() {
+    check_validation! {
+        "
+        diagnostic(error, derivative_uniformity);
+
+        @group(0) @binding(0) var t: texture_2d<f32>;
+        @group(0) @binding(1) var s: sampler;
+
+        @fragment
+        fn main(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
+            if uv.x > 0.5 {
+                return textureSample(t, s, uv);
+            }
+            return vec4(0.0);
+        }
+        ",
+        "
+        diagnostic(error, derivative_uniformity);
+
+        @fragment
+        fn main(@location(0) x: f32) -> @location(0) vec4<f32> {
+            if x > 0.5 {
+                return vec4(dpdx(x));
+            }
+            return vec4(0.0);
+        }
+        ":
+        Err(naga::valid::ValidationError::EntryPoint {
+            source: naga::valid::EntryPointError::Function(
+                naga::valid::FunctionError::NonUniformControlFlow(..)
+            ),
+            ..
+        })
+    }
+
+    check_validation! {
+        "
+        @fragment
+        fn main(@location(0) x: f32) -> @location(0) vec4<f32> {
+            if x > 0.5 {
+                return vec4(dpdx(x));
+            }
+            return vec4(0.0);
+        }
+        ",
+        "
+        diagnostic(off, derivative_uniformity);
+
+        @fragment
+        fn main(@location(0) x: f32) -> @location(0) vec4<f32> {
+            if x > 0.5 {
+                return vec4(dpdx(x));
+            }
+            return vec4(0.0);
+        }
+        ",
+        "
+        diagnostic(error, derivative_uniformity);
+
+        @diagnostic(warning, derivative_uniformity)
+        @fragment
+        fn main(@location(0) x: f32) -> @location(0) vec4<f32> {
+            if x > 0.5 {
+                return vec4(dpdx(x));
+            }
+            return vec4(0.0);
+        }
+        ",
+        "
+        @fragment
+        fn main(@location(0) x: f32) -> @location(0) vec4<f32> {
+            @diagnostic(info, derivative_uniformity)
+            if x > 0.5 {
+                return vec4(dpdx(x));
+            }
+            return vec4(0.0);
+        }
+        ",
+        "
+        @fragment
+        fn main(@location(0) x: f32) -> @location(0) vec4<f32> {
+            if x > 0.5 @diagnostic(off, derivative_uniformity) {
+                return vec4(dpdx(x));
+            }
+            return vec4(0.0);
+        }
+        ":
+        Ok(_)
+    }
+}
@@ -43,6 +43,7 @@ mod scissor_tests;
 mod shader;
 mod shader_primitive_index;
 mod shader_view_format;
+mod storage_texture_access;
 mod subgroup_operations;
 mod texture_bounds;
 mod texture_view_creation;
@@ -0,0 +1,101 @@
+//! Tests for the storage texture access modes allowed by each format.
+
+use wgpu_test::{fail, gpu_test, GpuTestConfiguration, TestParameters};
+
+fn create_storage_texture_layout(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    access: wgpu::StorageTextureAccess,
+) {
+    let _bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: None,
+        entries: &[wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::StorageTexture {
+                access,
+                format,
+                view_dimension: wgpu::TextureViewDimension::D2,
+            },
+            count: None,
+        }],
+    });
+}
+
+#[gpu_test]
+static STORAGE_TEXTURE_ACCESS_TIERS: GpuTestConfiguration = GpuTestConfiguration::new()
+    .parameters(
+        TestParameters::default()
+            .downlevel_flags(
+                wgpu::DownlevelFlags::COMPUTE_SHADERS
+                    | wgpu::DownlevelFlags::WEBGPU_TEXTURE_FORMAT_SUPPORT,
+            )
+            .limits(wgpu::Limits::default()),
+    )
+    .run_sync(|ctx| {
+        let device = &ctx.device;
+        // All storage formats can be read from and written to separately.
+        for format in [
+            wgpu::TextureFormat::R32Float,
+            wgpu::TextureFormat::Rgba8Unorm,
+        ] {
+            create_storage_texture_layout(device, format, wgpu::StorageTextureAccess::WriteOnly);
+            create_storage_texture_layout(device, format, wgpu::StorageTextureAccess::ReadOnly);
+        }
+        // The 32-bit single channel formats can be both read from and written to.
+        create_storage_texture_layout(
+            device,
+            wgpu::TextureFormat::R32Float,
+            wgpu::StorageTextureAccess::ReadWrite,
+        );
+        // This is supposed to fail, since `rgba8unorm` is not in the read-write tier.
+        fail(
+            &ctx.device,
+            || {
+                create_storage_texture_layout(
+                    device,
+                    wgpu::TextureFormat::Rgba8Unorm,
+                    wgpu::StorageTextureAccess::ReadWrite,
+                );
+            },
+            Some("storage texture format rgba8unorm does not support readwrite access"),
+        );
+    });
+
+#[gpu_test]
+static STORAGE_TEXTURE_READ_ONLY_ADAPTER_SPECIFIC: GpuTestConfiguration =
+    GpuTestConfiguration::new()
+        .parameters(
+            TestParameters::default()
+                .features(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES)
+                .downlevel_flags(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+                .limits(wgpu::Limits::default()),
+        )
+        .run_sync(|ctx| {
+            // With adapter specific format features, formats outside the
+            // read-write tier can still be read from if they can be written to.
+            for format in [
+                wgpu::TextureFormat::Rgba8Unorm,
+                wgpu::TextureFormat::Rgba16Float,
+                wgpu::TextureFormat::Rg32Uint,
+            ] {
+                let features = ctx.adapter.get_texture_format_features(format);
+                if !features
+                    .allowed_usages
+                    .contains(wgpu::TextureUsages::STORAGE_BINDING)
+                {
+                    continue;
+                }
+                assert!(
+                    features
+                        .flags
+                        .contains(wgpu::TextureFormatFeatureFlags::STORAGE_READ_ONLY),
+                    "{format:?} can be written to but not read from"
+                );
+                create_storage_texture_layout(
+                    &ctx.device,
+                    format,
+                    wgpu::StorageTextureAccess::ReadOnly,
+                );
+            }
+        });
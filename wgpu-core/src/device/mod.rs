@@ -510,6 +510,10 @@ pub fn create_validator(
         Caps::EARLY_DEPTH_TEST,
         features.contains(wgt::Features::SHADER_EARLY_DEPTH_TEST),
     );
+    caps.set(
+        Caps::SHADER_FLOAT16,
+        features.contains(wgt::Features::SHADER_F16),
+    );
     caps.set(
         Caps::SHADER_INT64,
         features.contains(wgt::Features::SHADER_INT64),
@@ -528,6 +532,10 @@ pub fn create_validator(
         Caps::MULTISAMPLED_SHADING,
         downlevel.contains(wgt::DownlevelFlags::MULTISAMPLED_SHADING),
     );
+    caps.set(
+        Caps::CLIP_DISTANCE,
+        features.contains(wgt::Features::CLIP_DISTANCES),
+    );
     caps.set(
         Caps::DUAL_SOURCE_BLENDING,
         features.contains(wgt::Features::DUAL_SOURCE_BLENDING),
@@ -548,6 +556,14 @@ pub fn create_validator(
         Caps::SUBGROUP_VERTEX_STAGE,
         features.contains(wgt::Features::SUBGROUP_VERTEX),
     );
+    caps.set(
+        Caps::SUBGROUP_QUAD,
+        features.contains(wgt::Features::SUBGROUP_QUAD),
+    );
+    caps.set(
+        Caps::SUBGROUP_CLUSTERED,
+        features.contains(wgt::Features::SUBGROUP_CLUSTERED),
+    );
 
     naga::valid::Validator::new(flags, caps)
 }
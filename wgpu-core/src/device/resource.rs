@@ -1573,33 +1573,56 @@ impl Device {
         }
 
         profiling::scope!("naga::validate");
-        let debug_source =
-            if self.instance_flags.contains(wgt::InstanceFlags::DEBUG) && !source.is_empty() {
-                Some(hal::DebugSource {
-                    file_name: Cow::Owned(
-                        desc.label
-                            .as_ref()
-                            .map_or("shader".to_string(), |l| l.to_string()),
-                    ),
-                    source_code: Cow::Owned(source.clone()),
-                })
-            } else {
-                None
-            };
+        let debug_source = if (self.instance_flags.contains(wgt::InstanceFlags::DEBUG)
+            || desc.non_semantic_debug_info)
+            && !source.is_empty()
+        {
+            Some(hal::DebugSource {
+                file_name: Cow::Owned(
+                    desc.label
+                        .as_ref()
+                        .map_or("shader".to_string(), |l| l.to_string()),
+                ),
+                source_code: Cow::Owned(source.clone()),
+                non_semantic_debug_info: desc.non_semantic_debug_info,
+            })
+        } else {
+            None
+        };
 
-        let info = create_validator(
-            self.features,
-            self.downlevel.flags,
-            naga::valid::ValidationFlags::all(),
-        )
-        .validate(&module)
-        .map_err(|inner| {
-            pipeline::CreateShaderModuleError::Validation(naga::error::ShaderError {
-                source,
-                label: desc.label.as_ref().map(|l| l.to_string()),
-                inner: Box::new(inner),
+        let validate = |module: &naga::Module| {
+            create_validator(
+                self.features,
+                self.downlevel.flags,
+                naga::valid::ValidationFlags::all(),
+            )
+            .validate(module)
+            .map_err(|inner| {
+                pipeline::CreateShaderModuleError::Validation(naga::error::ShaderError {
+                    source: source.clone(),
+                    label: desc.label.as_ref().map(|l| l.to_string()),
+                    inner: Box::new(inner),
+                })
             })
-        })?;
+        };
+        let info = validate(&module)?;
+
+        #[cfg(feature = "optimize")]
+        let (module, info) = if desc.optimize {
+            profiling::scope!("naga::opt::optimize");
+            let mut optimized = module.into_owned();
+            naga::opt::optimize(&mut optimized, naga::opt::Passes::all());
+            // The passes should preserve validity, but the backends rely on
+            // it, so don't take that on faith.
+            let info = validate(&optimized)?;
+            (Cow::Owned(optimized), info)
+        } else {
+            (module, info)
+        };
+        #[cfg(not(feature = "optimize"))]
+        if desc.optimize {
+            return Err(pipeline::CreateShaderModuleError::OptimizationUnavailable);
+        }
 
         let interface = validation::Interface::new(&module, &info, self.limits.clone());
         let hal_shader = hal::ShaderInput::Naga(hal::NagaShader {
@@ -1818,7 +1841,7 @@ impl Device {
                 Bt::StorageTexture {
                     access,
                     view_dimension,
-                    format: _,
+                    format,
                 } => {
                     match view_dimension {
                         TextureViewDimension::Cube | TextureViewDimension::CubeArray => {
@@ -1829,19 +1852,29 @@ impl Device {
                         }
                         _ => (),
                     }
-                    match access {
-                        wgt::StorageTextureAccess::ReadOnly
-                        | wgt::StorageTextureAccess::ReadWrite
-                            if !self.features.contains(
-                                wgt::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES,
-                            ) =>
-                        {
-                            return Err(binding_model::CreateBindGroupLayoutError::Entry {
+                    let format_features =
+                        self.describe_format_features(format).map_err(|error| {
+                            binding_model::CreateBindGroupLayoutError::Entry {
                                 binding: entry.binding,
-                                error: BindGroupLayoutEntryError::StorageTextureReadWrite,
-                            });
+                                error: error.into(),
+                            }
+                        })?;
+                    let required_flag = match access {
+                        wgt::StorageTextureAccess::ReadOnly => {
+                            wgt::TextureFormatFeatureFlags::STORAGE_READ_ONLY
                         }
-                        _ => (),
+                        wgt::StorageTextureAccess::WriteOnly => {
+                            wgt::TextureFormatFeatureFlags::STORAGE_WRITE_ONLY
+                        }
+                        wgt::StorageTextureAccess::ReadWrite => {
+                            wgt::TextureFormatFeatureFlags::STORAGE_READ_WRITE
+                        }
+                    };
+                    if !format_features.flags.contains(required_flag) {
+                        return Err(binding_model::CreateBindGroupLayoutError::Entry {
+                            binding: entry.binding,
+                            error: BindGroupLayoutEntryError::StorageTextureAccess(access, format),
+                        });
                     }
                     (
                         Some(
@@ -1849,17 +1882,9 @@ impl Device {
                                 | wgt::Features::STORAGE_RESOURCE_BINDING_ARRAY,
                         ),
                         match access {
-                            wgt::StorageTextureAccess::WriteOnly => WritableStorage::Yes,
-                            wgt::StorageTextureAccess::ReadOnly => {
-                                required_features |=
-                                    wgt::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES;
-                                WritableStorage::No
-                            }
-                            wgt::StorageTextureAccess::ReadWrite => {
-                                required_features |=
-                                    wgt::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES;
-                                WritableStorage::Yes
-                            }
+                            wgt::StorageTextureAccess::WriteOnly
+                            | wgt::StorageTextureAccess::ReadWrite => WritableStorage::Yes,
+                            wgt::StorageTextureAccess::ReadOnly => WritableStorage::No,
                         },
                     )
                 }
@@ -2528,7 +2553,7 @@ impl Device {
                         if !view
                             .format_features
                             .flags
-                            .contains(wgt::TextureFormatFeatureFlags::STORAGE_READ_WRITE)
+                            .contains(wgt::TextureFormatFeatureFlags::STORAGE_READ_ONLY)
                         {
                             return Err(Error::StorageReadNotSupported(view.desc.format));
                         }
@@ -2540,7 +2565,7 @@ impl Device {
                             .flags
                             .contains(wgt::TextureFormatFeatureFlags::STORAGE_READ_WRITE)
                         {
-                            return Err(Error::StorageReadNotSupported(view.desc.format));
+                            return Err(Error::StorageReadWriteNotSupported(view.desc.format));
                         }
 
                         hal::TextureUses::STORAGE_READ_WRITE
@@ -520,6 +520,17 @@ impl Adapter {
         );
 
         let mut flags = wgt::TextureFormatFeatureFlags::empty();
+        // Following the WebGPU format tiers, every format that can be written to
+        // as a storage texture can also be read from, except `bgra8unorm`.
+        flags.set(
+            wgt::TextureFormatFeatureFlags::STORAGE_READ_ONLY,
+            caps.contains(Tfc::STORAGE_READ_WRITE)
+                || (caps.contains(Tfc::STORAGE) && format != wgt::TextureFormat::Bgra8Unorm),
+        );
+        flags.set(
+            wgt::TextureFormatFeatureFlags::STORAGE_WRITE_ONLY,
+            caps.contains(Tfc::STORAGE),
+        );
         flags.set(
             wgt::TextureFormatFeatureFlags::STORAGE_READ_WRITE,
             caps.contains(Tfc::STORAGE_READ_WRITE),
@@ -36,12 +36,26 @@ pub enum ShaderModuleSource<'a> {
     Dummy(PhantomData<&'a ()>),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShaderModuleDescriptor<'a> {
     pub label: Label<'a>,
     #[cfg_attr(feature = "serde", serde(default))]
     pub shader_bound_checks: wgt::ShaderBoundChecks,
+    /// Ask backends to embed detailed debug information for shader debuggers,
+    /// such as `NonSemantic.Shader.DebugInfo.100` in SPIR-V.
+    ///
+    /// This also attaches the shader source to the module, even if
+    /// [`wgt::InstanceFlags::DEBUG`] isn't set.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub non_semantic_debug_info: bool,
+    /// Run naga's optimization passes over the module after validating it.
+    ///
+    /// See `naga::opt::optimize`. The optimized module is validated again
+    /// before it is passed to the backend. This requires the `optimize`
+    /// feature; without it, creating the module fails.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub optimize: bool,
 }
 
 #[derive(Debug)]
@@ -109,6 +123,8 @@ pub enum CreateShaderModuleError {
     Validation(#[from] ShaderError<naga::WithSpan<naga::valid::ValidationError>>),
     #[error(transparent)]
     MissingFeatures(#[from] MissingFeatures),
+    #[error("Shader optimization was requested, but `wgpu-core` was built without the `optimize` feature")]
+    OptimizationUnavailable,
     #[error(
         "Shader global {bind:?} uses a group index {group} that exceeds the max_bind_groups limit of {limit}."
     )]
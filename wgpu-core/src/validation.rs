@@ -817,7 +817,7 @@ impl Interface {
                 location,
                 interpolation,
                 sampling,
-                .. // second_blend_source
+                .. // blend_src
             }) => Varying::Local {
                 location,
                 iv: InterfaceVar {
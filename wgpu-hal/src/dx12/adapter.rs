@@ -315,7 +315,8 @@ impl super::Adapter {
             | wgt::Features::SHADER_PRIMITIVE_INDEX
             | wgt::Features::RG11B10UFLOAT_RENDERABLE
             | wgt::Features::DUAL_SOURCE_BLENDING
-            | wgt::Features::TEXTURE_FORMAT_NV12;
+            | wgt::Features::TEXTURE_FORMAT_NV12
+            | wgt::Features::CLIP_DISTANCES;
 
         //TODO: in order to expose this, we need to run a compute shader
         // that extract the necessary statistics out of the D3D12 result.
@@ -378,8 +379,10 @@ impl super::Adapter {
                 && features1.Int64ShaderOps.as_bool(),
         );
 
+        // The quad intrinsics are part of the same shader model 6.0 wave
+        // operations. HLSL has no clustered reductions.
         features.set(
-            wgt::Features::SUBGROUP,
+            wgt::Features::SUBGROUP | wgt::Features::SUBGROUP_QUAD,
             shader_model >= naga::back::hlsl::ShaderModel::V6_0
                 && hr.is_ok()
                 && features1.WaveOps.as_bool(),
@@ -456,6 +456,10 @@ impl super::Adapter {
             wgt::Features::MULTIVIEW,
             extensions.contains("OVR_multiview2") || extensions.contains("GL_OVR_multiview2"),
         );
+        features.set(
+            wgt::Features::CLIP_DISTANCES,
+            full_ver.is_some() || extensions.contains("GL_EXT_clip_cull_distance"),
+        );
         features.set(
             wgt::Features::DUAL_SOURCE_BLENDING,
             extensions.contains("GL_EXT_blend_func_extended")
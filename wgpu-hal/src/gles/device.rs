@@ -18,12 +18,25 @@ type ShaderStage<'a> = (
 );
 type NameBindingMap = rustc_hash::FxHashMap<String, (super::BindingRegister, u8)>;
 
+/// A [`naga::back::glsl::PushConstantItem`], with its type resolved.
+///
+/// The item's type handle refers to the module handed to the GLSL
+/// backend, which `process_overrides` may have compacted down to a single
+/// entry point, so we can't look it up in the original shader module.
+struct PushConstantItem {
+    access_path: String,
+    offset: u32,
+    ty: naga::TypeInner,
+    size_bytes: u32,
+}
+
 struct CompilationContext<'a> {
     layout: &'a super::PipelineLayout,
     sampler_map: &'a mut super::SamplerBindMap,
     name_binding_map: &'a mut NameBindingMap,
-    push_constant_items: &'a mut Vec<naga::back::glsl::PushConstantItem>,
+    push_constant_items: &'a mut Vec<PushConstantItem>,
     multiview: Option<std::num::NonZeroU32>,
+    clip_distance_count: &'a mut u32,
 }
 
 impl CompilationContext<'_> {
@@ -102,7 +115,46 @@ impl CompilationContext<'_> {
             }
         }
 
-        *self.push_constant_items = reflection_info.push_constant_items;
+        *self.push_constant_items = reflection_info
+            .push_constant_items
+            .into_iter()
+            .map(|item| {
+                let ty = module.types[item.ty].inner.clone();
+                PushConstantItem {
+                    access_path: item.access_path,
+                    offset: item.offset,
+                    size_bytes: ty.size(module.to_ctx()),
+                    ty,
+                }
+            })
+            .collect();
+    }
+}
+
+/// Returns the number of `gl_ClipDistance` elements written by the entry point `ep`.
+fn clip_distance_count(module: &naga::Module, ep: &naga::EntryPoint) -> u32 {
+    let Some(ref result) = ep.function.result else {
+        return 0;
+    };
+    let clip_distance_ty = match result.binding {
+        Some(naga::Binding::BuiltIn(naga::BuiltIn::ClipDistance)) => Some(result.ty),
+        Some(_) => None,
+        None => match module.types[result.ty].inner {
+            naga::TypeInner::Struct { ref members, .. } => members
+                .iter()
+                .find(|member| {
+                    member.binding == Some(naga::Binding::BuiltIn(naga::BuiltIn::ClipDistance))
+                })
+                .map(|member| member.ty),
+            _ => None,
+        },
+    };
+    match clip_distance_ty.map(|ty| &module.types[ty].inner) {
+        Some(&naga::TypeInner::Array {
+            size: naga::ArraySize::Constant(size),
+            ..
+        }) => size.get(),
+        _ => 0,
     }
 }
 
@@ -218,12 +270,17 @@ impl super::Device {
         let (module, info) = naga::back::pipeline_constants::process_overrides(
             &stage.module.naga.module,
             &stage.module.naga.info,
+            Some((naga_stage, stage.entry_point)),
             stage.constants,
         )
         .map_err(|e| {
             let msg = format!("{e}");
             crate::PipelineError::PipelineConstants(map_naga_stage(naga_stage), msg)
         })?;
+        let (module, info) = naga::back::pointer_arguments::specialize_pointer_arguments(
+            &module, &info,
+        )
+        .map_err(|e| crate::PipelineError::Linkage(map_naga_stage(naga_stage), format!("{e}")))?;
 
         let entry_point_index = module
             .entry_points
@@ -281,6 +338,11 @@ impl super::Device {
 
         log::debug!("Naga generated shader:\n{}", output);
 
+        if naga_stage == naga::ShaderStage::Vertex {
+            *context.clip_distance_count =
+                clip_distance_count(&module, &module.entry_points[entry_point_index]);
+        }
+
         context.consume_reflection(
             gl,
             &module,
@@ -369,6 +431,7 @@ impl super::Device {
         let mut push_constant_items = ArrayVec::<_, { crate::MAX_CONCURRENT_SHADER_STAGES }>::new();
         let mut sampler_map = [None; super::MAX_TEXTURE_SLOTS];
         let mut has_stages = wgt::ShaderStages::empty();
+        let mut clip_distance_count = 0;
         let mut shaders_to_delete = ArrayVec::<_, { crate::MAX_CONCURRENT_SHADER_STAGES }>::new();
 
         for &(naga_stage, stage) in &shaders {
@@ -383,6 +446,7 @@ impl super::Device {
                 name_binding_map: &mut name_binding_map,
                 push_constant_items: pc_item,
                 multiview,
+                clip_distance_count: &mut clip_distance_count,
             };
 
             let shader = Self::create_shader(gl, naga_stage, stage, context, program)?;
@@ -456,17 +520,14 @@ impl super::Device {
 
         let mut uniforms = ArrayVec::new();
 
-        for (stage_idx, stage_items) in push_constant_items.into_iter().enumerate() {
+        for stage_items in push_constant_items {
             for item in stage_items {
-                let naga_module = &shaders[stage_idx].1.module.naga.module;
-                let type_inner = &naga_module.types[item.ty].inner;
-
                 let location = unsafe { gl.get_uniform_location(program, &item.access_path) };
 
                 log::trace!(
                     "push constant item: name={}, ty={:?}, offset={}, location={:?}",
                     item.access_path,
-                    type_inner,
+                    item.ty,
                     item.offset,
                     location,
                 );
@@ -475,8 +536,8 @@ impl super::Device {
                     uniforms.push(super::PushConstantDesc {
                         location,
                         offset: item.offset,
-                        size_bytes: type_inner.size(naga_module.to_ctx()),
-                        ty: type_inner.clone(),
+                        size_bytes: item.size_bytes,
+                        ty: item.ty,
                     });
                 }
             }
@@ -494,6 +555,7 @@ impl super::Device {
             sampler_map,
             first_instance_location,
             push_constant_descs: uniforms,
+            clip_distance_count,
         }))
     }
 }
@@ -86,6 +86,11 @@ impl super::Queue {
         if self.features.contains(wgt::Features::DEPTH_CLIP_CONTROL) {
             unsafe { gl.disable(glow::DEPTH_CLAMP) };
         }
+        if self.features.contains(wgt::Features::CLIP_DISTANCES) {
+            for i in 0..super::MAX_CLIP_DISTANCES {
+                unsafe { gl.disable(glow::CLIP_DISTANCE0 + i) };
+            }
+        }
 
         unsafe { gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, None) };
         let mut current_index_buffer = self.current_index_buffer.lock();
@@ -1387,6 +1392,25 @@ impl super::Queue {
                     unsafe { gl.disable(glow::SAMPLE_ALPHA_TO_COVERAGE) };
                 }
             }
+            C::SetClipDistances {
+                old_count,
+                new_count,
+            } => {
+                // The shader validator limits clip distances to the number
+                // of `CLIP_DISTANCE` enums, but don't rely on it here.
+                let old_count = old_count.min(super::MAX_CLIP_DISTANCES);
+                let new_count = new_count.min(super::MAX_CLIP_DISTANCES);
+
+                // Disable clip planes that are no longer active
+                for i in new_count..old_count {
+                    unsafe { gl.disable(glow::CLIP_DISTANCE0 + i) };
+                }
+
+                // Enable clip planes that are now active
+                for i in old_count..new_count {
+                    unsafe { gl.enable(glow::CLIP_DISTANCE0 + i) };
+                }
+            }
             C::SetProgram(program) => {
                 unsafe { gl.use_program(Some(program)) };
             }
@@ -2117,6 +2117,9 @@ pub struct ShaderModuleDescriptor<'a> {
 pub struct DebugSource {
     pub file_name: Cow<'static, str>,
     pub source_code: Cow<'static, str>,
+    /// Embed detailed debug information, such as SPIR-V's
+    /// `NonSemantic.Shader.DebugInfo.100`, in addition to the source.
+    pub non_semantic_debug_info: bool,
 }
 
 /// Describes a programmable pipeline stage.
@@ -852,7 +852,8 @@ impl super::PrivateCapabilities {
             | F::TEXTURE_FORMAT_16BIT_NORM
             | F::SHADER_F16
             | F::DEPTH32FLOAT_STENCIL8
-            | F::BGRA8UNORM_STORAGE;
+            | F::BGRA8UNORM_STORAGE
+            | F::CLIP_DISTANCES;
 
         features.set(F::FLOAT32_FILTERABLE, self.supports_float_filtering);
         features.set(
@@ -904,6 +905,9 @@ impl super::PrivateCapabilities {
             F::SHADER_INT64,
             self.int64 && self.msl_version >= MTLLanguageVersion::V2_3,
         );
+        // `SHADER_INT64_ATOMIC_ALL_OPS` is never exposed: `atomic_ulong` only
+        // supports min and max, and there's no 64-bit compare-exchange to
+        // build the other operations from.
         features.set(
             F::SHADER_INT64_ATOMIC_MIN_MAX,
             self.int64_atomics && self.msl_version >= MTLLanguageVersion::V2_4,
@@ -918,7 +922,7 @@ impl super::PrivateCapabilities {
         features.set(F::RG11B10UFLOAT_RENDERABLE, self.format_rg11b10_all);
 
         if self.supports_simd_scoped_operations {
-            features.insert(F::SUBGROUP | F::SUBGROUP_BARRIER);
+            features.insert(F::SUBGROUP | F::SUBGROUP_BARRIER | F::SUBGROUP_QUAD);
         }
 
         features
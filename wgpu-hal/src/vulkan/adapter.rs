@@ -111,6 +111,11 @@ pub struct PhysicalDeviceFeatures {
 
     /// Features provided by `VK_EXT_subgroup_size_control`, promoted to Vulkan 1.3.
     subgroup_size_control: Option<vk::PhysicalDeviceSubgroupSizeControlFeatures<'static>>,
+
+    /// Features provided by `VK_EXT_shader_demote_to_helper_invocation`, promoted
+    /// to Vulkan 1.3.
+    shader_demote_to_helper_invocation:
+        Option<vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures<'static>>,
 }
 
 impl PhysicalDeviceFeatures {
@@ -145,6 +150,9 @@ impl PhysicalDeviceFeatures {
         if let Some(ref mut feature) = self.zero_initialize_workgroup_memory {
             info = info.push_next(feature);
         }
+        if let Some(ref mut feature) = self.shader_demote_to_helper_invocation {
+            info = info.push_next(feature);
+        }
         if let Some(ref mut feature) = self.acceleration_structure {
             info = info.push_next(feature);
         }
@@ -282,7 +290,7 @@ impl PhysicalDeviceFeatures {
                         | wgt::Features::STORAGE_RESOURCE_BINDING_ARRAY,
                 ))
                 //.shader_storage_image_array_dynamic_indexing(
-                //.shader_clip_distance(requested_features.contains(wgt::Features::SHADER_CLIP_DISTANCE))
+                .shader_clip_distance(requested_features.contains(wgt::Features::CLIP_DISTANCES))
                 //.shader_cull_distance(requested_features.contains(wgt::Features::SHADER_CULL_DISTANCE))
                 .shader_float64(requested_features.contains(wgt::Features::SHADER_F64))
                 .shader_int64(requested_features.contains(wgt::Features::SHADER_INT64))
@@ -423,6 +431,18 @@ impl PhysicalDeviceFeatures {
             } else {
                 None
             },
+            shader_demote_to_helper_invocation: if device_api_version >= vk::API_VERSION_1_3
+                || enabled_extensions.contains(&ext::shader_demote_to_helper_invocation::NAME)
+            {
+                Some(
+                    vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures::default()
+                        .shader_demote_to_helper_invocation(
+                            private_caps.shader_demote_to_helper_invocation,
+                        ),
+                )
+            } else {
+                None
+            },
             shader_atomic_int64: if device_api_version >= vk::API_VERSION_1_2
                 || enabled_extensions.contains(&khr::shader_atomic_int64::NAME)
             {
@@ -642,6 +662,7 @@ impl PhysicalDeviceFeatures {
 
         features.set(F::DEPTH_CLIP_CONTROL, self.core.depth_clamp != 0);
         features.set(F::DUAL_SOURCE_BLENDING, self.core.dual_src_blend != 0);
+        features.set(F::CLIP_DISTANCES, self.core.shader_clip_distance != 0);
 
         if let Some(ref multiview) = self.multiview {
             features.set(F::MULTIVIEW, multiview.multiview != 0);
@@ -693,6 +714,21 @@ impl PhysicalDeviceFeatures {
                         .contains(vk::ShaderStageFlags::VERTEX),
                 );
                 features.insert(F::SUBGROUP_BARRIER);
+                features.set(
+                    F::SUBGROUP_QUAD,
+                    subgroup
+                        .supported_operations
+                        .contains(vk::SubgroupFeatureFlags::QUAD)
+                        && subgroup.supported_stages.contains(
+                            vk::ShaderStageFlags::COMPUTE | vk::ShaderStageFlags::FRAGMENT,
+                        ),
+                );
+                features.set(
+                    F::SUBGROUP_CLUSTERED,
+                    subgroup
+                        .supported_operations
+                        .contains(vk::SubgroupFeatureFlags::CLUSTERED),
+                );
             }
         }
 
@@ -958,6 +994,11 @@ impl PhysicalDeviceProperties {
             if requested_features.contains(wgt::Features::SUBGROUP) {
                 extensions.push(ext::subgroup_size_control::NAME);
             }
+
+            // Optional `VK_EXT_shader_demote_to_helper_invocation`
+            if self.supports_extension(ext::shader_demote_to_helper_invocation::NAME) {
+                extensions.push(ext::shader_demote_to_helper_invocation::NAME);
+            }
         }
 
         // Optional `VK_KHR_swapchain_mutable_format`
@@ -1338,6 +1379,16 @@ impl super::InstanceShared {
                 features2 = features2.push_next(next);
             }
 
+            // `VK_EXT_shader_demote_to_helper_invocation` is promoted to 1.3
+            if capabilities.device_api_version >= vk::API_VERSION_1_3
+                || capabilities.supports_extension(ext::shader_demote_to_helper_invocation::NAME)
+            {
+                let next = features
+                    .shader_demote_to_helper_invocation
+                    .insert(vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures::default());
+                features2 = features2.push_next(next);
+            }
+
             // `VK_EXT_subgroup_size_control` is promoted to 1.3
             if capabilities.device_api_version >= vk::API_VERSION_1_3
                 || capabilities.supports_extension(ext::subgroup_size_control::NAME)
@@ -1537,6 +1588,9 @@ impl super::Instance {
                 .map_or(false, |ext| {
                     ext.shader_zero_initialize_workgroup_memory == vk::TRUE
                 }),
+            shader_demote_to_helper_invocation: phd_features
+                .shader_demote_to_helper_invocation
+                .is_some_and(|ext| ext.shader_demote_to_helper_invocation == vk::TRUE),
             image_format_list: phd_capabilities.device_api_version >= vk::API_VERSION_1_2
                 || phd_capabilities.supports_extension(khr::image_format_list::NAME),
         };
@@ -1761,6 +1815,14 @@ impl super::Adapter {
                 capabilities.push(spv::Capability::GroupNonUniformShuffleRelative);
             }
 
+            if features.contains(wgt::Features::SUBGROUP_QUAD) {
+                capabilities.push(spv::Capability::GroupNonUniformQuad);
+            }
+
+            if features.contains(wgt::Features::SUBGROUP_CLUSTERED) {
+                capabilities.push(spv::Capability::GroupNonUniformClustered);
+            }
+
             if features.intersects(
                 wgt::Features::SAMPLED_TEXTURE_AND_STORAGE_BUFFER_ARRAY_NON_UNIFORM_INDEXING
                     | wgt::Features::UNIFORM_BUFFER_AND_STORAGE_TEXTURE_ARRAY_NON_UNIFORM_INDEXING,
@@ -1775,6 +1837,16 @@ impl super::Adapter {
                 capabilities.push(spv::Capability::RayQueryKHR);
             }
 
+            if self.private_caps.shader_demote_to_helper_invocation {
+                capabilities.push(spv::Capability::DemoteToHelperInvocation);
+            }
+
+            if features.contains(wgt::Features::SHADER_F16) {
+                capabilities.push(spv::Capability::Float16);
+                capabilities.push(spv::Capability::StorageBuffer16BitAccess);
+                capabilities.push(spv::Capability::UniformAndStorageBuffer16BitAccess);
+            }
+
             if features.contains(wgt::Features::SHADER_INT64) {
                 capabilities.push(spv::Capability::Int64);
             }
@@ -770,7 +770,11 @@ impl super::Device {
                             source_code: &debug.source_code,
                             file_name: debug.file_name.as_ref().as_ref(),
                             language: naga::back::spv::SourceLanguage::WGSL,
-                        })
+                        });
+                        if debug.non_semantic_debug_info {
+                            temp_options.flags |= naga::back::spv::WriterFlags::DEBUG
+                                | naga::back::spv::WriterFlags::NON_SEMANTIC_DEBUG_INFO;
+                        }
                     }
                     if !stage.zero_initialize_workgroup_memory {
                         temp_options.zero_initialize_workgroup_memory =
@@ -785,11 +789,15 @@ impl super::Device {
                 let (module, info) = naga::back::pipeline_constants::process_overrides(
                     &naga_shader.module,
                     &naga_shader.info,
+                    Some((naga_stage, stage.entry_point)),
                     stage.constants,
                 )
                 .map_err(|e| {
                     crate::PipelineError::PipelineConstants(stage_flags, format!("{e}"))
                 })?;
+                let (module, info) =
+                    naga::back::pointer_arguments::specialize_pointer_arguments(&module, &info)
+                        .map_err(|e| crate::PipelineError::Linkage(stage_flags, format!("{e}")))?;
 
                 let spv = {
                     profiling::scope!("naga::spv::write_vec");
@@ -1745,6 +1753,14 @@ impl crate::Device for super::Device {
                             file_name: d.file_name.as_ref().as_ref(),
                             language: naga::back::spv::SourceLanguage::WGSL,
                         });
+                if naga_shader
+                    .debug_source
+                    .as_ref()
+                    .is_some_and(|d| d.non_semantic_debug_info)
+                {
+                    naga_options.flags |= naga::back::spv::WriterFlags::DEBUG
+                        | naga::back::spv::WriterFlags::NON_SEMANTIC_DEBUG_INFO;
+                }
                 if !desc.runtime_checks {
                     naga_options.bounds_check_policies = naga::proc::BoundsCheckPolicies {
                         index: naga::proc::BoundsCheckPolicy::Unchecked,
@@ -531,6 +531,7 @@ struct PrivateCapabilities {
 
     robust_image_access2: bool,
     zero_initialize_workgroup_memory: bool,
+    shader_demote_to_helper_invocation: bool,
     image_format_list: bool,
 }
 
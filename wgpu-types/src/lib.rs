@@ -392,8 +392,6 @@ bitflags::bitflags! {
 
         /// Allows shaders to acquire the FP16 ability
         ///
-        /// Note: this is not supported in `naga` yet, only through `spirv-passthrough` right now.
-        ///
         /// Supported Platforms:
         /// - Vulkan
         /// - Metal
@@ -434,7 +432,21 @@ bitflags::bitflags! {
         /// This is a web and native feature.
         const FLOAT32_FILTERABLE = 1 << 11;
 
-        // Bits 12-19 available for webgpu features. Should you chose to use some of them for
+        /// Allows shaders to write to the `clip_distances` built-in, which defines up to 8
+        /// user clip planes.
+        ///
+        /// In WGSL, this requires the `enable clip_distances;` directive.
+        ///
+        /// Supported Platforms:
+        /// - Vulkan (with `shaderClipDistance`)
+        /// - DX12
+        /// - Metal
+        /// - OpenGL (Desktop 3.0+ or with `GL_EXT_clip_cull_distance` on GLES)
+        ///
+        /// This is a web and native feature.
+        const CLIP_DISTANCES = 1 << 12;
+
+        // Bits 13-19 available for webgpu features. Should you chose to use some of them for
         // for native features, don't forget to update `all_webgpu_mask` and `all_native_mask`
         // accordingly.
 
@@ -820,6 +832,16 @@ bitflags::bitflags! {
         ///
         /// This is a native only feature.
         const VERTEX_ATTRIBUTE_64BIT = 1 << 45;
+        /// Allows shaders to pass a constant cluster size to the subgroup
+        /// reduction built-ins, reducing over clusters of lanes separately.
+        ///
+        /// Requires [`Features::SUBGROUP`].
+        ///
+        /// Supported Platforms:
+        /// - Vulkan
+        ///
+        /// This is a native only feature.
+        const SUBGROUP_CLUSTERED = 1 << 46;
         /// Allows for creation of textures of format [`TextureFormat::NV12`]
         ///
         /// Supported platforms:
@@ -886,6 +908,9 @@ bitflags::bitflags! {
         /// Allows two outputs from a shader to be used for blending.
         /// Note that dual-source blending doesn't support multiple render targets.
         ///
+        /// In WGSL, this requires the `enable dual_source_blending;` directive. The two
+        /// outputs are marked `@location(0) @blend_src(0)` and `@location(0) @blend_src(1)`.
+        ///
         /// For more info see the OpenGL ES extension GL_EXT_blend_func_extended.
         ///
         /// Supported platforms:
@@ -969,6 +994,18 @@ bitflags::bitflags! {
         /// [VK_GOOGLE_display_timing]: https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_GOOGLE_display_timing.html
         /// [`Surface::as_hal()`]: https://docs.rs/wgpu/latest/wgpu/struct.Surface.html#method.as_hal
         const VULKAN_GOOGLE_DISPLAY_TIMING = 1 << 62;
+        /// Allows compute and fragment shaders to use the quad subgroup built-ins:
+        /// `quadBroadcast`, `quadSwapX`, `quadSwapY` and `quadSwapDiagonal`.
+        ///
+        /// Requires [`Features::SUBGROUP`].
+        ///
+        /// Supported Platforms:
+        /// - Vulkan
+        /// - DX12
+        /// - Metal
+        ///
+        /// This is a native only feature.
+        const SUBGROUP_QUAD = 1 << 63;
     }
 }
 
@@ -2362,10 +2399,16 @@ bitflags::bitflags! {
         /// to a render pass for an automatic driver-implemented resolve.
         const MULTISAMPLE_RESOLVE = 1 << 5;
         /// When used as a STORAGE texture, then a texture with this format can be bound with
-        /// [`StorageTextureAccess::ReadOnly`] or [`StorageTextureAccess::ReadWrite`].
+        /// [`StorageTextureAccess::ReadWrite`].
         const STORAGE_READ_WRITE = 1 << 6;
         /// If not present, the texture can't be blended into the render target.
         const BLENDABLE = 1 << 7;
+        /// When used as a STORAGE texture, then a texture with this format can be bound with
+        /// [`StorageTextureAccess::ReadOnly`].
+        const STORAGE_READ_ONLY = 1 << 8;
+        /// When used as a STORAGE texture, then a texture with this format can be bound with
+        /// [`StorageTextureAccess::WriteOnly`].
+        const STORAGE_WRITE_ONLY = 1 << 9;
     }
 }
 
@@ -3509,6 +3552,21 @@ impl TextureFormat {
         flags.set(TextureFormatFeatureFlags::FILTERABLE, is_filterable);
         flags.set(TextureFormatFeatureFlags::BLENDABLE, is_blendable);
 
+        // Storage texture access modes follow the tiers of the WebGPU spec: every
+        // storage format can be written to, all but `bgra8unorm` can be read from,
+        // and only the 32-bit single channel formats can be read from and written to.
+        if allowed_usages.contains(TextureUsages::STORAGE_BINDING) {
+            flags |= TextureFormatFeatureFlags::STORAGE_WRITE_ONLY;
+            flags.set(
+                TextureFormatFeatureFlags::STORAGE_READ_ONLY,
+                *self != Self::Bgra8Unorm,
+            );
+            flags.set(
+                TextureFormatFeatureFlags::STORAGE_READ_WRITE,
+                matches!(*self, Self::R32Uint | Self::R32Sint | Self::R32Float),
+            );
+        }
+
         TextureFormatFeatures {
             allowed_usages,
             flags,
@@ -6554,8 +6612,7 @@ pub enum StorageTextureAccess {
     /// The texture can only be read in the shader and it must be annotated with `read` (WGSL) or
     /// `readonly` (GLSL).
     ///
-    /// [`Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES`] must be enabled to use this access
-    /// mode. This is a native-only extension.
+    /// The format must support [`TextureFormatFeatureFlags::STORAGE_READ_ONLY`].
     ///
     /// Example WGSL syntax:
     /// ```rust,ignore
@@ -6571,8 +6628,9 @@ pub enum StorageTextureAccess {
     /// The texture can be both read and written in the shader and must be annotated with
     /// `read_write` in WGSL.
     ///
-    /// [`Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES`] must be enabled to use this access
-    /// mode.  This is a nonstandard, native-only extension.
+    /// The format must support [`TextureFormatFeatureFlags::STORAGE_READ_WRITE`]. Without
+    /// [`Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES`], this is only guaranteed for
+    /// [`TextureFormat::R32Uint`], [`TextureFormat::R32Sint`] and [`TextureFormat::R32Float`].
     ///
     /// Example WGSL syntax:
     /// ```rust,ignore
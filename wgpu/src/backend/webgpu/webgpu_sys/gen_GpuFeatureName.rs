@@ -44,4 +44,5 @@ pub enum GpuFeatureName {
     Rg11b10ufloatRenderable = "rg11b10ufloat-renderable",
     Bgra8unormStorage = "bgra8unorm-storage",
     Float32Filterable = "float32-filterable",
+    ClipDistances = "clip-distances",
 }
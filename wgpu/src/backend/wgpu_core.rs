@@ -792,6 +792,8 @@ impl crate::Context for ContextWgpuCore {
         let descriptor = wgc::pipeline::ShaderModuleDescriptor {
             label: desc.label.map(Borrowed),
             shader_bound_checks,
+            non_semantic_debug_info: false,
+            optimize: false,
         };
         let source = match desc.source {
             #[cfg(feature = "spirv")]
@@ -851,6 +853,8 @@ impl crate::Context for ContextWgpuCore {
             // Doesn't matter the value since spirv shaders aren't mutated to include
             // runtime checks
             shader_bound_checks: unsafe { wgt::ShaderBoundChecks::unchecked() },
+            non_semantic_debug_info: false,
+            optimize: false,
         };
         let (id, error) = unsafe {
             self.0.device_create_shader_module_spirv(